@@ -59,6 +59,9 @@ pub enum BatchError {
 
     #[error("Task aborted: {0}")]
     Aborted(String),
+
+    #[error("Out of memory for the query, the memory limit is {0} bytes")]
+    OutOfMemory(u64),
 }
 
 impl From<BatchError> for RwError {
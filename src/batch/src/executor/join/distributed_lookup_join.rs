@@ -209,6 +209,9 @@ impl BoxedExecutorBuilder for DistributedLookupJoinExecutorBuilder {
             } else {
                 None
             },
+            bloom_false_positive: None,
+            compression_algorithm: None,
+            compression_level: None,
         };
         let value_indices = table_desc
             .get_value_indices()
@@ -17,6 +17,7 @@ use std::iter::empty;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use futures::StreamExt;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
 use risingwave_common::array::{Array, DataChunk, RowRef};
@@ -33,6 +34,7 @@ use risingwave_expr::expr::{build_from_prost, BoxedExpression, Expression};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 
 use super::{ChunkedData, JoinType, RowId};
+use crate::error::BatchError;
 use crate::executor::{
     BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
 };
@@ -49,6 +51,10 @@ use crate::task::{BatchTaskContext, ShutdownToken};
 /// 3. Concatenate the matched pair of probe side row and build side row into a single row and push
 /// it into the data chunk builder.
 /// 4. Yield chunks from the builder.
+///
+/// If the build side turns out to be much larger than expected (exceeding the configured
+/// threshold) while the probe side is smaller, the two sides are swapped at runtime so that the
+/// hash table is still built on the smaller input.
 pub struct HashJoinExecutor<K> {
     /// Join type e.g. inner, left outer, ...
     join_type: JoinType,
@@ -73,6 +79,9 @@ pub struct HashJoinExecutor<K> {
     null_matched: Vec<bool>,
     identity: String,
     chunk_size: usize,
+    /// The number of rows the build side can exceed before the executor considers swapping the
+    /// two sides at runtime, so that the hash table is built on the smaller input.
+    swap_build_threshold: usize,
 
     shutdown_rx: ShutdownToken,
 
@@ -220,24 +229,146 @@ struct RightNonEquiJoinState {
     build_row_matched: ChunkedData<bool>,
 }
 
+/// An executor that yields some already materialized chunks before the rest of an underlying
+/// stream, used to replay the input consumed while deciding whether to swap the two sides.
+struct BufferedStreamExecutor {
+    schema: Schema,
+    identity: String,
+    buffered: Vec<DataChunk, MonitoredGlobalAlloc>,
+    stream: BoxedDataChunkStream,
+}
+
+impl Executor for BufferedStreamExecutor {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl BufferedStreamExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        for chunk in self.buffered {
+            yield chunk;
+        }
+        #[for_await]
+        for chunk in self.stream {
+            yield chunk?;
+        }
+    }
+}
+
 impl<K: HashKey> HashJoinExecutor<K> {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(self: Box<Self>) {
-        let probe_data_types = self.probe_side_source.schema().data_types();
-        let build_data_types = self.build_side_source.schema().data_types();
-        let full_data_types = [probe_data_types.clone(), build_data_types.clone()].concat();
+        let mut join_type = self.join_type;
+        let mut output_indices = self.output_indices;
+        let mut probe_key_idxs = self.probe_key_idxs;
+        let mut build_key_idxs = self.build_key_idxs;
+        let mut probe_data_types = self.probe_side_source.schema().data_types();
+        let mut build_data_types = self.build_side_source.schema().data_types();
+        let build_side_schema = self.build_side_source.schema().clone();
 
         let mut build_side = Vec::new_in(self.mem_ctx.global_allocator());
         let mut build_row_count = 0;
-        #[for_await]
-        for build_chunk in self.build_side_source.execute() {
+        let mut build_stream = self.build_side_source.execute();
+        while let Some(build_chunk) = build_stream.next().await {
             let build_chunk = build_chunk?;
             if build_chunk.cardinality() > 0 {
                 build_row_count += build_chunk.cardinality();
-                self.mem_ctx.add(build_chunk.estimated_heap_size() as i64);
+                if !self.mem_ctx.try_add(build_chunk.estimated_heap_size() as i64) {
+                    Err(BatchError::OutOfMemory(self.mem_ctx.mem_limit()))?;
+                }
                 build_side.push(build_chunk);
             }
+            // If the build side turns out to be much larger than expected, stop materializing it
+            // and examine the probe side, so that the hash table can be built on the smaller
+            // input instead. The swap is not possible with a non-equi condition, whose input
+            // references cannot be rewritten at runtime.
+            if self.cond.is_none() && build_row_count > self.swap_build_threshold {
+                break;
+            }
+        }
+
+        let mut probe_side = self.probe_side_source;
+        if self.cond.is_none() && build_row_count > self.swap_build_threshold {
+            // Buffer the probe side until it's exhausted or contains no fewer rows than what has
+            // been materialized of the build side.
+            let probe_side_schema = probe_side.schema().clone();
+            let mut probe_chunks = Vec::new_in(self.mem_ctx.global_allocator());
+            let mut probe_row_count = 0;
+            let mut probe_stream = probe_side.execute();
+            let mut probe_side_smaller = true;
+            while let Some(probe_chunk) = probe_stream.next().await {
+                let probe_chunk = probe_chunk?;
+                if probe_chunk.cardinality() > 0 {
+                    probe_row_count += probe_chunk.cardinality();
+                    if !self.mem_ctx.try_add(probe_chunk.estimated_heap_size() as i64) {
+                        Err(BatchError::OutOfMemory(self.mem_ctx.mem_limit()))?;
+                    }
+                    probe_chunks.push(probe_chunk);
+                }
+                if probe_row_count >= build_row_count {
+                    probe_side_smaller = false;
+                    break;
+                }
+            }
+            if probe_side_smaller {
+                // Swap the roles of the two sides. The buffered probe chunks become the new
+                // build side, while the materialized part of the original build side, followed
+                // by the rest of its stream, is replayed as the new probe side.
+                if join_type.keep_all() {
+                    // The full row layout becomes [build, probe], so remap the output indices to
+                    // keep the output schema unchanged.
+                    for idx in &mut output_indices {
+                        *idx = if *idx < probe_data_types.len() {
+                            *idx + build_data_types.len()
+                        } else {
+                            *idx - probe_data_types.len()
+                        };
+                    }
+                }
+                join_type = join_type.swapped();
+                std::mem::swap(&mut probe_key_idxs, &mut build_key_idxs);
+                std::mem::swap(&mut probe_data_types, &mut build_data_types);
+                let buffered = std::mem::replace(&mut build_side, probe_chunks);
+                build_row_count = probe_row_count;
+                probe_side = Box::new(BufferedStreamExecutor {
+                    schema: build_side_schema,
+                    identity: self.identity.clone(),
+                    buffered,
+                    stream: build_stream,
+                });
+            } else {
+                // The probe side is no smaller, so keep the original roles and materialize the
+                // rest of the build side.
+                while let Some(build_chunk) = build_stream.next().await {
+                    let build_chunk = build_chunk?;
+                    if build_chunk.cardinality() > 0 {
+                        build_row_count += build_chunk.cardinality();
+                        if !self.mem_ctx.try_add(build_chunk.estimated_heap_size() as i64) {
+                            Err(BatchError::OutOfMemory(self.mem_ctx.mem_limit()))?;
+                        }
+                        build_side.push(build_chunk);
+                    }
+                }
+                probe_side = Box::new(BufferedStreamExecutor {
+                    schema: probe_side_schema,
+                    identity: self.identity.clone(),
+                    buffered: probe_chunks,
+                    stream: probe_stream,
+                });
+            }
         }
+        let full_data_types = [probe_data_types.clone(), build_data_types.clone()].concat();
+
         let mut hash_map = JoinHashMap::with_capacity_and_hasher_in(
             build_row_count,
             PrecomputedBuildHasher,
@@ -250,7 +381,7 @@ impl<K: HashKey> HashJoinExecutor<K> {
 
         // Build hash map
         for (build_chunk_id, build_chunk) in build_side.iter().enumerate() {
-            let build_keys = K::build(&self.build_key_idxs, build_chunk)?;
+            let build_keys = K::build(&build_key_idxs, build_chunk)?;
 
             for (build_row_id, (build_key, visible)) in build_keys
                 .into_iter()
@@ -264,16 +395,18 @@ impl<K: HashKey> HashJoinExecutor<K> {
                 // Only insert key to hash map if it is consistent with the null safe restriction.
                 if build_key.null_bitmap().is_subset(&null_matched) {
                     let row_id = RowId::new(build_chunk_id, build_row_id);
-                    self.mem_ctx.add(build_key.estimated_heap_size() as i64);
+                    if !self.mem_ctx.try_add(build_key.estimated_heap_size() as i64) {
+                        Err(BatchError::OutOfMemory(self.mem_ctx.mem_limit()))?;
+                    }
                     next_build_row_with_same_key[row_id] = hash_map.insert(build_key, row_id);
                 }
             }
         }
 
         let params = EquiJoinParams::new(
-            self.probe_side_source,
+            probe_side,
             probe_data_types,
-            self.probe_key_idxs,
+            probe_key_idxs,
             build_side,
             build_data_types,
             full_data_types,
@@ -284,7 +417,7 @@ impl<K: HashKey> HashJoinExecutor<K> {
         );
 
         if let Some(cond) = self.cond.as_ref() {
-            let stream = match self.join_type {
+            let stream = match join_type {
                 JoinType::Inner => Self::do_inner_join_with_non_equi_condition(params, cond),
                 JoinType::LeftOuter => {
                     Self::do_left_outer_join_with_non_equi_condition(params, cond)
@@ -310,7 +443,7 @@ impl<K: HashKey> HashJoinExecutor<K> {
             #[for_await]
             for chunk in stream {
                 for output_chunk in
-                    output_chunk_builder.append_chunk(chunk?.project(&self.output_indices))
+                    output_chunk_builder.append_chunk(chunk?.project(&output_indices))
                 {
                     yield output_chunk
                 }
@@ -319,7 +452,7 @@ impl<K: HashKey> HashJoinExecutor<K> {
                 yield output_chunk
             }
         } else {
-            let stream = match self.join_type {
+            let stream = match join_type {
                 JoinType::Inner => Self::do_inner_join(params),
                 JoinType::LeftOuter => Self::do_left_outer_join(params),
                 JoinType::LeftSemi => Self::do_left_semi_anti_join::<false>(params),
@@ -331,7 +464,7 @@ impl<K: HashKey> HashJoinExecutor<K> {
             };
             #[for_await]
             for chunk in stream {
-                yield chunk?.project(&self.output_indices)
+                yield chunk?.project(&output_indices)
             }
         }
     }
@@ -1826,6 +1959,11 @@ impl BoxedExecutorBuilder for HashJoinExecutor<()> {
             identity: identity.clone(),
             right_key_types,
             chunk_size: context.context.get_config().developer.chunk_size,
+            swap_build_threshold: context
+                .context
+                .get_config()
+                .developer
+                .hash_join_swap_build_threshold,
             shutdown_rx: context.shutdown_rx.clone(),
             mem_ctx: context.context.create_executor_mem_context(&identity),
         }
@@ -1845,6 +1983,7 @@ struct HashJoinExecutorArgs {
     identity: String,
     right_key_types: Vec<DataType>,
     chunk_size: usize,
+    swap_build_threshold: usize,
     shutdown_rx: ShutdownToken,
     mem_ctx: MemoryContext,
 }
@@ -1864,6 +2003,7 @@ impl HashKeyDispatcher for HashJoinExecutorArgs {
             self.cond,
             self.identity,
             self.chunk_size,
+            self.swap_build_threshold,
             self.shutdown_rx,
             self.mem_ctx,
         ))
@@ -1887,6 +2027,7 @@ impl<K> HashJoinExecutor<K> {
         cond: Option<BoxedExpression>,
         identity: String,
         chunk_size: usize,
+        swap_build_threshold: usize,
         shutdown_rx: ShutdownToken,
         mem_ctx: MemoryContext,
     ) -> Self {
@@ -1922,6 +2063,7 @@ impl<K> HashJoinExecutor<K> {
             cond,
             identity,
             chunk_size,
+            swap_build_threshold,
             shutdown_rx,
             mem_ctx,
             _phantom: PhantomData,
@@ -2011,6 +2153,7 @@ mod tests {
         left_types: Vec<DataType>,
         right_types: Vec<DataType>,
         join_type: JoinType,
+        swap_build_threshold: usize,
     }
 
     /// Sql for creating test data:
@@ -2035,6 +2178,14 @@ mod tests {
                 left_types: vec![DataType::Int32, DataType::Float32],
                 right_types: vec![DataType::Int32, DataType::Float64],
                 join_type,
+                swap_build_threshold: usize::MAX,
+            }
+        }
+
+        fn with_swap_build_threshold(join_type: JoinType, swap_build_threshold: usize) -> Self {
+            Self {
+                swap_build_threshold,
+                ..Self::with_join_type(join_type)
             }
         }
 
@@ -2169,6 +2320,7 @@ mod tests {
                 cond,
                 "HashJoinExecutor".to_string(),
                 chunk_size,
+                self.swap_build_threshold,
                 shutdown_rx,
                 mem_ctx,
             ))
@@ -2779,6 +2931,87 @@ mod tests {
         test_fixture.do_test(expected_chunk, true, false).await;
     }
 
+    /// The build side (right table, 18 rows) exceeds the threshold while the probe side (left
+    /// table, 10 rows) is smaller, so the two sides are swapped at runtime and the rows are
+    /// produced in the order of the right table.
+    #[tokio::test]
+    async fn test_inner_join_with_swapped_build_side() {
+        let test_fixture = TestFixture::with_swap_build_threshold(JoinType::Inner, 6);
+
+        let expected_chunk = DataChunk::from_pretty(
+            "i   f   i   F
+             2   .   2   .
+             3   .   3   .
+             3   3.9 3   .
+             4   6.6 4   7.5
+             3   .   3   3.7
+             3   3.9 3   3.7",
+        );
+
+        test_fixture.do_test(expected_chunk, false, false).await;
+    }
+
+    /// The build side exceeds the threshold but the probe side is found to be no smaller before
+    /// it's exhausted, so the original roles are kept and the buffered probe chunks are replayed.
+    #[tokio::test]
+    async fn test_inner_join_with_swap_not_beneficial() {
+        let test_fixture = TestFixture::with_swap_build_threshold(JoinType::Inner, 5);
+
+        let expected_chunk = DataChunk::from_pretty(
+            "i   f   i   F
+             2   .   2   .
+             3   3.9 3   3.7
+             3   3.9 3   .
+             4   6.6 4   7.5
+             3   .   3   3.7
+             3   .   3   .",
+        );
+
+        test_fixture.do_test(expected_chunk, false, false).await;
+    }
+
+    /// A left outer join executed as a right outer join after the swap: matched rows come in the
+    /// order of the right table, followed by the unmatched left rows padded with NULLs.
+    #[tokio::test]
+    async fn test_left_outer_join_with_swapped_build_side() {
+        let test_fixture = TestFixture::with_swap_build_threshold(JoinType::LeftOuter, 6);
+
+        let expected_chunk = DataChunk::from_pretty(
+            "i   f   i   F
+             2   .   2   .
+             3   .   3   .
+             3   3.9 3   .
+             4   6.6 4   7.5
+             3   .   3   3.7
+             3   3.9 3   3.7
+             1   6.1 .   .
+             .   8.4 .   .
+             .   .   .   .
+             .   0.7 .   .
+             5   .   .   .
+             .   5.5 .   .",
+        );
+
+        test_fixture.do_test(expected_chunk, false, false).await;
+    }
+
+    /// A left semi join executed as a right semi join after the swap. The output happens to be in
+    /// the same order as without the swap, since both follow the order of the left table.
+    #[tokio::test]
+    async fn test_left_semi_join_with_swapped_build_side() {
+        let test_fixture = TestFixture::with_swap_build_threshold(JoinType::LeftSemi, 6);
+
+        let expected_chunk = DataChunk::from_pretty(
+            "i   f
+             2   .
+             3   3.9
+             4   6.6
+             3   .",
+        );
+
+        test_fixture.do_test(expected_chunk, false, false).await;
+    }
+
     #[tokio::test]
     async fn test_process_left_outer_join_non_equi_condition() {
         let chunk = DataChunk::from_pretty(
@@ -103,6 +103,21 @@ impl JoinType {
     fn keep_right(self) -> bool {
         matches!(self, JoinType::RightAnti | JoinType::RightSemi)
     }
+
+    /// The join type that produces the same result after the probe side and the build side are
+    /// swapped.
+    fn swapped(self) -> Self {
+        match self {
+            JoinType::Inner => JoinType::Inner,
+            JoinType::LeftOuter => JoinType::RightOuter,
+            JoinType::LeftSemi => JoinType::RightSemi,
+            JoinType::LeftAnti => JoinType::RightAnti,
+            JoinType::RightOuter => JoinType::LeftOuter,
+            JoinType::RightSemi => JoinType::LeftSemi,
+            JoinType::RightAnti => JoinType::LeftAnti,
+            JoinType::FullOuter => JoinType::FullOuter,
+        }
+    }
 }
 
 /// The layout be like:
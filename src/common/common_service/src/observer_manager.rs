@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::iter;
 use std::time::Duration;
 
 use risingwave_common::bail;
@@ -62,6 +63,9 @@ pub struct ObserverManager<T: NotificationClient, S: ObserverState> {
     rx: T::Channel,
     client: T,
     observer_states: S,
+    /// The version of the latest notification received on the current subscription, compared
+    /// against heartbeats from meta to detect missed notifications.
+    last_version: u64,
 }
 
 pub trait ObserverState: Send + 'static {
@@ -94,6 +98,7 @@ where
             rx,
             client,
             observer_states,
+            last_version: 0,
         }
     }
 
@@ -122,6 +127,15 @@ where
             unreachable!();
         };
 
+        // Notifications dropped below are still covered by the snapshot, so they count towards
+        // the latest version received on this subscription.
+        self.last_version = notification_vec
+            .iter()
+            .map(|notification| notification.version)
+            .chain(iter::once(init_notification.version))
+            .max()
+            .unwrap();
+
         notification_vec.retain_mut(|notification| match notification.info.as_ref().unwrap() {
             Info::Database(_)
             | Info::Schema(_)
@@ -146,6 +160,7 @@ where
             Info::ServingParallelUnitMappings(_) => true,
             Info::Snapshot(_) | Info::HummockWriteLimits(_) => unreachable!(),
             Info::HummockStats(_) => true,
+            Info::Heartbeat(_) => false,
         });
 
         self.observer_states
@@ -170,12 +185,28 @@ where
             loop {
                 match self.rx.message().await {
                     Ok(resp) => {
-                        if resp.is_none() {
+                        let Some(resp) = resp else {
                             tracing::error!("Stream of notification terminated.");
                             self.re_subscribe().await;
                             continue;
+                        };
+                        if let Some(Info::Heartbeat(heartbeat)) = resp.info.as_ref() {
+                            // The heartbeat carries the version of the latest notification sent
+                            // to this subscriber type. Seeing a newer version than what we have
+                            // received means some notifications were missed, e.g. due to meta
+                            // failover, so refresh the local state with a new snapshot.
+                            if heartbeat.last_sent_version > self.last_version {
+                                tracing::warn!(
+                                    "notifications missed: local version {}, latest sent version {}",
+                                    self.last_version,
+                                    heartbeat.last_sent_version,
+                                );
+                                self.re_subscribe().await;
+                            }
+                            continue;
                         }
-                        self.observer_states.handle_notification(resp.unwrap());
+                        self.last_version = self.last_version.max(resp.version);
+                        self.observer_states.handle_notification(resp);
                     }
                     Err(e) => {
                         tracing::error!("Receives meta's notification err {:?}", e);
@@ -255,3 +286,149 @@ impl NotificationClient for RpcNotificationClient {
             .map_err(RpcError::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use risingwave_pb::meta::subscribe_response::Operation;
+    use risingwave_pb::meta::{MetaSnapshot, NotificationHeartbeat};
+    use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+    use super::*;
+
+    struct TestChannel(UnboundedReceiver<SubscribeResponse>);
+
+    #[async_trait::async_trait]
+    impl Channel for TestChannel {
+        type Item = SubscribeResponse;
+
+        async fn message(&mut self) -> std::result::Result<Option<SubscribeResponse>, Status> {
+            Ok(self.0.recv().await)
+        }
+    }
+
+    type SharedTx = Arc<Mutex<Option<UnboundedSender<SubscribeResponse>>>>;
+
+    /// A notification client that lets the test send notifications manually, so that some of
+    /// them can be dropped on purpose.
+    struct TestNotificationClient {
+        subscribe_count: Arc<AtomicUsize>,
+        snapshot_version: Arc<AtomicU64>,
+        tx: SharedTx,
+    }
+
+    #[async_trait::async_trait]
+    impl NotificationClient for TestNotificationClient {
+        type Channel = TestChannel;
+
+        async fn subscribe(&self, _subscribe_type: SubscribeType) -> Result<Self::Channel> {
+            self.subscribe_count.fetch_add(1, Ordering::SeqCst);
+            let (tx, rx) = unbounded_channel();
+            // Send the initial snapshot like the meta service does on subscription.
+            tx.send(SubscribeResponse {
+                status: None,
+                operation: Operation::Snapshot as i32,
+                version: self.snapshot_version.load(Ordering::SeqCst),
+                info: Some(Info::Snapshot(MetaSnapshot::default())),
+            })
+            .unwrap();
+            *self.tx.lock().unwrap() = Some(tx);
+            Ok(TestChannel(rx))
+        }
+    }
+
+    #[derive(Default)]
+    struct TestObserverState {
+        snapshot_count: Arc<AtomicUsize>,
+        versions: Arc<Mutex<Vec<u64>>>,
+    }
+
+    impl ObserverState for TestObserverState {
+        type SubscribeType = SubscribeFrontend;
+
+        fn handle_notification(&mut self, resp: SubscribeResponse) {
+            self.versions.lock().unwrap().push(resp.version);
+        }
+
+        fn handle_initialization_notification(&mut self, resp: SubscribeResponse) {
+            assert!(matches!(resp.info, Some(Info::Snapshot(_))));
+            self.snapshot_count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn notification(version: u64) -> SubscribeResponse {
+        SubscribeResponse {
+            status: None,
+            operation: Operation::Update as i32,
+            version,
+            info: Some(Info::HummockStats(Default::default())),
+        }
+    }
+
+    fn heartbeat(last_sent_version: u64) -> SubscribeResponse {
+        SubscribeResponse {
+            status: None,
+            operation: Operation::Unspecified as i32,
+            version: 0,
+            info: Some(Info::Heartbeat(NotificationHeartbeat { last_sent_version })),
+        }
+    }
+
+    async fn wait_until(f: impl Fn() -> bool) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !f() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timeout while waiting for the observer to catch up");
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_detects_dropped_notifications() {
+        let client = TestNotificationClient {
+            subscribe_count: Arc::new(AtomicUsize::new(0)),
+            snapshot_version: Arc::new(AtomicU64::new(10)),
+            tx: Arc::new(Mutex::new(None)),
+        };
+        let state = TestObserverState::default();
+
+        let subscribe_count = client.subscribe_count.clone();
+        let snapshot_version = client.snapshot_version.clone();
+        let tx = client.tx.clone();
+        let snapshot_count = state.snapshot_count.clone();
+        let versions = state.versions.clone();
+
+        let observer_manager = ObserverManager::new(client, state).await;
+        let _handle = observer_manager.start().await;
+        wait_until(|| snapshot_count.load(Ordering::SeqCst) == 1).await;
+
+        let send =
+            |resp: SubscribeResponse| tx.lock().unwrap().as_ref().unwrap().send(resp).unwrap();
+
+        // Notifications received in order don't trigger a re-subscription, no matter whether
+        // heartbeats are interleaved.
+        send(notification(11));
+        send(heartbeat(11));
+        send(notification(12));
+        wait_until(|| versions.lock().unwrap().len() == 2).await;
+        assert_eq!(subscribe_count.load(Ordering::SeqCst), 1);
+
+        // Simulate dropping notifications 13 and 14: the next heartbeat reveals the gap and the
+        // observer re-subscribes to fetch a fresh snapshot.
+        snapshot_version.store(14, Ordering::SeqCst);
+        send(heartbeat(14));
+        wait_until(|| snapshot_count.load(Ordering::SeqCst) == 2).await;
+        assert_eq!(subscribe_count.load(Ordering::SeqCst), 2);
+
+        // The new subscription works as usual, and the heartbeat matching the snapshot version
+        // doesn't trigger another re-subscription.
+        send(heartbeat(14));
+        send(notification(15));
+        wait_until(|| versions.lock().unwrap().len() == 3).await;
+        assert_eq!(*versions.lock().unwrap(), vec![11, 12, 15]);
+        assert_eq!(subscribe_count.load(Ordering::SeqCst), 2);
+    }
+}
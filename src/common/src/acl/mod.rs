@@ -69,6 +69,7 @@ impl From<PbAction> for AclMode {
             PbAction::Create => AclMode::Create,
             PbAction::Connect => AclMode::Connect,
             PbAction::Usage => AclMode::Usage,
+            PbAction::Execute => AclMode::Execute,
         }
     }
 }
@@ -83,6 +84,7 @@ impl From<AclMode> for PbAction {
             AclMode::Create => PbAction::Create,
             AclMode::Connect => PbAction::Connect,
             AclMode::Usage => PbAction::Usage,
+            AclMode::Execute => PbAction::Execute,
             _ => unreachable!(),
         }
     }
@@ -103,7 +105,7 @@ pub static ALL_AVAILABLE_TABLE_MODES: LazyLock<AclModeSet> =
 pub static ALL_AVAILABLE_SOURCE_MODES: LazyLock<AclModeSet> = LazyLock::new(AclModeSet::readonly);
 pub static ALL_AVAILABLE_MVIEW_MODES: LazyLock<AclModeSet> = LazyLock::new(AclModeSet::readonly);
 pub static ALL_AVAILABLE_VIEW_MODES: LazyLock<AclModeSet> = LazyLock::new(AclModeSet::readonly);
-pub static ALL_AVAILABLE_SINK_MODES: LazyLock<AclModeSet> = LazyLock::new(AclModeSet::empty);
+pub static ALL_AVAILABLE_SINK_MODES: LazyLock<AclModeSet> = LazyLock::new(AclModeSet::readonly);
 pub static ALL_AVAILABLE_FUNCTION_MODES: LazyLock<AclModeSet> =
     LazyLock::new(|| BitFlags::from(AclMode::Execute).into());
 pub static ALL_AVAILABLE_CONNECTION_MODES: LazyLock<AclModeSet> =
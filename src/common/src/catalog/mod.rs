@@ -81,6 +81,12 @@ pub const DEFAULT_KEY_COLUMN_NAME: &str = "_rw_key";
 /// [this rfc](https://github.com/risingwavelabs/rfcs/pull/20).
 pub const KAFKA_TIMESTAMP_COLUMN_NAME: &str = "_rw_kafka_timestamp";
 
+/// For iceberg source, we attach hidden columns [`ICEBERG_SNAPSHOT_ID_COLUMN_NAME`] of type
+/// [`DataType::Int64`] and [`ICEBERG_FILE_PATH_COLUMN_NAME`] of type [`DataType::Varchar`] to
+/// it, recording the snapshot and the data file each row was read from.
+pub const ICEBERG_SNAPSHOT_ID_COLUMN_NAME: &str = "_rw_iceberg_snapshot_id";
+pub const ICEBERG_FILE_PATH_COLUMN_NAME: &str = "_rw_iceberg_file_path";
+
 pub fn is_system_schema(schema_name: &str) -> bool {
     SYSTEM_SCHEMAS.iter().any(|s| *s == schema_name)
 }
@@ -282,6 +288,12 @@ impl From<TableId> for u32 {
 #[derive(Clone, Debug, PartialEq, Default, Copy)]
 pub struct TableOption {
     pub retention_seconds: Option<u32>, // second
+    pub bloom_false_positive: Option<f64>,
+    /// SST block compression codec applied during compaction, encoded as in
+    /// `CompactTask::compression_algorithm`: 0 none, 1 lz4, 2 zstd.
+    pub compression_algorithm: Option<u32>,
+    /// Compression level for the codec above, e.g. `1`-`22` for zstd.
+    pub compression_level: Option<u32>,
 }
 
 impl From<&risingwave_pb::hummock::TableOption> for TableOption {
@@ -292,8 +304,29 @@ impl From<&risingwave_pb::hummock::TableOption> for TableOption {
             } else {
                 Some(table_option.retention_seconds)
             };
+        let bloom_false_positive = if table_option.bloom_false_positive == 0.0 {
+            None
+        } else {
+            Some(table_option.bloom_false_positive)
+        };
+        // The codec is shifted by one in the pb representation so that 0 means unset.
+        let compression_algorithm = if table_option.compression_algorithm == 0 {
+            None
+        } else {
+            Some(table_option.compression_algorithm - 1)
+        };
+        let compression_level = if table_option.compression_level == 0 {
+            None
+        } else {
+            Some(table_option.compression_level)
+        };
 
-        Self { retention_seconds }
+        Self {
+            retention_seconds,
+            bloom_false_positive,
+            compression_algorithm,
+            compression_level,
+        }
     }
 }
 
@@ -303,13 +336,18 @@ impl From<&TableOption> for risingwave_pb::hummock::TableOption {
             retention_seconds: table_option
                 .retention_seconds
                 .unwrap_or(hummock::TABLE_OPTION_DUMMY_RETENTION_SECOND),
+            bloom_false_positive: table_option.bloom_false_positive.unwrap_or(0.0),
+            compression_algorithm: table_option
+                .compression_algorithm
+                .map(|algorithm| algorithm + 1)
+                .unwrap_or(0),
+            compression_level: table_option.compression_level.unwrap_or(0),
         }
     }
 }
 
 impl TableOption {
     pub fn build_table_option(table_properties: &HashMap<String, String>) -> Self {
-        // now we only support ttl for TableOption
         let mut result = TableOption::default();
         if let Some(ttl_string) = table_properties.get(hummock::PROPERTIES_RETENTION_SECOND_KEY) {
             match ttl_string.trim().parse::<u32>() {
@@ -324,6 +362,48 @@ impl TableOption {
                 }
             };
         }
+        if let Some(fpr_string) = table_properties.get(hummock::PROPERTIES_BLOOM_FALSE_POSITIVE_KEY)
+        {
+            match fpr_string.trim().parse::<f64>() {
+                Ok(fpr) if fpr > 0.0 && fpr < 1.0 => result.bloom_false_positive = Some(fpr),
+                _ => {
+                    tracing::info!(
+                        "build_table_option ignore invalid bloom_false_positive {}",
+                        fpr_string
+                    );
+                    result.bloom_false_positive = None;
+                }
+            };
+        }
+        if let Some(algorithm_string) =
+            table_properties.get(hummock::PROPERTIES_COMPRESSION_ALGORITHM_KEY)
+        {
+            match algorithm_string.trim().to_lowercase().as_str() {
+                "none" => result.compression_algorithm = Some(0),
+                "lz4" => result.compression_algorithm = Some(1),
+                "zstd" => result.compression_algorithm = Some(2),
+                _ => {
+                    tracing::info!(
+                        "build_table_option ignore invalid compression_algorithm {}",
+                        algorithm_string
+                    );
+                    result.compression_algorithm = None;
+                }
+            };
+        }
+        if let Some(level_string) = table_properties.get(hummock::PROPERTIES_COMPRESSION_LEVEL_KEY)
+        {
+            match level_string.trim().parse::<u32>() {
+                Ok(level) if level > 0 => result.compression_level = Some(level),
+                _ => {
+                    tracing::info!(
+                        "build_table_option ignore invalid compression_level {}",
+                        level_string
+                    );
+                    result.compression_level = None;
+                }
+            };
+        }
 
         result
     }
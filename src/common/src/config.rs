@@ -159,6 +159,7 @@ pub enum MetaBackend {
     #[default]
     Mem,
     Etcd,
+    Sql,
 }
 
 /// The section `[meta]` in `risingwave.toml`.
@@ -212,6 +213,46 @@ pub struct MetaConfig {
     #[serde(default)]
     pub enable_scale_in_when_recovery: bool,
 
+    /// Whether to first try to recover only the actors on the failed workers when a barrier
+    /// collection fails, falling back to full recovery if that is not possible.
+    #[serde(default)]
+    pub enable_partial_recovery: bool,
+
+    /// The maximum time a barrier may stay in the collection phase before the workers that
+    /// have not collected it are reported with diagnostics, in seconds. 0 disables the check.
+    #[serde(default)]
+    pub checkpoint_alignment_timeout_sec: u64,
+
+    /// Whether exceeding `checkpoint_alignment_timeout_sec` also aborts the collection so
+    /// that the stuck workers go through recovery, instead of only logging diagnostics.
+    #[serde(default)]
+    pub recover_stuck_barrier: bool,
+
+    /// The maximum number of attempts for an RPC to a worker node, including the first one.
+    /// Only connection-level failures are retried.
+    #[serde(default = "default::meta::worker_rpc_retry_max_attempts")]
+    pub worker_rpc_retry_max_attempts: usize,
+
+    /// Base interval of the exponential backoff between two attempts of an RPC to a worker
+    /// node, in milliseconds.
+    #[serde(default = "default::meta::worker_rpc_retry_base_interval_ms")]
+    pub worker_rpc_retry_base_interval_ms: u64,
+
+    /// The number of consecutive connection failures to a worker node after which RPCs to it
+    /// fail fast instead of going through the full retry backoff each time.
+    #[serde(default = "default::meta::worker_rpc_circuit_break_threshold")]
+    pub worker_rpc_circuit_break_threshold: usize,
+
+    /// How long RPCs to a worker node keep failing fast after its circuit opened, in
+    /// milliseconds.
+    #[serde(default = "default::meta::worker_rpc_circuit_break_cooldown_ms")]
+    pub worker_rpc_circuit_break_cooldown_ms: u64,
+
+    /// Whether to spread the actors of each fragment across failure domains (currently the
+    /// hosts of compute nodes) when scheduling streaming jobs.
+    #[serde(default)]
+    pub enable_failure_domain_spread: bool,
+
     #[serde(default = "default::meta::meta_leader_lease_secs")]
     pub meta_leader_lease_secs: u64,
 
@@ -226,6 +267,11 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::default_parallelism")]
     pub default_parallelism: DefaultParallelism,
 
+    /// The maximum number of parallel units used to serve a fragment's batch reads on the
+    /// serving compute nodes. Unlimited if not specified.
+    #[serde(default)]
+    pub max_serving_parallelism: Option<u64>,
+
     /// Whether to enable deterministic compaction scheduling, which
     /// will disable all auto scheduling of compaction tasks.
     /// Should only be used in e2e tests.
@@ -236,9 +282,30 @@ pub struct MetaConfig {
     #[serde(default)]
     pub enable_committed_sst_sanity_check: bool,
 
+    /// Perform a canary read of each newly committed SST object before the commit becomes
+    /// visible, to catch object store write corruption before downstream sinks acknowledge
+    /// the epoch to external systems.
+    #[serde(default)]
+    pub enable_canary_checkpoint_validation: bool,
+
     #[serde(default = "default::meta::node_num_monitor_interval_sec")]
     pub node_num_monitor_interval_sec: u64,
 
+    /// Webhook to POST alerts fired by the built-in alerting rules to. Alerting is disabled if
+    /// unset.
+    #[serde(default)]
+    pub alert_webhook_url: Option<String>,
+
+    /// Interval of evaluating the built-in alerting rules.
+    #[serde(default = "default::meta::alert_interval_sec")]
+    pub alert_interval_sec: u64,
+
+    /// User-defined alerting rules, evaluated together with the built-in ones. Each rule
+    /// fires when `<signal> <op> <threshold>` holds, e.g.
+    /// `{ name = "too_few_compute_nodes", signal = "running_compute_nodes", op = "<", threshold = 2 }`.
+    #[serde(default)]
+    pub alert_rules: Vec<AlertRule>,
+
     #[serde(default = "default::meta::backend")]
     pub backend: MetaBackend,
 
@@ -289,6 +356,47 @@ pub struct MetaConfig {
     pub compaction_config: CompactionConfig,
 }
 
+/// A user-defined alerting rule, see [`MetaConfig::alert_rules`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AlertRule {
+    /// Stable identifier of the rule, reported with the fired alert.
+    pub name: String,
+    /// The cluster signal the rule observes.
+    pub signal: AlertSignal,
+    /// How the signal is compared against `threshold`.
+    pub op: AlertCompareOp,
+    pub threshold: u64,
+}
+
+/// Cluster signals that user-defined alerting rules can observe.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSignal {
+    /// Number of compute nodes in the `Running` state.
+    RunningComputeNodes,
+    /// Number of compute nodes that have registered but not become running yet.
+    StartingComputeNodes,
+    /// Number of compactor nodes in the `Running` state.
+    RunningCompactors,
+    /// Number of frontend nodes in the `Running` state.
+    RunningFrontends,
+}
+
+/// Comparison operator of a user-defined alerting rule.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AlertCompareOp {
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+    #[serde(rename = "==")]
+    Eq,
+}
+
 #[derive(Clone, Debug, Default)]
 pub enum DefaultParallelism {
     #[default]
@@ -406,6 +514,12 @@ pub struct StreamingConfig {
     #[serde(default = "default::streaming::in_flight_barrier_nums")]
     pub in_flight_barrier_nums: usize,
 
+    /// The minimum number of barriers in-flight in the compute nodes. The barrier concurrency
+    /// is adaptively shrunk towards this floor when barriers are slow to commit, and expanded
+    /// back towards `in_flight_barrier_nums` when they complete quickly.
+    #[serde(default = "default::streaming::min_in_flight_barrier_nums")]
+    pub min_in_flight_barrier_nums: usize,
+
     /// The thread number of the streaming actor runtime in the compute node. The default value is
     /// decided by `tokio`.
     #[serde(default)]
@@ -422,6 +536,17 @@ pub struct StreamingConfig {
     #[serde(default = "default::streaming::unique_user_stream_errors")]
     pub unique_user_stream_errors: usize,
 
+    /// Interval of the liveness probe that checks for stuck actors on the compute node, in
+    /// seconds. Set to 0 to disable the probe.
+    #[serde(default = "default::streaming::actor_liveness_probe_interval_sec")]
+    pub actor_liveness_probe_interval_sec: u64,
+
+    /// The number of consecutive probe intervals without barrier progress after which the
+    /// actors yet to collect the oldest in-flight barrier are considered stuck and reported
+    /// for recovery.
+    #[serde(default = "default::streaming::actor_liveness_probe_max_missed_intervals")]
+    pub actor_liveness_probe_max_missed_intervals: u32,
+
     #[serde(default, flatten)]
     pub unrecognized: Unrecognized<Self>,
 }
@@ -561,6 +686,12 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::object_store_read_timeout_ms")]
     pub object_store_read_timeout_ms: u64,
 
+    /// Number of consecutive object store request failures after which the store is
+    /// reported unavailable, e.g. via the `object_store_unavailable` metric. 0 disables
+    /// the tracking.
+    #[serde(default = "default::storage::object_store_unavailable_threshold")]
+    pub object_store_unavailable_threshold: usize,
+
     #[serde(default = "default::s3_objstore_config::object_store_keepalive_ms")]
     pub object_store_keepalive_ms: Option<u64>,
     #[serde(default = "default::s3_objstore_config::object_store_recv_buffer_size")]
@@ -779,6 +910,21 @@ pub struct StreamingDeveloperConfig {
     /// The max heap size of dirty groups of `HashAggExecutor`.
     #[serde(default = "default::developer::stream_hash_agg_max_dirty_groups_heap_size")]
     pub hash_agg_max_dirty_groups_heap_size: usize,
+
+    /// The rate limit (rows/s) applied to backfill snapshot reads outside the maintenance
+    /// window. Throttling is disabled if unset.
+    #[serde(default)]
+    pub backfill_throttled_rate_limit: Option<u32>,
+
+    /// The start hour (UTC, inclusive) of the daily maintenance window during which backfill
+    /// runs at full speed.
+    #[serde(default = "default::developer::stream_backfill_maintenance_window_start_hour")]
+    pub backfill_maintenance_window_start_hour: u64,
+
+    /// The end hour (UTC, exclusive) of the daily maintenance window. If equal to the start
+    /// hour, the window is empty and the throttled rate limit always applies.
+    #[serde(default = "default::developer::stream_backfill_maintenance_window_end_hour")]
+    pub backfill_maintenance_window_end_hour: u64,
 }
 
 /// The subsections `[batch.developer]`.
@@ -798,6 +944,12 @@ pub struct BatchDeveloperConfig {
     /// The size of a chunk produced by `RowSeqScanExecutor`
     #[serde(default = "default::developer::batch_chunk_size")]
     pub chunk_size: usize,
+
+    /// The number of rows the build side of a hash join can exceed before the executor considers
+    /// swapping the two sides at runtime, so that the hash table is built on the smaller input
+    /// when the optimizer's estimate turns out to be wrong.
+    #[serde(default = "default::developer::batch_hash_join_swap_build_threshold")]
+    pub hash_join_swap_build_threshold: usize,
 }
 /// The section `[system]` in `risingwave.toml`. All these fields are used to initialize the system
 /// parameters persisted in Meta store. Most fields are for testing purpose only and should not be
@@ -916,10 +1068,30 @@ pub mod default {
             30
         }
 
+        pub fn worker_rpc_retry_max_attempts() -> usize {
+            3
+        }
+
+        pub fn worker_rpc_retry_base_interval_ms() -> u64 {
+            50
+        }
+
+        pub fn worker_rpc_circuit_break_threshold() -> usize {
+            10
+        }
+
+        pub fn worker_rpc_circuit_break_cooldown_ms() -> u64 {
+            10000
+        }
+
         pub fn default_parallelism() -> DefaultParallelism {
             DefaultParallelism::Full
         }
 
+        pub fn alert_interval_sec() -> u64 {
+            60
+        }
+
         pub fn node_num_monitor_interval_sec() -> u64 {
             10
         }
@@ -1083,6 +1255,10 @@ pub mod default {
             60 * 60 * 1000
         }
 
+        pub fn object_store_unavailable_threshold() -> usize {
+            8
+        }
+
         pub fn compactor_max_sst_key_count() -> u64 {
             2 * 1024 * 1024 // 200w
         }
@@ -1113,6 +1289,10 @@ pub mod default {
             10000
         }
 
+        pub fn min_in_flight_barrier_nums() -> usize {
+            1
+        }
+
         pub fn async_stack_trace() -> AsyncStackTraceOption {
             AsyncStackTraceOption::default()
         }
@@ -1120,6 +1300,14 @@ pub mod default {
         pub fn unique_user_stream_errors() -> usize {
             10
         }
+
+        pub fn actor_liveness_probe_interval_sec() -> u64 {
+            30
+        }
+
+        pub fn actor_liveness_probe_max_missed_intervals() -> u32 {
+            4
+        }
     }
 
     pub mod file_cache {
@@ -1239,6 +1427,10 @@ pub mod default {
             1024
         }
 
+        pub fn batch_hash_join_swap_build_threshold() -> usize {
+            4194304
+        }
+
         pub fn stream_enable_executor_row_count() -> bool {
             false
         }
@@ -1271,6 +1463,14 @@ pub mod default {
             32768
         }
 
+        pub fn stream_backfill_maintenance_window_start_hour() -> u64 {
+            0
+        }
+
+        pub fn stream_backfill_maintenance_window_end_hour() -> u64 {
+            0
+        }
+
         pub fn stream_hash_agg_max_dirty_groups_heap_size() -> usize {
             64 << 20 // 64MB
         }
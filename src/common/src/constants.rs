@@ -33,6 +33,20 @@ pub mod hummock {
 
     pub const TABLE_OPTION_DUMMY_RETENTION_SECOND: u32 = 0;
     pub const PROPERTIES_RETENTION_SECOND_KEY: &str = "retention_seconds";
+    /// Per-state-table override for which part of the key is fed into the SST filters.
+    /// Accepts `"full_key"` (point lookups on non-prefix key columns), `"prefix"` (the
+    /// default, following `read_prefix_len_hint`) and `"none"`.
+    pub const PROPERTIES_FILTER_KEY_MODE_KEY: &str = "filter_key_mode";
+    /// Per-state-table override for the false positive rate of the SST filters, a float in
+    /// `(0, 1)`. Tables not setting it follow the `bloom_false_positive` system parameter.
+    pub const PROPERTIES_BLOOM_FALSE_POSITIVE_KEY: &str = "bloom_false_positive";
+    /// Per-state-table override for the SST block compression codec applied during
+    /// compaction, one of `"none"`, `"lz4"` and `"zstd"`. Tables not setting it follow the
+    /// compaction group config.
+    pub const PROPERTIES_COMPRESSION_ALGORITHM_KEY: &str = "compression_algorithm";
+    /// Compression level for the codec in `compression_algorithm`, e.g. `1`-`22` for zstd.
+    /// Tables not setting it use the codec's default level.
+    pub const PROPERTIES_COMPRESSION_LEVEL_KEY: &str = "compression_level";
 }
 
 pub mod log_store {
@@ -187,6 +187,31 @@ pub enum ErrorCode {
     UnrecognizedConfigurationParameter(String),
 }
 
+impl ErrorCode {
+    /// The PostgreSQL SQLSTATE code (see <https://www.postgresql.org/docs/current/errcodes-appendix.html>)
+    /// for this kind of error, reported to clients in error responses so that drivers can branch
+    /// on the error class instead of matching on the message.
+    ///
+    /// The mapping is coarse-grained for now: errors without an obvious counterpart report the
+    /// `internal_error` code.
+    pub fn sqlstate(&self) -> &'static str {
+        match self {
+            ErrorCode::NotImplemented(..) | ErrorCode::NotSupported(..) => "0A000",
+            ErrorCode::IoError(_) => "58030",
+            ErrorCode::BindError(_) | ErrorCode::BindErrorRoot { .. } => "42000",
+            ErrorCode::CatalogError(_)
+            | ErrorCode::ItemNotFound(_)
+            | ErrorCode::UnrecognizedConfigurationParameter(_) => "42704",
+            ErrorCode::ProtocolError(_) => "08P01",
+            ErrorCode::InvalidInputSyntax(_) => "42601",
+            ErrorCode::ExprError(_) => "22000",
+            ErrorCode::InvalidConfigValue { .. } | ErrorCode::InvalidParameterValue(_) => "22023",
+            ErrorCode::PermissionDenied(_) => "42501",
+            _ => "XX000",
+        }
+    }
+}
+
 pub fn internal_error(msg: impl Into<String>) -> RwError {
     ErrorCode::InternalError(msg.into()).into()
 }
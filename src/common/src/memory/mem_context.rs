@@ -46,6 +46,9 @@ impl<const N: usize> MemCounter for LabelGuardedIntGauge<N> {
 struct MemoryContextInner {
     counter: Box<dyn MemCounter>,
     parent: Option<MemoryContext>,
+    /// Upper bound of the memory usage tracked by this context, in bytes. [`u64::MAX`] means
+    /// unlimited. The limit is enforced only through [`MemoryContext::try_add`].
+    mem_limit: u64,
 }
 
 #[derive(Clone)]
@@ -57,9 +60,23 @@ pub struct MemoryContext {
 
 impl MemoryContext {
     pub fn new(parent: Option<MemoryContext>, counter: impl MemCounter) -> Self {
+        Self::new_with_mem_limit(parent, counter, u64::MAX)
+    }
+
+    /// Creates a memory context whose usage is bounded by `mem_limit`. Note that the limit takes
+    /// effect only for memory accounted through [`Self::try_add`].
+    pub fn new_with_mem_limit(
+        parent: Option<MemoryContext>,
+        counter: impl MemCounter,
+        mem_limit: u64,
+    ) -> Self {
         let c = Box::new(counter);
         Self {
-            inner: Some(Arc::new(MemoryContextInner { counter: c, parent })),
+            inner: Some(Arc::new(MemoryContextInner {
+                counter: c,
+                parent,
+                mem_limit,
+            })),
         }
     }
 
@@ -72,6 +89,10 @@ impl MemoryContext {
         Self::new(None, counter)
     }
 
+    pub fn root_with_mem_limit(counter: impl MemCounter, mem_limit: u64) -> Self {
+        Self::new_with_mem_limit(None, counter, mem_limit)
+    }
+
     /// Add `bytes` memory usage. Pass negative value to decrease memory usage.
     pub fn add(&self, bytes: i64) {
         if let Some(inner) = &self.inner {
@@ -83,6 +104,33 @@ impl MemoryContext {
         }
     }
 
+    /// Add `bytes` memory usage if it does not exceed the limit of this context or any of its
+    /// ancestors. Returns `false` and leaves all counters unchanged if some limit would be
+    /// exceeded.
+    pub fn try_add(&self, bytes: i64) -> bool {
+        let Some(inner) = &self.inner else {
+            return true;
+        };
+        if inner.mem_limit != u64::MAX
+            && inner.counter.get_bytes_used().saturating_add(bytes) as u64 > inner.mem_limit
+        {
+            return false;
+        }
+        if let Some(parent) = &inner.parent && !parent.try_add(bytes) {
+            return false;
+        }
+        inner.counter.add(bytes);
+        true
+    }
+
+    /// Returns the memory limit of this context, or [`u64::MAX`] if unlimited.
+    pub fn mem_limit(&self) -> u64 {
+        match &self.inner {
+            Some(inner) => inner.mem_limit,
+            None => u64::MAX,
+        }
+    }
+
     pub fn get_bytes_used(&self) -> i64 {
         if let Some(inner) = &self.inner {
             inner.counter.get_bytes_used()
@@ -104,3 +152,33 @@ impl Drop for MemoryContextInner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::LabelGuardedIntGauge;
+
+    #[test]
+    fn test_try_add_with_mem_limit() {
+        let root_gauge = LabelGuardedIntGauge::<4>::test_int_gauge();
+        let root = MemoryContext::root_with_mem_limit(root_gauge.clone(), 100);
+        let child_gauge = LabelGuardedIntGauge::<4>::test_int_gauge();
+        let child = MemoryContext::new(Some(root.clone()), child_gauge.clone());
+
+        // Within the root's limit.
+        assert!(child.try_add(60));
+        assert_eq!(60, child.get_bytes_used());
+        assert_eq!(60, root.get_bytes_used());
+
+        // The unlimited child permits it, but the root's limit rejects it. Neither of the
+        // counters should be changed.
+        assert!(!child.try_add(50));
+        assert_eq!(60, child.get_bytes_used());
+        assert_eq!(60, root.get_bytes_used());
+
+        // Released memory can be reused.
+        child.add(-30);
+        assert!(child.try_add(50));
+        assert_eq!(80, root.get_bytes_used());
+    }
+}
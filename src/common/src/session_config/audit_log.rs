@@ -0,0 +1,117 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use super::{ConfigEntry, CONFIG_KEYS};
+use crate::error::ErrorCode::{self, InvalidConfigValue};
+use crate::error::RwError;
+use crate::session_config::AUDIT_LOG_LEVEL;
+
+/// Which classes of statements are recorded in the audit log.
+#[derive(Copy, Default, Debug, Clone, PartialEq, Eq)]
+pub enum AuditLogLevel {
+    /// Audit logging is disabled.
+    #[default]
+    None,
+    /// Record DDL statements only.
+    Ddl,
+    /// Record DML statements only.
+    Dml,
+    /// Record both DDL and DML statements.
+    All,
+}
+
+impl AuditLogLevel {
+    pub fn log_ddl(&self) -> bool {
+        matches!(self, Self::Ddl | Self::All)
+    }
+
+    pub fn log_dml(&self) -> bool {
+        matches!(self, Self::Dml | Self::All)
+    }
+}
+
+impl ConfigEntry for AuditLogLevel {
+    fn entry_name() -> &'static str {
+        CONFIG_KEYS[AUDIT_LOG_LEVEL]
+    }
+}
+
+impl TryFrom<&[&str]> for AuditLogLevel {
+    type Error = RwError;
+
+    fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
+        if value.len() != 1 {
+            return Err(ErrorCode::InternalError(format!(
+                "SET {} takes only one argument",
+                Self::entry_name()
+            ))
+            .into());
+        }
+
+        let s = value[0];
+        if s.eq_ignore_ascii_case("none") {
+            Ok(Self::None)
+        } else if s.eq_ignore_ascii_case("ddl") {
+            Ok(Self::Ddl)
+        } else if s.eq_ignore_ascii_case("dml") {
+            Ok(Self::Dml)
+        } else if s.eq_ignore_ascii_case("all") {
+            Ok(Self::All)
+        } else {
+            Err(InvalidConfigValue {
+                config_entry: Self::entry_name().to_string(),
+                config_value: s.to_string(),
+            })?
+        }
+    }
+}
+
+impl std::fmt::Display for AuditLogLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Ddl => write!(f, "ddl"),
+            Self::Dml => write!(f, "dml"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_audit_log_level() {
+        assert_eq!(
+            AuditLogLevel::try_from(["none"].as_slice()).unwrap(),
+            AuditLogLevel::None
+        );
+        assert_eq!(
+            AuditLogLevel::try_from(["DDL"].as_slice()).unwrap(),
+            AuditLogLevel::Ddl
+        );
+        assert_eq!(
+            AuditLogLevel::try_from(["dml"].as_slice()).unwrap(),
+            AuditLogLevel::Dml
+        );
+        assert_eq!(
+            AuditLogLevel::try_from(["All"].as_slice()).unwrap(),
+            AuditLogLevel::All
+        );
+        assert!(AuditLogLevel::try_from(["ab"].as_slice()).is_err());
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log;
 mod over_window;
 mod query_mode;
 mod search_path;
@@ -22,6 +23,7 @@ mod visibility_mode;
 use std::num::NonZeroU64;
 use std::ops::Deref;
 
+pub use audit_log::AuditLogLevel;
 use chrono_tz::Tz;
 use educe::{self, Educe};
 use itertools::Itertools;
@@ -38,7 +40,7 @@ use crate::util::epoch::Epoch;
 
 // This is a hack, &'static str is not allowed as a const generics argument.
 // TODO: refine this using the adt_const_params feature.
-const CONFIG_KEYS: [&str; 40] = [
+const CONFIG_KEYS: [&str; 45] = [
     "RW_IMPLICIT_FLUSH",
     "CREATE_COMPACTION_GROUP_FOR_MV",
     "QUERY_MODE",
@@ -79,6 +81,11 @@ const CONFIG_KEYS: [&str; 40] = [
     "BACKGROUND_DDL",
     "SERVER_ENCODING",
     "STREAMING_ENABLE_ARRANGEMENT_BACKFILL",
+    "RW_ENABLE_QUERY_RESULT_CACHE",
+    "ENABLE_DML_TRANSACTION",
+    "RW_STREAMING_ENABLE_ADAPTIVE_TWO_PHASE_AGG",
+    "AUDIT_LOG_LEVEL",
+    "RW_BATCH_ENABLE_FAST_PATH",
 ];
 
 // MUST HAVE 1v1 relationship to CONFIG_KEYS. e.g. CONFIG_KEYS[IMPLICIT_FLUSH] =
@@ -123,6 +130,11 @@ const STREAMING_OVER_WINDOW_CACHE_POLICY: usize = 36;
 const BACKGROUND_DDL: usize = 37;
 const SERVER_ENCODING: usize = 38;
 const STREAMING_ENABLE_ARRANGEMENT_BACKFILL: usize = 39;
+const RW_ENABLE_QUERY_RESULT_CACHE: usize = 40;
+const ENABLE_DML_TRANSACTION: usize = 41;
+const STREAMING_ENABLE_ADAPTIVE_TWO_PHASE_AGG: usize = 42;
+const AUDIT_LOG_LEVEL: usize = 43;
+const BATCH_ENABLE_FAST_PATH: usize = 44;
 
 trait ConfigEntry: Default + for<'a> TryFrom<&'a [&'a str], Error = RwError> {
     fn entry_name() -> &'static str;
@@ -330,6 +342,8 @@ type StreamingEnableDeltaJoin = ConfigBool<STREAMING_ENABLE_DELTA_JOIN, false>;
 type StreamingEnableBushyJoin = ConfigBool<STREAMING_ENABLE_BUSHY_JOIN, true>;
 type EnableTwoPhaseAgg = ConfigBool<ENABLE_TWO_PHASE_AGG, true>;
 type ForceTwoPhaseAgg = ConfigBool<FORCE_TWO_PHASE_AGG, false>;
+type StreamingEnableAdaptiveTwoPhaseAgg =
+    ConfigBool<STREAMING_ENABLE_ADAPTIVE_TWO_PHASE_AGG, false>;
 type EnableSharePlan = ConfigBool<RW_ENABLE_SHARE_PLAN, true>;
 type IntervalStyle = ConfigString<INTERVAL_STYLE>;
 type BatchParallelism = ConfigU64<BATCH_PARALLELISM, 0>;
@@ -349,6 +363,9 @@ type CdcBackfill = ConfigBool<CDC_BACKFILL, false>;
 type BackgroundDdl = ConfigBool<BACKGROUND_DDL, false>;
 type ServerEncoding = ConfigString<SERVER_ENCODING>;
 type StreamingEnableArrangementBackfill = ConfigBool<STREAMING_ENABLE_ARRANGEMENT_BACKFILL, false>;
+type EnableQueryResultCache = ConfigBool<RW_ENABLE_QUERY_RESULT_CACHE, false>;
+type EnableDmlTransaction = ConfigBool<ENABLE_DML_TRANSACTION, false>;
+type BatchEnableFastPath = ConfigBool<BATCH_ENABLE_FAST_PATH, false>;
 
 /// Report status or notice to caller.
 pub trait ConfigReporter {
@@ -393,6 +410,10 @@ pub struct ConfigMap {
     /// execution
     batch_enable_sort_agg: BatchEnableSortAgg,
 
+    /// Execute queries that only read system catalog tables or constant values directly in
+    /// the frontend, bypassing the batch task scheduler. Defaults to false.
+    batch_enable_fast_path: BatchEnableFastPath,
+
     /// It's the max gap allowed to transform small range scan scan into multi point lookup.
     max_split_range_gap: MaxSplitRangeGap,
 
@@ -425,6 +446,14 @@ pub struct ConfigMap {
     /// Enable arrangement backfill for streaming queries. Defaults to false.
     streaming_enable_arrangement_backfill: StreamingEnableArrangementBackfill,
 
+    /// Cache results of deterministic batch queries until the committed epoch of the
+    /// tables they reference advances. Defaults to false.
+    enable_query_result_cache: EnableQueryResultCache,
+
+    /// Enable buffering DML statements in an explicit read-write transaction, to be applied
+    /// together at `COMMIT`. Defaults to false.
+    enable_dml_transaction: EnableDmlTransaction,
+
     /// Enable join ordering for streaming and batch queries. Defaults to true.
     enable_join_ordering: EnableJoinOrdering,
 
@@ -437,6 +466,10 @@ pub struct ConfigMap {
     /// Setting this to true will always set `ENABLE_TWO_PHASE_AGG` to false.
     force_two_phase_agg: ForceTwoPhaseAgg,
 
+    /// Adaptively insert a stateless local aggregation before the shuffle for grouped
+    /// aggregations, if the local aggregation is cheap. Defaults to false.
+    streaming_enable_adaptive_two_phase_agg: StreamingEnableAdaptiveTwoPhaseAgg,
+
     /// Enable sharing of common sub-plans.
     /// This means that DAG structured query plans can be constructed,
     /// rather than only tree structured query plans.
@@ -505,6 +538,10 @@ pub struct ConfigMap {
     /// Shows the server-side character set encoding. At present, this parameter can be shown but not set, because the encoding is determined at database creation time.
     #[educe(Default(expression = "ConfigString::<SERVER_ENCODING>(String::from(\"UTF8\"))"))]
     server_encoding: ServerEncoding,
+
+    /// Which classes of statements are recorded in the audit log. Can be "none", "ddl", "dml"
+    /// or "all". Defaults to "none".
+    audit_log_level: AuditLogLevel,
 }
 
 impl ConfigMap {
@@ -536,6 +573,8 @@ impl ConfigMap {
             self.batch_enable_lookup_join = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(BatchEnableSortAgg::entry_name()) {
             self.batch_enable_sort_agg = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(BatchEnableFastPath::entry_name()) {
+            self.batch_enable_fast_path = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(MaxSplitRangeGap::entry_name()) {
             self.max_split_range_gap = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(SearchPath::entry_name()) {
@@ -551,7 +590,10 @@ impl ConfigMap {
                 config_entry: Timezone::entry_name().to_string(),
                 config_value: raw.0.to_string(),
             })?;
-            self.timezone = raw;
+            if self.timezone != raw {
+                self.timezone = raw.clone();
+                reporter.report_status(Timezone::entry_name(), raw.0);
+            }
         } else if key.eq_ignore_ascii_case(StreamingParallelism::entry_name()) {
             self.streaming_parallelism = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(StreamingEnableDeltaJoin::entry_name()) {
@@ -560,6 +602,10 @@ impl ConfigMap {
             self.streaming_enable_bushy_join = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(StreamingEnableArrangementBackfill::entry_name()) {
             self.streaming_enable_arrangement_backfill = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(EnableQueryResultCache::entry_name()) {
+            self.enable_query_result_cache = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(EnableDmlTransaction::entry_name()) {
+            self.enable_dml_transaction = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(EnableJoinOrdering::entry_name()) {
             self.enable_join_ordering = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(EnableTwoPhaseAgg::entry_name()) {
@@ -572,6 +618,8 @@ impl ConfigMap {
             if *self.force_two_phase_agg {
                 self.enable_two_phase_agg = ConfigBool(true);
             }
+        } else if key.eq_ignore_ascii_case(StreamingEnableAdaptiveTwoPhaseAgg::entry_name()) {
+            self.streaming_enable_adaptive_two_phase_agg = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(ForceSplitDistinctAgg::entry_name()) {
             self.force_split_distinct_agg = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(EnableSharePlan::entry_name()) {
@@ -640,6 +688,8 @@ impl ConfigMap {
                 .into());
             }
             // No actual assignment because we only support UTF8.
+        } else if key.eq_ignore_ascii_case(AuditLogLevel::entry_name()) {
+            self.audit_log_level = val.as_slice().try_into()?;
         } else {
             return Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into());
         }
@@ -647,6 +697,24 @@ impl ConfigMap {
         Ok(())
     }
 
+    /// Reset all configurations to their default values, as if the session were newly
+    /// connected. Used by `DISCARD ALL`. Changes to the parameters that clients keep track of
+    /// are reported through `reporter`.
+    pub fn reset(&mut self, mut reporter: impl ConfigReporter) {
+        info!("reset config");
+        let default = Self::default();
+        if self.application_name != default.application_name {
+            reporter.report_status(
+                ApplicationName::entry_name(),
+                default.application_name.0.clone(),
+            );
+        }
+        if self.timezone != default.timezone {
+            reporter.report_status(Timezone::entry_name(), default.timezone.0.clone());
+        }
+        *self = default;
+    }
+
     pub fn get(&self, key: &str) -> Result<String, RwError> {
         if key.eq_ignore_ascii_case(ImplicitFlush::entry_name()) {
             Ok(self.implicit_flush.to_string())
@@ -664,6 +732,8 @@ impl ConfigMap {
             Ok(self.batch_enable_lookup_join.to_string())
         } else if key.eq_ignore_ascii_case(BatchEnableSortAgg::entry_name()) {
             Ok(self.batch_enable_sort_agg.to_string())
+        } else if key.eq_ignore_ascii_case(BatchEnableFastPath::entry_name()) {
+            Ok(self.batch_enable_fast_path.to_string())
         } else if key.eq_ignore_ascii_case(MaxSplitRangeGap::entry_name()) {
             Ok(self.max_split_range_gap.to_string())
         } else if key.eq_ignore_ascii_case(SearchPath::entry_name()) {
@@ -684,12 +754,18 @@ impl ConfigMap {
             Ok(self.streaming_enable_bushy_join.to_string())
         } else if key.eq_ignore_ascii_case(StreamingEnableArrangementBackfill::entry_name()) {
             Ok(self.streaming_enable_arrangement_backfill.to_string())
+        } else if key.eq_ignore_ascii_case(EnableQueryResultCache::entry_name()) {
+            Ok(self.enable_query_result_cache.to_string())
+        } else if key.eq_ignore_ascii_case(EnableDmlTransaction::entry_name()) {
+            Ok(self.enable_dml_transaction.to_string())
         } else if key.eq_ignore_ascii_case(EnableJoinOrdering::entry_name()) {
             Ok(self.enable_join_ordering.to_string())
         } else if key.eq_ignore_ascii_case(EnableTwoPhaseAgg::entry_name()) {
             Ok(self.enable_two_phase_agg.to_string())
         } else if key.eq_ignore_ascii_case(ForceTwoPhaseAgg::entry_name()) {
             Ok(self.force_two_phase_agg.to_string())
+        } else if key.eq_ignore_ascii_case(StreamingEnableAdaptiveTwoPhaseAgg::entry_name()) {
+            Ok(self.streaming_enable_adaptive_two_phase_agg.to_string())
         } else if key.eq_ignore_ascii_case(EnableSharePlan::entry_name()) {
             Ok(self.enable_share_plan.to_string())
         } else if key.eq_ignore_ascii_case(IntervalStyle::entry_name()) {
@@ -733,6 +809,8 @@ impl ConfigMap {
             Ok(self.background_ddl.to_string())
         } else if key.eq_ignore_ascii_case(ServerEncoding::entry_name()) {
             Ok(self.server_encoding.to_string())
+        } else if key.eq_ignore_ascii_case(AuditLogLevel::entry_name()) {
+            Ok(self.audit_log_level.to_string())
         } else {
             Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into())
         }
@@ -780,6 +858,11 @@ impl ConfigMap {
                 setting : self.batch_enable_sort_agg.to_string(),
                 description : String::from("To enable the usage of sort agg instead of hash join when order property is satisfied for batch execution.")
             },
+            VariableInfo{
+                name : BatchEnableFastPath::entry_name().to_lowercase(),
+                setting : self.batch_enable_fast_path.to_string(),
+                description : String::from("To execute queries that only read system catalog tables or constant values directly in the frontend, bypassing the batch task scheduler.")
+            },
             VariableInfo{
                 name : MaxSplitRangeGap::entry_name().to_lowercase(),
                 setting : self.max_split_range_gap.to_string(),
@@ -825,6 +908,16 @@ impl ConfigMap {
                 setting : self.streaming_enable_arrangement_backfill.to_string(),
                 description: String::from("Enable arrangement backfill in streaming queries.")
             },
+            VariableInfo{
+                name : EnableQueryResultCache::entry_name().to_lowercase(),
+                setting : self.enable_query_result_cache.to_string(),
+                description: String::from("Cache results of deterministic batch queries until the committed epoch advances.")
+            },
+            VariableInfo{
+                name : EnableDmlTransaction::entry_name().to_lowercase(),
+                setting : self.enable_dml_transaction.to_string(),
+                description: String::from("Enable buffering DML statements in an explicit read-write transaction, applied together at COMMIT.")
+            },
             VariableInfo{
                 name : EnableJoinOrdering::entry_name().to_lowercase(),
                 setting : self.enable_join_ordering.to_string(),
@@ -840,6 +933,11 @@ impl ConfigMap {
                 setting : self.force_two_phase_agg.to_string(),
                 description: String::from("Force two phase aggregation.")
             },
+            VariableInfo{
+                name : StreamingEnableAdaptiveTwoPhaseAgg::entry_name().to_lowercase(),
+                setting : self.streaming_enable_adaptive_two_phase_agg.to_string(),
+                description: String::from("Adaptively insert a stateless local aggregation before the shuffle for grouped aggregations, if the local aggregation is cheap.")
+            },
             VariableInfo{
                 name : EnableSharePlan::entry_name().to_lowercase(),
                 setting : self.enable_share_plan.to_string(),
@@ -940,6 +1038,11 @@ impl ConfigMap {
                 setting : self.server_encoding.to_string(),
                 description : String::from("Sets the server character set encoding.")
             },
+            VariableInfo{
+                name: AuditLogLevel::entry_name().to_lowercase(),
+                setting: self.audit_log_level.to_string(),
+                description: String::from(r#"Which classes of statements are recorded in the audit log. Can be "none", "ddl", "dml" or "all"."#),
+            },
         ]
     }
 
@@ -975,6 +1078,10 @@ impl ConfigMap {
         *self.batch_enable_sort_agg
     }
 
+    pub fn get_batch_enable_fast_path(&self) -> bool {
+        *self.batch_enable_fast_path
+    }
+
     pub fn get_max_split_range_gap(&self) -> u64 {
         if *self.max_split_range_gap < 0 {
             0
@@ -1021,6 +1128,14 @@ impl ConfigMap {
         *self.streaming_enable_arrangement_backfill
     }
 
+    pub fn get_enable_query_result_cache(&self) -> bool {
+        *self.enable_query_result_cache
+    }
+
+    pub fn get_enable_dml_transaction(&self) -> bool {
+        *self.enable_dml_transaction
+    }
+
     pub fn get_enable_join_ordering(&self) -> bool {
         *self.enable_join_ordering
     }
@@ -1037,6 +1152,10 @@ impl ConfigMap {
         *self.force_two_phase_agg
     }
 
+    pub fn get_streaming_enable_adaptive_two_phase_agg(&self) -> bool {
+        *self.streaming_enable_adaptive_two_phase_agg
+    }
+
     pub fn get_enable_share_plan(&self) -> bool {
         *self.enable_share_plan
     }
@@ -1090,4 +1209,8 @@ impl ConfigMap {
     pub fn get_server_encoding(&self) -> &str {
         &self.server_encoding
     }
+
+    pub fn get_audit_log_level(&self) -> AuditLogLevel {
+        self.audit_log_level
+    }
 }
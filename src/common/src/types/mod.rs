@@ -63,6 +63,7 @@ mod struct_type;
 mod successor;
 mod timestamptz;
 mod to_binary;
+mod to_sql;
 mod to_text;
 
 pub use self::datetime::{Date, Time, Timestamp};
@@ -0,0 +1,64 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, IsNull, ToSql, Type};
+
+use crate::types::{Decimal, ScalarImpl};
+
+/// Bind a scalar as a parameter of a query to an external Postgres-compatible system, e.g. when
+/// snapshotting an external table. The type oid is not checked, since the scalar is already typed
+/// by our own catalog.
+impl ToSql for ScalarImpl {
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match self {
+            ScalarImpl::Bool(v) => v.to_sql(ty, out),
+            ScalarImpl::Int16(v) => v.to_sql(ty, out),
+            ScalarImpl::Int32(v) => v.to_sql(ty, out),
+            ScalarImpl::Int64(v) => v.to_sql(ty, out),
+            ScalarImpl::Serial(v) => v.into_inner().to_sql(ty, out),
+            ScalarImpl::Float32(v) => v.0.to_sql(ty, out),
+            ScalarImpl::Float64(v) => v.0.to_sql(ty, out),
+            ScalarImpl::Utf8(v) => v.as_ref().to_sql(ty, out),
+            ScalarImpl::Decimal(Decimal::Normalized(v)) => v.to_sql(ty, out),
+            ScalarImpl::Date(v) => v.0.to_sql(ty, out),
+            ScalarImpl::Time(v) => v.0.to_sql(ty, out),
+            ScalarImpl::Timestamp(v) => v.0.to_sql(ty, out),
+            ScalarImpl::Timestamptz(v) => v.to_datetime_utc().to_sql(ty, out),
+            ScalarImpl::Interval(v) => v.to_sql(ty, out),
+            ScalarImpl::Bytea(v) => (&**v).to_sql(ty, out),
+            ScalarImpl::Jsonb(_)
+            | ScalarImpl::Decimal(_)
+            | ScalarImpl::Int256(_)
+            | ScalarImpl::Struct(_)
+            | ScalarImpl::List(_) => Err(format!(
+                "cannot bind ScalarImpl::{} as a sql parameter",
+                self.get_ident()
+            )
+            .into()),
+        }
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+}
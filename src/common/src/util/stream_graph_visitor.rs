@@ -180,6 +180,11 @@ fn visit_stream_node_tables_inner<F>(
                 always!(node.state_table, "AppendOnlyDedup");
             }
 
+            // Upsert normalize
+            NodeBody::UpsertNormalize(node) => {
+                always!(node.state_table, "UpsertNormalize");
+            }
+
             // EOWC over window
             NodeBody::EowcOverWindow(node) => {
                 always!(node.state_table, "EowcOverWindow");
@@ -35,6 +35,8 @@ pub const SYSTEM_RESERVED_MEMORY_PROPORTION: f64 = 0.2;
 
 pub const STORAGE_MEMORY_PROPORTION: f64 = 0.3;
 
+pub const COMPUTE_BATCH_MEMORY_PROPORTION: f64 = 0.3;
+
 pub const COMPACTOR_MEMORY_PROPORTION: f64 = 0.1;
 
 pub const STORAGE_BLOCK_CACHE_MEMORY_PROPORTION: f64 = 0.3;
@@ -200,6 +202,10 @@ pub fn storage_memory_config(
     }
 }
 
+pub fn batch_mem_limit(compute_memory_bytes: usize) -> u64 {
+    (compute_memory_bytes as f64 * COMPUTE_BATCH_MEMORY_PROPORTION) as u64
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::config::StorageConfig;
@@ -14,13 +14,16 @@
 
 use std::sync::LazyLock;
 
-use prometheus::core::{AtomicU64, GenericCounterVec};
-use prometheus::{register_int_counter_vec_with_registry, Registry};
+use prometheus::core::{AtomicI64, AtomicU64, GenericCounterVec, GenericGaugeVec};
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry, Registry,
+};
 use risingwave_common::monitor::GLOBAL_METRICS_REGISTRY;
 
 #[derive(Clone)]
 pub struct ExchangeServiceMetrics {
     pub stream_fragment_exchange_bytes: GenericCounterVec<AtomicU64>,
+    pub stream_fragment_exchange_record_permits: GenericGaugeVec<AtomicI64>,
 }
 
 pub static GLOBAL_EXCHANGE_SERVICE_METRICS: LazyLock<ExchangeServiceMetrics> =
@@ -36,8 +39,17 @@ impl ExchangeServiceMetrics {
         )
         .unwrap();
 
+        let stream_fragment_exchange_record_permits = register_int_gauge_vec_with_registry!(
+            "stream_exchange_frag_record_permits",
+            "Available record permits (credits) of the exchange channel to downstream Fragment",
+            &["up_fragment_id", "down_fragment_id"],
+            registry
+        )
+        .unwrap();
+
         Self {
             stream_fragment_exchange_bytes,
+            stream_fragment_exchange_record_permits,
         }
     }
 }
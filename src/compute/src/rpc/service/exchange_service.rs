@@ -153,6 +153,12 @@ impl ExchangeServiceImpl {
         pin_mut!(select_stream);
 
         while let Some(r) = select_stream.try_next().await? {
+            // Report the remaining credits of this channel for back-pressure diagnosis.
+            metrics
+                .stream_fragment_exchange_record_permits
+                .with_label_values(&[&up_fragment_id, &down_fragment_id])
+                .set(permits.records_available() as i64);
+
             match r {
                 Either::Left(permits_to_add) => {
                     permits.add_permits(permits_to_add);
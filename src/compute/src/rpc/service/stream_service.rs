@@ -163,8 +163,16 @@ impl StreamService for StreamServiceImpl {
             ));
         }
 
+        // Create an `inject_barrier` span linked to the epoch span on the meta node, so that
+        // we can tell how long it takes to get the barrier into the actors on this node.
+        let span = barrier.tracing_context().attach(tracing::info_span!(
+            "inject_barrier",
+            prev_epoch = barrier.epoch.prev,
+            curr_epoch = barrier.epoch.curr,
+        ));
         self.mgr
             .send_barrier(&barrier, req.actor_ids_to_send, req.actor_ids_to_collect)
+            .instrument(span)
             .await?;
 
         Ok(Response::new(InjectBarrierResponse {
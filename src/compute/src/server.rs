@@ -68,7 +68,7 @@ use tower::Layer;
 
 use crate::memory_management::memory_manager::GlobalMemoryManager;
 use crate::memory_management::{
-    reserve_memory_bytes, storage_memory_config, MIN_COMPUTE_MEMORY_MB,
+    batch_mem_limit, reserve_memory_bytes, storage_memory_config, MIN_COMPUTE_MEMORY_MB,
 };
 use crate::observer::observer_manager::ComputeObserverNode;
 use crate::rpc::service::config_service::ConfigServiceImpl;
@@ -272,6 +272,7 @@ pub async fn compute_node_serve(
     let batch_mgr = Arc::new(BatchManager::new(
         config.batch.clone(),
         batch_manager_metrics,
+        batch_mem_limit(compute_memory_bytes),
     ));
     let stream_mgr = Arc::new(LocalStreamManager::new(
         advertise_addr.clone(),
@@ -368,6 +369,14 @@ pub async fn compute_node_serve(
     #[cfg(any())]
     stream_mgr.clone().spawn_print_trace();
 
+    // Run a background liveness probe that detects stuck actors and reports them for recovery.
+    if config.streaming.actor_liveness_probe_interval_sec > 0 {
+        stream_mgr.clone().spawn_actor_liveness_probe(
+            Duration::from_secs(config.streaming.actor_liveness_probe_interval_sec),
+            config.streaming.actor_liveness_probe_max_missed_intervals,
+        );
+    }
+
     // Boot the runtime gRPC services.
     let batch_srv = BatchServiceImpl::new(batch_mgr.clone(), batch_env);
     let exchange_srv =
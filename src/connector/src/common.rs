@@ -412,6 +412,91 @@ pub struct UpsertMessage<'a> {
     pub record: Cow<'a, [u8]>,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, WithOptions)]
+pub struct MqttCommon {
+    /// The url of the broker to connect to, e.g. `tcp://localhost:1883` or `ssl://localhost:8883`.
+    #[serde(rename = "url")]
+    pub url: String,
+
+    /// The topic name to subscribe to. The MQTT topic wildcards `+` and `#` are supported.
+    #[serde(rename = "topic")]
+    pub topic: String,
+
+    /// Username of the MQTT broker.
+    #[serde(rename = "username")]
+    pub username: Option<String>,
+
+    /// Password of the MQTT broker.
+    #[serde(rename = "password")]
+    pub password: Option<String>,
+
+    /// Prefix of the client id used to connect to the broker, `risingwave` if not specified. The
+    /// actor id is appended to it to make the client id unique.
+    #[serde(rename = "client_prefix")]
+    pub client_prefix: Option<String>,
+
+    /// The quality of service used for the subscription, accepts `at_most_once`, `at_least_once`
+    /// (default) or `exactly_once`.
+    #[serde(rename = "qos")]
+    pub qos: Option<String>,
+
+    /// PEM-encoded CA certificate used to verify the broker's certificate. Required when the url
+    /// scheme is `ssl` or `mqtts`.
+    #[serde(rename = "tls.ca")]
+    pub ca: Option<String>,
+}
+
+impl MqttCommon {
+    pub(crate) fn build_client(
+        &self,
+        id: u32,
+    ) -> anyhow::Result<(rumqttc::v5::AsyncClient, rumqttc::v5::EventLoop)> {
+        let client_id = format!(
+            "{}_{}",
+            self.client_prefix.as_deref().unwrap_or("risingwave"),
+            id
+        );
+
+        let mut url = Url::parse(&self.url)?;
+        let ssl = matches!(url.scheme(), "mqtts" | "ssl");
+        url.query_pairs_mut().append_pair("client_id", &client_id);
+
+        let mut options = rumqttc::v5::MqttOptions::parse_url(url.to_string())
+            .map_err(|e| anyhow_error!("invalid mqtt url: {:?}", e))?;
+        options.set_keep_alive(Duration::from_secs(10));
+
+        if ssl {
+            let ca = self
+                .ca
+                .as_ref()
+                .ok_or_else(|| anyhow!("`tls.ca` is required when connecting with ssl"))?;
+            options.set_transport(rumqttc::Transport::Tls(rumqttc::TlsConfiguration::Simple {
+                ca: ca.as_bytes().to_vec(),
+                alpn: None,
+                client_auth: None,
+            }));
+        }
+
+        if let Some(username) = &self.username {
+            options.set_credentials(username, self.password.clone().unwrap_or_default());
+        }
+
+        Ok(rumqttc::v5::AsyncClient::new(options, 10))
+    }
+
+    pub(crate) fn qos(&self) -> anyhow::Result<rumqttc::v5::mqttbytes::QoS> {
+        match self.qos.as_deref() {
+            None | Some("at_least_once") => Ok(rumqttc::v5::mqttbytes::QoS::AtLeastOnce),
+            Some("at_most_once") => Ok(rumqttc::v5::mqttbytes::QoS::AtMostOnce),
+            Some("exactly_once") => Ok(rumqttc::v5::mqttbytes::QoS::ExactlyOnce),
+            Some(qos) => Err(anyhow!(
+                "invalid qos {}, accept at_most_once/at_least_once/exactly_once",
+                qos
+            )),
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Deserialize, Serialize, Debug, Clone, WithOptions)]
 pub struct NatsCommon {
@@ -143,8 +143,18 @@ impl AvroParserConfig {
             )?;
             tracing::debug!("infer key subject {subject_key:?}, value subject {subject_value}");
 
+            let schema = match avro_config.schema_version {
+                // Pin the reader schema to the requested version; writer schemas are still
+                // resolved by the id embedded in each message.
+                Some(version) => {
+                    resolver
+                        .get_by_subject_name_version(&subject_value, version)
+                        .await?
+                }
+                None => resolver.get_by_subject_name(&subject_value).await?,
+            };
             Ok(Self {
-                schema: resolver.get_by_subject_name(&subject_value).await?,
+                schema,
                 key_schema: if let Some(subject_key) = subject_key {
                     Some(resolver.get_by_subject_name(&subject_key).await?)
                 } else {
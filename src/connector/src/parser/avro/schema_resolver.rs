@@ -51,6 +51,21 @@ impl ConfluentSchemaResolver {
         self.parse_and_cache_schema(raw_schema).await
     }
 
+    /// Get a fixed version of the subject, used to pin the reader schema while writer schemas
+    /// keep evolving.
+    pub async fn get_by_subject_name_version(
+        &self,
+        subject_name: &str,
+        version: i32,
+    ) -> Result<Arc<Schema>> {
+        let raw_schema = self
+            .confluent_client
+            .get_subject_version(subject_name, version)
+            .await?
+            .schema;
+        self.parse_and_cache_schema(raw_schema).await
+    }
+
     pub async fn get_raw_schema_by_subject_name(
         &self,
         subject_name: &str,
@@ -39,13 +39,14 @@ use self::avro::AvroAccessBuilder;
 use self::bytes_parser::BytesAccessBuilder;
 pub use self::mysql::mysql_row_to_datums;
 use self::plain_parser::PlainParser;
+pub use self::postgres::postgres_row_to_datums;
 use self::simd_json_parser::DebeziumJsonAccessBuilder;
 use self::unified::{AccessImpl, AccessResult};
 use self::upsert_parser::UpsertParser;
 use self::util::get_kafka_topic;
 use crate::aws_auth::AwsAuthProps;
 use crate::parser::maxwell::MaxwellParser;
-use crate::schema::schema_registry::SchemaRegistryAuth;
+use crate::schema::schema_registry::{SchemaRegistryAuth, SCHEMA_REGISTRY_VERSION};
 use crate::source::{
     extract_source_struct, BoxSourceStream, SourceColumnDesc, SourceColumnType, SourceContext,
     SourceContextRef, SourceEncode, SourceFormat, SourceMeta, SourceWithStateStream, SplitId,
@@ -62,6 +63,7 @@ mod json_parser;
 mod maxwell;
 mod mysql;
 mod plain_parser;
+mod postgres;
 mod protobuf;
 mod unified;
 mod upsert_parser;
@@ -812,6 +814,7 @@ pub struct AvroProperties {
     pub record_name: Option<String>,
     pub key_record_name: Option<String>,
     pub name_strategy: PbSchemaRegistryNameStrategy,
+    pub schema_version: Option<i32>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -913,6 +916,17 @@ impl SpecificParserConfig {
                 if info.use_schema_registry {
                     config.topic = get_kafka_topic(props)?.clone();
                     config.client_config = SchemaRegistryAuth::from(props);
+                    config.schema_version = props
+                        .get(SCHEMA_REGISTRY_VERSION)
+                        .map(|v| {
+                            v.parse().map_err(|_| {
+                                RwError::from(ProtocolError(format!(
+                                    "invalid {}: {}",
+                                    SCHEMA_REGISTRY_VERSION, v
+                                )))
+                            })
+                        })
+                        .transpose()?;
                 } else {
                     config.aws_auth_props = Some(AwsAuthProps::from_pairs(
                         props.iter().map(|(k, v)| (k.as_str(), v.as_str())),
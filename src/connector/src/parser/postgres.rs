@@ -0,0 +1,99 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{NaiveDate, Utc};
+use risingwave_common::catalog::Schema;
+use risingwave_common::types::{
+    DataType, Date, Datum, Decimal, Interval, JsonbVal, ScalarImpl, Time, Timestamp, Timestamptz,
+};
+use rust_decimal::Decimal as RustDecimal;
+use tokio_postgres::Row as PgRow;
+
+pub fn postgres_row_to_datums(row: &PgRow, schema: &Schema) -> Vec<Datum> {
+    let mut datums = vec![];
+    for i in 0..schema.fields.len() {
+        let rw_field = &schema.fields[i];
+        let datum = {
+            match rw_field.data_type {
+                DataType::Boolean => {
+                    let v = row.get::<_, Option<bool>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Int16 => {
+                    let v = row.get::<_, Option<i16>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Int32 => {
+                    let v = row.get::<_, Option<i32>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Int64 => {
+                    let v = row.get::<_, Option<i64>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Float32 => {
+                    let v = row.get::<_, Option<f32>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Float64 => {
+                    let v = row.get::<_, Option<f64>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Decimal => {
+                    let v = row.get::<_, Option<RustDecimal>>(i);
+                    v.map(|v| ScalarImpl::from(Decimal::from(v)))
+                }
+                DataType::Varchar => {
+                    let v = row.get::<_, Option<String>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Date => {
+                    let v = row.get::<_, Option<NaiveDate>>(i);
+                    v.map(|v| ScalarImpl::from(Date::from(v)))
+                }
+                DataType::Time => {
+                    let v = row.get::<_, Option<chrono::NaiveTime>>(i);
+                    v.map(|v| ScalarImpl::from(Time::from(v)))
+                }
+                DataType::Timestamp => {
+                    let v = row.get::<_, Option<chrono::NaiveDateTime>>(i);
+                    v.map(|v| ScalarImpl::from(Timestamp::from(v)))
+                }
+                DataType::Timestamptz => {
+                    let v = row.get::<_, Option<chrono::DateTime<Utc>>>(i);
+                    v.map(|v| ScalarImpl::from(Timestamptz::from_micros(v.timestamp_micros())))
+                }
+                DataType::Bytea => {
+                    let v = row.get::<_, Option<Vec<u8>>>(i);
+                    v.map(|v| ScalarImpl::from(v.into_boxed_slice()))
+                }
+                DataType::Jsonb => {
+                    let v = row.get::<_, Option<serde_json::Value>>(i);
+                    v.map(|v| ScalarImpl::from(JsonbVal::from(v)))
+                }
+                DataType::Interval => {
+                    let v = row.get::<_, Option<Interval>>(i);
+                    v.map(ScalarImpl::from)
+                }
+                DataType::Struct(_) | DataType::List(_) | DataType::Int256 | DataType::Serial => {
+                    // Struct, List, Int256 and Serial are not supported
+                    tracing::warn!(rw_field.name, ?rw_field.data_type, "unsupported data type, set to null");
+                    None
+                }
+            }
+        };
+        datums.push(datum);
+    }
+    datums
+}
@@ -26,6 +26,9 @@ use serde::de::DeserializeOwned;
 use super::util::*;
 
 pub const SCHEMA_REGISTRY_USERNAME: &str = "schema.registry.username";
+/// Optional property to pin the reader schema to a fixed version of the subject instead of
+/// always resolving `latest`, so that writer schemas can keep evolving independently.
+pub const SCHEMA_REGISTRY_VERSION: &str = "schema.registry.version";
 pub const SCHEMA_REGISTRY_PASSWORD: &str = "schema.registry.password";
 
 #[derive(Debug, Clone, Default)]
@@ -154,6 +157,24 @@ impl Client {
         self.get_subject(subject).await.map(|s| s.schema)
     }
 
+    /// get a specific version of the subject
+    pub async fn get_subject_version(&self, subject: &str, version: i32) -> Result<Subject> {
+        let res: GetBySubjectResp = self
+            .concurrent_req(
+                Method::GET,
+                &["subjects", subject, "versions", &version.to_string()],
+            )
+            .await?;
+        Ok(Subject {
+            schema: ConfluentSchema {
+                id: res.id,
+                content: res.schema,
+            },
+            version: res.version,
+            name: res.subject,
+        })
+    }
+
     /// get the latest version of the subject
     pub async fn get_subject(&self, subject: &str) -> Result<Subject> {
         let res: GetBySubjectResp = self
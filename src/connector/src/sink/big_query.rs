@@ -29,7 +29,10 @@ use risingwave_common::types::DataType;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::serde_as;
+use tokio_retry::strategy::{jitter, ExponentialBackoff};
+use tokio_retry::Retry;
 use url::Url;
+use uuid::Uuid;
 use with_options::WithOptions;
 use yup_oauth2::ServiceAccountKey;
 
@@ -45,6 +48,10 @@ use crate::sink::{
 
 pub const BIGQUERY_SINK: &str = "bigquery";
 const BIGQUERY_INSERT_MAX_NUMS: usize = 1024;
+/// Initial backoff, in milliseconds, between retries of a failed insert request.
+const BIGQUERY_RETRY_BASE_DELAY_MS: u64 = 100;
+/// Maximum number of retries of a failed insert request before failing the sink.
+const BIGQUERY_RETRY_TIMES: usize = 5;
 
 #[derive(Deserialize, Serialize, Debug, Clone, WithOptions)]
 pub struct BigQueryCommon {
@@ -232,7 +239,11 @@ impl Sink for BigQuerySink {
             self.is_append_only,
         )
         .await?
-        .into_log_sinker(writer_param.sink_metrics))
+        .into_log_sinker(
+            writer_param.sink_metrics,
+            writer_param.error_policy,
+            writer_param.throttle,
+        ))
     }
 
     async fn validate(&self) -> Result<()> {
@@ -331,7 +342,9 @@ impl BigQuerySinkWriter {
                 )));
             }
             insert_vec.push(TableDataInsertAllRequestRows {
-                insert_id: None,
+                // Used by BigQuery to deduplicate rows on a best-effort basis when an
+                // insert request is retried.
+                insert_id: Some(Uuid::new_v4().simple().to_string()),
                 json: Value::Object(self.row_encoder.encode(row)?),
             })
         }
@@ -348,16 +361,37 @@ impl BigQuerySinkWriter {
         if !self.insert_request.is_empty() {
             let insert_request =
                 mem::replace(&mut self.insert_request, TableDataInsertAllRequest::new());
-            self.client
-                .tabledata()
-                .insert_all(
-                    &self.config.common.project,
-                    &self.config.common.dataset,
-                    &self.config.common.table,
-                    insert_request,
-                )
-                .await
-                .map_err(|e| SinkError::BigQuery(e.into()))?;
+            // Transient failures, e.g. hitting the streaming insert quota, are retried
+            // with backoff. The `insert_id` of each row lets BigQuery deduplicate the
+            // rows of a retried request.
+            let resp = Retry::spawn(
+                ExponentialBackoff::from_millis(BIGQUERY_RETRY_BASE_DELAY_MS)
+                    .map(jitter)
+                    .take(BIGQUERY_RETRY_TIMES),
+                || async {
+                    self.client
+                        .tabledata()
+                        .insert_all(
+                            &self.config.common.project,
+                            &self.config.common.dataset,
+                            &self.config.common.table,
+                            insert_request.clone(),
+                        )
+                        .await
+                },
+            )
+            .await
+            .map_err(|e| SinkError::BigQuery(e.into()))?;
+            // A successful response may still report failed rows, e.g. on schema
+            // mismatches, which must not be silently dropped.
+            if let Some(insert_errors) = resp.insert_errors
+                && !insert_errors.is_empty()
+            {
+                return Err(SinkError::BigQuery(anyhow::anyhow!(
+                    "failed to insert rows: {:?}",
+                    insert_errors
+                )));
+            }
         }
         Ok(())
     }
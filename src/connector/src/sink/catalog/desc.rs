@@ -95,6 +95,7 @@ impl SinkDesc {
             initialized_at_epoch: None,
             db_name: self.db_name,
             sink_from_name: self.sink_from_name,
+            is_paused: false,
         }
     }
 
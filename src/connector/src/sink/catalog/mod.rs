@@ -296,6 +296,9 @@ pub struct SinkCatalog {
 
     /// Name for the table info for Debezium sink
     pub sink_from_name: String,
+
+    /// Whether delivery to the external system is paused by `ALTER SINK ... PAUSE`.
+    pub is_paused: bool,
 }
 
 impl SinkCatalog {
@@ -333,6 +336,7 @@ impl SinkCatalog {
             db_name: self.db_name.clone(),
             sink_from_name: self.sink_from_name.clone(),
             stream_job_status: PbStreamJobStatus::Creating.into(),
+            is_paused: self.is_paused,
         }
     }
 
@@ -421,6 +425,7 @@ impl From<PbSink> for SinkCatalog {
             initialized_at_epoch: pb.initialized_at_epoch.map(Epoch::from),
             db_name: pb.db_name,
             sink_from_name: pb.sink_from_name,
+            is_paused: pb.is_paused,
         }
     }
 }
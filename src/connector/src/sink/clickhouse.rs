@@ -25,7 +25,7 @@ use risingwave_common::types::{DataType, Decimal, ScalarRefImpl, Serial};
 use serde::ser::{SerializeSeq, SerializeStruct};
 use serde::Serialize;
 use serde_derive::Deserialize;
-use serde_with::serde_as;
+use serde_with::{serde_as, DisplayFromStr};
 use with_options::WithOptions;
 
 use super::{DummySinkCommitCoordinator, SinkWriterParam};
@@ -45,6 +45,7 @@ const QUERY_COLUMN: &str =
 pub const CLICKHOUSE_SINK: &str = "clickhouse";
 const BUFFER_SIZE: usize = 1024;
 
+#[serde_as]
 #[derive(Deserialize, Serialize, Debug, Clone, WithOptions)]
 pub struct ClickHouseCommon {
     #[serde(rename = "clickhouse.url")]
@@ -57,18 +58,39 @@ pub struct ClickHouseCommon {
     pub database: String,
     #[serde(rename = "clickhouse.table")]
     pub table: String,
+    /// Enable [async inserts](https://clickhouse.com/docs/en/optimize/asynchronous-inserts) so
+    /// that ClickHouse buffers the inserted rows and batches them on the server side.
+    /// `wait_for_async_insert` is always enabled to keep the delivery guarantee.
+    #[serde(rename = "clickhouse.async_insert", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub async_insert: Option<bool>,
+    /// The maximum size in bytes of the server-side buffer before a batch is flushed. Maps to
+    /// the `async_insert_max_data_size` setting of ClickHouse.
+    #[serde(rename = "clickhouse.async_insert.max_data_size", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub async_insert_max_data_size: Option<usize>,
+    /// The maximum time in milliseconds the server waits before a batch is flushed. Maps to
+    /// the `async_insert_busy_timeout_ms` setting of ClickHouse.
+    #[serde(rename = "clickhouse.async_insert.busy_timeout_ms", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub async_insert_busy_timeout_ms: Option<usize>,
+    /// The column used to mark deleted rows when sinking into a `ReplacingMergeTree` table in
+    /// upsert mode.
+    #[serde(rename = "clickhouse.delete.column")]
+    pub delete_column: Option<String>,
 }
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 enum ClickHouseEngine {
     MergeTree,
-    ReplacingMergeTree,
+    ReplacingMergeTree(Option<String>),
     SummingMergeTree,
     AggregatingMergeTree,
     CollapsingMergeTree(String),
     VersionedCollapsingMergeTree(String),
     GraphiteMergeTree,
+    Distributed,
 }
 impl ClickHouseEngine {
     pub fn is_collapsing_engine(&self) -> bool {
@@ -79,6 +101,21 @@ impl ClickHouseEngine {
         )
     }
 
+    pub fn is_delete_replacing_engine(&self) -> bool {
+        matches!(self, ClickHouseEngine::ReplacingMergeTree(Some(_)))
+    }
+
+    pub fn is_distributed_engine(&self) -> bool {
+        matches!(self, ClickHouseEngine::Distributed)
+    }
+
+    pub fn get_delete_col(&self) -> Option<String> {
+        match self {
+            ClickHouseEngine::ReplacingMergeTree(Some(delete_col)) => Some(delete_col.to_string()),
+            _ => None,
+        }
+    }
+
     pub fn get_sign_name(&self) -> Option<String> {
         match self {
             ClickHouseEngine::CollapsingMergeTree(sign_name) => Some(sign_name.to_string()),
@@ -89,10 +126,15 @@ impl ClickHouseEngine {
         }
     }
 
-    pub fn from_query_engine(engine_name: &ClickhouseQueryEngine) -> Result<Self> {
+    pub fn from_query_engine(
+        engine_name: &ClickhouseQueryEngine,
+        config: &ClickHouseConfig,
+    ) -> Result<Self> {
         match engine_name.engine.as_str() {
             "MergeTree" => Ok(ClickHouseEngine::MergeTree),
-            "ReplacingMergeTree" => Ok(ClickHouseEngine::ReplacingMergeTree),
+            "ReplacingMergeTree" => Ok(ClickHouseEngine::ReplacingMergeTree(
+                config.common.delete_column.clone(),
+            )),
             "SummingMergeTree" => Ok(ClickHouseEngine::SummingMergeTree),
             "AggregatingMergeTree" => Ok(ClickHouseEngine::AggregatingMergeTree),
             "VersionedCollapsingMergeTree" => {
@@ -120,6 +162,7 @@ impl ClickHouseEngine {
                 Ok(ClickHouseEngine::CollapsingMergeTree(sign_name))
             }
             "GraphiteMergeTree" => Ok(ClickHouseEngine::GraphiteMergeTree),
+            "Distributed" => Ok(ClickHouseEngine::Distributed),
             _ => Err(SinkError::ClickHouse(format!(
                 "Cannot find clickhouse engine {:?}",
                 engine_name.engine
@@ -131,6 +174,24 @@ impl ClickHouseEngine {
 const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(5);
 
 impl ClickHouseCommon {
+    /// Build the settings passed to ClickHouse with every query, as query parameters of the url.
+    fn settings(&self) -> Vec<(&str, String)> {
+        let mut settings = vec![];
+        if self.async_insert.unwrap_or(false) {
+            settings.push(("async_insert", "1".to_string()));
+            // Wait until the buffer is flushed before responding, otherwise an acknowledged
+            // batch may get lost when the server crashes.
+            settings.push(("wait_for_async_insert", "1".to_string()));
+            if let Some(max_data_size) = self.async_insert_max_data_size {
+                settings.push(("async_insert_max_data_size", max_data_size.to_string()));
+            }
+            if let Some(busy_timeout_ms) = self.async_insert_busy_timeout_ms {
+                settings.push(("async_insert_busy_timeout_ms", busy_timeout_ms.to_string()));
+            }
+        }
+        settings
+    }
+
     pub(crate) fn build_client(&self) -> anyhow::Result<ClickHouseClient> {
         use hyper_tls::HttpsConnector;
 
@@ -139,8 +200,16 @@ impl ClickHouseCommon {
             .pool_idle_timeout(POOL_IDLE_TIMEOUT)
             .build::<_, hyper::Body>(https);
 
+        // The client preserves existing query parameters of the url when it appends its own,
+        // so the settings attached here take effect for every query.
+        let mut url = self.url.clone();
+        for (name, value) in self.settings() {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&format!("{}={}", name, value));
+        }
+
         let client = ClickHouseClient::with_http_client(client)
-            .with_url(&self.url)
+            .with_url(url)
             .with_user(&self.user)
             .with_password(&self.password)
             .with_database(&self.database);
@@ -178,6 +247,14 @@ impl ClickHouseConfig {
                 SINK_TYPE_UPSERT
             )));
         }
+        if !config.common.async_insert.unwrap_or(false)
+            && (config.common.async_insert_max_data_size.is_some()
+                || config.common.async_insert_busy_timeout_ms.is_some())
+        {
+            return Err(SinkError::Config(anyhow!(
+                "`clickhouse.async_insert` must be enabled to specify `clickhouse.async_insert.max_data_size` or `clickhouse.async_insert.busy_timeout_ms`"
+            )));
+        }
         Ok(config)
     }
 }
@@ -339,9 +416,17 @@ impl Sink for ClickHouseSink {
         let (clickhouse_column, clickhouse_engine) =
             query_column_engine_from_ck(client, &self.config).await?;
 
-        if !self.is_append_only && !clickhouse_engine.is_collapsing_engine() {
-            return Err(SinkError::ClickHouse(
-                "If you want to use upsert, please modify your engine is `VersionedCollapsingMergeTree` or `CollapsingMergeTree` in ClickHouse".to_owned()));
+        if !self.is_append_only {
+            if clickhouse_engine.is_distributed_engine() {
+                return Err(SinkError::ClickHouse(
+                    "If you want to use upsert with a `Distributed` table, please sink into its underlying local table directly".to_owned()));
+            }
+            if !clickhouse_engine.is_collapsing_engine()
+                && !clickhouse_engine.is_delete_replacing_engine()
+            {
+                return Err(SinkError::ClickHouse(
+                    "If you want to use upsert, please modify your engine is `VersionedCollapsingMergeTree` or `CollapsingMergeTree` in ClickHouse, or use `ReplacingMergeTree` with `clickhouse.delete.column`".to_owned()));
+            }
         }
 
         self.check_column_name_and_type(&clickhouse_column)?;
@@ -351,7 +436,7 @@ impl Sink for ClickHouseSink {
         Ok(())
     }
 
-    async fn new_log_sinker(&self, _writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
+    async fn new_log_sinker(&self, writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
         Ok(ClickHouseSinkWriter::new(
             self.config.clone(),
             self.schema.clone(),
@@ -359,7 +444,7 @@ impl Sink for ClickHouseSink {
             self.is_append_only,
         )
         .await?
-        .into_log_sinker(usize::MAX))
+        .into_log_sinker(usize::MAX, writer_param.throttle))
     }
 }
 pub struct ClickHouseSinkWriter {
@@ -406,6 +491,9 @@ impl ClickHouseSinkWriter {
         if let Some(sign) = clickhouse_engine.get_sign_name() {
             rw_fields_name_after_calibration.push(sign);
         }
+        if let Some(delete_col) = clickhouse_engine.get_delete_col() {
+            rw_fields_name_after_calibration.push(delete_col);
+        }
         Ok(Self {
             config,
             schema,
@@ -495,17 +583,26 @@ impl ClickHouseSinkWriter {
                         clickhouse_filed_vec.push(ClickHouseFieldWithNull::WithoutSome(
                             ClickHouseField::Int8(1),
                         ));
+                    } else if self.clickhouse_engine.is_delete_replacing_engine() {
+                        clickhouse_filed_vec.push(ClickHouseFieldWithNull::WithoutSome(
+                            ClickHouseField::Bool(false),
+                        ));
                     }
                 }
                 Op::Delete | Op::UpdateDelete => {
-                    if !self.clickhouse_engine.is_collapsing_engine() {
+                    if self.clickhouse_engine.is_collapsing_engine() {
+                        clickhouse_filed_vec.push(ClickHouseFieldWithNull::WithoutSome(
+                            ClickHouseField::Int8(-1),
+                        ));
+                    } else if self.clickhouse_engine.is_delete_replacing_engine() {
+                        clickhouse_filed_vec.push(ClickHouseFieldWithNull::WithoutSome(
+                            ClickHouseField::Bool(true),
+                        ));
+                    } else {
                         return Err(SinkError::ClickHouse(
                             "Clickhouse engine don't support upsert".to_string(),
                         ));
                     }
-                    clickhouse_filed_vec.push(ClickHouseFieldWithNull::WithoutSome(
-                        ClickHouseField::Int8(-1),
-                    ))
                 }
             }
             let clickhouse_column = ClickHouseColumn {
@@ -569,11 +666,15 @@ async fn query_column_engine_from_ck(
         )));
     }
 
-    let clickhouse_engine = ClickHouseEngine::from_query_engine(clickhouse_engine.get(0).unwrap())?;
+    let clickhouse_engine =
+        ClickHouseEngine::from_query_engine(clickhouse_engine.get(0).unwrap(), config)?;
 
     if let Some(sign) = &clickhouse_engine.get_sign_name() {
         clickhouse_column.retain(|a| sign.ne(&a.name))
     }
+    if let Some(delete_col) = &clickhouse_engine.get_delete_col() {
+        clickhouse_column.retain(|a| delete_col.ne(&a.name))
+    }
     Ok((clickhouse_column, clickhouse_engine))
 }
 
@@ -30,12 +30,13 @@ use risingwave_common::types::DataType;
 use serde::Deserialize;
 use serde_derive::Serialize;
 use serde_json::Value;
-use serde_with::serde_as;
+use serde_with::{serde_as, DisplayFromStr};
 use with_options::WithOptions;
 
+use super::catalog::SinkId;
 use super::doris_starrocks_connector::{
-    HeaderBuilder, InserterInner, InserterInnerBuilder, DORIS_DELETE_SIGN, DORIS_SUCCESS_STATUS,
-    POOL_IDLE_TIMEOUT,
+    HeaderBuilder, InserterInner, InserterInnerBuilder, DORIS_COMMITTED_JOB_STATUS,
+    DORIS_DELETE_SIGN, DORIS_DUPLICATE_LABEL_STATUS, DORIS_SUCCESS_STATUS, POOL_IDLE_TIMEOUT,
 };
 use super::{Result, SinkError, SINK_TYPE_APPEND_ONLY, SINK_TYPE_OPTION, SINK_TYPE_UPSERT};
 use crate::sink::encoder::{JsonEncoder, RowEncoder, TimestampHandlingMode};
@@ -44,6 +45,10 @@ use crate::sink::{DummySinkCommitCoordinator, Sink, SinkParam, SinkWriter, SinkW
 
 pub const DORIS_SINK: &str = "doris";
 
+const fn _default_stream_load_buffer_size() -> usize {
+    64 * 1024
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, WithOptions)]
 pub struct DorisCommon {
     #[serde(rename = "doris.url")]
@@ -76,6 +81,15 @@ pub struct DorisConfig {
     #[serde(flatten)]
     pub common: DorisCommon,
 
+    /// The buffer size (in bytes) of a Stream Load task. Rows are accumulated in the buffer
+    /// and flushed to the backend as one HTTP chunk once it fills up.
+    #[serde(
+        rename = "doris.buffer_size",
+        default = "_default_stream_load_buffer_size"
+    )]
+    #[serde_as(as = "DisplayFromStr")]
+    pub buffer_size: usize,
+
     pub r#type: String, // accept "append-only" or "upsert"
 }
 impl DorisConfig {
@@ -101,6 +115,7 @@ pub struct DorisSink {
     schema: Schema,
     pk_indices: Vec<usize>,
     is_append_only: bool,
+    sink_id: SinkId,
 }
 
 impl DorisSink {
@@ -109,12 +124,14 @@ impl DorisSink {
         schema: Schema,
         pk_indices: Vec<usize>,
         is_append_only: bool,
+        sink_id: SinkId,
     ) -> Result<Self> {
         Ok(Self {
             config,
             schema,
             pk_indices,
             is_append_only,
+            sink_id,
         })
     }
 }
@@ -201,9 +218,14 @@ impl Sink for DorisSink {
             self.schema.clone(),
             self.pk_indices.clone(),
             self.is_append_only,
+            format!("rw-sink-{}-{}", self.sink_id, writer_param.executor_id),
         )
         .await?
-        .into_log_sinker(writer_param.sink_metrics))
+        .into_log_sinker(
+            writer_param.sink_metrics,
+            writer_param.error_policy,
+            writer_param.throttle,
+        ))
     }
 
     async fn validate(&self) -> Result<()> {
@@ -233,6 +255,8 @@ pub struct DorisSinkWriter {
     is_append_only: bool,
     client: Option<DorisClient>,
     row_encoder: JsonEncoder,
+    label_prefix: String,
+    epoch: u64,
 }
 
 impl TryFrom<SinkParam> for DorisSink {
@@ -240,12 +264,14 @@ impl TryFrom<SinkParam> for DorisSink {
 
     fn try_from(param: SinkParam) -> std::result::Result<Self, Self::Error> {
         let schema = param.schema();
+        let sink_id = param.sink_id;
         let config = DorisConfig::from_hashmap(param.properties)?;
         DorisSink::new(
             config,
             schema,
             param.downstream_pk,
             param.sink_type.is_append_only(),
+            sink_id,
         )
     }
 }
@@ -256,6 +282,7 @@ impl DorisSinkWriter {
         schema: Schema,
         pk_indices: Vec<usize>,
         is_append_only: bool,
+        label_prefix: String,
     ) -> Result<Self> {
         let mut decimal_map = HashMap::default();
         let doris_schema = config
@@ -285,7 +312,8 @@ impl DorisSinkWriter {
             config.common.database.clone(),
             config.common.table.clone(),
             header,
-        );
+        )
+        .set_buffer_size(config.buffer_size);
         Ok(Self {
             config,
             schema: schema.clone(),
@@ -299,6 +327,8 @@ impl DorisSinkWriter {
                 TimestampHandlingMode::String,
                 decimal_map,
             ),
+            label_prefix,
+            epoch: 0,
         })
     }
 
@@ -379,7 +409,12 @@ impl DorisSinkWriter {
 impl SinkWriter for DorisSinkWriter {
     async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
         if self.client.is_none() {
-            self.client = Some(DorisClient::new(self.inseter_inner_builder.build().await?));
+            // The label is derived from the sink and the epoch, so that a load replayed
+            // after recovery carries the same label and is deduplicated by Doris.
+            let label = format!("{}-{}", self.label_prefix, self.epoch);
+            self.client = Some(DorisClient::new(
+                self.inseter_inner_builder.build(label).await?,
+            ));
         }
         if self.is_append_only {
             self.append_only(chunk).await
@@ -388,7 +423,8 @@ impl SinkWriter for DorisSinkWriter {
         }
     }
 
-    async fn begin_epoch(&mut self, _epoch: u64) -> Result<()> {
+    async fn begin_epoch(&mut self, epoch: u64) -> Result<()> {
+        self.epoch = epoch;
         Ok(())
     }
 
@@ -518,6 +554,8 @@ pub struct DorisInsertResultResponse {
     status: String,
     #[serde(rename = "TwoPhaseCommit")]
     two_phase_commit: String,
+    #[serde(rename = "ExistingJobStatus")]
+    existing_job_status: Option<String>,
     #[serde(rename = "Message")]
     message: String,
     #[serde(rename = "NumberTotalRows")]
@@ -576,6 +614,14 @@ impl DorisClient {
             .map_err(|err| SinkError::DorisStarrocksConnect(err.into()))?;
 
         if !DORIS_SUCCESS_STATUS.contains(&res.status.as_str()) {
+            // A load rejected as a duplicate label means that the load of this epoch was
+            // already committed before a recovery, so the replayed data can be discarded.
+            if res.status == DORIS_DUPLICATE_LABEL_STATUS
+                && let Some(existing_job_status) = &res.existing_job_status
+                && DORIS_COMMITTED_JOB_STATUS.contains(&existing_job_status.as_str())
+            {
+                return Ok(res);
+            }
             return Err(SinkError::DorisStarrocksConnect(anyhow::anyhow!(
                 "Insert error: {:?}, error url: {:?}",
                 res.message,
@@ -28,9 +28,11 @@ use tokio::task::JoinHandle;
 
 use super::{Result, SinkError};
 
-const BUFFER_SIZE: usize = 64 * 1024;
-const MIN_CHUNK_SIZE: usize = BUFFER_SIZE - 1024;
+pub(crate) const STREAM_LOAD_BUFFER_SIZE: usize = 64 * 1024;
+const MIN_CHUNK_HEADROOM: usize = 1024;
 pub(crate) const DORIS_SUCCESS_STATUS: [&str; 2] = ["Success", "Publish Timeout"];
+pub(crate) const DORIS_DUPLICATE_LABEL_STATUS: &str = "Label Already Exists";
+pub(crate) const DORIS_COMMITTED_JOB_STATUS: [&str; 2] = ["FINISHED", "VISIBLE"];
 pub(crate) const DORIS_DELETE_SIGN: &str = "__DORIS_DELETE_SIGN__";
 pub(crate) const STARROCKS_DELETE_SIGN: &str = "__op";
 
@@ -60,13 +62,6 @@ impl HeaderBuilder {
         self
     }
 
-    /// The method is temporarily not in use, reserved for later use in 2PC.
-    /// Doris will generate a default, non-repeating label.
-    pub fn set_label(mut self, label: String) -> Self {
-        self.header.insert("label".to_string(), label);
-        self
-    }
-
     pub fn set_columns_name(mut self, columns_name: Vec<&str>) -> Self {
         let columns_name_str = columns_name.join(",");
         self.header.insert("columns".to_string(), columns_name_str);
@@ -148,6 +143,7 @@ impl HeaderBuilder {
 pub struct InserterInnerBuilder {
     url: String,
     header: HashMap<String, String>,
+    buffer_size: usize,
     sender: Option<Sender>,
 }
 impl InserterInnerBuilder {
@@ -158,14 +154,23 @@ impl InserterInnerBuilder {
             url,
             sender: None,
             header,
+            buffer_size: STREAM_LOAD_BUFFER_SIZE,
         }
     }
 
+    /// Set the size of the buffer that accumulates rows before they are sent to the backend
+    /// as one HTTP chunk. Defaults to [`STREAM_LOAD_BUFFER_SIZE`].
+    pub fn set_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
     fn build_request_and_client(
         &self,
         uri: String,
+        label: &str,
     ) -> (Builder, Client<HttpsConnector<HttpConnector>>) {
-        let mut builder = Request::put(uri);
+        let mut builder = Request::put(uri).header("label", label);
         for (k, v) in &self.header {
             builder = builder.header(k, v);
         }
@@ -178,8 +183,10 @@ impl InserterInnerBuilder {
         (builder, client)
     }
 
-    pub async fn build(&self) -> Result<InserterInner> {
-        let (builder, client) = self.build_request_and_client(self.url.clone());
+    /// Start a new load labeled with `label`. If a load with the same label was already
+    /// committed before, the backend will reject the new one as a duplicate.
+    pub async fn build(&self, label: String) -> Result<InserterInner> {
+        let (builder, client) = self.build_request_and_client(self.url.clone(), &label);
         let request_get_url = builder
             .body(Body::empty())
             .map_err(|err| SinkError::DorisStarrocksConnect(err.into()))?;
@@ -208,7 +215,7 @@ impl InserterInnerBuilder {
             )));
         };
 
-        let (builder, client) = self.build_request_and_client(be_url.to_string());
+        let (builder, client) = self.build_request_and_client(be_url.to_string(), &label);
         let (sender, body) = Body::channel();
         let request = builder
             .body(body)
@@ -235,7 +242,7 @@ impl InserterInnerBuilder {
                 )))
             }
         });
-        Ok(InserterInner::new(sender, handle))
+        Ok(InserterInner::new(sender, handle, self.buffer_size))
     }
 }
 
@@ -243,13 +250,19 @@ pub struct InserterInner {
     sender: Option<Sender>,
     join_handle: Option<JoinHandle<Result<Vec<u8>>>>,
     buffer: BytesMut,
+    buffer_size: usize,
 }
 impl InserterInner {
-    pub fn new(sender: Sender, join_handle: JoinHandle<Result<Vec<u8>>>) -> Self {
+    pub fn new(
+        sender: Sender,
+        join_handle: JoinHandle<Result<Vec<u8>>>,
+        buffer_size: usize,
+    ) -> Self {
         Self {
             sender: Some(sender),
             join_handle: Some(join_handle),
-            buffer: BytesMut::with_capacity(BUFFER_SIZE),
+            buffer: BytesMut::with_capacity(buffer_size),
+            buffer_size,
         }
     }
 
@@ -258,7 +271,7 @@ impl InserterInner {
             return Ok(());
         }
 
-        let chunk = mem::replace(&mut self.buffer, BytesMut::with_capacity(BUFFER_SIZE));
+        let chunk = mem::replace(&mut self.buffer, BytesMut::with_capacity(self.buffer_size));
 
         let is_timed_out = match tokio::time::timeout(
             SEND_CHUNK_TIMEOUT,
@@ -293,7 +306,7 @@ impl InserterInner {
 
     pub async fn write(&mut self, data: Bytes) -> Result<()> {
         self.buffer.put_slice(&data);
-        if self.buffer.len() >= MIN_CHUNK_SIZE {
+        if self.buffer.len() >= self.buffer_size.saturating_sub(MIN_CHUNK_HEADROOM) {
             self.send_chunk().await?;
         }
         Ok(())
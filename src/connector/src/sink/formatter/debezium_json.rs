@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use risingwave_common::array::Op;
@@ -19,7 +20,7 @@ use risingwave_common::catalog::{Field, Schema};
 use serde_json::{json, Map, Value};
 use tracing::warn;
 
-use super::{Result, SinkFormatter, StreamChunk};
+use super::{Result, SinkError, SinkFormatter, StreamChunk};
 use crate::sink::encoder::{
     JsonEncoder, RowEncoder, TimestampHandlingMode, TimestamptzHandlingMode,
 };
@@ -39,6 +40,31 @@ impl Default for DebeziumAdapterOpts {
     }
 }
 
+impl DebeziumAdapterOpts {
+    pub const TOMBSTONE_OPTION_KEY: &'static str = "debezium.tombstone";
+
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let gen_tombstone = match options
+            .get(Self::TOMBSTONE_OPTION_KEY)
+            .map(std::ops::Deref::deref)
+        {
+            Some("true") => true,
+            Some("false") => false,
+            Some(v) => {
+                return Err(SinkError::Config(anyhow::anyhow!(
+                    "unrecognized {} value {}",
+                    Self::TOMBSTONE_OPTION_KEY,
+                    v
+                )))
+            }
+            // Tombstone events after deletes are part of the standard Debezium envelope,
+            // so emit them unless the user opts out.
+            None => true,
+        };
+        Ok(Self { gen_tombstone })
+    }
+}
+
 fn concat_debezium_name_field(db_name: &str, sink_from_name: &str, value: &str) -> String {
     DEBEZIUM_NAME_FIELD_PREFIX.to_owned() + "." + db_name + "." + sink_from_name + "." + value
 }
@@ -107,12 +133,16 @@ impl SinkFormatter for DebeziumJsonFormatter {
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as u64;
+            // ref https://debezium.io/documentation/reference/2.4/connectors/postgresql.html#postgresql-create-events
             let source_field = json!({
-                // todo: still some missing fields in source field
-                // ref https://debezium.io/documentation/reference/2.4/connectors/postgresql.html#postgresql-create-events
+                "version": env!("CARGO_PKG_VERSION"),
+                "connector": "risingwave",
+                "name": db_name,
+                "ts_ms": ts_ms,
+                // All events a sink emits come from the changelog, never from a snapshot.
+                "snapshot": "false",
                 "db": db_name,
                 "table": sink_from_name,
-                "ts_ms": ts_ms,
             });
 
             let mut update_cache: Option<Map<String, Value>> = None;
@@ -215,16 +245,36 @@ pub(crate) fn schema_to_json(schema: &Schema, db_name: &str, sink_from_name: &st
             json!({
                 "type": "string",
                 "optional": false,
-                "field": "db"
+                "field": "version"
             }),
             json!({
                 "type": "string",
-                "optional": true,
-                "field": "table"
+                "optional": false,
+                "field": "connector"
+            }),
+            json!({
+                "type": "string",
+                "optional": false,
+                "field": "name"
             }),
             json!({
                 "type": "int64",
                 "optional": false,
+                "field": "ts_ms"
+            }),
+            json!({
+                "type": "string",
+                "optional": true,
+                "field": "snapshot"
+            }),
+            json!({
+                "type": "string",
+                "optional": false,
+                "field": "db"
+            }),
+            json!({
+                "type": "string",
+                "optional": true,
                 "field": "table"
             }),
         ],
@@ -313,7 +363,7 @@ mod tests {
     use super::*;
     use crate::sink::utils::chunk_to_json;
 
-    const SCHEMA_JSON_RESULT: &str = r#"{"fields":[{"field":"before","fields":[{"field":"v1","optional":true,"type":"int32"},{"field":"v2","optional":true,"type":"float"},{"field":"v3","optional":true,"type":"string"}],"name":"RisingWave.test_db.test_table.Key","optional":true,"type":"struct"},{"field":"after","fields":[{"field":"v1","optional":true,"type":"int32"},{"field":"v2","optional":true,"type":"float"},{"field":"v3","optional":true,"type":"string"}],"name":"RisingWave.test_db.test_table.Key","optional":true,"type":"struct"},{"field":"source","fields":[{"field":"db","optional":false,"type":"string"},{"field":"table","optional":true,"type":"string"},{"field":"table","optional":false,"type":"int64"}],"name":"RisingWave.test_db.test_table.Source","optional":false,"type":"struct"},{"field":"op","optional":false,"type":"string"},{"field":"ts_ms","optional":false,"type":"int64"}],"name":"RisingWave.test_db.test_table.Envelope","optional":false,"type":"struct"}"#;
+    const SCHEMA_JSON_RESULT: &str = r#"{"fields":[{"field":"before","fields":[{"field":"v1","optional":true,"type":"int32"},{"field":"v2","optional":true,"type":"float"},{"field":"v3","optional":true,"type":"string"}],"name":"RisingWave.test_db.test_table.Key","optional":true,"type":"struct"},{"field":"after","fields":[{"field":"v1","optional":true,"type":"int32"},{"field":"v2","optional":true,"type":"float"},{"field":"v3","optional":true,"type":"string"}],"name":"RisingWave.test_db.test_table.Key","optional":true,"type":"struct"},{"field":"source","fields":[{"field":"version","optional":false,"type":"string"},{"field":"connector","optional":false,"type":"string"},{"field":"name","optional":false,"type":"string"},{"field":"ts_ms","optional":false,"type":"int64"},{"field":"snapshot","optional":true,"type":"string"},{"field":"db","optional":false,"type":"string"},{"field":"table","optional":true,"type":"string"}],"name":"RisingWave.test_db.test_table.Source","optional":false,"type":"struct"},{"field":"op","optional":false,"type":"string"},{"field":"ts_ms","optional":false,"type":"int64"}],"name":"RisingWave.test_db.test_table.Envelope","optional":false,"type":"struct"}"#;
 
     #[test]
     fn test_chunk_to_json() -> Result<()> {
@@ -116,11 +116,20 @@ impl SinkFormatterImpl {
                         Ok(SinkFormatterImpl::AppendOnlyJson(formatter))
                     }
                     SinkEncode::Protobuf => {
-                        // By passing `None` as `aws_auth_props`, reading from `s3://` not supported yet.
-                        let descriptor =
-                            crate::schema::protobuf::fetch_descriptor(&format_desc.options, None)
-                                .await
-                                .map_err(|e| SinkError::Config(anyhow!("{e:?}")))?;
+                        // AWS auth props are extracted from the format options to support
+                        // reading the schema file from `s3://`.
+                        let aws_auth_props = crate::aws_auth::AwsAuthProps::from_pairs(
+                            format_desc
+                                .options
+                                .iter()
+                                .map(|(k, v)| (k.as_str(), v.as_str())),
+                        );
+                        let descriptor = crate::schema::protobuf::fetch_descriptor(
+                            &format_desc.options,
+                            Some(&aws_auth_props),
+                        )
+                        .await
+                        .map_err(|e| SinkError::Config(anyhow!("{e:?}")))?;
                         let val_encoder = ProtoEncoder::new(schema, None, descriptor)?;
                         let formatter = AppendOnlyFormatter::new(key_encoder, val_encoder);
                         Ok(SinkFormatterImpl::AppendOnlyProto(formatter))
@@ -160,7 +169,7 @@ impl SinkFormatterImpl {
                     pk_indices,
                     db_name,
                     sink_from_name,
-                    DebeziumAdapterOpts::default(),
+                    DebeziumAdapterOpts::from_options(&format_desc.options)?,
                 )))
             }
             SinkFormat::Upsert => {
@@ -240,6 +240,21 @@ impl IcebergConfig {
 
         Ok(iceberg_configs)
     }
+
+    /// Load the table from the configured catalog. Shared by the iceberg sink and source.
+    pub async fn load_table(&self) -> Result<Table> {
+        let catalog = load_catalog(&self.build_iceberg_configs()?)
+            .await
+            .map_err(|e| SinkError::Iceberg(anyhow!("Unable to load iceberg catalog: {e}")))?;
+
+        let table_id = TableIdentifier::new(self.table_name.split('.'))
+            .map_err(|e| SinkError::Iceberg(anyhow!("Unable to parse table name: {e}")))?;
+
+        catalog
+            .load_table(&table_id)
+            .await
+            .map_err(|err| SinkError::Iceberg(anyhow!(err)))
+    }
 }
 
 pub struct IcebergSink {
@@ -268,17 +283,7 @@ impl Debug for IcebergSink {
 
 impl IcebergSink {
     async fn create_table(&self) -> Result<Table> {
-        let catalog = load_catalog(&self.config.build_iceberg_configs()?)
-            .await
-            .map_err(|e| SinkError::Iceberg(anyhow!("Unable to load iceberg catalog: {e}")))?;
-
-        let table_id = TableIdentifier::new(self.config.table_name.split('.'))
-            .map_err(|e| SinkError::Iceberg(anyhow!("Unable to parse table name: {e}")))?;
-
-        let table = catalog
-            .load_table(&table_id)
-            .await
-            .map_err(|err| SinkError::Iceberg(anyhow!(err)))?;
+        let table = self.config.load_table().await?;
 
         let sink_schema = self.param.schema();
         let iceberg_schema = table
@@ -361,7 +366,11 @@ impl Sink for IcebergSink {
             inner,
         )
         .await?
-        .into_log_sinker(writer_param.sink_metrics))
+        .into_log_sinker(
+            writer_param.sink_metrics,
+            writer_param.error_policy,
+            writer_param.throttle,
+        ))
     }
 
     async fn new_coordinator(&self) -> Result<Self::Coordinator> {
@@ -12,33 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::anyhow;
+use async_trait::async_trait;
+use futures::future::try_join_all;
 use futures::{Future, FutureExt, TryFuture};
 use rdkafka::error::KafkaError;
 use rdkafka::message::ToBytes;
-use rdkafka::producer::{DeliveryFuture, FutureProducer, FutureRecord};
+use rdkafka::producer::{DeliveryFuture, FutureProducer, FutureRecord, Producer};
 use rdkafka::types::RDKafkaErrorCode;
 use rdkafka::ClientConfig;
-use risingwave_common::array::StreamChunk;
+use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::catalog::Schema;
+use risingwave_common::row::Row;
+use risingwave_common::util::hash_util::Crc32FastBuilder;
 use serde_derive::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use strum_macros::{Display, EnumString};
 use with_options::WithOptions;
 
-use super::catalog::{SinkFormat, SinkFormatDesc};
-use super::{Sink, SinkError, SinkParam};
+use super::catalog::{SinkFormat, SinkFormatDesc, SinkId};
+use super::{LogSinker, Sink, SinkError, SinkParam};
 use crate::common::KafkaCommon;
 use crate::sink::catalog::desc::SinkDesc;
 use crate::sink::formatter::SinkFormatterImpl;
-use crate::sink::log_store::DeliveryFutureManagerAddFuture;
+use crate::sink::log_store::{DeliveryFutureManagerAddFuture, LogReader};
 use crate::sink::writer::{
     AsyncTruncateLogSinkerOf, AsyncTruncateSinkWriter, AsyncTruncateSinkWriterExt, FormattedSink,
+    LogSinkerOf, SinkWriter, SinkWriterExt,
 };
 use crate::sink::{DummySinkCommitCoordinator, Result, SinkWriterParam};
 use crate::source::kafka::{KafkaProperties, KafkaSplitEnumerator, PrivateLinkProducerContext};
@@ -75,6 +80,22 @@ enum CompressionCodec {
     Zstd,
 }
 
+/// Strategy used to assign each message a topic partition.
+#[derive(Debug, Clone, PartialEq, Display, Serialize, Deserialize, EnumString)]
+#[strum(serialize_all = "snake_case")]
+enum PartitionStrategy {
+    /// Hash the message key with librdkafka's `murmur2_random` partitioner, which is compatible
+    /// with the default partitioner of the Java producer. Keyless messages are assigned a random
+    /// partition.
+    KeyHash,
+    /// Cycle through all partitions regardless of the message key, so that skewed keys and
+    /// keyless topics are spread evenly. Only allowed for append-only sinks.
+    RoundRobin,
+    /// Hash the value of the column specified by `partition_column` instead of the message key.
+    /// Only allowed for append-only sinks.
+    Column,
+}
+
 /// See <https://github.com/confluentinc/librdkafka/blob/master/CONFIGURATION.md>
 /// for the detailed meaning of these librdkafka producer properties
 #[serde_as]
@@ -138,6 +159,14 @@ pub struct RdKafkaPropertiesProducer {
     #[serde_as(as = "Option<DisplayFromStr>")]
     batch_size: Option<usize>,
 
+    /// Delay in milliseconds to wait to assign new sticky partitions for each topic. By default,
+    /// set to double the time of `queue.buffering.max.ms`. To disable sticky behavior, set to 0.
+    /// Sticky partitioning improves batching for messages without a key by producing them to a
+    /// single partition until the linger time expires.
+    #[serde(rename = "properties.sticky.partitioning.linger.ms")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    sticky_partitioning_linger_ms: Option<usize>,
+
     /// Compression codec to use for compressing message sets.
     #[serde(rename = "properties.compression.codec")]
     #[serde_as(as = "Option<DisplayFromStr>")]
@@ -191,6 +220,9 @@ impl RdKafkaPropertiesProducer {
         if let Some(v) = self.batch_size {
             c.set("batch.size", v.to_string());
         }
+        if let Some(v) = self.sticky_partitioning_linger_ms {
+            c.set("sticky.partitioning.linger.ms", v.to_string());
+        }
         if let Some(v) = &self.compression_codec {
             c.set("compression.codec", v.to_string());
         }
@@ -227,6 +259,24 @@ pub struct KafkaConfig {
     /// as a string.
     pub primary_key: Option<String>,
 
+    /// Strategy used to assign messages to topic partitions: `key_hash` (default), `round_robin`
+    /// or `column`.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    partition_strategy: Option<PartitionStrategy>,
+
+    /// Column whose value is hashed to pick the partition, instead of the message key.
+    /// Required (and only valid) when `partition_strategy = 'column'`.
+    partition_column: Option<String>,
+
+    /// Use a transactional producer and commit one Kafka transaction per checkpoint, so that
+    /// consumers reading with `isolation.level = 'read_committed'` see each checkpoint's rows
+    /// atomically, at the cost of increased end-to-end latency. Note that delivery remains
+    /// at-least-once: the transaction is committed when the checkpoint barrier is collected,
+    /// before the checkpoint itself is durably committed, so an epoch replayed after a failure
+    /// in between re-produces its rows in a new transaction.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub transactional: Option<bool>,
+
     #[serde(flatten)]
     pub rdkafka_properties: RdKafkaPropertiesProducer,
 }
@@ -239,10 +289,18 @@ impl KafkaConfig {
         Ok(config)
     }
 
+    fn is_transactional(&self) -> bool {
+        self.transactional.unwrap_or(false)
+    }
+
     pub(crate) fn set_client(&self, c: &mut rdkafka::ClientConfig) {
         self.common.set_client(c);
         self.rdkafka_properties.set_client(c);
 
+        if let Some(PartitionStrategy::KeyHash) = self.partition_strategy {
+            c.set("partitioner", "murmur2_random");
+        }
+
         tracing::info!("kafka client starts with: {:?}", c);
     }
 }
@@ -265,6 +323,7 @@ impl From<KafkaConfig> for KafkaProperties {
 #[derive(Debug)]
 pub struct KafkaSink {
     pub config: KafkaConfig,
+    sink_id: SinkId,
     schema: Schema,
     pk_indices: Vec<usize>,
     format_desc: SinkFormatDesc,
@@ -280,6 +339,7 @@ impl TryFrom<SinkParam> for KafkaSink {
         let config = KafkaConfig::from_hashmap(param.properties)?;
         Ok(Self {
             config,
+            sink_id: param.sink_id,
             schema,
             pk_indices: param.downstream_pk,
             format_desc: param
@@ -291,17 +351,114 @@ impl TryFrom<SinkParam> for KafkaSink {
     }
 }
 
+/// Client-side partition assignment for strategies that librdkafka cannot express.
+///
+/// Partitions are assigned per *row*, so these strategies only apply to append-only sinks, where
+/// [`AppendOnlyFormatter`](crate::sink::formatter::AppendOnlyFormatter) emits exactly one message
+/// per inserted row.
+enum KafkaPartitioner {
+    RoundRobin { num_partitions: i32, next: i32 },
+    Column { col_index: usize, num_partitions: i32 },
+}
+
+impl KafkaPartitioner {
+    /// Assigns a partition to each message that will be produced for `chunk`, following the
+    /// produce order of [`AppendOnlyFormatter`](crate::sink::formatter::AppendOnlyFormatter):
+    /// one message per inserted row.
+    fn assign_partitions(&mut self, chunk: &StreamChunk) -> VecDeque<i32> {
+        let mut partitions = VecDeque::with_capacity(chunk.cardinality());
+        for (op, row) in chunk.rows() {
+            if op != Op::Insert {
+                continue;
+            }
+            let partition = match self {
+                KafkaPartitioner::RoundRobin {
+                    num_partitions,
+                    next,
+                } => {
+                    let partition = *next;
+                    *next = (*next + 1) % *num_partitions;
+                    partition
+                }
+                KafkaPartitioner::Column {
+                    col_index,
+                    num_partitions,
+                } => {
+                    let hash = row.project(&[*col_index]).hash(Crc32FastBuilder).value();
+                    (hash % *num_partitions as u64) as i32
+                }
+            };
+            partitions.push_back(partition);
+        }
+        partitions
+    }
+}
+
+impl KafkaSink {
+    async fn fetch_partition_num(&self) -> Result<i32> {
+        let mut enumerator = KafkaSplitEnumerator::new(
+            KafkaProperties::from(self.config.clone()),
+            Arc::new(SourceEnumeratorContext::default()),
+        )
+        .await?;
+        let num_partitions = enumerator.list_splits().await?.len();
+        if num_partitions == 0 {
+            return Err(SinkError::Config(anyhow!(
+                "topic {} has no partitions",
+                self.config.common.topic
+            )));
+        }
+        Ok(num_partitions as i32)
+    }
+
+    async fn build_partitioner(&self) -> Result<Option<KafkaPartitioner>> {
+        match self.config.partition_strategy {
+            // `key_hash` is handled by librdkafka's own partitioner.
+            None | Some(PartitionStrategy::KeyHash) => Ok(None),
+            Some(PartitionStrategy::RoundRobin) => Ok(Some(KafkaPartitioner::RoundRobin {
+                num_partitions: self.fetch_partition_num().await?,
+                next: 0,
+            })),
+            Some(PartitionStrategy::Column) => {
+                let col_name = self.config.partition_column.as_ref().ok_or_else(|| {
+                    SinkError::Config(anyhow!(
+                        "`partition_column` must be specified when `partition_strategy` is 'column'"
+                    ))
+                })?;
+                let col_index = self
+                    .schema
+                    .fields()
+                    .iter()
+                    .position(|f| &f.name == col_name)
+                    .ok_or_else(|| {
+                        SinkError::Config(anyhow!(
+                            "partition_column {} not found in sink schema",
+                            col_name
+                        ))
+                    })?;
+                Ok(Some(KafkaPartitioner::Column {
+                    col_index,
+                    num_partitions: self.fetch_partition_num().await?,
+                }))
+            }
+        }
+    }
+}
+
 impl Sink for KafkaSink {
     type Coordinator = DummySinkCommitCoordinator;
-    type LogSinker = AsyncTruncateLogSinkerOf<KafkaSinkWriter>;
+    type LogSinker = KafkaLogSinker;
 
     const SINK_NAME: &'static str = KAFKA_SINK;
 
     fn default_sink_decouple(desc: &SinkDesc) -> bool {
+        // A transactional sink commits at every checkpoint barrier, so there is nothing to
+        // gain from decoupling it.
         desc.sink_type.is_append_only()
+            && desc.properties.get("transactional").map(|s| s.as_str()) != Some("true")
     }
 
-    async fn new_log_sinker(&self, _writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
+    async fn new_log_sinker(&self, writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
         let formatter = SinkFormatterImpl::new(
             &self.format_desc,
             self.schema.clone(),
@@ -311,18 +468,44 @@ impl Sink for KafkaSink {
             &self.config.common.topic,
         )
         .await?;
-        let max_delivery_buffer_size = (self
-            .config
-            .rdkafka_properties
-            .queue_buffering_max_messages
-            .as_ref()
-            .cloned()
-            .unwrap_or(KAFKA_WRITER_MAX_QUEUE_SIZE) as f32
-            * KAFKA_WRITER_MAX_QUEUE_SIZE_RATIO) as usize;
-
-        Ok(KafkaSinkWriter::new(self.config.clone(), formatter)
-            .await?
-            .into_log_sinker(max_delivery_buffer_size))
+
+        let partitioner = self.build_partitioner().await?;
+
+        if self.config.is_transactional() {
+            // The transactional id must be stable across recoveries and unique among the
+            // parallel writers of this sink, so that re-initialization fences the previous
+            // incarnation of the same writer and aborts the transaction it may have left open.
+            let transactional_id = format!(
+                "rw-sink-{}-{}",
+                self.sink_id.sink_id, writer_param.executor_id
+            );
+            let writer = KafkaTransactionalSinkWriter::new(
+                self.config.clone(),
+                formatter,
+                partitioner,
+                transactional_id,
+            )
+            .await?;
+            Ok(KafkaLogSinker::Transactional(writer.into_log_sinker(
+                writer_param.sink_metrics,
+                writer_param.error_policy,
+                writer_param.throttle,
+            )))
+        } else {
+            let max_delivery_buffer_size = (self
+                .config
+                .rdkafka_properties
+                .queue_buffering_max_messages
+                .as_ref()
+                .cloned()
+                .unwrap_or(KAFKA_WRITER_MAX_QUEUE_SIZE) as f32
+                * KAFKA_WRITER_MAX_QUEUE_SIZE_RATIO) as usize;
+
+            let writer = KafkaSinkWriter::new(self.config.clone(), formatter, partitioner).await?;
+            Ok(KafkaLogSinker::NonTransactional(
+                writer.into_log_sinker(max_delivery_buffer_size, writer_param.throttle),
+            ))
+        }
     }
 
     async fn validate(&self) -> Result<()> {
@@ -333,6 +516,27 @@ impl Sink for KafkaSink {
                 self.format_desc.format
             )));
         }
+        // Row-level partition assignment is only well-defined when each row maps to exactly one
+        // message, i.e. for append-only sinks.
+        if matches!(
+            self.config.partition_strategy,
+            Some(PartitionStrategy::RoundRobin | PartitionStrategy::Column)
+        ) && self.format_desc.format != SinkFormat::AppendOnly
+        {
+            return Err(SinkError::Config(anyhow!(
+                "partition_strategy '{}' is only supported for append-only kafka sinks",
+                self.config.partition_strategy.as_ref().unwrap()
+            )));
+        }
+        if self.config.partition_column.is_some()
+            && self.config.partition_strategy != Some(PartitionStrategy::Column)
+        {
+            return Err(SinkError::Config(anyhow!(
+                "`partition_column` requires `partition_strategy = 'column'`"
+            )));
+        }
+        // Check that `partition_column` resolves to a column of the sink schema.
+        self.build_partitioner().await?;
         // Check for formatter constructor error, before it is too late for error reporting.
         SinkFormatterImpl::new(
             &self.format_desc,
@@ -375,6 +579,9 @@ struct KafkaPayloadWriter<'a> {
     inner: &'a FutureProducer<PrivateLinkProducerContext>,
     add_future: DeliveryFutureManagerAddFuture<'a, KafkaSinkDeliveryFuture>,
     config: &'a KafkaConfig,
+    /// Explicit partition for each message of the current chunk, in produce order.
+    /// `None` to let librdkafka's partitioner decide.
+    partitions: Option<VecDeque<i32>>,
 }
 
 pub type KafkaSinkDeliveryFuture = impl TryFuture<Ok = (), Error = SinkError> + Unpin + 'static;
@@ -383,10 +590,15 @@ pub struct KafkaSinkWriter {
     formatter: SinkFormatterImpl,
     inner: FutureProducer<PrivateLinkProducerContext>,
     config: KafkaConfig,
+    partitioner: Option<KafkaPartitioner>,
 }
 
 impl KafkaSinkWriter {
-    async fn new(config: KafkaConfig, formatter: SinkFormatterImpl) -> Result<Self> {
+    async fn new(
+        config: KafkaConfig,
+        formatter: SinkFormatterImpl,
+        partitioner: Option<KafkaPartitioner>,
+    ) -> Result<Self> {
         let inner: FutureProducer<PrivateLinkProducerContext> = {
             let mut c = ClientConfig::new();
 
@@ -413,8 +625,10 @@ impl KafkaSinkWriter {
             formatter,
             inner,
             config: config.clone(),
+            partitioner,
         })
     }
+
 }
 
 impl AsyncTruncateSinkWriter for KafkaSinkWriter {
@@ -425,10 +639,15 @@ impl AsyncTruncateSinkWriter for KafkaSinkWriter {
         chunk: StreamChunk,
         add_future: DeliveryFutureManagerAddFuture<'a, Self::DeliveryFuture>,
     ) -> Result<()> {
+        let partitions = self
+            .partitioner
+            .as_mut()
+            .map(|p| p.assign_partitions(&chunk));
         let mut payload_writer = KafkaPayloadWriter {
             inner: &mut self.inner,
             add_future,
             config: &self.config,
+            partitions,
         };
         dispatch_sink_formatter_impl!(&self.formatter, formatter, {
             payload_writer.write_chunk(chunk, formatter).await
@@ -508,6 +727,9 @@ impl<'w> KafkaPayloadWriter<'w> {
     ) -> Result<()> {
         let topic = self.config.common.topic.clone();
         let mut record = FutureRecord::<[u8], [u8]>::to(topic.as_str());
+        if let Some(partition) = self.partitions.as_mut().and_then(|p| p.pop_front()) {
+            record = record.partition(partition);
+        }
         if let Some(key_str) = &event_key_object {
             record = record.key(key_str);
         }
@@ -553,6 +775,208 @@ impl<'a> FormattedSink for KafkaPayloadWriter<'a> {
     }
 }
 
+pub enum KafkaLogSinker {
+    /// At-least-once delivery: produce in the background and truncate the log store as the
+    /// delivery callbacks resolve.
+    NonTransactional(AsyncTruncateLogSinkerOf<KafkaSinkWriter>),
+    /// At-least-once delivery with atomic per-checkpoint visibility: wrap each checkpoint
+    /// epoch in a producer transaction.
+    Transactional(LogSinkerOf<KafkaTransactionalSinkWriter>),
+}
+
+#[async_trait]
+impl LogSinker for KafkaLogSinker {
+    async fn consume_log_and_sink(self, log_reader: impl LogReader) -> Result<()> {
+        match self {
+            KafkaLogSinker::NonTransactional(inner) => {
+                inner.consume_log_and_sink(log_reader).await
+            }
+            KafkaLogSinker::Transactional(inner) => inner.consume_log_and_sink(log_reader).await,
+        }
+    }
+}
+
+/// Timeout for the transaction control requests (`init`, `commit` and `abort`) to the broker.
+/// These requests are issued after all messages of the transaction have been acknowledged, so
+/// they only take a protocol round trip.
+const KAFKA_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct KafkaTransactionalSinkWriter {
+    formatter: SinkFormatterImpl,
+    inner: FutureProducer<PrivateLinkProducerContext>,
+    config: KafkaConfig,
+    partitioner: Option<KafkaPartitioner>,
+    /// Delivery futures of the messages produced in the current transaction. All of them must
+    /// resolve successfully before the transaction is committed.
+    delivery_futures: Vec<KafkaSinkDeliveryFuture>,
+    /// Whether a transaction is currently open. A transaction spans all epochs between two
+    /// checkpoints, so this is not reset on non-checkpoint barriers.
+    in_transaction: bool,
+}
+
+impl KafkaTransactionalSinkWriter {
+    async fn new(
+        config: KafkaConfig,
+        formatter: SinkFormatterImpl,
+        partitioner: Option<KafkaPartitioner>,
+        transactional_id: String,
+    ) -> Result<Self> {
+        let inner: FutureProducer<PrivateLinkProducerContext> = {
+            let mut c = ClientConfig::new();
+
+            // KafkaConfig configuration
+            config.common.set_security_properties(&mut c);
+            config.set_client(&mut c);
+
+            // ClientConfig configuration
+            c.set("bootstrap.servers", &config.common.brokers);
+            // Transactions imply an idempotent producer.
+            c.set("enable.idempotence", "true");
+            c.set("transactional.id", transactional_id);
+
+            let producer_ctx = PrivateLinkProducerContext::new(
+                config.common.broker_rewrite_map.clone(),
+                // fixme: enable kafka native metrics for sink
+                None,
+                None,
+            )?;
+
+            c.create_with_context(producer_ctx).await?
+        };
+
+        // Register the transactional id with the broker. This also fences the previous
+        // incarnation of this writer after a recovery and aborts the transaction it may have
+        // left open, so that replayed data is never observed twice by read-committed consumers.
+        inner.init_transactions(KAFKA_TRANSACTION_TIMEOUT).await?;
+
+        Ok(KafkaTransactionalSinkWriter {
+            formatter,
+            inner,
+            config,
+            partitioner,
+            delivery_futures: vec![],
+            in_transaction: false,
+        })
+    }
+}
+
+#[async_trait]
+impl SinkWriter for KafkaTransactionalSinkWriter {
+    async fn begin_epoch(&mut self, _epoch: u64) -> Result<()> {
+        if !self.in_transaction {
+            self.inner.begin_transaction()?;
+            self.in_transaction = true;
+        }
+        Ok(())
+    }
+
+    async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
+        let partitions = self
+            .partitioner
+            .as_mut()
+            .map(|p| p.assign_partitions(&chunk));
+        let mut payload_writer = KafkaTransactionalPayloadWriter {
+            inner: &self.inner,
+            config: &self.config,
+            partitions,
+            delivery_futures: &mut self.delivery_futures,
+        };
+        dispatch_sink_formatter_impl!(&self.formatter, formatter, {
+            payload_writer.write_chunk(chunk, formatter).await
+        })
+    }
+
+    async fn barrier(&mut self, is_checkpoint: bool) -> Result<()> {
+        if is_checkpoint {
+            // All messages of the transaction must be delivered before committing, otherwise an
+            // undelivered message would be silently dropped from the committed transaction.
+            try_join_all(self.delivery_futures.drain(..)).await?;
+            self.inner
+                .commit_transaction(KAFKA_TRANSACTION_TIMEOUT)
+                .await?;
+            self.in_transaction = false;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if self.in_transaction {
+            self.delivery_futures.clear();
+            self.inner
+                .abort_transaction(KAFKA_TRANSACTION_TIMEOUT)
+                .await?;
+            self.in_transaction = false;
+        }
+        Ok(())
+    }
+}
+
+struct KafkaTransactionalPayloadWriter<'a> {
+    inner: &'a FutureProducer<PrivateLinkProducerContext>,
+    config: &'a KafkaConfig,
+    /// Explicit partition for each message of the current chunk, in produce order.
+    /// `None` to let librdkafka's partitioner decide.
+    partitions: Option<VecDeque<i32>>,
+    delivery_futures: &'a mut Vec<KafkaSinkDeliveryFuture>,
+}
+
+impl<'w> KafkaTransactionalPayloadWriter<'w> {
+    async fn send_result<'a, K, P>(&'a mut self, mut record: FutureRecord<'a, K, P>) -> Result<()>
+    where
+        K: ToBytes + ?Sized,
+        P: ToBytes + ?Sized,
+    {
+        for i in 0..self.config.max_retry_num {
+            match self.inner.send_result(record) {
+                Ok(delivery_future) => {
+                    self.delivery_futures
+                        .push(KafkaPayloadWriter::map_delivery_future(delivery_future));
+                    return Ok(());
+                }
+                Err((e, rec)) => {
+                    record = rec;
+                    match e {
+                        KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull) => {
+                            // Unlike the non-transactional writer, there is no delivery future
+                            // manager to await here, so back off until librdkafka has drained
+                            // part of its queue.
+                            tracing::warn!(
+                                "Producer queue full. Await and retry #{}",
+                                i
+                            );
+                            tokio::time::sleep(self.config.retry_interval).await;
+                            continue;
+                        }
+                        _ => return Err(e.into()),
+                    }
+                }
+            }
+        }
+
+        Err(KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull).into())
+    }
+}
+
+impl<'a> FormattedSink for KafkaTransactionalPayloadWriter<'a> {
+    type K = Vec<u8>;
+    type V = Vec<u8>;
+
+    async fn write_one(&mut self, k: Option<Self::K>, v: Option<Self::V>) -> Result<()> {
+        let topic = self.config.common.topic.clone();
+        let mut record = FutureRecord::<[u8], [u8]>::to(topic.as_str());
+        if let Some(partition) = self.partitions.as_mut().and_then(|p| p.pop_front()) {
+            record = record.partition(partition);
+        }
+        if let Some(key_str) = &k {
+            record = record.key(key_str);
+        }
+        if let Some(payload) = &v {
+            record = record.payload(payload);
+        }
+        self.send_result(record).await
+    }
+}
+
 #[cfg(test)]
 mod test {
     use maplit::hashmap;
@@ -583,6 +1007,7 @@ mod test {
             "properties.retry.backoff.ms".to_string() => "114514".to_string(),
             "properties.batch.num.messages".to_string() => "114514".to_string(),
             "properties.batch.size".to_string() => "114514".to_string(),
+            "properties.sticky.partitioning.linger.ms".to_string() => "114514".to_string(),
             "properties.compression.codec".to_string() => "zstd".to_string(),
             "properties.message.timeout.ms".to_string() => "114514".to_string(),
             "properties.max.in.flight.requests.per.connection".to_string() => "114514".to_string(),
@@ -596,6 +1021,10 @@ mod test {
             c.rdkafka_properties.compression_codec,
             Some(CompressionCodec::Zstd)
         );
+        assert_eq!(
+            c.rdkafka_properties.sticky_partitioning_linger_ms,
+            Some(114514)
+        );
         assert_eq!(c.rdkafka_properties.message_timeout_ms, 114514);
         assert_eq!(
             c.rdkafka_properties.max_in_flight_requests_per_connection,
@@ -648,12 +1077,19 @@ mod test {
             "properties.sasl.password".to_string() => "test".to_string(),
             "properties.retry.max".to_string() => "20".to_string(),
             "properties.retry.interval".to_string() => "500ms".to_string(),
+            "partition_strategy".to_string() => "round_robin".to_string(),
+            "transactional".to_string() => "true".to_string(),
         };
         let config = KafkaConfig::from_hashmap(properties).unwrap();
         assert_eq!(config.common.brokers, "localhost:9092");
         assert_eq!(config.common.topic, "test");
         assert_eq!(config.max_retry_num, 20);
         assert_eq!(config.retry_interval, Duration::from_millis(500));
+        assert_eq!(
+            config.partition_strategy,
+            Some(PartitionStrategy::RoundRobin)
+        );
+        assert!(config.is_transactional());
 
         // Optional fields eliminated.
         let properties: HashMap<String, String> = hashmap! {
@@ -732,6 +1168,7 @@ mod test {
                     TimestamptzHandlingMode::UtcString,
                 ),
             )),
+            None,
         )
         .await
         .unwrap();
@@ -747,6 +1184,7 @@ mod test {
                     inner: &sink.inner,
                     add_future: future_manager.start_write_chunk(i, j),
                     config: &sink.config,
+                    partitions: None,
                 };
                 match writer
                     .send_result(
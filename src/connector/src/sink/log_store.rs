@@ -24,6 +24,7 @@ use futures::{TryFuture, TryFutureExt};
 use risingwave_common::array::StreamChunk;
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::util::epoch::{EpochPair, INVALID_EPOCH};
+use tokio::sync::watch;
 
 use crate::sink::SinkMetrics;
 
@@ -198,6 +199,33 @@ impl<F: Fn(StreamChunk) -> StreamChunk + Send + 'static, R: LogReader> LogReader
     }
 }
 
+pub struct PausableLogReader<R: LogReader> {
+    inner: R,
+    paused: watch::Receiver<bool>,
+}
+
+impl<R: LogReader> LogReader for PausableLogReader<R> {
+    async fn init(&mut self) -> LogStoreResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next_item(&mut self) -> LogStoreResult<(u64, LogStoreReadItem)> {
+        // Hold back the next item while delivery is paused. `wait_for` is cancellation safe,
+        // so the cancellation safety of `next_item` is preserved.
+        if *self.paused.borrow() {
+            self.paused
+                .wait_for(|paused| !*paused)
+                .await
+                .map_err(|_| anyhow!("pause state sender dropped"))?;
+        }
+        self.inner.next_item().await
+    }
+
+    async fn truncate(&mut self, offset: TruncateOffset) -> LogStoreResult<()> {
+        self.inner.truncate(offset).await
+    }
+}
+
 pub struct MonitoredLogReader<R: LogReader> {
     inner: R,
     read_epoch: u64,
@@ -240,6 +268,15 @@ where
         TransformChunkLogReader { f, inner: self }
     }
 
+    /// Hold back items while the value in `paused` is `true`, so that nothing is delivered to
+    /// the external system until the sink is resumed.
+    pub fn pausable(self, paused: watch::Receiver<bool>) -> PausableLogReader<T> {
+        PausableLogReader {
+            inner: self,
+            paused,
+        }
+    }
+
     pub fn monitored(self, metrics: SinkMetrics) -> MonitoredLogReader<T> {
         MonitoredLogReader {
             read_epoch: INVALID_EPOCH,
@@ -36,6 +36,7 @@ pub mod utils;
 pub mod writer;
 
 use std::collections::HashMap;
+use std::num::{NonZeroU32, NonZeroUsize};
 
 use ::clickhouse::error::Error as ClickHouseError;
 use ::redis::RedisError;
@@ -132,6 +133,123 @@ pub const SINK_TYPE_APPEND_ONLY: &str = "append-only";
 pub const SINK_TYPE_DEBEZIUM: &str = "debezium";
 pub const SINK_TYPE_UPSERT: &str = "upsert";
 pub const SINK_USER_FORCE_APPEND_ONLY_OPTION: &str = "force_append_only";
+pub const SINK_ON_ERROR_OPTION: &str = "on_error";
+pub const SINK_ON_ERROR_RETRY: &str = "retry";
+pub const SINK_ON_ERROR_SKIP: &str = "skip";
+pub const SINK_RATE_LIMIT_OPTION: &str = "sink_rate_limit";
+pub const SINK_MAX_CONCURRENCY_OPTION: &str = "sink_max_concurrency";
+pub const SINK_SCHEMA_CHANGE_OPTION: &str = "schema.change.policy";
+pub const SINK_SCHEMA_CHANGE_PERMIT: &str = "permit";
+pub const SINK_SCHEMA_CHANGE_BLOCK: &str = "block";
+
+/// How the sink writer handles a chunk that fails to be written to the external system.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SinkErrorPolicy {
+    /// Propagate the error, so that the sink is restarted and retried from the last
+    /// checkpoint. A poison record will block the sink forever.
+    #[default]
+    Retry,
+    /// Log the error and drop the failed chunk, then continue with the next one.
+    Skip,
+}
+
+impl SinkErrorPolicy {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self> {
+        match properties.get(SINK_ON_ERROR_OPTION).map(|s| s.as_str()) {
+            None => Ok(Self::default()),
+            Some(s) if s.eq_ignore_ascii_case(SINK_ON_ERROR_RETRY) => Ok(Self::Retry),
+            Some(s) if s.eq_ignore_ascii_case(SINK_ON_ERROR_SKIP) => Ok(Self::Skip),
+            Some(other) => Err(SinkError::Config(anyhow!(
+                "`{}` must be {} or {}: {}",
+                SINK_ON_ERROR_OPTION,
+                SINK_ON_ERROR_RETRY,
+                SINK_ON_ERROR_SKIP,
+                other
+            ))),
+        }
+    }
+}
+
+/// How a sink reacts to a schema change on the table it is created from, parsed from the
+/// `schema.change.policy` WITH option.
+///
+/// A sink keeps emitting the column set captured at `CREATE SINK` time, so a schema change
+/// on the upstream table is never propagated to the downstream system.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SinkSchemaChangePolicy {
+    /// Let the schema change proceed; the sink keeps its original schema.
+    #[default]
+    Permit,
+    /// Reject `ALTER TABLE` on the upstream table while this sink exists.
+    Block,
+}
+
+impl SinkSchemaChangePolicy {
+    pub fn from_option(value: Option<&str>) -> Result<Self> {
+        match value {
+            None => Ok(Self::default()),
+            Some(s) if s.eq_ignore_ascii_case(SINK_SCHEMA_CHANGE_PERMIT) => Ok(Self::Permit),
+            Some(s) if s.eq_ignore_ascii_case(SINK_SCHEMA_CHANGE_BLOCK) => Ok(Self::Block),
+            Some(other) => Err(SinkError::Config(anyhow!(
+                "`{}` must be {} or {}: {}",
+                SINK_SCHEMA_CHANGE_OPTION,
+                SINK_SCHEMA_CHANGE_PERMIT,
+                SINK_SCHEMA_CHANGE_BLOCK,
+                other
+            ))),
+        }
+    }
+
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self> {
+        Self::from_option(properties.get(SINK_SCHEMA_CHANGE_OPTION).map(|s| s.as_str()))
+    }
+}
+
+/// Throttling of a sink writer towards the external system, parsed from the
+/// `sink_rate_limit` and `sink_max_concurrency` WITH options at creation time. Unset
+/// options leave the corresponding dimension unthrottled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SinkThrottle {
+    /// Maximum rows per second written to the external system, enforced with a token
+    /// bucket.
+    pub rate_limit: Option<NonZeroU32>,
+    /// Maximum number of concurrent in-flight requests to the external system, for sinks
+    /// that deliver asynchronously.
+    pub max_concurrency: Option<NonZeroUsize>,
+}
+
+impl SinkThrottle {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self> {
+        let rate_limit = properties
+            .get(SINK_RATE_LIMIT_OPTION)
+            .map(|v| {
+                v.parse::<NonZeroU32>().map_err(|_| {
+                    SinkError::Config(anyhow!(
+                        "`{}` must be a positive integer: {}",
+                        SINK_RATE_LIMIT_OPTION,
+                        v
+                    ))
+                })
+            })
+            .transpose()?;
+        let max_concurrency = properties
+            .get(SINK_MAX_CONCURRENCY_OPTION)
+            .map(|v| {
+                v.parse::<NonZeroUsize>().map_err(|_| {
+                    SinkError::Config(anyhow!(
+                        "`{}` must be a positive integer: {}",
+                        SINK_MAX_CONCURRENCY_OPTION,
+                        v
+                    ))
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            rate_limit,
+            max_concurrency,
+        })
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SinkParam {
@@ -255,6 +373,8 @@ pub struct SinkWriterParam {
     pub vnode_bitmap: Option<Bitmap>,
     pub meta_client: Option<MetaClient>,
     pub sink_metrics: SinkMetrics,
+    pub error_policy: SinkErrorPolicy,
+    pub throttle: SinkThrottle,
 }
 
 impl SinkWriterParam {
@@ -265,6 +385,8 @@ impl SinkWriterParam {
             vnode_bitmap: Default::default(),
             meta_client: Default::default(),
             sink_metrics: SinkMetrics::for_test(),
+            error_policy: Default::default(),
+            throttle: Default::default(),
         }
     }
 }
@@ -167,7 +167,7 @@ impl Sink for PulsarSink {
         desc.sink_type.is_append_only()
     }
 
-    async fn new_log_sinker(&self, _writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
+    async fn new_log_sinker(&self, writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
         Ok(PulsarSinkWriter::new(
             self.config.clone(),
             self.schema.clone(),
@@ -177,7 +177,7 @@ impl Sink for PulsarSink {
             self.sink_from_name.clone(),
         )
         .await?
-        .into_log_sinker(PULSAR_SEND_FUTURE_BUFFER_MAX_SIZE))
+        .into_log_sinker(PULSAR_SEND_FUTURE_BUFFER_MAX_SIZE, writer_param.throttle))
     }
 
     async fn validate(&self) -> Result<()> {
@@ -108,7 +108,7 @@ impl Sink for RedisSink {
 
     const SINK_NAME: &'static str = "redis";
 
-    async fn new_log_sinker(&self, _writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
+    async fn new_log_sinker(&self, writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
         Ok(RedisSinkWriter::new(
             self.config.clone(),
             self.schema.clone(),
@@ -118,7 +118,7 @@ impl Sink for RedisSink {
             self.sink_from_name.clone(),
         )
         .await?
-        .into_log_sinker(usize::MAX))
+        .into_log_sinker(usize::MAX, writer_param.throttle))
     }
 
     async fn validate(&self) -> Result<()> {
@@ -486,7 +486,11 @@ impl<R: RemoteSinkTrait> Sink for CoordinatedRemoteSink<R> {
             .await?,
         )
         .await?
-        .into_log_sinker(writer_param.sink_metrics))
+        .into_log_sinker(
+            writer_param.sink_metrics,
+            writer_param.error_policy,
+            writer_param.throttle,
+        ))
     }
 
     async fn new_coordinator(&self) -> Result<Self::Coordinator> {
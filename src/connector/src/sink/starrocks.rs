@@ -28,10 +28,13 @@ use risingwave_common::types::DataType;
 use serde::Deserialize;
 use serde_derive::Serialize;
 use serde_json::Value;
-use serde_with::serde_as;
+use serde_with::{serde_as, DisplayFromStr};
+use with_options::WithOptions;
 
+use super::catalog::SinkId;
 use super::doris_starrocks_connector::{
-    HeaderBuilder, InserterInner, InserterInnerBuilder, DORIS_SUCCESS_STATUS, STARROCKS_DELETE_SIGN,
+    HeaderBuilder, InserterInner, InserterInnerBuilder, DORIS_COMMITTED_JOB_STATUS,
+    DORIS_DUPLICATE_LABEL_STATUS, DORIS_SUCCESS_STATUS, STARROCKS_DELETE_SIGN,
 };
 use super::encoder::{JsonEncoder, RowEncoder, TimestampHandlingMode};
 use super::writer::LogSinkerOf;
@@ -44,7 +47,11 @@ const STARROCK_MYSQL_PREFER_SOCKET: &str = "false";
 const STARROCK_MYSQL_MAX_ALLOWED_PACKET: usize = 1024;
 const STARROCK_MYSQL_WAIT_TIMEOUT: usize = 28800;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+const fn _default_stream_load_buffer_size() -> usize {
+    64 * 1024
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, WithOptions)]
 pub struct StarrocksCommon {
     #[serde(rename = "starrocks.host")]
     pub host: String,
@@ -63,11 +70,20 @@ pub struct StarrocksCommon {
 }
 
 #[serde_as]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, WithOptions)]
 pub struct StarrocksConfig {
     #[serde(flatten)]
     pub common: StarrocksCommon,
 
+    /// The buffer size (in bytes) of a Stream Load task. Rows are accumulated in the buffer
+    /// and flushed to the backend as one HTTP chunk once it fills up.
+    #[serde(
+        rename = "starrocks.buffer_size",
+        default = "_default_stream_load_buffer_size"
+    )]
+    #[serde_as(as = "DisplayFromStr")]
+    pub buffer_size: usize,
+
     pub r#type: String, // accept "append-only" or "upsert"
 }
 impl StarrocksConfig {
@@ -93,6 +109,7 @@ pub struct StarrocksSink {
     schema: Schema,
     pk_indices: Vec<usize>,
     is_append_only: bool,
+    sink_id: SinkId,
 }
 
 impl StarrocksSink {
@@ -101,12 +118,14 @@ impl StarrocksSink {
         schema: Schema,
         pk_indices: Vec<usize>,
         is_append_only: bool,
+        sink_id: SinkId,
     ) -> Result<Self> {
         Ok(Self {
             config,
             schema,
             pk_indices,
             is_append_only,
+            sink_id,
         })
     }
 }
@@ -210,9 +229,14 @@ impl Sink for StarrocksSink {
             self.schema.clone(),
             self.pk_indices.clone(),
             self.is_append_only,
+            format!("rw-sink-{}-{}", self.sink_id, writer_param.executor_id),
         )
         .await?
-        .into_log_sinker(writer_param.sink_metrics))
+        .into_log_sinker(
+            writer_param.sink_metrics,
+            writer_param.error_policy,
+            writer_param.throttle,
+        ))
     }
 
     async fn validate(&self) -> Result<()> {
@@ -262,6 +286,8 @@ pub struct StarrocksSinkWriter {
     is_append_only: bool,
     client: Option<StarrocksClient>,
     row_encoder: JsonEncoder,
+    label_prefix: String,
+    epoch: u64,
 }
 
 impl TryFrom<SinkParam> for StarrocksSink {
@@ -269,12 +295,14 @@ impl TryFrom<SinkParam> for StarrocksSink {
 
     fn try_from(param: SinkParam) -> std::result::Result<Self, Self::Error> {
         let schema = param.schema();
+        let sink_id = param.sink_id;
         let config = StarrocksConfig::from_hashmap(param.properties)?;
         StarrocksSink::new(
             config,
             schema,
             param.downstream_pk,
             param.sink_type.is_append_only(),
+            sink_id,
         )
     }
 }
@@ -285,6 +313,7 @@ impl StarrocksSinkWriter {
         schema: Schema,
         pk_indices: Vec<usize>,
         is_append_only: bool,
+        label_prefix: String,
     ) -> Result<Self> {
         let mut decimal_map = HashMap::default();
         let starrocks_columns = StarrocksSchemaClient::new(
@@ -342,7 +371,8 @@ impl StarrocksSinkWriter {
             config.common.database.clone(),
             config.common.table.clone(),
             header,
-        );
+        )
+        .set_buffer_size(config.buffer_size);
         Ok(Self {
             config,
             schema: schema.clone(),
@@ -356,6 +386,8 @@ impl StarrocksSinkWriter {
                 TimestampHandlingMode::String,
                 decimal_map,
             ),
+            label_prefix,
+            epoch: 0,
         })
     }
 
@@ -441,8 +473,11 @@ impl StarrocksSinkWriter {
 impl SinkWriter for StarrocksSinkWriter {
     async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
         if self.client.is_none() {
+            // The label is derived from the sink and the epoch, so that a load replayed
+            // after recovery carries the same label and is deduplicated by StarRocks.
+            let label = format!("{}-{}", self.label_prefix, self.epoch);
             self.client = Some(StarrocksClient::new(
-                self.inserter_innet_builder.build().await?,
+                self.inserter_innet_builder.build(label).await?,
             ));
         }
         if self.is_append_only {
@@ -452,7 +487,8 @@ impl SinkWriter for StarrocksSinkWriter {
         }
     }
 
-    async fn begin_epoch(&mut self, _epoch: u64) -> Result<()> {
+    async fn begin_epoch(&mut self, epoch: u64) -> Result<()> {
+        self.epoch = epoch;
         Ok(())
     }
 
@@ -555,6 +591,8 @@ pub struct StarrocksInsertResultResponse {
     label: String,
     #[serde(rename = "Status")]
     status: String,
+    #[serde(rename = "ExistingJobStatus")]
+    existing_job_status: Option<String>,
     #[serde(rename = "Message")]
     message: String,
     #[serde(rename = "NumberTotalRows")]
@@ -579,6 +617,8 @@ pub struct StarrocksInsertResultResponse {
     commit_and_publish_time_ms: i32,
     #[serde(rename = "StreamLoadPlanTimeMs")]
     stream_load_plan_time_ms: Option<i32>,
+    #[serde(rename = "ErrorURL")]
+    err_url: Option<String>,
 }
 
 pub struct StarrocksClient {
@@ -600,9 +640,18 @@ impl StarrocksClient {
             .map_err(|err| SinkError::DorisStarrocksConnect(err.into()))?;
 
         if !DORIS_SUCCESS_STATUS.contains(&res.status.as_str()) {
+            // A load rejected as a duplicate label means that the load of this epoch was
+            // already committed before a recovery, so the replayed data can be discarded.
+            if res.status == DORIS_DUPLICATE_LABEL_STATUS
+                && let Some(existing_job_status) = &res.existing_job_status
+                && DORIS_COMMITTED_JOB_STATUS.contains(&existing_job_status.as_str())
+            {
+                return Ok(res);
+            }
             return Err(SinkError::DorisStarrocksConnect(anyhow::anyhow!(
-                "Insert error: {:?}",
+                "Insert error: {:?}, error url: {:?}",
                 res.message,
+                res.err_url
             )));
         };
         Ok(res)
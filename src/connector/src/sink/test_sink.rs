@@ -58,7 +58,10 @@ impl Sink for TestSink {
         writer_param: SinkWriterParam,
     ) -> crate::sink::Result<Self::LogSinker> {
         let metrics = writer_param.sink_metrics.clone();
-        Ok(build_box_writer(self.param.clone(), writer_param).into_log_sinker(metrics))
+        let error_policy = writer_param.error_policy;
+        let throttle = writer_param.throttle;
+        Ok(build_box_writer(self.param.clone(), writer_param)
+            .into_log_sinker(metrics, error_policy, throttle))
     }
 }
 
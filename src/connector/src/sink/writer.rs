@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::future::{Future, Ready};
+use std::num::NonZeroU32;
 use std::pin::pin;
 use std::sync::Arc;
 use std::time::Instant;
@@ -20,6 +21,9 @@ use std::time::Instant;
 use async_trait::async_trait;
 use futures::future::{select, Either};
 use futures::TryFuture;
+use governor::clock::MonotonicClock;
+use governor::state::{InMemoryState, NotKeyed};
+use governor::{InsufficientCapacity, Quota, RateLimiter};
 use risingwave_common::array::StreamChunk;
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::util::drop_either_future;
@@ -30,7 +34,34 @@ use crate::sink::log_store::{
     DeliveryFutureManager, DeliveryFutureManagerAddFuture, LogReader, LogStoreReadItem,
     TruncateOffset,
 };
-use crate::sink::{LogSinker, Result, SinkError, SinkMetrics};
+use crate::sink::{
+    LogSinker, Result, SinkError, SinkErrorPolicy, SinkMetrics, SinkThrottle,
+    SINK_RATE_LIMIT_OPTION,
+};
+
+type SinkRateLimiter = RateLimiter<NotKeyed, InMemoryState, MonotonicClock>;
+
+/// Builds the token bucket enforcing the `sink_rate_limit` option, if it is set.
+fn build_rate_limiter(throttle: &SinkThrottle) -> Option<SinkRateLimiter> {
+    throttle
+        .rate_limit
+        .map(|limit| RateLimiter::direct_with_clock(Quota::per_second(limit), &MonotonicClock))
+}
+
+/// Waits until the token bucket has capacity for all rows of the chunk.
+async fn wait_for_quota(rate_limiter: &SinkRateLimiter, chunk: &StreamChunk) {
+    let Some(n) = NonZeroU32::new(chunk.cardinality() as u32) else {
+        return;
+    };
+    if let Err(InsufficientCapacity(_)) = rate_limiter.until_n_ready(n).await {
+        // Deliver the chunk anyway rather than blocking the sink forever.
+        tracing::error!(
+            "`{}` smaller than chunk cardinality {}",
+            SINK_RATE_LIMIT_OPTION,
+            n,
+        );
+    }
+}
 
 #[async_trait]
 pub trait SinkWriter: Send + 'static {
@@ -110,13 +141,22 @@ pub trait FormattedSink {
 pub struct LogSinkerOf<W> {
     writer: W,
     sink_metrics: SinkMetrics,
+    error_policy: SinkErrorPolicy,
+    throttle: SinkThrottle,
 }
 
 impl<W> LogSinkerOf<W> {
-    pub fn new(writer: W, sink_metrics: SinkMetrics) -> Self {
+    pub fn new(
+        writer: W,
+        sink_metrics: SinkMetrics,
+        error_policy: SinkErrorPolicy,
+        throttle: SinkThrottle,
+    ) -> Self {
         LogSinkerOf {
             writer,
             sink_metrics,
+            error_policy,
+            throttle,
         }
     }
 }
@@ -126,6 +166,8 @@ impl<W: SinkWriter<CommitMetadata = ()>> LogSinker for LogSinkerOf<W> {
     async fn consume_log_and_sink(self, mut log_reader: impl LogReader) -> Result<()> {
         let mut sink_writer = self.writer;
         let sink_metrics = self.sink_metrics;
+        let error_policy = self.error_policy;
+        let rate_limiter = build_rate_limiter(&self.throttle);
         #[derive(Debug)]
         enum LogConsumerState {
             /// Mark that the log consumer is not initialized yet
@@ -182,9 +224,23 @@ impl<W: SinkWriter<CommitMetadata = ()>> LogSinker for LogSinkerOf<W> {
             };
             match item {
                 LogStoreReadItem::StreamChunk { chunk, .. } => {
+                    if let Some(rate_limiter) = &rate_limiter {
+                        wait_for_quota(rate_limiter, &chunk).await;
+                    }
                     if let Err(e) = sink_writer.write_batch(chunk).await {
-                        sink_writer.abort().await?;
-                        return Err(e);
+                        match error_policy {
+                            SinkErrorPolicy::Retry => {
+                                sink_writer.abort().await?;
+                                return Err(e);
+                            }
+                            SinkErrorPolicy::Skip => {
+                                tracing::warn!(
+                                    "failed to write a chunk to the sink, dropping it as \
+                                    `on_error = 'skip'` is set: {:?}",
+                                    e
+                                );
+                            }
+                        }
                     }
                 }
                 LogStoreReadItem::Barrier { is_checkpoint } => {
@@ -219,10 +275,17 @@ impl<T> T
 where
     T: SinkWriter<CommitMetadata = ()> + Sized,
 {
-    pub fn into_log_sinker(self, sink_metrics: SinkMetrics) -> LogSinkerOf<Self> {
+    pub fn into_log_sinker(
+        self,
+        sink_metrics: SinkMetrics,
+        error_policy: SinkErrorPolicy,
+        throttle: SinkThrottle,
+    ) -> LogSinkerOf<Self> {
         LogSinkerOf {
             writer: self,
             sink_metrics,
+            error_policy,
+            throttle,
         }
     }
 }
@@ -230,13 +293,19 @@ where
 pub struct AsyncTruncateLogSinkerOf<W: AsyncTruncateSinkWriter> {
     writer: W,
     future_manager: DeliveryFutureManager<W::DeliveryFuture>,
+    throttle: SinkThrottle,
 }
 
 impl<W: AsyncTruncateSinkWriter> AsyncTruncateLogSinkerOf<W> {
-    pub fn new(writer: W, max_future_count: usize) -> Self {
+    pub fn new(writer: W, max_future_count: usize, throttle: SinkThrottle) -> Self {
+        // `sink_max_concurrency` tightens the connector's own bound on in-flight requests.
+        let max_future_count = throttle
+            .max_concurrency
+            .map_or(max_future_count, |limit| limit.get().min(max_future_count));
         AsyncTruncateLogSinkerOf {
             writer,
             future_manager: DeliveryFutureManager::new(max_future_count),
+            throttle,
         }
     }
 }
@@ -244,6 +313,7 @@ impl<W: AsyncTruncateSinkWriter> AsyncTruncateLogSinkerOf<W> {
 #[async_trait]
 impl<W: AsyncTruncateSinkWriter> LogSinker for AsyncTruncateLogSinkerOf<W> {
     async fn consume_log_and_sink(mut self, mut log_reader: impl LogReader) -> Result<()> {
+        let rate_limiter = build_rate_limiter(&self.throttle);
         log_reader.init().await?;
         loop {
             let select_result = drop_either_future(
@@ -258,6 +328,9 @@ impl<W: AsyncTruncateSinkWriter> LogSinker for AsyncTruncateLogSinkerOf<W> {
                     let (epoch, item) = item_result?;
                     match item {
                         LogStoreReadItem::StreamChunk { chunk_id, chunk } => {
+                            if let Some(rate_limiter) = &rate_limiter {
+                                wait_for_quota(rate_limiter, &chunk).await;
+                            }
                             let add_future = self.future_manager.start_write_chunk(epoch, chunk_id);
                             self.writer.write_chunk(chunk, add_future).await?;
                         }
@@ -283,7 +356,11 @@ impl<T> T
 where
     T: AsyncTruncateSinkWriter + Sized,
 {
-    pub fn into_log_sinker(self, max_future_count: usize) -> AsyncTruncateLogSinkerOf<Self> {
-        AsyncTruncateLogSinkerOf::new(self, max_future_count)
+    pub fn into_log_sinker(
+        self,
+        max_future_count: usize,
+        throttle: SinkThrottle,
+    ) -> AsyncTruncateLogSinkerOf<Self> {
+        AsyncTruncateLogSinkerOf::new(self, max_future_count, throttle)
     }
 }
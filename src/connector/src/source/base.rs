@@ -420,6 +420,15 @@ impl ConnectorProperties {
         matches!(self, ConnectorProperties::Kinesis(_))
     }
 
+    pub fn enable_split_rebalance(&self) -> bool {
+        // Splits of these connectors can be migrated between actors, since their readers resume
+        // a migrated split from the per-split offset recorded in the source state.
+        matches!(
+            self,
+            ConnectorProperties::Kafka(_) | ConnectorProperties::Kinesis(_)
+        )
+    }
+
     pub fn init_from_pb_source(&mut self, source: &PbSource) {
         dispatch_source_prop!(self, prop, prop.init_from_pb_source(source))
     }
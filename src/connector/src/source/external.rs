@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::future::Future;
 
@@ -24,14 +25,16 @@ use mysql_async::prelude::*;
 use mysql_common::params::Params;
 use mysql_common::value::Value;
 use risingwave_common::bail;
-use risingwave_common::catalog::{Schema, OFFSET_COLUMN_NAME};
+use risingwave_common::catalog::{Field, Schema, OFFSET_COLUMN_NAME};
 use risingwave_common::row::OwnedRow;
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_common::util::iter_util::ZipEqFast;
 use serde_derive::{Deserialize, Serialize};
+use tokio_postgres::types::PgLsn;
+use tokio_postgres::NoTls;
 
 use crate::error::ConnectorError;
-use crate::parser::mysql_row_to_datums;
+use crate::parser::{mysql_row_to_datums, postgres_row_to_datums};
 use crate::source::MockExternalTableReader;
 
 pub type ConnectorResult<T> = std::result::Result<T, ConnectorError>;
@@ -59,10 +62,10 @@ impl CdcTableType {
     }
 
     pub fn can_backfill(&self) -> bool {
-        matches!(self, Self::MySql)
+        matches!(self, Self::MySql | Self::Postgres)
     }
 
-    pub fn create_table_reader(
+    pub async fn create_table_reader(
         &self,
         properties: HashMap<String, String>,
         schema: Schema,
@@ -71,6 +74,9 @@ impl CdcTableType {
             Self::MySql => Ok(ExternalTableReaderImpl::MySql(
                 MySqlExternalTableReader::new(properties, schema)?,
             )),
+            Self::Postgres => Ok(ExternalTableReaderImpl::Postgres(
+                PostgresExternalTableReader::new(properties, schema).await?,
+            )),
             _ => bail!(ConnectorError::Config(anyhow!(
                 "invalid external table type: {:?}",
                 *self
@@ -134,13 +140,21 @@ impl MySqlOffset {
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct PostgresOffset {
     pub txid: u64,
     pub lsn: u64,
     pub tx_usec: u64,
 }
 
+// The upstream WAL LSN is strictly increasing in commit order, while `txid` is assigned at
+// transaction start and thus not comparable, so offsets are ordered by LSN only.
+impl PartialOrd for PostgresOffset {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.lsn.partial_cmp(&other.lsn)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum CdcOffset {
     MySql(MySqlOffset),
@@ -209,6 +223,32 @@ impl MySqlOffset {
     }
 }
 
+impl PostgresOffset {
+    pub fn parse_str(offset: &str) -> ConnectorResult<Self> {
+        let dbz_offset: DebeziumOffset = serde_json::from_str(offset).map_err(|e| {
+            ConnectorError::Internal(anyhow!("invalid upstream offset: {}, error: {}", offset, e))
+        })?;
+
+        Ok(Self {
+            txid: dbz_offset.source_offset.txid.unwrap_or_default(),
+            lsn: dbz_offset
+                .source_offset
+                .lsn
+                .ok_or_else(|| anyhow!("lsn not found in offset"))?,
+            tx_usec: dbz_offset.source_offset.tx_usec.unwrap_or_default(),
+        })
+    }
+}
+
+/// A primary-key range of an external table for parallel snapshot reads, analogous to a source
+/// split. The range is left-exclusive and right-inclusive, and a `None` bound is unbounded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotSplit {
+    pub split_id: i32,
+    pub left_bound_pk: Option<OwnedRow>,
+    pub right_bound_pk: Option<OwnedRow>,
+}
+
 pub trait ExternalTableReader {
     fn get_normalized_table_name(&self, table_name: &SchemaTableName) -> String;
 
@@ -222,11 +262,21 @@ pub trait ExternalTableReader {
         start_pk: Option<OwnedRow>,
         primary_keys: Vec<String>,
     ) -> BoxStream<'_, ConnectorResult<OwnedRow>>;
+
+    /// Enumerate primary-key ranges of roughly `split_size` rows each, by sampling every
+    /// `split_size`-th primary key of the table in order.
+    fn get_snapshot_splits(
+        &self,
+        table_name: SchemaTableName,
+        primary_keys: Vec<String>,
+        split_size: u64,
+    ) -> impl Future<Output = ConnectorResult<Vec<SnapshotSplit>>> + Send + '_;
 }
 
 #[derive(Debug)]
 pub enum ExternalTableReaderImpl {
     MySql(MySqlExternalTableReader),
+    Postgres(PostgresExternalTableReader),
     Mock(MockExternalTableReader),
 }
 
@@ -291,6 +341,80 @@ impl ExternalTableReader for MySqlExternalTableReader {
     ) -> BoxStream<'_, ConnectorResult<OwnedRow>> {
         self.snapshot_read_inner(table_name, start_pk, primary_keys)
     }
+
+    async fn get_snapshot_splits(
+        &self,
+        table_name: SchemaTableName,
+        primary_keys: Vec<String>,
+        split_size: u64,
+    ) -> ConnectorResult<Vec<SnapshotSplit>> {
+        let pk_schema = Schema::new(
+            primary_keys
+                .iter()
+                .map(|pk| {
+                    self.rw_schema
+                        .fields
+                        .iter()
+                        .find(|f| &f.name == pk)
+                        .cloned()
+                        .ok_or_else(|| {
+                            ConnectorError::Internal(anyhow!(
+                                "primary key {} not found in schema",
+                                pk
+                            ))
+                        })
+                })
+                .try_collect::<_, Vec<Field>, _>()?,
+        );
+        let pk_fields = primary_keys.iter().map(|pk| format!("`{}`", pk)).join(",");
+        let order_key = primary_keys.iter().join(",");
+        let sql = format!(
+            "SELECT {} FROM {} ORDER BY {} LIMIT 1 OFFSET :offset",
+            pk_fields,
+            self.get_normalized_table_name(&table_name),
+            order_key
+        );
+
+        let mut conn = self
+            .pool
+            .get_conn()
+            .await
+            .map_err(|e| ConnectorError::Connection(anyhow!(e)))?;
+
+        // Set session timezone to UTC
+        conn.exec_drop("SET time_zone = \"+00:00\"", ()).await?;
+
+        let mut splits = Vec::new();
+        let mut left_bound_pk: Option<OwnedRow> = None;
+        loop {
+            let offset = (splits.len() as u64 + 1) * split_size;
+            let params = Params::from(vec![("offset".to_string(), Value::from(offset))]);
+            let row = conn
+                .exec_first::<mysql_async::Row, _, _>(sql.as_str(), params)
+                .await?;
+            match row {
+                Some(mut row) => {
+                    let bound = OwnedRow::new(mysql_row_to_datums(&mut row, &pk_schema));
+                    splits.push(SnapshotSplit {
+                        split_id: splits.len() as i32,
+                        left_bound_pk: left_bound_pk.clone(),
+                        right_bound_pk: Some(bound.clone()),
+                    });
+                    left_bound_pk = Some(bound);
+                }
+                None => {
+                    // The rest of the table forms the last, right-unbounded split.
+                    splits.push(SnapshotSplit {
+                        split_id: splits.len() as i32,
+                        left_bound_pk,
+                        right_bound_pk: None,
+                    });
+                    break;
+                }
+            }
+        }
+        Ok(splits)
+    }
 }
 
 impl MySqlExternalTableReader {
@@ -476,10 +600,230 @@ impl MySqlExternalTableReader {
     }
 }
 
+pub struct PostgresExternalTableReader {
+    config: ExternalTableConfig,
+    rw_schema: Schema,
+    field_names: String,
+    client: tokio_postgres::Client,
+}
+
+impl std::fmt::Debug for PostgresExternalTableReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresExternalTableReader")
+            .field("config", &self.config)
+            .field("rw_schema", &self.rw_schema)
+            .finish()
+    }
+}
+
+impl ExternalTableReader for PostgresExternalTableReader {
+    fn get_normalized_table_name(&self, table_name: &SchemaTableName) -> String {
+        format!(
+            "\"{}\".\"{}\"",
+            table_name.schema_name, table_name.table_name
+        )
+    }
+
+    async fn current_cdc_offset(&self) -> ConnectorResult<CdcOffset> {
+        let row = self
+            .client
+            .query_one("SELECT pg_current_wal_lsn()", &[])
+            .await?;
+        let lsn: PgLsn = row.get(0);
+
+        let txid_row = self.client.query_one("SELECT txid_current()", &[]).await?;
+        let txid: i64 = txid_row.get(0);
+
+        Ok(CdcOffset::Postgres(PostgresOffset {
+            txid: txid as u64,
+            lsn: lsn.into(),
+            tx_usec: 0,
+        }))
+    }
+
+    fn parse_binlog_offset(&self, offset: &str) -> ConnectorResult<CdcOffset> {
+        Ok(CdcOffset::Postgres(PostgresOffset::parse_str(offset)?))
+    }
+
+    fn snapshot_read(
+        &self,
+        table_name: SchemaTableName,
+        start_pk: Option<OwnedRow>,
+        primary_keys: Vec<String>,
+    ) -> BoxStream<'_, ConnectorResult<OwnedRow>> {
+        self.snapshot_read_inner(table_name, start_pk, primary_keys)
+    }
+
+    async fn get_snapshot_splits(
+        &self,
+        table_name: SchemaTableName,
+        primary_keys: Vec<String>,
+        split_size: u64,
+    ) -> ConnectorResult<Vec<SnapshotSplit>> {
+        let pk_schema = Schema::new(
+            primary_keys
+                .iter()
+                .map(|pk| {
+                    self.rw_schema
+                        .fields
+                        .iter()
+                        .find(|f| &f.name == pk)
+                        .cloned()
+                        .ok_or_else(|| {
+                            ConnectorError::Internal(anyhow!(
+                                "primary key {} not found in schema",
+                                pk
+                            ))
+                        })
+                })
+                .try_collect::<_, Vec<Field>, _>()?,
+        );
+        let pk_fields = primary_keys.iter().map(|pk| format!("\"{}\"", pk)).join(",");
+        let order_key = primary_keys.iter().join(",");
+        let sql = format!(
+            "SELECT {} FROM {} ORDER BY {} LIMIT 1 OFFSET $1",
+            pk_fields,
+            self.get_normalized_table_name(&table_name),
+            order_key
+        );
+
+        let mut splits = Vec::new();
+        let mut left_bound_pk: Option<OwnedRow> = None;
+        loop {
+            let offset = ((splits.len() as u64 + 1) * split_size) as i64;
+            let row = self.client.query_opt(sql.as_str(), &[&offset]).await?;
+            match row {
+                Some(row) => {
+                    let bound = OwnedRow::new(postgres_row_to_datums(&row, &pk_schema));
+                    splits.push(SnapshotSplit {
+                        split_id: splits.len() as i32,
+                        left_bound_pk: left_bound_pk.clone(),
+                        right_bound_pk: Some(bound.clone()),
+                    });
+                    left_bound_pk = Some(bound);
+                }
+                None => {
+                    // The rest of the table forms the last, right-unbounded split.
+                    splits.push(SnapshotSplit {
+                        split_id: splits.len() as i32,
+                        left_bound_pk,
+                        right_bound_pk: None,
+                    });
+                    break;
+                }
+            }
+        }
+        Ok(splits)
+    }
+}
+
+impl PostgresExternalTableReader {
+    pub async fn new(
+        properties: HashMap<String, String>,
+        rw_schema: Schema,
+    ) -> ConnectorResult<Self> {
+        tracing::debug!(?rw_schema, "create postgres external table reader");
+
+        let config = serde_json::from_value::<ExternalTableConfig>(
+            serde_json::to_value(properties).unwrap(),
+        )
+        .map_err(|e| {
+            ConnectorError::Config(anyhow!(
+                "fail to extract postgres connector properties: {}",
+                e
+            ))
+        })?;
+
+        let database_url = format!(
+            "postgres://{}:{}@{}:{}/{}",
+            config.username, config.password, config.host, config.port, config.database
+        );
+        let (client, connection) = tokio_postgres::connect(&database_url, NoTls).await?;
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("postgres connection error: {:?}", e);
+            }
+        });
+
+        let field_names = rw_schema
+            .fields
+            .iter()
+            .filter(|f| f.name != OFFSET_COLUMN_NAME)
+            .map(|f| format!("\"{}\"", f.name.as_str()))
+            .join(",");
+
+        Ok(Self {
+            config,
+            rw_schema,
+            field_names,
+            client,
+        })
+    }
+
+    #[try_stream(boxed, ok = OwnedRow, error = ConnectorError)]
+    async fn snapshot_read_inner(
+        &self,
+        table_name: SchemaTableName,
+        start_pk_row: Option<OwnedRow>,
+        primary_keys: Vec<String>,
+    ) {
+        let order_key = primary_keys.iter().join(",");
+        let sql = if start_pk_row.is_none() {
+            format!(
+                "SELECT {} FROM {} ORDER BY {}",
+                self.field_names,
+                self.get_normalized_table_name(&table_name),
+                order_key
+            )
+        } else {
+            let filter_expr = Self::filter_expression(&primary_keys);
+            format!(
+                "SELECT {} FROM {} WHERE {} ORDER BY {}",
+                self.field_names,
+                self.get_normalized_table_name(&table_name),
+                filter_expr,
+                order_key
+            )
+        };
+
+        // fill in start primary key params
+        let params: Vec<Option<ScalarImpl>> = match start_pk_row {
+            Some(pk_row) => pk_row.into_iter().collect(),
+            None => Vec::new(),
+        };
+
+        let stream = self.client.query_raw(&sql, &params).await?;
+        let row_stream = stream.map(|row| {
+            let row = row?;
+            Ok::<_, ConnectorError>(OwnedRow::new(postgres_row_to_datums(&row, &self.rw_schema)))
+        });
+
+        pin_mut!(row_stream);
+        #[for_await]
+        for row in row_stream {
+            let row = row?;
+            yield row;
+        }
+    }
+
+    // postgres supports row value comparison, so the starting point can be expressed as a
+    // single condition with positional parameters bound to the last-seen primary key.
+    // (a, b) > ($1, $2)
+    fn filter_expression(columns: &[String]) -> String {
+        let col_expr = columns.iter().map(|col| format!("\"{}\"", col)).join(", ");
+        let arg_expr = (1..=columns.len()).map(|i| format!("${}", i)).join(", ");
+        format!("({}) > ({})", col_expr, arg_expr)
+    }
+}
+
 impl ExternalTableReader for ExternalTableReaderImpl {
     fn get_normalized_table_name(&self, table_name: &SchemaTableName) -> String {
         match self {
             ExternalTableReaderImpl::MySql(mysql) => mysql.get_normalized_table_name(table_name),
+            ExternalTableReaderImpl::Postgres(postgres) => {
+                postgres.get_normalized_table_name(table_name)
+            }
             ExternalTableReaderImpl::Mock(mock) => mock.get_normalized_table_name(table_name),
         }
     }
@@ -487,6 +831,7 @@ impl ExternalTableReader for ExternalTableReaderImpl {
     async fn current_cdc_offset(&self) -> ConnectorResult<CdcOffset> {
         match self {
             ExternalTableReaderImpl::MySql(mysql) => mysql.current_cdc_offset().await,
+            ExternalTableReaderImpl::Postgres(postgres) => postgres.current_cdc_offset().await,
             ExternalTableReaderImpl::Mock(mock) => mock.current_cdc_offset().await,
         }
     }
@@ -494,6 +839,7 @@ impl ExternalTableReader for ExternalTableReaderImpl {
     fn parse_binlog_offset(&self, offset: &str) -> ConnectorResult<CdcOffset> {
         match self {
             ExternalTableReaderImpl::MySql(mysql) => mysql.parse_binlog_offset(offset),
+            ExternalTableReaderImpl::Postgres(postgres) => postgres.parse_binlog_offset(offset),
             ExternalTableReaderImpl::Mock(mock) => mock.parse_binlog_offset(offset),
         }
     }
@@ -506,6 +852,30 @@ impl ExternalTableReader for ExternalTableReaderImpl {
     ) -> BoxStream<'_, ConnectorResult<OwnedRow>> {
         self.snapshot_read_inner(table_name, start_pk, primary_keys)
     }
+
+    async fn get_snapshot_splits(
+        &self,
+        table_name: SchemaTableName,
+        primary_keys: Vec<String>,
+        split_size: u64,
+    ) -> ConnectorResult<Vec<SnapshotSplit>> {
+        match self {
+            ExternalTableReaderImpl::MySql(mysql) => {
+                mysql
+                    .get_snapshot_splits(table_name, primary_keys, split_size)
+                    .await
+            }
+            ExternalTableReaderImpl::Postgres(postgres) => {
+                postgres
+                    .get_snapshot_splits(table_name, primary_keys, split_size)
+                    .await
+            }
+            ExternalTableReaderImpl::Mock(mock) => {
+                mock.get_snapshot_splits(table_name, primary_keys, split_size)
+                    .await
+            }
+        }
+    }
 }
 
 impl ExternalTableReaderImpl {
@@ -520,6 +890,9 @@ impl ExternalTableReaderImpl {
             ExternalTableReaderImpl::MySql(mysql) => {
                 mysql.snapshot_read(table_name, start_pk, primary_keys)
             }
+            ExternalTableReaderImpl::Postgres(postgres) => {
+                postgres.snapshot_read(table_name, start_pk, primary_keys)
+            }
             ExternalTableReaderImpl::Mock(mock) => {
                 mock.snapshot_read(table_name, start_pk, primary_keys)
             }
@@ -546,7 +919,8 @@ mod tests {
     use crate::sink::catalog::SinkType;
     use crate::sink::SinkParam;
     use crate::source::external::{
-        CdcOffset, ExternalTableReader, MySqlExternalTableReader, MySqlOffset, SchemaTableName,
+        CdcOffset, ExternalTableReader, MySqlExternalTableReader, MySqlOffset,
+        PostgresExternalTableReader, PostgresOffset, SchemaTableName,
     };
 
     #[test]
@@ -559,6 +933,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_postgres_filter_expr() {
+        let cols = vec!["aa".to_string(), "bb".to_string(), "cc".to_string()];
+        let expr = PostgresExternalTableReader::filter_expression(&cols);
+        assert_eq!(expr, "(\"aa\", \"bb\", \"cc\") > ($1, $2, $3)");
+    }
+
+    #[test]
+    fn test_postgres_wal_offset() {
+        let off0_str = r#"{ "sourcePartition": { "server": "test" }, "sourceOffset": { "last_snapshot_record": false, "lsn": 29973552, "txId": 1046, "ts_usec": 1670826189008456, "snapshot": true }, "isHeartbeat": false }"#;
+        let off1_str = r#"{ "sourcePartition": { "server": "test" }, "sourceOffset": { "last_snapshot_record": false, "lsn": 29973552, "txId": 1043, "ts_usec": 1670826189008456, "snapshot": true }, "isHeartbeat": false }"#;
+        let off2_str = r#"{ "sourcePartition": { "server": "test" }, "sourceOffset": { "last_snapshot_record": false, "lsn": 29986688, "txId": 1051, "ts_usec": 1670826192402504, "snapshot": true }, "isHeartbeat": false }"#;
+
+        let off0 = CdcOffset::Postgres(PostgresOffset::parse_str(off0_str).unwrap());
+        let off1 = CdcOffset::Postgres(PostgresOffset::parse_str(off1_str).unwrap());
+        let off2 = CdcOffset::Postgres(PostgresOffset::parse_str(off2_str).unwrap());
+
+        // offsets are compared by LSN only, `txId` does not participate
+        assert!(off0 <= off1);
+        assert!(off1 >= off0);
+        assert!(off1 < off2);
+    }
+
     #[test]
     fn test_mysql_binlog_offset() {
         let off0_str = r#"{ "sourcePartition": { "server": "test" }, "sourceOffset": { "ts_sec": 1670876905, "file": "binlog.000001", "pos": 105622, "snapshot": true }, "isHeartbeat": false }"#;
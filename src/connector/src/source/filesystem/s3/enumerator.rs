@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use anyhow::Context;
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use aws_sdk_s3::client::Client;
+use aws_sdk_sqs::error::DisplayErrorContext;
+use serde::Deserialize;
 
 use crate::aws_auth::AwsAuthProps;
 use crate::aws_utils::{default_conn_config, s3_client};
@@ -65,6 +69,14 @@ pub struct S3SplitEnumerator {
 
     // token get the next page, used when the current page is truncated
     pub(crate) next_continuation_token: Option<String>,
+
+    // set when event-driven discovery via an SQS queue is enabled
+    pub(crate) sqs_client: Option<aws_sdk_sqs::Client>,
+    pub(crate) sqs_queue_url: Option<String>,
+    // objects discovered so far, from the backfill listing and subsequent events;
+    // only maintained in event-driven mode
+    pub(crate) discovered_objects: BTreeMap<String, FsSplit>,
+    pub(crate) backfill_finished: bool,
 }
 
 #[async_trait]
@@ -87,6 +99,10 @@ impl SplitEnumerator for S3SplitEnumerator {
         } else {
             (None, None)
         };
+        let sqs_client = properties
+            .sqs_queue_url
+            .is_some()
+            .then(|| aws_sdk_sqs::Client::new(&sdk_config));
 
         Ok(S3SplitEnumerator {
             bucket_name: properties.bucket_name,
@@ -94,19 +110,138 @@ impl SplitEnumerator for S3SplitEnumerator {
             prefix,
             client: s3_client,
             next_continuation_token: None,
+            sqs_client,
+            sqs_queue_url: properties.sqs_queue_url,
+            discovered_objects: BTreeMap::new(),
+            backfill_finished: false,
         })
     }
 
     async fn list_splits(&mut self) -> anyhow::Result<Vec<Self::Split>> {
-        let mut objects = Vec::new();
+        let Some(queue_url) = self.sqs_queue_url.clone() else {
+            // List-driven discovery: re-list the whole bucket on every call.
+            let mut objects = Vec::new();
+            loop {
+                let (files, has_finished) = self.get_next_page::<FsSplit>().await?;
+                objects.extend(files);
+                if has_finished {
+                    break;
+                }
+            }
+            return Ok(objects);
+        };
+
+        // Event-driven discovery: list the bucket once for backfill, then only consume
+        // event notifications from the SQS queue.
+        if !self.backfill_finished {
+            loop {
+                let (files, has_finished) = self.get_next_page::<FsSplit>().await?;
+                self.discovered_objects
+                    .extend(files.into_iter().map(|split| (split.name.clone(), split)));
+                if has_finished {
+                    self.backfill_finished = true;
+                    break;
+                }
+            }
+        }
+        self.consume_object_events(&queue_url).await?;
+        Ok(self.discovered_objects.values().cloned().collect())
+    }
+}
+
+/// The parts of an S3 event notification message that object discovery cares about.
+/// See <https://docs.aws.amazon.com/AmazonS3/latest/userguide/notification-content-structure.html>.
+#[derive(Debug, Deserialize)]
+struct S3EventNotification {
+    #[serde(rename = "Records", default)]
+    records: Vec<S3EventRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct S3EventRecord {
+    #[serde(rename = "eventName")]
+    event_name: String,
+    s3: S3EventEntity,
+}
+
+#[derive(Debug, Deserialize)]
+struct S3EventEntity {
+    object: S3EventObject,
+}
+
+#[derive(Debug, Deserialize)]
+struct S3EventObject {
+    key: String,
+    #[serde(default)]
+    size: usize,
+}
+
+impl S3SplitEnumerator {
+    /// Drains the SQS queue and applies each event notification to the set of discovered
+    /// objects. Messages are deleted from the queue once applied.
+    async fn consume_object_events(&mut self, queue_url: &str) -> anyhow::Result<()> {
+        let sqs_client = self.sqs_client.clone().expect("sqs client must be built");
         loop {
-            let (files, has_finished) = self.get_next_page::<FsSplit>().await?;
-            objects.extend(files);
-            if has_finished {
-                break;
+            let output = sqs_client
+                .receive_message()
+                .queue_url(queue_url)
+                .max_number_of_messages(10)
+                .send()
+                .await
+                .map_err(|e| anyhow!(DisplayErrorContext(e)))?;
+            let messages = output.messages.unwrap_or_default();
+            if messages.is_empty() {
+                return Ok(());
+            }
+            for message in &messages {
+                if let Some(body) = message.body() {
+                    self.apply_event_notification(body);
+                }
+                if let Some(receipt_handle) = message.receipt_handle() {
+                    sqs_client
+                        .delete_message()
+                        .queue_url(queue_url)
+                        .receipt_handle(receipt_handle)
+                        .send()
+                        .await
+                        .map_err(|e| anyhow!(DisplayErrorContext(e)))?;
+                }
+            }
+        }
+    }
+
+    fn apply_event_notification(&mut self, body: &str) {
+        let Ok(notification) = serde_json::from_str::<S3EventNotification>(body) else {
+            // e.g. the `s3:TestEvent` sent when the notification is first configured
+            tracing::warn!("ignoring unparsable S3 event notification: {}", body);
+            return;
+        };
+        for record in notification.records {
+            // object keys in event notifications are url-encoded
+            let decoded = urlencoding::decode(&record.s3.object.key.replace('+', " "))
+                .map(|key| key.into_owned());
+            let Ok(key) = decoded else {
+                tracing::warn!(
+                    "ignoring S3 event with undecodable object key: {}",
+                    record.s3.object.key
+                );
+                continue;
+            };
+            if !self
+                .matcher
+                .as_ref()
+                .map(|m| m.matches(&key))
+                .unwrap_or(true)
+            {
+                continue;
+            }
+            if record.event_name.starts_with("ObjectCreated") {
+                self.discovered_objects
+                    .insert(key.clone(), FsSplit::new(key, 0, record.s3.object.size));
+            } else if record.event_name.starts_with("ObjectRemoved") {
+                self.discovered_objects.remove(&key);
             }
         }
-        Ok(objects)
     }
 }
 
@@ -136,6 +271,7 @@ mod tests {
             access: None,
             secret: None,
             endpoint_url: None,
+            sqs_queue_url: None,
         };
         let mut enumerator =
             S3SplitEnumerator::new(props.clone(), SourceEnumeratorContext::default().into())
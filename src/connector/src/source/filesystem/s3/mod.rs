@@ -38,6 +38,11 @@ pub struct S3Properties {
     pub secret: Option<String>,
     #[serde(rename = "s3.endpoint_url")]
     pub endpoint_url: Option<String>,
+    /// URL of an SQS queue receiving the bucket's event notifications. When set, new
+    /// objects are discovered from `ObjectCreated` events instead of repeatedly listing
+    /// the bucket, after an initial listing for backfill.
+    #[serde(rename = "s3.sqs_queue_url", default)]
+    pub sqs_queue_url: Option<String>,
 }
 
 impl SourceProperties for S3Properties {
@@ -261,6 +261,7 @@ mod tests {
             access: None,
             secret: None,
             endpoint_url: None,
+            sqs_queue_url: None,
         };
         let mut enumerator =
             S3SplitEnumerator::new(props.clone(), SourceEnumeratorContext::default().into())
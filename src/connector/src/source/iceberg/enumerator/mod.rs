@@ -0,0 +1,50 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::split::IcebergSplit;
+use super::IcebergProperties;
+use crate::source::{SourceEnumeratorContextRef, SplitEnumerator};
+
+#[derive(Debug, Clone)]
+pub struct IcebergSplitEnumerator {}
+
+#[async_trait]
+impl SplitEnumerator for IcebergSplitEnumerator {
+    type Properties = IcebergProperties;
+    type Split = IcebergSplit;
+
+    async fn new(
+        properties: Self::Properties,
+        _context: SourceEnumeratorContextRef,
+    ) -> anyhow::Result<IcebergSplitEnumerator> {
+        // Load the table once to validate the catalog and object store options early, instead
+        // of failing in the readers.
+        properties.load_table().await?;
+        Ok(Self {})
+    }
+
+    async fn list_splits(&mut self) -> anyhow::Result<Vec<IcebergSplit>> {
+        // TODO: to simplify the logic, the whole table is one split for the first version.
+        // The reader starts with an initial scan (`snapshot_id: None`) and the actual
+        // progress is restored from the split state on recovery.
+        Ok(vec![IcebergSplit {
+            split_id: Arc::from("0"),
+            snapshot_id: None,
+        }])
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod enumerator;
+pub mod source;
+pub mod split;
+
+use anyhow::anyhow;
+use icelake::Table;
+use serde::Deserialize;
+use with_options::WithOptions;
+
+use crate::sink::iceberg::IcebergConfig;
+use crate::source::iceberg::enumerator::IcebergSplitEnumerator;
+use crate::source::iceberg::source::{IcebergSplit, IcebergSplitReader};
+use crate::source::SourceProperties;
+
+pub const ICEBERG_CONNECTOR: &str = "iceberg";
+
+/// Properties of the iceberg source, sharing the catalog and object store options (and their
+/// names) with the iceberg sink.
+#[derive(Clone, Debug, Deserialize, WithOptions)]
+pub struct IcebergProperties {
+    #[serde(rename = "table.name")]
+    pub table_name: String, // Full name of table, must include schema name
+
+    #[serde(rename = "database.name")]
+    pub database_name: String, // Use as catalog name.
+
+    // Catalog type supported by iceberg, such as "storage", "rest".
+    // If not set, we use "storage" as default.
+    #[serde(rename = "catalog.type")]
+    pub catalog_type: Option<String>,
+
+    #[serde(rename = "warehouse.path")]
+    pub warehouse_path: String, // Path of iceberg warehouse, only applicable in storage catalog.
+
+    #[serde(rename = "catalog.uri")]
+    pub uri: Option<String>, // URI of iceberg catalog, only applicable in rest catalog.
+
+    #[serde(rename = "s3.region")]
+    pub region: Option<String>,
+
+    #[serde(rename = "s3.endpoint")]
+    pub endpoint: Option<String>,
+
+    #[serde(rename = "s3.access.key")]
+    pub access_key: String,
+
+    #[serde(rename = "s3.secret.key")]
+    pub secret_key: String,
+
+    /// Interval of polling the catalog for newly committed snapshots, in milliseconds.
+    /// Defaults to 10 seconds if not set.
+    #[serde(rename = "scan.interval.ms")]
+    pub scan_interval_ms: Option<String>,
+}
+
+impl IcebergProperties {
+    fn to_iceberg_config(&self) -> IcebergConfig {
+        IcebergConfig {
+            connector: ICEBERG_CONNECTOR.to_string(),
+            // The sink type is irrelevant when only loading the table, but `append-only` also
+            // matches what the source is able to consume.
+            r#type: "append-only".to_string(),
+            force_append_only: false,
+            table_name: self.table_name.clone(),
+            database_name: self.database_name.clone(),
+            catalog_type: self.catalog_type.clone(),
+            path: self.warehouse_path.clone(),
+            uri: self.uri.clone(),
+            region: self.region.clone(),
+            endpoint: self.endpoint.clone(),
+            access_key: self.access_key.clone(),
+            secret_key: self.secret_key.clone(),
+            primary_key: None,
+        }
+    }
+
+    /// Load the iceberg table behind this source from its catalog. Called every poll to pick
+    /// up newly committed snapshots, since the table metadata is immutable once loaded.
+    pub async fn load_table(&self) -> anyhow::Result<Table> {
+        self.to_iceberg_config()
+            .load_table()
+            .await
+            .map_err(|e| anyhow!(e))
+    }
+}
+
+impl SourceProperties for IcebergProperties {
+    type Split = IcebergSplit;
+    type SplitEnumerator = IcebergSplitEnumerator;
+    type SplitReader = IcebergSplitReader;
+
+    const SOURCE_NAME: &'static str = ICEBERG_CONNECTOR;
+}
@@ -0,0 +1,342 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{StreamExt, TryStreamExt};
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use maplit::hashmap;
+use opendal::services::S3;
+use opendal::Operator;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use risingwave_common::array::{DataChunk, Op, StreamChunk};
+use risingwave_common::catalog::{
+    ICEBERG_FILE_PATH_COLUMN_NAME, ICEBERG_SNAPSHOT_ID_COLUMN_NAME,
+};
+use risingwave_common::error::RwError;
+use risingwave_common::estimate_size::EstimateSize;
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, ScalarImpl, ToOwnedDatum};
+use url::Url;
+
+use super::IcebergSplit;
+use crate::parser::ParserConfig;
+use crate::source::iceberg::IcebergProperties;
+use crate::source::{
+    BoxSourceWithStateStream, Column, SourceColumnDesc, SourceContextRef, SplitMetaData,
+    SplitReader, StreamChunkWithState,
+};
+
+const DEFAULT_SCAN_INTERVAL_MS: u64 = 10_000;
+
+/// The reader performs an initial scan of all data files of the current snapshot, and then
+/// polls the catalog to tail the data files added by newly committed snapshots. Only
+/// append-only tables are supported: a snapshot that rewrites or deletes data files fails the
+/// reader.
+///
+/// Delivery is at-least-once. Offsets are tracked at snapshot granularity, so recovering in
+/// the middle of a snapshot re-reads all of its data files.
+pub struct IcebergSplitReader {
+    properties: IcebergProperties,
+    split: IcebergSplit,
+    operator: Operator,
+    bucket: String,
+    scan_interval: Duration,
+    parser_config: ParserConfig,
+    source_ctx: SourceContextRef,
+}
+
+#[async_trait]
+impl SplitReader for IcebergSplitReader {
+    type Properties = IcebergProperties;
+    type Split = IcebergSplit;
+
+    async fn new(
+        properties: IcebergProperties,
+        splits: Vec<IcebergSplit>,
+        parser_config: ParserConfig,
+        source_ctx: SourceContextRef,
+        _columns: Option<Vec<Column>>,
+    ) -> Result<Self> {
+        // TODO: to simplify the logic, the whole table is one split for the first version.
+        assert!(splits.len() == 1);
+        let split = splits.into_iter().next().unwrap();
+
+        let scan_interval = Duration::from_millis(match &properties.scan_interval_ms {
+            Some(interval) => interval
+                .parse()
+                .map_err(|e| anyhow!("invalid scan.interval.ms: {}", e))?,
+            None => DEFAULT_SCAN_INTERVAL_MS,
+        });
+        let (operator, bucket) = build_operator(&properties)?;
+
+        Ok(Self {
+            properties,
+            split,
+            operator,
+            bucket,
+            scan_interval,
+            parser_config,
+            source_ctx,
+        })
+    }
+
+    fn into_stream(self) -> BoxSourceWithStateStream {
+        let actor_id = self.source_ctx.source_info.actor_id.to_string();
+        let source_id = self.source_ctx.source_info.source_id.to_string();
+        let split_id = self.split.id();
+        let metrics = self.source_ctx.metrics.clone();
+
+        self.into_snapshot_stream()
+            .inspect_ok(move |chunk_with_states: &StreamChunkWithState| {
+                metrics
+                    .partition_input_count
+                    .with_label_values(&[&actor_id, &source_id, &split_id])
+                    .inc_by(chunk_with_states.chunk.cardinality() as u64);
+                metrics
+                    .partition_input_bytes
+                    .with_label_values(&[&actor_id, &source_id, &split_id])
+                    .inc_by(chunk_with_states.chunk.estimated_size() as u64);
+            })
+            .boxed()
+    }
+}
+
+impl IcebergSplitReader {
+    #[try_stream(boxed, ok = StreamChunkWithState, error = RwError)]
+    async fn into_snapshot_stream(self) {
+        let chunk_size = self.source_ctx.source_ctrl_opts.chunk_size;
+        let split_id = self.split.id();
+        let rw_columns = self.parser_config.common.rw_columns.clone();
+        let data_types = rw_columns
+            .iter()
+            .map(|col| col.data_type.clone())
+            .collect_vec();
+
+        // The id of the last snapshot whose data files have all been delivered. Chunks carry
+        // it as their offset (except for the last chunk of a snapshot), so that recovering in
+        // the middle of a snapshot re-reads the whole snapshot instead of losing its
+        // remainder.
+        let mut delivered_snapshot = self.split.snapshot_id;
+        // The paths of the data files of the last delivered snapshot.
+        let mut seen_files: HashSet<String> = HashSet::new();
+
+        if let Some(snapshot_id) = delivered_snapshot {
+            let table = self.properties.load_table().await?;
+            if table.current_table_metadata().current_snapshot_id == Some(snapshot_id) {
+                // The table has not moved since the recorded snapshot, so its file set is
+                // exactly the current one.
+                seen_files = table
+                    .current_data_files()
+                    .await
+                    .map_err(|e| anyhow!(e))?
+                    .into_iter()
+                    .map(|file| file.file_path)
+                    .collect();
+            } else {
+                // The file set of the recorded snapshot cannot be reconstructed once the
+                // table has moved past it, so fall back to re-scanning the whole table.
+                // TODO: read the manifest list of the recorded snapshot instead.
+                tracing::warn!(
+                    snapshot_id,
+                    "iceberg table has moved past the recorded snapshot, re-scanning the table"
+                );
+                delivered_snapshot = None;
+            }
+        }
+
+        loop {
+            let table = self.properties.load_table().await?;
+            let current_snapshot = table.current_table_metadata().current_snapshot_id;
+            if current_snapshot.is_none() || current_snapshot == delivered_snapshot {
+                // No snapshot committed since the last poll.
+                tokio::time::sleep(self.scan_interval).await;
+                continue;
+            }
+            let current_snapshot = current_snapshot.unwrap();
+
+            let current_files: HashSet<String> = table
+                .current_data_files()
+                .await
+                .map_err(|e| anyhow!(e))?
+                .into_iter()
+                .map(|file| file.file_path)
+                .collect();
+            if let Some(removed) = seen_files.iter().find(|path| !current_files.contains(*path))
+            {
+                return Err(anyhow!(
+                    "data file {} was rewritten or deleted, only append-only iceberg tables are supported",
+                    removed
+                )
+                .into());
+            }
+
+            // Deliver the data files added since the last delivered snapshot, in a
+            // deterministic order.
+            let mut new_files = current_files
+                .iter()
+                .filter(|path| !seen_files.contains(*path))
+                .cloned()
+                .collect_vec();
+            new_files.sort();
+
+            let prev_offset = delivered_snapshot
+                .map(|id| id.to_string())
+                .unwrap_or_default();
+            let mut pending: Vec<(Op, OwnedRow)> = Vec::new();
+            for file_path in &new_files {
+                let bytes = self
+                    .operator
+                    .read(&operator_path(file_path, &self.bucket)?)
+                    .await
+                    .map_err(|e| anyhow!(e))?;
+                let builder =
+                    ParquetRecordBatchReaderBuilder::try_new(Bytes::from(bytes))
+                        .map_err(|e| anyhow!(e))?
+                        .with_batch_size(chunk_size);
+                let column_indices =
+                    map_columns_to_file(&rw_columns, builder.schema(), file_path)?;
+                for batch in builder.build().map_err(|e| anyhow!(e))? {
+                    let batch = batch.map_err(|e| anyhow!(e))?;
+                    let data_chunk = DataChunk::try_from(&batch)?;
+                    for i in 0..data_chunk.capacity() {
+                        let row = OwnedRow::new(
+                            rw_columns
+                                .iter()
+                                .zip_eq(column_indices.iter())
+                                .map(|(col, idx)| match col.name.as_str() {
+                                    ICEBERG_SNAPSHOT_ID_COLUMN_NAME => {
+                                        Some(ScalarImpl::Int64(current_snapshot))
+                                    }
+                                    ICEBERG_FILE_PATH_COLUMN_NAME => {
+                                        Some(ScalarImpl::Utf8(file_path.as_str().into()))
+                                    }
+                                    _ => idx.and_then(|idx| {
+                                        data_chunk.column_at(idx).value_at(i).to_owned_datum()
+                                    }),
+                                })
+                                .collect(),
+                        );
+                        pending.push((Op::Insert, row));
+                    }
+                    // Always keep at least one row pending, so that the last chunk of the
+                    // snapshot can carry the advanced offset.
+                    while pending.len() > chunk_size {
+                        let rows = pending.drain(..chunk_size).collect_vec();
+                        yield StreamChunkWithState {
+                            chunk: StreamChunk::from_rows(&rows, &data_types),
+                            split_offset_mapping: Some(
+                                hashmap! { split_id.clone() => prev_offset.clone() },
+                            ),
+                        };
+                    }
+                }
+            }
+            if !pending.is_empty() {
+                let rows = std::mem::take(&mut pending);
+                yield StreamChunkWithState {
+                    chunk: StreamChunk::from_rows(&rows, &data_types),
+                    split_offset_mapping: Some(
+                        hashmap! { split_id.clone() => current_snapshot.to_string() },
+                    ),
+                };
+            }
+
+            delivered_snapshot = Some(current_snapshot);
+            seen_files = current_files;
+        }
+    }
+}
+
+/// Build an opendal operator rooted at the bucket of the warehouse path.
+fn build_operator(properties: &IcebergProperties) -> Result<(Operator, String)> {
+    let url = Url::parse(&properties.warehouse_path).map_err(|e| anyhow!(e))?;
+    let bucket = url
+        .host_str()
+        .ok_or_else(|| {
+            anyhow!(
+                "Invalid s3 path: {}, bucket is missing",
+                properties.warehouse_path
+            )
+        })?
+        .to_string();
+
+    let mut builder = S3::default();
+    builder
+        .bucket(&bucket)
+        .access_key_id(&properties.access_key)
+        .secret_access_key(&properties.secret_key);
+    if let Some(region) = &properties.region {
+        builder.region(region);
+    }
+    if let Some(endpoint) = &properties.endpoint {
+        builder.endpoint(endpoint);
+    }
+    builder.disable_config_load();
+    let operator = Operator::new(builder).map_err(|e| anyhow!(e))?.finish();
+    Ok((operator, bucket))
+}
+
+/// Turn the absolute path of a data file into a path relative to the bucket root.
+fn operator_path(file_path: &str, bucket: &str) -> Result<String> {
+    let url = Url::parse(file_path).map_err(|e| anyhow!(e))?;
+    if url.host_str() != Some(bucket) {
+        return Err(anyhow!(
+            "data file {} is not in the warehouse bucket {}",
+            file_path,
+            bucket
+        ));
+    }
+    Ok(url.path().trim_start_matches('/').to_string())
+}
+
+/// For each source column, find the index of the corresponding column in the data file, or
+/// `None` for internal columns and for columns not present in the file, e.g. columns added to
+/// the table after the file was written.
+fn map_columns_to_file(
+    rw_columns: &[SourceColumnDesc],
+    file_schema: &arrow_schema::Schema,
+    file_path: &str,
+) -> Result<Vec<Option<usize>>> {
+    let mut column_indices = Vec::with_capacity(rw_columns.len());
+    for col in rw_columns {
+        let idx = if col.is_visible() {
+            match file_schema.index_of(&col.name) {
+                Ok(idx) => {
+                    let file_type = DataType::from(file_schema.field(idx).data_type());
+                    if file_type != col.data_type {
+                        return Err(anyhow!(
+                            "column {} of data file {} has type {}, but {} is expected",
+                            col.name,
+                            file_path,
+                            file_type,
+                            col.data_type
+                        ));
+                    }
+                    Some(idx)
+                }
+                Err(_) => None,
+            }
+        } else {
+            None
+        };
+        column_indices.push(idx);
+    }
+    Ok(column_indices)
+}
@@ -0,0 +1,58 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::anyhow;
+use risingwave_common::types::JsonbVal;
+use serde::{Deserialize, Serialize};
+
+use crate::source::{SplitId, SplitMetaData};
+
+/// The state of an iceberg split, which will be persisted to checkpoint. The offset of the
+/// split is the id of the last snapshot whose data files have all been delivered, so recovery
+/// restarts tailing right after that snapshot.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Hash)]
+pub struct IcebergSplit {
+    // TODO: to simplify the logic, the whole table is one split for the first version. May
+    // split by data file to use parallelism in future.
+    pub(crate) split_id: SplitId,
+    /// The id of the last fully delivered snapshot. `None` before the initial table scan
+    /// finishes.
+    pub(crate) snapshot_id: Option<i64>,
+}
+
+impl SplitMetaData for IcebergSplit {
+    fn id(&self) -> SplitId {
+        self.split_id.clone()
+    }
+
+    fn restore_from_json(value: JsonbVal) -> anyhow::Result<Self> {
+        serde_json::from_value(value.take()).map_err(|e| anyhow!(e))
+    }
+
+    fn encode_to_json(&self) -> JsonbVal {
+        serde_json::to_value(self.clone()).unwrap().into()
+    }
+
+    fn update_with_offset(&mut self, start_offset: String) -> anyhow::Result<()> {
+        // An empty offset means the initial table scan has not finished yet.
+        self.snapshot_id = if start_offset.is_empty() {
+            None
+        } else {
+            Some(start_offset.parse().map_err(|e| {
+                anyhow!("invalid snapshot id offset {:?}: {}", start_offset, e)
+            })?)
+        };
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use anyhow::Result;
 use async_trait::async_trait;
 use aws_sdk_kinesis::types::Shard;
@@ -26,7 +28,48 @@ pub struct KinesisSplitEnumerator {
     client: kinesis_client,
 }
 
-impl KinesisSplitEnumerator {}
+impl KinesisSplitEnumerator {
+    /// Convert the shards listed from the stream into splits.
+    ///
+    /// A shard sealed by a resharding stays in the listing until its retention expires. It is
+    /// returned with its ending sequence number recorded, so that the reader stops at the end of
+    /// the shard instead of polling the closed shard forever. A child shard whose parents are
+    /// still open is held back until the resharding completes, so that its records are not
+    /// delivered before the parent shards are sealed.
+    fn shards_to_splits(shards: Vec<Shard>) -> Vec<KinesisSplit> {
+        // Shard ids that are still open, i.e. may still receive new records.
+        let open_shards: HashSet<&str> = shards
+            .iter()
+            .filter(|x| {
+                x.sequence_number_range()
+                    .and_then(|r| r.ending_sequence_number())
+                    .is_none()
+            })
+            .filter_map(|x| x.shard_id())
+            .collect();
+
+        shards
+            .iter()
+            .filter(|x| {
+                (x.parent_shard_id().into_iter())
+                    .chain(x.adjacent_parent_shard_id())
+                    .all(|parent| !open_shards.contains(parent))
+            })
+            .map(|x| KinesisSplit {
+                shard_id: x.shard_id().unwrap_or_default().to_string().into(),
+                // handle start with position in reader part
+                start_position: KinesisOffset::None,
+                end_position: match x
+                    .sequence_number_range()
+                    .and_then(|r| r.ending_sequence_number())
+                {
+                    Some(end) => KinesisOffset::SequenceNumber(end.to_string()),
+                    None => KinesisOffset::None,
+                },
+            })
+            .collect()
+    }
+}
 
 #[async_trait]
 impl SplitEnumerator for KinesisSplitEnumerator {
@@ -72,24 +115,56 @@ impl SplitEnumerator for KinesisSplitEnumerator {
                 None => break,
             }
         }
-        Ok(shard_collect
-            .into_iter()
-            .map(|x| KinesisSplit {
-                shard_id: x.shard_id().unwrap_or_default().to_string().into(),
-                // handle start with position in reader part
-                start_position: KinesisOffset::None,
-                end_position: KinesisOffset::None,
-            })
-            .collect())
+        Ok(Self::shards_to_splits(shard_collect))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use aws_sdk_kinesis::config::Region;
+    use aws_sdk_kinesis::types::SequenceNumberRange;
 
     use super::*;
 
+    fn shard(id: &str, parent: Option<&str>, end: Option<&str>) -> Shard {
+        let mut range = SequenceNumberRange::builder().starting_sequence_number("0");
+        if let Some(end) = end {
+            range = range.ending_sequence_number(end);
+        }
+        let mut builder = Shard::builder()
+            .shard_id(id)
+            .sequence_number_range(range.build());
+        if let Some(parent) = parent {
+            builder = builder.parent_shard_id(parent);
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_shards_to_splits() {
+        // Mid-resharding: the child is listed while its parent is still open, hold it back.
+        let splits = KinesisSplitEnumerator::shards_to_splits(vec![
+            shard("shardId-0", None, None),
+            shard("shardId-1", Some("shardId-0"), None),
+        ]);
+        assert_eq!(splits.len(), 1);
+        assert_eq!(splits[0].shard_id.as_ref(), "shardId-0");
+
+        // Resharding completed: the sealed parent carries its ending sequence number and its
+        // children become assignable.
+        let splits = KinesisSplitEnumerator::shards_to_splits(vec![
+            shard("shardId-0", None, Some("42")),
+            shard("shardId-1", Some("shardId-0"), None),
+            shard("shardId-2", Some("shardId-0"), None),
+        ]);
+        assert_eq!(splits.len(), 3);
+        assert_eq!(
+            splits[0].end_position,
+            KinesisOffset::SequenceNumber("42".to_string())
+        );
+        assert_eq!(splits[1].end_position, KinesisOffset::None);
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_kinesis_split_enumerator() -> Result<()> {
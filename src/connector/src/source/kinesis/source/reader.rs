@@ -145,17 +145,32 @@ impl CommonSplitReader for KinesisSplitReader {
                             ))
                         })
                         .collect::<Vec<SourceMessage>>();
-                    if chunk.is_empty() {
+                    let no_progress = chunk.is_empty();
+                    if !no_progress {
+                        self.latest_offset = Some(chunk.last().unwrap().offset.clone());
+                        tracing::debug!(
+                            "shard {:?} latest offset: {:?}",
+                            self.shard_id,
+                            self.latest_offset
+                        );
+                        yield chunk;
+                    }
+                    // A shard sealed by a resharding returns a null iterator once all its
+                    // records are consumed. Stop reading so that its child shards take over,
+                    // instead of busily renewing an iterator at the end of the shard.
+                    if self.shard_iter.is_none()
+                        && !matches!(self.end_position, KinesisOffset::None)
+                    {
+                        tracing::info!(
+                            "shard {:?} has been drained after resharding, stop reading",
+                            self.shard_id
+                        );
+                        break;
+                    }
+                    if no_progress {
                         tokio::time::sleep(Duration::from_millis(200)).await;
                         continue;
                     }
-                    self.latest_offset = Some(chunk.last().unwrap().offset.clone());
-                    tracing::debug!(
-                        "shard {:?} latest offset: {:?}",
-                        self.shard_id,
-                        self.latest_offset
-                    );
-                    yield chunk;
                 }
                 Err(SdkError::ServiceError(e)) if e.err().is_resource_not_found_exception() => {
                     tracing::warn!("shard {:?} is closed, stop reading", self.shard_id);
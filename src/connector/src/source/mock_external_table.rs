@@ -21,7 +21,7 @@ use risingwave_common::types::ScalarImpl;
 
 use crate::error::ConnectorError;
 use crate::source::external::{
-    CdcOffset, ConnectorResult, ExternalTableReader, MySqlOffset, SchemaTableName,
+    CdcOffset, ConnectorResult, ExternalTableReader, MySqlOffset, SchemaTableName, SnapshotSplit,
 };
 
 #[derive(Debug)]
@@ -121,4 +121,17 @@ impl ExternalTableReader for MockExternalTableReader {
     ) -> BoxStream<'_, ConnectorResult<OwnedRow>> {
         self.snapshot_read_inner()
     }
+
+    async fn get_snapshot_splits(
+        &self,
+        _table_name: SchemaTableName,
+        _primary_keys: Vec<String>,
+        _split_size: u64,
+    ) -> ConnectorResult<Vec<SnapshotSplit>> {
+        Ok(vec![SnapshotSplit {
+            split_id: 0,
+            left_bound_pk: None,
+            right_bound_pk: None,
+        }])
+    }
 }
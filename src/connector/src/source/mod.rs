@@ -18,17 +18,21 @@ pub mod data_gen_util;
 pub mod datagen;
 pub mod filesystem;
 pub mod google_pubsub;
+pub mod iceberg;
 pub mod kafka;
 pub mod kinesis;
 pub mod monitor;
+pub mod mqtt;
 pub mod nats;
 pub mod nexmark;
 pub mod pulsar;
 pub use base::{UPSTREAM_SOURCE_KEY, *};
 pub(crate) use common::*;
 pub use google_pubsub::GOOGLE_PUBSUB_CONNECTOR;
+pub use iceberg::ICEBERG_CONNECTOR;
 pub use kafka::KAFKA_CONNECTOR;
 pub use kinesis::KINESIS_CONNECTOR;
+pub use mqtt::MQTT_CONNECTOR;
 pub use nats::NATS_CONNECTOR;
 mod common;
 pub mod external;
@@ -0,0 +1,53 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use anyhow;
+use async_trait::async_trait;
+
+use super::source::MqttSplit;
+use super::MqttProperties;
+use crate::source::{SourceEnumeratorContextRef, SplitEnumerator, SplitId};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MqttSplitEnumerator {
+    topic: String,
+    split_id: SplitId,
+}
+
+#[async_trait]
+impl SplitEnumerator for MqttSplitEnumerator {
+    type Properties = MqttProperties;
+    type Split = MqttSplit;
+
+    async fn new(
+        properties: Self::Properties,
+        _context: SourceEnumeratorContextRef,
+    ) -> anyhow::Result<MqttSplitEnumerator> {
+        Ok(Self {
+            topic: properties.common.topic,
+            split_id: Arc::from("0"),
+        })
+    }
+
+    async fn list_splits(&mut self) -> anyhow::Result<Vec<MqttSplit>> {
+        // An MQTT subscription (possibly a wildcard) cannot be enumerated upfront, so a single
+        // split covering the whole subscription is returned.
+        Ok(vec![MqttSplit::new(
+            self.topic.clone(),
+            self.split_id.clone(),
+        )])
+    }
+}
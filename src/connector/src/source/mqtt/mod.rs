@@ -0,0 +1,41 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod enumerator;
+pub mod source;
+pub mod split;
+
+use serde::Deserialize;
+use with_options::WithOptions;
+
+use crate::common::MqttCommon;
+use crate::source::mqtt::enumerator::MqttSplitEnumerator;
+use crate::source::mqtt::source::{MqttSplit, MqttSplitReader};
+use crate::source::SourceProperties;
+
+pub const MQTT_CONNECTOR: &str = "mqtt";
+
+#[derive(Clone, Debug, Deserialize, WithOptions)]
+pub struct MqttProperties {
+    #[serde(flatten)]
+    pub common: MqttCommon,
+}
+
+impl SourceProperties for MqttProperties {
+    type Split = MqttSplit;
+    type SplitEnumerator = MqttSplitEnumerator;
+    type SplitReader = MqttSplitReader;
+
+    const SOURCE_NAME: &'static str = MQTT_CONNECTOR;
+}
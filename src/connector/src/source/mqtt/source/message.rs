@@ -0,0 +1,48 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rumqttc::v5::mqttbytes::v5::Publish;
+
+use crate::source::base::SourceMessage;
+use crate::source::{SourceMeta, SplitId};
+
+#[derive(Clone, Debug)]
+pub struct MqttMessage {
+    pub split_id: SplitId,
+    pub packet_id: u16,
+    pub payload: Vec<u8>,
+}
+
+impl From<MqttMessage> for SourceMessage {
+    fn from(message: MqttMessage) -> Self {
+        SourceMessage {
+            key: None,
+            payload: Some(message.payload),
+            // For MQTT, use the packet id as offset to skip redelivered packets after recovery.
+            offset: message.packet_id.to_string(),
+            split_id: message.split_id,
+            meta: SourceMeta::Empty,
+        }
+    }
+}
+
+impl MqttMessage {
+    pub fn new(split_id: SplitId, message: Publish) -> Self {
+        MqttMessage {
+            split_id,
+            packet_id: message.pkid,
+            payload: message.payload.to_vec(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures_async_stream::try_stream;
+use rumqttc::v5::{AsyncClient, Event, EventLoop, Incoming};
+
+use super::message::MqttMessage;
+use super::MqttSplit;
+use crate::parser::ParserConfig;
+use crate::source::common::{into_chunk_stream, CommonSplitReader};
+use crate::source::mqtt::MqttProperties;
+use crate::source::{
+    BoxSourceWithStateStream, Column, SourceContextRef, SourceMessage, SplitId, SplitReader,
+};
+
+pub struct MqttSplitReader {
+    client: AsyncClient,
+    eventloop: EventLoop,
+    properties: MqttProperties,
+    parser_config: ParserConfig,
+    source_ctx: SourceContextRef,
+    split_id: SplitId,
+    last_packet_id: Option<u16>,
+}
+
+#[async_trait]
+impl SplitReader for MqttSplitReader {
+    type Properties = MqttProperties;
+    type Split = MqttSplit;
+
+    async fn new(
+        properties: MqttProperties,
+        splits: Vec<MqttSplit>,
+        parser_config: ParserConfig,
+        source_ctx: SourceContextRef,
+        _columns: Option<Vec<Column>>,
+    ) -> Result<Self> {
+        // An MQTT source always produces a single split.
+        assert!(splits.len() == 1);
+        let split = splits.into_iter().next().unwrap();
+        let split_id = split.split_id;
+        let last_packet_id = split.last_packet_id;
+
+        let (client, eventloop) = properties
+            .common
+            .build_client(source_ctx.source_info.actor_id)?;
+        client
+            .subscribe(split.topic.clone(), properties.common.qos()?)
+            .await
+            .map_err(|e| anyhow!(e))?;
+
+        Ok(Self {
+            client,
+            eventloop,
+            properties,
+            parser_config,
+            source_ctx,
+            split_id,
+            last_packet_id,
+        })
+    }
+
+    fn into_stream(self) -> BoxSourceWithStateStream {
+        let parser_config = self.parser_config.clone();
+        let source_context = self.source_ctx.clone();
+        into_chunk_stream(self, parser_config, source_context)
+    }
+}
+
+impl CommonSplitReader for MqttSplitReader {
+    #[try_stream(ok = Vec<SourceMessage>, error = anyhow::Error)]
+    async fn into_data_stream(mut self) {
+        loop {
+            match self.eventloop.poll().await {
+                Ok(Event::Incoming(Incoming::Publish(publish))) => {
+                    // Skip the packet if it is a redelivery of the last packet delivered before
+                    // recovery.
+                    if publish.dup
+                        && publish.pkid != 0
+                        && self.last_packet_id == Some(publish.pkid)
+                    {
+                        continue;
+                    }
+                    self.last_packet_id = Some(publish.pkid);
+                    yield vec![SourceMessage::from(MqttMessage::new(
+                        self.split_id.clone(),
+                        publish,
+                    ))];
+                }
+                Ok(_) => (),
+                Err(e) => return Err(anyhow!(e)),
+            }
+        }
+    }
+}
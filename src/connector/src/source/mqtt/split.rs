@@ -0,0 +1,63 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Ok};
+use risingwave_common::types::JsonbVal;
+use serde::{Deserialize, Serialize};
+
+use crate::source::{SplitId, SplitMetaData};
+
+/// The states of an MQTT split, which will be persisted to checkpoint.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Hash)]
+pub struct MqttSplit {
+    pub(crate) topic: String,
+    // TODO: an MQTT subscription cannot be consumed in parallel, so a single split is used.
+    pub(crate) split_id: SplitId,
+    /// The packet id of the last delivered publish, used to skip redelivered packets after
+    /// recovery.
+    pub(crate) last_packet_id: Option<u16>,
+}
+
+impl SplitMetaData for MqttSplit {
+    fn id(&self) -> SplitId {
+        self.split_id.clone()
+    }
+
+    fn restore_from_json(value: JsonbVal) -> anyhow::Result<Self> {
+        serde_json::from_value(value.take()).map_err(|e| anyhow!(e))
+    }
+
+    fn encode_to_json(&self) -> JsonbVal {
+        serde_json::to_value(self.clone()).unwrap().into()
+    }
+
+    fn update_with_offset(&mut self, last_packet_id: String) -> anyhow::Result<()> {
+        self.last_packet_id = if last_packet_id.is_empty() {
+            None
+        } else {
+            Some(last_packet_id.parse()?)
+        };
+        Ok(())
+    }
+}
+
+impl MqttSplit {
+    pub fn new(topic: String, split_id: SplitId) -> Self {
+        Self {
+            topic,
+            split_id,
+            last_packet_id: None,
+        }
+    }
+}
@@ -19,6 +19,7 @@ pub use list_kv::*;
 mod sst_dump;
 pub use sst_dump::*;
 mod compaction_group;
+mod ingest_sstables;
 mod list_version_deltas;
 mod pause_resume;
 mod trigger_full_gc;
@@ -26,6 +27,7 @@ mod trigger_manual_compaction;
 mod validate_version;
 
 pub use compaction_group::*;
+pub use ingest_sstables::*;
 pub use list_version_deltas::*;
 pub use pause_resume::*;
 pub use trigger_full_gc::*;
@@ -0,0 +1,38 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_hummock_sdk::CompactionGroupId;
+use risingwave_pb::hummock::SstableInfo;
+
+use crate::CtlContext;
+
+pub async fn ingest_sstables(
+    context: &CtlContext,
+    compaction_group_id: CompactionGroupId,
+    target_level: u32,
+    sst_info_path: String,
+) -> anyhow::Result<()> {
+    let sstables: Vec<SstableInfo> =
+        serde_json::from_str(&std::fs::read_to_string(&sst_info_path)?)?;
+    let meta_client = context.meta_client().await?;
+    let sst_object_ids = sstables.iter().map(|sst| sst.object_id).collect::<Vec<_>>();
+    meta_client
+        .ingest_sstables(compaction_group_id, target_level, sstables)
+        .await?;
+    println!(
+        "Succeed: ingested SST objects {:#?} into level {} of compaction group {}.",
+        sst_object_ids, target_level, compaction_group_id
+    );
+    Ok(())
+}
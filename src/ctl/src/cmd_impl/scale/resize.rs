@@ -17,6 +17,7 @@ use std::process::exit;
 
 use inquire::Confirm;
 use itertools::Itertools;
+use risingwave_pb::common::WorkerNode;
 use risingwave_pb::meta::get_reschedule_plan_request::{
     PbPolicy, StableResizePolicy, WorkerChanges,
 };
@@ -361,20 +362,8 @@ pub async fn resize(ctl_ctx: &CtlContext, scale_ctx: ScaleCommandContext) -> any
     Ok(())
 }
 
-pub async fn update_schedulability(
-    context: &CtlContext,
-    workers: Vec<String>,
-    target: Schedulability,
-) -> anyhow::Result<()> {
-    let meta_client = context.meta_client().await?;
-
-    let GetClusterInfoResponse { worker_nodes, .. } = match meta_client.get_cluster_info().await {
-        Ok(resp) => resp,
-        Err(e) => {
-            fail!("Failed to get cluster info: {:?}", e);
-        }
-    };
-
+/// Resolve worker inputs (either worker ids or `host:port` addresses) to worker ids.
+fn resolve_worker_ids(worker_nodes: &[WorkerNode], workers: Vec<String>) -> Vec<u32> {
     let worker_ids: HashSet<_> = worker_nodes.iter().map(|worker| worker.id).collect();
 
     let worker_index_by_host: HashMap<_, _> = worker_nodes
@@ -404,7 +393,24 @@ pub async fn update_schedulability(
         }
     }
 
-    let target_worker_ids = target_worker_ids.into_iter().collect_vec();
+    target_worker_ids.into_iter().collect_vec()
+}
+
+pub async fn update_schedulability(
+    context: &CtlContext,
+    workers: Vec<String>,
+    target: Schedulability,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let GetClusterInfoResponse { worker_nodes, .. } = match meta_client.get_cluster_info().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            fail!("Failed to get cluster info: {:?}", e);
+        }
+    };
+
+    let target_worker_ids = resolve_worker_ids(&worker_nodes, workers);
 
     meta_client
         .update_schedulability(&target_worker_ids, target)
@@ -412,3 +418,54 @@ pub async fn update_schedulability(
 
     Ok(())
 }
+
+/// Gracefully drain compute nodes so that they are safe to terminate: cordon them, migrate their
+/// actors away via a barrier-based reschedule, and finally let the meta node verify that nothing
+/// is left and unpin the hummock read versions they hold.
+pub async fn drain(context: &CtlContext, workers: Vec<String>, yes: bool) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let GetClusterInfoResponse { worker_nodes, .. } = match meta_client.get_cluster_info().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            fail!("Failed to get cluster info: {:?}", e);
+        }
+    };
+
+    let target_worker_ids = resolve_worker_ids(&worker_nodes, workers.clone());
+
+    // 1. Cordon the workers, so that no new actors will be scheduled on them.
+    meta_client
+        .update_schedulability(&target_worker_ids, Schedulability::Unschedulable)
+        .await?;
+    println!("Worker(s) {:?} cordoned", target_worker_ids);
+
+    // 2. Migrate the actors away via a barrier-based reschedule excluding the workers.
+    resize(
+        context,
+        ScaleCommandContext {
+            exclude_workers: Some(workers),
+            include_workers: None,
+            target_parallelism: None,
+            generate: false,
+            output: None,
+            yes,
+            fragments: None,
+            target_parallelism_per_worker: None,
+        },
+    )
+    .await?;
+
+    // 3. Let the meta node verify that the workers no longer host any actor and unpin the
+    // hummock read versions they hold.
+    meta_client
+        .complete_drain_workers(&target_worker_ids)
+        .await?;
+
+    println!(
+        "Worker(s) {:?} drained, now safe to terminate",
+        target_worker_ids
+    );
+
+    Ok(())
+}
@@ -235,6 +235,17 @@ enum HummockCommands {
         #[clap(long)]
         table_ids: Vec<u32>,
     },
+    /// Ingest externally built SSTs into a non-L0 level of a compaction group, bypassing the
+    /// regular write path. Reads the `SstableInfo` list as JSON from the given file; the SST
+    /// objects must already be uploaded to the object store.
+    IngestSstables {
+        #[clap(long)]
+        compaction_group_id: u64,
+        #[clap(long)]
+        target_level: u32,
+        #[clap(long)]
+        sst_info_path: String,
+    },
     /// Pause version checkpoint, which subsequently pauses GC of delta log and SST object.
     PauseVersionCheckpoint,
     /// Resume version checkpoint, which subsequently resumes GC of delta log and SST object.
@@ -381,6 +392,23 @@ enum ScaleCommands {
         )]
         workers: Vec<String>,
     },
+    /// Gracefully drain compute nodes: cordon them, migrate their actors away via reschedule,
+    /// and unpin their hummock read versions, so that they are safe to terminate afterwards
+    #[clap(verbatim_doc_comment)]
+    Drain {
+        /// Workers that need to be drained, both id and host are supported.
+        #[clap(
+            long,
+            required = true,
+            value_delimiter = ',',
+            value_name = "id or host,..."
+        )]
+        workers: Vec<String>,
+
+        /// Automatic yes to prompts
+        #[clap(short = 'y', long, default_value_t = false)]
+        yes: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -601,6 +629,19 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             cmd_impl::hummock::split_compaction_group(context, compaction_group_id, &table_ids)
                 .await?;
         }
+        Commands::Hummock(HummockCommands::IngestSstables {
+            compaction_group_id,
+            target_level,
+            sst_info_path,
+        }) => {
+            cmd_impl::hummock::ingest_sstables(
+                context,
+                compaction_group_id,
+                target_level,
+                sst_info_path,
+            )
+            .await?;
+        }
         Commands::Hummock(HummockCommands::PauseVersionCheckpoint) => {
             cmd_impl::hummock::pause_version_checkpoint(context).await?;
         }
@@ -691,6 +732,9 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             cmd_impl::scale::update_schedulability(context, workers, Schedulability::Schedulable)
                 .await?
         }
+        Commands::Scale(ScaleCommands::Drain { workers, yes }) => {
+            cmd_impl::scale::drain(context, workers, yes).await?
+        }
         Commands::Debug(DebugCommands::Dump { common }) => cmd_impl::debug::dump(common).await?,
     }
     Ok(())
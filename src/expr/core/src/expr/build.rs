@@ -23,6 +23,7 @@ use super::expr_array_transform::ArrayTransformExpression;
 use super::expr_case::CaseExpression;
 use super::expr_coalesce::CoalesceExpression;
 use super::expr_field::FieldExpression;
+use super::expr_fused_cmp::FusedCompareExpression;
 use super::expr_in::InExpression;
 use super::expr_some_all::SomeAllExpression;
 use super::expr_udf::UdfExpression;
@@ -120,7 +121,13 @@ where
                 E::Vnode => VnodeExpression::build_boxed(prost, build_child),
 
                 // General types, lookup in the function signature map
-                _ => FuncCallBuilder::build_boxed(prost, build_child),
+                _ => {
+                    // Try to fuse a comparison with a constant into a specialized kernel first.
+                    if let Some(expr) = FusedCompareExpression::try_build(prost) {
+                        return Ok(expr.boxed());
+                    }
+                    FuncCallBuilder::build_boxed(prost, build_child)
+                }
             },
 
             RexNode::Now(_) => unreachable!("now should not be built at backend"),
@@ -37,6 +37,7 @@ mod expr_array_transform;
 mod expr_case;
 mod expr_coalesce;
 mod expr_field;
+mod expr_fused_cmp;
 mod expr_in;
 mod expr_input_ref;
 mod expr_literal;
@@ -80,6 +80,24 @@ impl Frame {
         }
     }
 
+    pub fn groups(start: FrameBound<usize>, end: FrameBound<usize>) -> Self {
+        Self {
+            bounds: FrameBounds::Groups(start, end),
+            exclusion: FrameExclusion::default(),
+        }
+    }
+
+    pub fn groups_with_exclusion(
+        start: FrameBound<usize>,
+        end: FrameBound<usize>,
+        exclusion: FrameExclusion,
+    ) -> Self {
+        Self {
+            bounds: FrameBounds::Groups(start, end),
+            exclusion,
+        }
+    }
+
     pub fn is_unbounded(&self) -> bool {
         self.bounds.is_unbounded()
     }
@@ -95,6 +113,11 @@ impl Frame {
                 let end = FrameBound::from_protobuf(frame.get_end()?)?;
                 FrameBounds::Rows(start, end)
             }
+            PbType::Groups => {
+                let start = FrameBound::from_protobuf(frame.get_start()?)?;
+                let end = FrameBound::from_protobuf(frame.get_end()?)?;
+                FrameBounds::Groups(start, end)
+            }
         };
         let exclusion = FrameExclusion::from_protobuf(frame.get_exclusion()?)?;
         Ok(Self { bounds, exclusion })
@@ -110,6 +133,12 @@ impl Frame {
                 end: Some(end.to_protobuf()),
                 exclusion,
             },
+            FrameBounds::Groups(start, end) => PbWindowFrame {
+                r#type: PbType::Groups as _,
+                start: Some(start.to_protobuf()),
+                end: Some(end.to_protobuf()),
+                exclusion,
+            },
         }
     }
 }
@@ -117,19 +146,25 @@ impl Frame {
 impl FrameBounds {
     pub fn is_valid(&self) -> bool {
         match self {
-            Self::Rows(start, end) => start.partial_cmp(end).map(|o| o.is_le()).unwrap_or(false),
+            Self::Rows(start, end) | Self::Groups(start, end) => {
+                start.partial_cmp(end).map(|o| o.is_le()).unwrap_or(false)
+            }
         }
     }
 
     pub fn start_is_unbounded(&self) -> bool {
         match self {
-            Self::Rows(start, _) => matches!(start, FrameBound::UnboundedPreceding),
+            Self::Rows(start, _) | Self::Groups(start, _) => {
+                matches!(start, FrameBound::UnboundedPreceding)
+            }
         }
     }
 
     pub fn end_is_unbounded(&self) -> bool {
         match self {
-            Self::Rows(_, end) => matches!(end, FrameBound::UnboundedFollowing),
+            Self::Rows(_, end) | Self::Groups(_, end) => {
+                matches!(end, FrameBound::UnboundedFollowing)
+            }
         }
     }
 
@@ -144,6 +179,9 @@ impl Display for FrameBounds {
             Self::Rows(start, end) => {
                 write!(f, "ROWS BETWEEN {} AND {}", start, end)?;
             }
+            Self::Groups(start, end) => {
+                write!(f, "GROUPS BETWEEN {} AND {}", start, end)?;
+            }
         }
         Ok(())
     }
@@ -152,7 +190,7 @@ impl Display for FrameBounds {
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum FrameBounds {
     Rows(FrameBound<usize>, FrameBound<usize>),
-    // Groups(FrameBound<usize>, FrameBound<usize>),
+    Groups(FrameBound<usize>, FrameBound<usize>),
     // Range(FrameBound<ScalarImpl>, FrameBound<ScalarImpl>),
 }
 
@@ -272,7 +310,7 @@ impl FrameBound<usize> {
 pub enum FrameExclusion {
     CurrentRow,
     // Group,
-    // Ties,
+    Ties,
     #[default]
     NoOthers,
 }
@@ -281,6 +319,7 @@ impl Display for FrameExclusion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             FrameExclusion::CurrentRow => write!(f, "EXCLUDE CURRENT ROW")?,
+            FrameExclusion::Ties => write!(f, "EXCLUDE TIES")?,
             FrameExclusion::NoOthers => write!(f, "EXCLUDE NO OTHERS")?,
         }
         Ok(())
@@ -292,6 +331,7 @@ impl FrameExclusion {
         let excl = match exclusion {
             PbExclusion::Unspecified => bail!("unspecified type of `FrameExclusion`"),
             PbExclusion::CurrentRow => Self::CurrentRow,
+            PbExclusion::Ties => Self::Ties,
             PbExclusion::NoOthers => Self::NoOthers,
         };
         Ok(excl)
@@ -300,6 +340,7 @@ impl FrameExclusion {
     pub fn to_protobuf(self) -> PbExclusion {
         match self {
             Self::CurrentRow => PbExclusion::CurrentRow,
+            Self::Ties => PbExclusion::Ties,
             Self::NoOthers => PbExclusion::NoOthers,
         }
     }
@@ -25,6 +25,12 @@ struct Entry<K: Ord, V> {
     value: V,
 }
 
+/// Key type of [`WindowBuffer`] that can tell whether two keys belong to the same peer group,
+/// which is required by `GROUPS` frame mode and `EXCLUDE TIES` frame exclusion.
+pub trait PeerKey: Ord {
+    fn is_peer_of(&self, other: &Self) -> bool;
+}
+
 // TODO(rc): May be a good idea to extract this into a separate crate.
 /// A common sliding window buffer.
 pub struct WindowBuffer<K: Ord, V: Clone> {
@@ -43,7 +49,7 @@ pub struct CurrWindow<'a, K> {
     pub following_saturated: bool,
 }
 
-impl<K: Ord, V: Clone> WindowBuffer<K, V> {
+impl<K: PeerKey, V: Clone> WindowBuffer<K, V> {
     pub fn new(frame: Frame, enable_delta: bool) -> Self {
         assert!(frame.bounds.is_valid());
         if enable_delta {
@@ -85,6 +91,26 @@ impl<K: Ord, V: Clone> WindowBuffer<K, V> {
                         false // unbounded frame start, never preceding-saturated
                     }
                 }
+                FrameBounds::Groups(start, _) => {
+                    let start_off = start.to_offset();
+                    if let Some(start_off) = start_off {
+                        if start_off >= 0 {
+                            true // pure following frame, always preceding-saturated
+                        } else {
+                            // Check that there are at least `-start_off` peer groups before the
+                            // current one in the buffer.
+                            let mut n_groups = 0;
+                            let mut group_start = self.peer_group_start(self.curr_idx);
+                            while group_start > 0 && n_groups < start_off.unsigned_abs() {
+                                group_start = self.peer_group_start(group_start - 1);
+                                n_groups += 1;
+                            }
+                            n_groups >= start_off.unsigned_abs()
+                        }
+                    } else {
+                        false // unbounded frame start, never preceding-saturated
+                    }
+                }
             }
     }
 
@@ -110,9 +136,65 @@ impl<K: Ord, V: Clone> WindowBuffer<K, V> {
                         false // unbounded frame end, never following-saturated
                     }
                 }
+                FrameBounds::Groups(_, end) => {
+                    let end_off = end.to_offset();
+                    if let Some(end_off) = end_off {
+                        if end_off < 0 {
+                            true // pure preceding frame, always following-saturated
+                        } else {
+                            // Check that there are at least `end_off` peer groups after the
+                            // current one, and the last one of them is complete, i.e. followed
+                            // by a row of some other peer group. Note that for `end_off == 0`,
+                            // the frame ends at the last peer of the current row, so the current
+                            // peer group itself must be complete.
+                            let mut n_groups = 0;
+                            let mut group_end = self.peer_group_end(self.curr_idx);
+                            while group_end < self.buffer.len() && n_groups < end_off as usize {
+                                group_end = self.peer_group_end(group_end);
+                                n_groups += 1;
+                            }
+                            n_groups == end_off as usize && group_end < self.buffer.len()
+                        }
+                    } else {
+                        false // unbounded frame end, never following-saturated
+                    }
+                }
             }
     }
 
+    /// Get the start index of the peer group containing the row at `idx`.
+    fn peer_group_start(&self, idx: usize) -> usize {
+        let key = &self.buffer[idx].key;
+        let mut start = idx;
+        while start > 0 && self.buffer[start - 1].key.is_peer_of(key) {
+            start -= 1;
+        }
+        start
+    }
+
+    /// Get the exclusive end index of the peer group containing the row at `idx`.
+    fn peer_group_end(&self, idx: usize) -> usize {
+        let key = &self.buffer[idx].key;
+        let mut end = idx + 1;
+        while end < self.buffer.len() && self.buffer[end].key.is_peer_of(key) {
+            end += 1;
+        }
+        end
+    }
+
+    /// Get the index range of the peer group of the current row. If the current row is not in
+    /// the buffer yet, pretend it belongs to the last peer group in the buffer, which keeps the
+    /// result conservative.
+    fn curr_peer_group(&self) -> Range<usize> {
+        if self.curr_idx < self.buffer.len() {
+            self.peer_group_start(self.curr_idx)..self.peer_group_end(self.curr_idx)
+        } else if let Some(last_idx) = self.buffer.len().checked_sub(1) {
+            self.peer_group_start(last_idx)..self.buffer.len()
+        } else {
+            0..0
+        }
+    }
+
     /// Get the key part of the current row.
     pub fn curr_key(&self) -> Option<&K> {
         self.buffer.get(self.curr_idx).map(|Entry { key, .. }| key)
@@ -131,18 +213,38 @@ impl<K: Ord, V: Clone> WindowBuffer<K, V> {
         self.left_idx..self.right_excl_idx
     }
 
-    fn curr_window_exclusion(&self) -> Range<usize> {
+    fn curr_window_exclusions(&self) -> SmallVec<[Range<usize>; 2]> {
         // TODO(rc): should intersect with `curr_window_outer` to be more accurate
         match self.frame.exclusion {
-            FrameExclusion::CurrentRow => self.curr_idx..self.curr_idx + 1,
-            FrameExclusion::NoOthers => self.curr_idx..self.curr_idx,
+            FrameExclusion::CurrentRow => smallvec![self.curr_idx..self.curr_idx + 1],
+            FrameExclusion::Ties => {
+                // exclude the peers of the current row, but not the current row itself
+                let peer_group = self.curr_peer_group();
+                smallvec![
+                    peer_group.start..self.curr_idx,
+                    self.curr_idx + 1..peer_group.end,
+                ]
+            }
+            FrameExclusion::NoOthers => smallvec![],
         }
     }
 
-    fn curr_window_ranges(&self) -> (Range<usize>, Range<usize>) {
-        let selection = self.curr_window_outer();
-        let exclusion = self.curr_window_exclusion();
-        range_except(selection, exclusion)
+    fn curr_window_ranges(&self) -> SmallVec<[Range<usize>; 3]> {
+        let mut ranges: SmallVec<[Range<usize>; 3]> = smallvec![self.curr_window_outer()];
+        for exclusion in self.curr_window_exclusions() {
+            let mut new_ranges = SmallVec::new();
+            for range in ranges {
+                let (left, right) = range_except(range, exclusion.clone());
+                if !left.is_empty() {
+                    new_ranges.push(left);
+                }
+                if !right.is_empty() {
+                    new_ranges.push(right);
+                }
+            }
+            ranges = new_ranges;
+        }
+        ranges
     }
 
     /// Iterate over values in the current window.
@@ -150,10 +252,9 @@ impl<K: Ord, V: Clone> WindowBuffer<K, V> {
         assert!(self.left_idx <= self.right_excl_idx);
         assert!(self.right_excl_idx <= self.buffer.len());
 
-        let (left, right) = self.curr_window_ranges();
-        self.buffer
-            .range(left)
-            .chain(self.buffer.range(right))
+        self.curr_window_ranges()
+            .into_iter()
+            .flat_map(|range| self.buffer.range(range))
             .map(|Entry { value, .. }| value)
     }
 
@@ -204,6 +305,63 @@ impl<K: Ord, V: Clone> WindowBuffer<K, V> {
                     self.right_excl_idx = self.buffer.len();
                 }
             }
+            FrameBounds::Groups(start, end) => {
+                let curr_group = self.curr_peer_group();
+                if let Some(start_off) = start.to_offset() {
+                    if start_off <= 0 {
+                        // the frame starts at the first row of some preceding (or the current)
+                        // peer group
+                        let mut left_idx = curr_group.start;
+                        for _ in 0..start_off.unsigned_abs() {
+                            if left_idx == 0 {
+                                break;
+                            }
+                            left_idx = self.peer_group_start(left_idx - 1);
+                        }
+                        self.left_idx = left_idx;
+                    } else {
+                        // the frame starts at the first row of some following peer group
+                        let mut left_idx = curr_group.end;
+                        for _ in 1..start_off {
+                            if left_idx >= self.buffer.len() {
+                                break;
+                            }
+                            left_idx = self.peer_group_end(left_idx);
+                        }
+                        self.left_idx = left_idx;
+                    }
+                } else {
+                    // unbounded start
+                    self.left_idx = 0;
+                }
+                if let Some(end_off) = end.to_offset() {
+                    if end_off >= 0 {
+                        // the frame ends at the last row of some following (or the current)
+                        // peer group
+                        let mut right_excl_idx = curr_group.end;
+                        for _ in 0..end_off {
+                            if right_excl_idx >= self.buffer.len() {
+                                break;
+                            }
+                            right_excl_idx = self.peer_group_end(right_excl_idx);
+                        }
+                        self.right_excl_idx = right_excl_idx;
+                    } else {
+                        // the frame ends at the last row of some preceding peer group
+                        let mut right_excl_idx = curr_group.start;
+                        for _ in 1..end_off.unsigned_abs() {
+                            if right_excl_idx == 0 {
+                                break;
+                            }
+                            right_excl_idx = self.peer_group_start(right_excl_idx - 1);
+                        }
+                        self.right_excl_idx = right_excl_idx;
+                    }
+                } else {
+                    // unbounded end
+                    self.right_excl_idx = self.buffer.len();
+                }
+            }
         }
     }
 
@@ -370,6 +528,12 @@ mod tests {
     use super::*;
     use crate::window_function::{Frame, FrameBound};
 
+    impl PeerKey for i32 {
+        fn is_peer_of(&self, other: &Self) -> bool {
+            self == other
+        }
+    }
+
     #[test]
     fn test_range_diff() {
         fn test(
@@ -669,4 +833,92 @@ mod tests {
             vec!["hello"]
         );
     }
+
+    #[test]
+    fn test_rows_frame_exclude_ties() {
+        let mut buffer = WindowBuffer::new(
+            Frame::rows_with_exclusion(
+                FrameBound::UnboundedPreceding,
+                FrameBound::CurrentRow,
+                FrameExclusion::Ties,
+            ),
+            false,
+        );
+
+        buffer.append(1, "hello");
+        buffer.append(2, "world");
+        buffer.append(2, "!");
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["hello"]
+        );
+        let _ = buffer.slide();
+        // the following peer of the current row is outside the frame, nothing is excluded
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["hello", "world"]
+        );
+        let _ = buffer.slide();
+        // the preceding peer of the current row is excluded, but not the current row itself
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["hello", "!"]
+        );
+    }
+
+    #[test]
+    fn test_groups_frame_preceding_to_current_row() {
+        let mut buffer = WindowBuffer::new(
+            Frame::groups(FrameBound::Preceding(1), FrameBound::CurrentRow),
+            true,
+        );
+
+        buffer.append(1, "hello");
+        let window = buffer.curr_window();
+        assert_eq!(window.key, Some(&1));
+        assert!(!window.preceding_saturated);
+        // more peers of the current row may still come
+        assert!(!window.following_saturated);
+        buffer.append(1, "world");
+        buffer.append(2, "!");
+        let window = buffer.curr_window();
+        assert_eq!(window.key, Some(&1));
+        assert!(!window.preceding_saturated);
+        assert!(window.following_saturated);
+        // the frame ends at the last peer of the current row
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["hello", "world"]
+        );
+        let removed_keys = buffer.slide().map(|(k, _)| k).collect_vec();
+        assert!(removed_keys.is_empty());
+        let window = buffer.curr_window();
+        assert_eq!(window.key, Some(&1));
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["hello", "world"]
+        );
+        let removed_keys = buffer.slide().map(|(k, _)| k).collect_vec();
+        assert!(removed_keys.is_empty());
+        let window = buffer.curr_window();
+        assert_eq!(window.key, Some(&2));
+        assert!(window.preceding_saturated);
+        // the current peer group may still grow
+        assert!(!window.following_saturated);
+        buffer.append(3, "~");
+        let window = buffer.curr_window();
+        assert_eq!(window.key, Some(&2));
+        assert!(window.following_saturated);
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["hello", "world", "!"]
+        );
+        let removed_keys = buffer.slide().map(|(k, _)| k).collect_vec();
+        assert_eq!(removed_keys, vec![1, 1]);
+        assert_eq!(buffer.smallest_key(), Some(&2));
+        assert_eq!(
+            buffer.curr_window_values().cloned().collect_vec(),
+            vec!["!", "~"]
+        );
+    }
 }
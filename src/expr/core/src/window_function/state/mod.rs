@@ -36,6 +36,13 @@ pub struct StateKey {
     pub pk: DefaultOrdered<OwnedRow>,
 }
 
+impl buffer::PeerKey for StateKey {
+    fn is_peer_of(&self, other: &Self) -> bool {
+        // rows with the same `ORDER BY` value are peers
+        self.order_key == other.order_key
+    }
+}
+
 #[derive(Debug)]
 pub struct StatePos<'a> {
     /// Only 2 cases in which the `key` is `None`:
@@ -0,0 +1,105 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{
+    DataType, Datum, DatumRef, JsonbRef, JsonbVal, ScalarImpl, StructRef, StructType, StructValue,
+};
+use risingwave_expr::expr::Context;
+use risingwave_expr::{function, ExprError, Result};
+
+/// Expands the top-level JSON object in `jsonb` to a row whose type is given by the `base`
+/// argument. JSON fields that do not correspond to any field of the record type are ignored,
+/// and record fields missing from the JSON object are taken from `base`, or null if `base`
+/// itself is null.
+///
+/// # Examples
+///
+/// ```slt
+/// query T
+/// select jsonb_populate_record(
+///     null::struct<a int, b varchar>,
+///     '{"a": 1, "b": "foo", "c": true}'::jsonb
+/// );
+/// ----
+/// (1,foo)
+/// ```
+#[function(
+    "jsonb_populate_record(struct, jsonb) -> struct",
+    type_infer = "|args| Ok(args[0].clone())"
+)]
+fn jsonb_populate_record(
+    base: Option<StructRef<'_>>,
+    jsonb: Option<JsonbRef<'_>>,
+    ctx: &Context,
+) -> Result<Option<StructValue>> {
+    let Some(jsonb) = jsonb else {
+        return Ok(None);
+    };
+    let DataType::Struct(ty) = &ctx.return_type else {
+        return Err(ExprError::InvalidParam {
+            name: "base",
+            reason: "not a composite type".into(),
+        });
+    };
+    populate_record(ty, base, jsonb).map(Some)
+}
+
+/// Builds a record of the given struct type out of the top-level JSON object in `jsonb`.
+/// Record fields missing from the JSON object are taken from `base`, or null if `base` is null.
+pub fn populate_record(
+    ty: &StructType,
+    base: Option<StructRef<'_>>,
+    jsonb: JsonbRef<'_>,
+) -> Result<StructValue> {
+    if !jsonb.is_object() {
+        return Err(ExprError::InvalidParam {
+            name: "jsonb",
+            reason: format!("cannot populate a record from a JSON {}", jsonb.type_name()).into(),
+        });
+    }
+    let base_fields: Vec<DatumRef<'_>> = match base {
+        Some(base) => base.iter_fields_ref().collect(),
+        None => vec![],
+    };
+    let fields = ty
+        .iter()
+        .enumerate()
+        .map(|(i, (name, field_type))| match jsonb.access_object_field(name) {
+            Some(value) => populate_field(field_type, value),
+            None => Ok(base_fields
+                .get(i)
+                .copied()
+                .flatten()
+                .map(|scalar_ref| scalar_ref.into_scalar_impl())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(StructValue::new(fields))
+}
+
+/// Converts a single JSON value to a datum of the given type, following the PostgreSQL rule:
+/// a JSON string is taken as-is, any other JSON value is converted from its text form, as if
+/// casted from `varchar`.
+fn populate_field(ty: &DataType, value: JsonbRef<'_>) -> Result<Datum> {
+    if value.is_jsonb_null() {
+        return Ok(None);
+    }
+    if let DataType::Jsonb = ty {
+        return Ok(Some(JsonbVal::from(value).into()));
+    }
+    let text = value.force_string();
+    let scalar = ScalarImpl::from_text(text.as_bytes(), ty).map_err(|_| {
+        ExprError::Parse(format!("cannot cast jsonb {} to type {}", value.type_name(), ty).into())
+    })?;
+    Ok(Some(scalar))
+}
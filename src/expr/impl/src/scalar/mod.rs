@@ -50,6 +50,7 @@ mod jsonb_contains;
 mod jsonb_delete;
 mod jsonb_info;
 mod jsonb_object;
+pub mod jsonb_record;
 mod length;
 mod lower;
 mod md5;
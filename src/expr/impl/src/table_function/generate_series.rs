@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use num_traits::One;
-use risingwave_common::types::{CheckedAdd, Decimal, IsNegative};
+use risingwave_common::types::{CheckedAdd, Decimal, Interval, IsNegative, Timestamptz};
 use risingwave_expr::{function, ExprError, Result};
 
 #[function("generate_series(int4, int4) -> setof int4")]
@@ -47,6 +47,15 @@ where
     range_generic::<_, _, true>(start, stop, step)
 }
 
+#[function("generate_series(timestamptz, timestamptz, interval) -> setof timestamptz")]
+fn generate_series_timestamptz(
+    start: Timestamptz,
+    stop: Timestamptz,
+    step: Interval,
+) -> Result<impl Iterator<Item = Result<Timestamptz>>> {
+    range_timestamptz::<true>(start, stop, step)
+}
+
 #[function("generate_series(decimal, decimal, decimal) -> setof decimal")]
 fn generate_series_step_decimal(
     start: Decimal,
@@ -85,6 +94,15 @@ where
     range_generic::<_, _, false>(start, stop, step)
 }
 
+#[function("range(timestamptz, timestamptz, interval) -> setof timestamptz")]
+fn range_step_timestamptz(
+    start: Timestamptz,
+    stop: Timestamptz,
+    step: Interval,
+) -> Result<impl Iterator<Item = Result<Timestamptz>>> {
+    range_timestamptz::<false>(start, stop, step)
+}
+
 #[function("range(decimal, decimal, decimal) -> setof decimal")]
 fn range_step_decimal(
     start: Decimal,
@@ -95,6 +113,27 @@ fn range_step_decimal(
     range_generic::<_, _, false>(start, stop, step)
 }
 
+#[inline]
+fn range_timestamptz<const INCLUSIVE: bool>(
+    start: Timestamptz,
+    stop: Timestamptz,
+    step: Interval,
+) -> Result<impl Iterator<Item = Result<Timestamptz>>> {
+    // Without session TimeZone, we cannot add month/day in local time. See #5826.
+    if step.months() != 0 || step.days() != 0 {
+        return Err(ExprError::InvalidParam {
+            name: "step",
+            reason: "step size must not have month/day part for timestamptz series".into(),
+        });
+    }
+    Ok(range_generic::<_, _, INCLUSIVE>(
+        start.timestamp_micros(),
+        stop.timestamp_micros(),
+        step.usecs(),
+    )?
+    .map(|r| r.map(Timestamptz::from_micros)))
+}
+
 #[inline]
 fn range_generic<T, S, const INCLUSIVE: bool>(
     start: T,
@@ -158,7 +197,7 @@ mod tests {
     use futures_util::StreamExt;
     use risingwave_common::array::DataChunk;
     use risingwave_common::types::test_utils::IntervalTestExt;
-    use risingwave_common::types::{DataType, Decimal, Interval, ScalarImpl, Timestamp};
+    use risingwave_common::types::{DataType, Decimal, Interval, ScalarImpl, Timestamp, Timestamptz};
     use risingwave_expr::expr::{BoxedExpression, ExpressionBoxExt, LiteralExpression};
     use risingwave_expr::table_function::build;
     use risingwave_expr::ExprError;
@@ -239,6 +278,64 @@ mod tests {
         assert_eq!(actual_cnt, expect_cnt);
     }
 
+    #[tokio::test]
+    async fn test_generate_series_timestamptz() {
+        let start_time = Timestamptz::from_micros(1204329600000000); // 2008-03-01 00:00:00 UTC
+        let stop_time = Timestamptz::from_micros(1205020800000000); // 2008-03-09 00:00:00 UTC
+        let one_minute_step = Interval::from_minutes(1);
+        let one_hour_step = Interval::from_minutes(60);
+        generate_series_timestamptz(start_time, stop_time, one_minute_step, 60 * 24 * 8 + 1).await;
+        generate_series_timestamptz(start_time, stop_time, one_hour_step, 24 * 8 + 1).await;
+        generate_series_timestamptz(stop_time, start_time, -one_hour_step, 24 * 8 + 1).await;
+
+        // Steps with month/day part are not supported without a time zone.
+        let one_day_step = Interval::from_days(1);
+        let function = build(
+            PbType::GenerateSeries,
+            DataType::Timestamptz,
+            CHUNK_SIZE,
+            vec![
+                LiteralExpression::new(DataType::Timestamptz, Some(start_time.into())).boxed(),
+                LiteralExpression::new(DataType::Timestamptz, Some(stop_time.into())).boxed(),
+                LiteralExpression::new(DataType::Interval, Some(one_day_step.into())).boxed(),
+            ],
+        )
+        .unwrap();
+        let dummy_chunk = DataChunk::new_dummy(1);
+        let mut output = function.eval(&dummy_chunk).await;
+        assert!(output.next().await.unwrap().is_err());
+    }
+
+    async fn generate_series_timestamptz(
+        start: Timestamptz,
+        stop: Timestamptz,
+        step: Interval,
+        expect_cnt: usize,
+    ) {
+        fn literal(ty: DataType, v: ScalarImpl) -> BoxedExpression {
+            LiteralExpression::new(ty, Some(v)).boxed()
+        }
+        let function = build(
+            PbType::GenerateSeries,
+            DataType::Timestamptz,
+            CHUNK_SIZE,
+            vec![
+                literal(DataType::Timestamptz, start.into()),
+                literal(DataType::Timestamptz, stop.into()),
+                literal(DataType::Interval, step.into()),
+            ],
+        )
+        .unwrap();
+
+        let dummy_chunk = DataChunk::new_dummy(1);
+        let mut actual_cnt = 0;
+        let mut output = function.eval(&dummy_chunk).await;
+        while let Some(Ok(chunk)) = output.next().await {
+            actual_cnt += chunk.cardinality();
+        }
+        assert_eq!(actual_cnt, expect_cnt);
+    }
+
     #[tokio::test]
     async fn test_range_i32() {
         range_i32(2, 4, 1).await;
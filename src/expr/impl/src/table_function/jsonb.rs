@@ -15,8 +15,16 @@
 //! JSONB table functions.
 
 use anyhow::anyhow;
-use risingwave_common::types::JsonbRef;
-use risingwave_expr::{function, Result};
+use futures_async_stream::try_stream;
+use futures_util::stream::BoxStream;
+use risingwave_common::array::{ArrayBuilder, DataChunk, I32ArrayBuilder, JsonbArray};
+use risingwave_common::types::{DataType, JsonbRef, ScalarImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_expr::expr::BoxedExpression;
+use risingwave_expr::table_function::{BoxedTableFunction, TableFunction};
+use risingwave_expr::{build_function, function, ExprError, Result};
+
+use crate::scalar::jsonb_record::populate_record;
 
 /// Expands the top-level JSON array into a set of JSON values.
 #[function("jsonb_array_elements(jsonb) -> setof jsonb")]
@@ -49,3 +57,92 @@ fn jsonb_each_text(json: JsonbRef<'_>) -> Result<impl Iterator<Item = (&str, Box
     let elems = jsonb_each(json)?;
     Ok(elems.map(|(k, v)| (k, v.force_string().into())))
 }
+
+/// Expands the top-level JSON array of objects to a set of records, whose fields are given by
+/// the column definition list of the alias, e.g. `jsonb_to_recordset(...) AS x (a int, b text)`.
+///
+/// The record type is only known at binding time, so unlike the functions above, this one is
+/// built by hand from the return type instead of deriving the output from the signature.
+#[build_function("jsonb_to_recordset(jsonb) -> setof struct", type_infer = "panic")]
+fn build_jsonb_to_recordset(
+    return_type: DataType,
+    chunk_size: usize,
+    children: Vec<BoxedExpression>,
+) -> Result<BoxedTableFunction> {
+    let jsonb = children.into_iter().next().unwrap();
+    Ok(JsonbToRecordset {
+        return_type,
+        jsonb,
+        chunk_size,
+    }
+    .boxed())
+}
+
+#[derive(Debug)]
+struct JsonbToRecordset {
+    return_type: DataType,
+    jsonb: BoxedExpression,
+    chunk_size: usize,
+}
+
+#[async_trait::async_trait]
+impl TableFunction for JsonbToRecordset {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    async fn eval<'a>(&'a self, input: &'a DataChunk) -> BoxStream<'a, Result<DataChunk>> {
+        self.eval_inner(input)
+    }
+}
+
+impl JsonbToRecordset {
+    #[try_stream(boxed, ok = DataChunk, error = ExprError)]
+    async fn eval_inner<'a>(&'a self, input: &'a DataChunk) {
+        let array_ref = self.jsonb.eval(input).await?;
+        let array: &JsonbArray = array_ref.as_ref().into();
+
+        let mut index_builder = I32ArrayBuilder::new(self.chunk_size);
+        let mut value_builder = self.return_type.create_array_builder(self.chunk_size);
+        for (i, (json, visible)) in array
+            .iter()
+            .zip_eq_fast(input.visibility().iter())
+            .enumerate()
+        {
+            if let Some(json) = json && visible {
+                for elem in json.array_elements().map_err(|e| anyhow!(e))? {
+                    let record = ScalarImpl::Struct(populate_record(
+                        self.return_type.as_struct(),
+                        None,
+                        elem,
+                    )?);
+                    index_builder.append(Some(i as i32));
+                    value_builder.append(Some(record.as_scalar_ref_impl()));
+
+                    if index_builder.len() == self.chunk_size {
+                        let index_array = std::mem::replace(
+                            &mut index_builder,
+                            I32ArrayBuilder::new(self.chunk_size),
+                        )
+                        .finish()
+                        .into_ref();
+                        let value_array = std::mem::replace(
+                            &mut value_builder,
+                            self.return_type.create_array_builder(self.chunk_size),
+                        )
+                        .finish()
+                        .into_ref();
+                        yield DataChunk::new(vec![index_array, value_array], self.chunk_size);
+                    }
+                }
+            }
+        }
+
+        if !index_builder.is_empty() {
+            let len = index_builder.len();
+            let index_array = index_builder.finish().into_ref();
+            let value_array = value_builder.finish().into_ref();
+            yield DataChunk::new(vec![index_array, value_array], len);
+        }
+    }
+}
@@ -422,6 +422,7 @@ impl TestCase {
                     source_schema,
                     source_watermarks,
                     append_only,
+                    on_conflict,
                     cdc_table_info,
                     ..
                 } => {
@@ -439,6 +440,7 @@ impl TestCase {
                         source_schema,
                         source_watermarks,
                         append_only,
+                        on_conflict,
                         notice,
                         cdc_table_info,
                     )
@@ -463,6 +465,7 @@ impl TestCase {
                     columns,
                     include,
                     distributed_by,
+                    predicate,
                     if_not_exists,
                     // TODO: support unique and if_not_exist in planner test
                     ..
@@ -475,6 +478,7 @@ impl TestCase {
                         columns,
                         include,
                         distributed_by,
+                        predicate,
                     )
                     .await?;
                 }
@@ -730,7 +734,9 @@ impl TestCase {
                     &mut ret.stream_error,
                 ),
                 (
-                    EmitMode::OnWindowClose,
+                    EmitMode::OnWindowClose {
+                        allowed_lateness: None,
+                    },
                     self.expected_outputs.contains(&TestType::EowcStreamPlan),
                     &mut ret.eowc_stream_plan,
                     self.expected_outputs
@@ -791,6 +797,7 @@ impl TestCase {
                     format!("CREATE SINK {sink_name} AS {}", stmt),
                     options,
                     false,
+                    None,
                     "test_db".into(),
                     "test_table".into(),
                     format_desc,
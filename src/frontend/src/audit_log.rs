@@ -0,0 +1,96 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+
+/// Number of most recent audited statements retained per frontend node. The oldest entries
+/// are dropped on overflow.
+const AUDIT_LOG_CAPACITY: usize = 4096;
+
+/// An audited statement, see [`AuditLogManager`].
+#[derive(Clone)]
+pub struct AuditLogEntry {
+    /// Unix timestamp in milliseconds of when the statement was handled.
+    pub timestamp: u64,
+    pub user_name: String,
+    pub user_id: u32,
+    pub session_id: i32,
+    /// The statement class that made the statement auditable: `ddl` or `dml`.
+    pub statement_kind: &'static str,
+    /// Name of the object the statement operates on, if it can be derived from the
+    /// statement.
+    pub object_name: Option<String>,
+    pub sql: String,
+}
+
+/// A bounded, in-memory log of the statements audited on this frontend node, exposed
+/// through `rw_catalog.rw_audit_log`.
+///
+/// Which statements are audited is controlled per session by the `audit_log_level` session
+/// variable. Every audited statement is additionally emitted as a tracing event with target
+/// `audit_log`, which can be routed to an external collector for durable retention.
+pub struct AuditLogManager {
+    entries: Mutex<VecDeque<AuditLogEntry>>,
+}
+
+impl Default for AuditLogManager {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(AUDIT_LOG_CAPACITY)),
+        }
+    }
+}
+
+impl AuditLogManager {
+    /// Appends an entry, dropping the oldest one if the log is full.
+    pub fn record(
+        &self,
+        user_name: String,
+        user_id: u32,
+        session_id: i32,
+        statement_kind: &'static str,
+        object_name: Option<String>,
+        sql: String,
+    ) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("SystemTime before UNIX EPOCH")
+            .as_millis() as u64;
+        let entry = AuditLogEntry {
+            timestamp,
+            user_name,
+            user_id,
+            session_id,
+            statement_kind,
+            object_name,
+            sql,
+        };
+        let mut entries = self.entries.lock();
+        if entries.len() >= AUDIT_LOG_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Lists all retained entries, oldest first.
+    pub fn list(&self) -> Vec<AuditLogEntry> {
+        self.entries.lock().iter().cloned().collect()
+    }
+}
+
+pub type AuditLogManagerRef = Arc<AuditLogManager>;
@@ -79,7 +79,7 @@ pub struct BindContext {
     pub column_group_context: ColumnGroupContext,
     /// Map the cte's name to its Relation::Subquery.
     /// The `ShareId` of the value is used to help the planner identify the share plan.
-    pub cte_to_relation: HashMap<String, Rc<(ShareId, BoundQuery, TableAlias)>>,
+    pub cte_to_relation: HashMap<String, Rc<(ShareId, BoundQuery, TableAlias, Option<bool>)>>,
     /// Current lambda functions's arguments
     pub lambda_args: Option<HashMap<String, (usize, DataType)>>,
 }
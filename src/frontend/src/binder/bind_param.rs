@@ -199,6 +199,33 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn infer_from_context() {
+        // `$1 + 1::int4` implies `$1` is an int, so the text param is parsed as int rather
+        // than falling back to varchar.
+        expect_actual_eq(
+            create_expect_bound("select 1::int4 + 1::int4"),
+            create_actual_bound(
+                "select $1 + 1::int4",
+                vec![],
+                vec![Some("1".into())],
+                vec![Format::Text],
+            ),
+        );
+    }
+
+    #[tokio::test]
+    async fn export_types_inferred_from_context() {
+        // `Describe` on a prepared statement reports the inferred type via
+        // `export_param_types`, which JDBC relies on to encode `setObject` values.
+        let mut binder = mock_binder();
+        let stmt = parse_sql_statements("select $1 + 1::int4")
+            .unwrap()
+            .remove(0);
+        binder.bind(stmt).unwrap();
+        assert_eq!(binder.export_param_types().unwrap(), vec![DataType::Int32]);
+    }
+
     #[tokio::test]
     async fn infer_case() {
         expect_actual_eq(
@@ -14,7 +14,7 @@
 
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{BinaryOperator, Expr};
+use risingwave_sqlparser::ast::{BinaryOperator, Expr, QualifiedOperator};
 
 use crate::binder::Binder;
 use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall};
@@ -186,6 +186,15 @@ impl Binder {
                 func_types.push(ExprType::Not);
                 ExprType::RegexpEq
             }
+            BinaryOperator::PGQualified(qualified) => {
+                let op = Self::resolve_qualified_operator(&qualified)?;
+                func_types.extend(Self::resolve_binary_operator(
+                    op,
+                    bound_left,
+                    bound_right,
+                )?);
+                return Ok(func_types);
+            }
             _ => {
                 return Err(
                     ErrorCode::NotImplemented(format!("binary op: {:?}", op), 112.into()).into(),
@@ -195,4 +204,61 @@ impl Binder {
         func_types.push(final_type);
         Ok(func_types)
     }
+
+    /// Resolves a qualified operator like `OPERATOR(pg_catalog.=)` to the equivalent built-in
+    /// binary operator. All built-in operators live in `pg_catalog`, and user-defined operators
+    /// are not supported yet, so any other schema qualification is rejected.
+    fn resolve_qualified_operator(qualified: &QualifiedOperator) -> Result<BinaryOperator> {
+        if let Some(schema) = &qualified.schema
+            && schema.real_value() != "pg_catalog"
+        {
+            return Err(ErrorCode::NotImplemented(
+                format!("user-defined operator: {}", qualified),
+                112.into(),
+            )
+            .into());
+        }
+        let op = match qualified.name.as_str() {
+            "+" => BinaryOperator::Plus,
+            "-" => BinaryOperator::Minus,
+            "*" => BinaryOperator::Multiply,
+            "/" => BinaryOperator::Divide,
+            "%" => BinaryOperator::Modulo,
+            "=" => BinaryOperator::Eq,
+            "<>" | "!=" => BinaryOperator::NotEq,
+            "<" => BinaryOperator::Lt,
+            "<=" => BinaryOperator::LtEq,
+            ">" => BinaryOperator::Gt,
+            ">=" => BinaryOperator::GtEq,
+            "||" => BinaryOperator::Concat,
+            "^@" => BinaryOperator::Prefix,
+            "|" => BinaryOperator::BitwiseOr,
+            "&" => BinaryOperator::BitwiseAnd,
+            "#" => BinaryOperator::PGBitwiseXor,
+            "<<" => BinaryOperator::PGBitwiseShiftLeft,
+            ">>" => BinaryOperator::PGBitwiseShiftRight,
+            "~" => BinaryOperator::PGRegexMatch,
+            "~*" => BinaryOperator::PGRegexIMatch,
+            "!~" => BinaryOperator::PGRegexNotMatch,
+            "!~*" => BinaryOperator::PGRegexNotIMatch,
+            "->" => BinaryOperator::Arrow,
+            "->>" => BinaryOperator::LongArrow,
+            "#>" => BinaryOperator::HashArrow,
+            "#>>" => BinaryOperator::HashLongArrow,
+            "#-" => BinaryOperator::HashMinus,
+            "@>" => BinaryOperator::Contains,
+            "<@" => BinaryOperator::Contained,
+            "?" => BinaryOperator::Exists,
+            "?|" => BinaryOperator::ExistsAny,
+            "?&" => BinaryOperator::ExistsAll,
+            _ => {
+                return Err(ErrorCode::BindError(format!(
+                    "operator does not exist: {}",
+                    qualified
+                ))
+                .into());
+            }
+        };
+        Ok(op)
+    }
 }
@@ -148,6 +148,16 @@ impl Binder {
             .into());
         }
 
+        // `jsonb_to_recordset` returns `record`, whose type can only be given by a column
+        // definition list in `FROM`, where it is handled in `bind_table_function`.
+        if function_name == "jsonb_to_recordset" {
+            return Err(ErrorCode::BindError(
+                "function returning record called in context that cannot accept type record"
+                    .to_string(),
+            )
+            .into());
+        }
+
         // table function
         if let Ok(function_type) = TableFunctionType::from_str(function_name.as_str()) {
             self.ensure_table_function_allowed()?;
@@ -169,7 +179,13 @@ impl Binder {
                     self.ensure_table_function_allowed()?;
                     return Ok(TableFunction::new_user_defined(func.clone(), inputs).into());
                 }
-                Aggregate => todo!("support UDAF"),
+                Aggregate => {
+                    return Err(ErrorCode::NotImplemented(
+                        format!("invoking user defined aggregate function: {function_name}"),
+                        None.into(),
+                    )
+                    .into())
+                }
             }
         }
 
@@ -519,7 +535,7 @@ impl Binder {
             let exclusion = if let Some(exclusion) = frame.exclusion {
                 match exclusion {
                     WindowFrameExclusion::CurrentRow => FrameExclusion::CurrentRow,
-                    WindowFrameExclusion::Group | WindowFrameExclusion::Ties => {
+                    WindowFrameExclusion::Group => {
                         return Err(ErrorCode::NotImplemented(
                             format!(
                                 "window frame exclusion `{}` is not supported yet",
@@ -529,13 +545,14 @@ impl Binder {
                         )
                         .into());
                     }
+                    WindowFrameExclusion::Ties => FrameExclusion::Ties,
                     WindowFrameExclusion::NoOthers => FrameExclusion::NoOthers,
                 }
             } else {
                 FrameExclusion::NoOthers
             };
             let bounds = match frame.units {
-                WindowFrameUnits::Rows => {
+                WindowFrameUnits::Rows | WindowFrameUnits::Groups => {
                     let convert_bound = |bound| match bound {
                         WindowFrameBound::CurrentRow => FrameBound::CurrentRow,
                         WindowFrameBound::Preceding(None) => FrameBound::UnboundedPreceding,
@@ -553,9 +570,22 @@ impl Binder {
                     } else {
                         FrameBound::CurrentRow
                     };
-                    FrameBounds::Rows(start, end)
+                    match frame.units {
+                        WindowFrameUnits::Rows => FrameBounds::Rows(start, end),
+                        WindowFrameUnits::Groups => {
+                            if order_by.sort_exprs.is_empty() {
+                                // align with PG
+                                return Err(ErrorCode::InvalidInputSyntax(
+                                    "GROUPS mode requires an ORDER BY clause".to_string(),
+                                )
+                                .into());
+                            }
+                            FrameBounds::Groups(start, end)
+                        }
+                        _ => unreachable!(),
+                    }
                 }
-                WindowFrameUnits::Range | WindowFrameUnits::Groups => {
+                WindowFrameUnits::Range => {
                     return Err(ErrorCode::NotImplemented(
                         format!(
                             "window frame in `{}` mode is not supported yet",
@@ -931,6 +961,7 @@ impl Binder {
                 ("to_jsonb", raw_call(ExprType::ToJsonb)),
                 ("jsonb_build_array", raw_call(ExprType::JsonbBuildArray)),
                 ("jsonb_build_object", raw_call(ExprType::JsonbBuildObject)),
+                ("jsonb_populate_record", raw_call(ExprType::JsonbPopulateRecord)),
                 // Functions that return a constant value
                 ("pi", pi()),
                 // greatest and least
@@ -1353,11 +1384,16 @@ impl Binder {
         if self.is_for_stream()
             && !matches!(
                 self.context.clause,
-                Some(Clause::Where) | Some(Clause::Having) | Some(Clause::JoinOn)
+                // Only `generate_series` ending with `now()` is supported in `FROM`, which is
+                // further checked by the optimizer.
+                Some(Clause::Where)
+                    | Some(Clause::Having)
+                    | Some(Clause::JoinOn)
+                    | Some(Clause::From)
             )
         {
             return Err(ErrorCode::InvalidInputSyntax(format!(
-                "For streaming queries, `NOW()` function is only allowed in `WHERE`, `HAVING` and `ON`. Found in clause: {:?}. Please please refer to https://www.risingwave.dev/docs/current/sql-pattern-temporal-filters/ for more information",
+                "For streaming queries, `NOW()` function is only allowed in `WHERE`, `HAVING`, `ON` and `FROM`. Found in clause: {:?}. Please please refer to https://www.risingwave.dev/docs/current/sql-pattern-temporal-filters/ for more information",
                 self.context.clause
             ))
             .into());
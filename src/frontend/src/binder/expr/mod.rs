@@ -379,6 +379,11 @@ impl Binder {
         FunctionCall::new(ExprType::Overlay, args).map(|f| f.into())
     }
 
+    /// Bind a parameter like `$1`. Its type is resolved by [`ParameterTypes`]: either specified
+    /// by the client in the `Parse` message, or inferred from the expression context later (e.g.
+    /// `$1 + int_col` implies int), so that `Describe` can report it back to the client.
+    ///
+    /// [`ParameterTypes`]: crate::binder::ParameterTypes
     fn bind_parameter(&mut self, index: u64) -> Result<ExprImpl> {
         Ok(Parameter::new(index, self.param_types.clone()).into())
     }
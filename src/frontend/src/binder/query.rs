@@ -19,7 +19,9 @@ use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_common::util::sort_util::{ColumnOrder, OrderType};
-use risingwave_sqlparser::ast::{Cte, Expr, Fetch, OrderByExpr, Query, Value, With};
+use risingwave_sqlparser::ast::{
+    Cte, Expr, Fetch, OrderByExpr, Query, SetExpr, TableFactor, TableWithJoins, Value, With,
+};
 
 use super::statement::RewriteExprsRecursive;
 use super::BoundValues;
@@ -277,20 +279,76 @@ impl Binder {
     }
 
     fn bind_with(&mut self, with: With) -> Result<()> {
-        if with.recursive {
-            Err(ErrorCode::NotImplemented("recursive cte".into(), None.into()).into())
-        } else {
-            for cte_table in with.cte_tables {
-                let Cte { alias, query, .. } = cte_table;
-                let table_name = alias.name.real_value();
-                let bound_query = self.bind_query(query)?;
-                let share_id = self.next_share_id();
-                self.context
-                    .cte_to_relation
-                    .insert(table_name, Rc::new((share_id, bound_query, alias)));
+        for cte_table in with.cte_tables {
+            let Cte {
+                alias,
+                query,
+                materialized,
+                ..
+            } = cte_table;
+            let table_name = alias.name.real_value();
+            // The `RECURSIVE` keyword itself is accepted, since many queries ported from
+            // PostgreSQL specify it out of habit without actually recursing. Only CTEs that
+            // reference themselves are rejected.
+            // TODO: Support self-referencing CTEs with an iterative executor (working table +
+            // delta), guarded by a max-iteration limit.
+            if with.recursive && query_references_table(&query, &table_name) {
+                return Err(ErrorCode::NotImplemented(
+                    format!("recursive CTE \"{table_name}\" referencing itself"),
+                    None.into(),
+                )
+                .into());
             }
-            Ok(())
+            let bound_query = self.bind_query(query)?;
+            let share_id = self.next_share_id();
+            self.context
+                .cte_to_relation
+                .insert(table_name, Rc::new((share_id, bound_query, alias, materialized)));
+        }
+        Ok(())
+    }
+}
+
+/// Check whether any relation in the `FROM` clauses of the query, recursively including
+/// derived subqueries and nested joins, references the given table name. Used to tell
+/// self-referencing recursive CTEs apart from `WITH RECURSIVE` queries that do not actually
+/// recurse.
+fn query_references_table(query: &Query, table_name: &str) -> bool {
+    set_expr_references_table(&query.body, table_name)
+}
+
+fn set_expr_references_table(set_expr: &SetExpr, table_name: &str) -> bool {
+    match set_expr {
+        SetExpr::Select(select) => select
+            .from
+            .iter()
+            .any(|table| table_with_joins_references_table(table, table_name)),
+        SetExpr::Query(query) => query_references_table(query, table_name),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_references_table(left, table_name)
+                || set_expr_references_table(right, table_name)
         }
+        SetExpr::Values(_) => false,
+    }
+}
+
+fn table_with_joins_references_table(table: &TableWithJoins, table_name: &str) -> bool {
+    table_factor_references_table(&table.relation, table_name)
+        || table
+            .joins
+            .iter()
+            .any(|join| table_factor_references_table(&join.relation, table_name))
+}
+
+fn table_factor_references_table(factor: &TableFactor, table_name: &str) -> bool {
+    match factor {
+        TableFactor::Table { name, .. } => name
+            .0
+            .last()
+            .map_or(false, |ident| ident.real_value() == table_name),
+        TableFactor::Derived { subquery, .. } => query_references_table(subquery, table_name),
+        TableFactor::TableFunction { .. } => false,
+        TableFactor::NestedJoin(table) => table_with_joins_references_table(table, table_name),
     }
 }
 
@@ -282,7 +282,7 @@ impl Binder {
                 true => field.name.to_string(),
                 false => alias_iter
                     .next()
-                    .map(|t| t.real_value())
+                    .map(|t| t.name.real_value())
                     .unwrap_or_else(|| field.name.to_string()),
             };
             field.name = name.clone();
@@ -337,7 +337,7 @@ impl Binder {
         {
             // Handles CTE
 
-            let (share_id, query, mut original_alias) = item.deref().clone();
+            let (share_id, query, mut original_alias, materialized) = item.deref().clone();
             debug_assert_eq!(original_alias.name.real_value(), table_name); // The original CTE alias ought to be its table name.
 
             if let Some(from_alias) = alias {
@@ -361,11 +361,17 @@ impl Binder {
                 Some(original_alias),
             )?;
 
-            // Share the CTE.
             let input_relation = Relation::Subquery(Box::new(BoundSubquery {
                 query,
                 lateral: false,
             }));
+            if materialized == Some(false) {
+                // A `NOT MATERIALIZED` CTE is inlined into each of its references and recomputed
+                // per reference, so that predicates and columns can be pushed into it.
+                return Ok(input_relation);
+            }
+            // Share the CTE, so that it is planned once and its result is reused by all
+            // references where the plan supports it.
             let share_relation = Relation::Share(Box::new(BoundShare {
                 share_id,
                 input: input_relation,
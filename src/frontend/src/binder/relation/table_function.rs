@@ -18,17 +18,18 @@ use itertools::Itertools;
 use risingwave_common::catalog::{
     Field, Schema, PG_CATALOG_SCHEMA_NAME, RW_INTERNAL_TABLE_FUNCTION_NAME,
 };
-use risingwave_common::error::ErrorCode;
-use risingwave_common::types::DataType;
+use risingwave_common::error::{ErrorCode, RwError};
+use risingwave_common::types::{DataType, StructType};
 use risingwave_sqlparser::ast::{Function, FunctionArg, ObjectName, TableAlias};
 
 use super::watermark::is_watermark_func;
 use super::{Binder, Relation, Result, WindowTableFunctionKind};
 use crate::binder::bind_context::Clause;
+use crate::binder::bind_data_type;
 use crate::catalog::system_catalog::pg_catalog::{
     PG_GET_KEYWORDS_FUNC_NAME, PG_KEYWORDS_TABLE_NAME,
 };
-use crate::expr::{Expr, ExprImpl};
+use crate::expr::{Expr, ExprImpl, TableFunction, TableFunctionType};
 
 impl Binder {
     /// Binds a table function AST, which is a function call in a relation position.
@@ -108,6 +109,12 @@ impl Binder {
                 self.bind_watermark(alias, args)?,
             )));
         };
+        // `jsonb_to_recordset` returns `setof record`, whose fields can only be given by a
+        // column definition list in the alias, e.g. `jsonb_to_recordset(...) AS t (a int, b
+        // text)`. So it cannot go through the normal type inference below.
+        if func_name.eq("jsonb_to_recordset") {
+            return self.bind_jsonb_to_recordset(alias, args, with_ordinality);
+        }
 
         self.push_context();
         let mut clause = Some(Clause::From);
@@ -175,4 +182,82 @@ impl Binder {
             with_ordinality,
         })
     }
+
+    /// Binds `jsonb_to_recordset`, whose record type is given by the column definition list of
+    /// the table alias instead of being inferred from the arguments.
+    fn bind_jsonb_to_recordset(
+        &mut self,
+        alias: Option<TableAlias>,
+        args: Vec<FunctionArg>,
+        with_ordinality: bool,
+    ) -> Result<Relation> {
+        let Some(column_defs) = alias
+            .as_ref()
+            .map(|alias| &alias.columns)
+            .filter(|columns| !columns.is_empty() && columns.iter().all(|c| c.data_type.is_some()))
+        else {
+            // Same error report as PostgreSQL.
+            return Err(ErrorCode::BindError(
+                "a column definition list is required for functions returning \"record\""
+                    .to_string(),
+            )
+            .into());
+        };
+        let fields = column_defs
+            .iter()
+            .map(|column| {
+                Ok((
+                    column.name.real_value(),
+                    bind_data_type(column.data_type.as_ref().unwrap())?,
+                ))
+            })
+            .try_collect::<_, Vec<_>, RwError>()?;
+        let struct_type = StructType::new(fields);
+
+        self.push_context();
+        let mut clause = Some(Clause::From);
+        std::mem::swap(&mut self.context.clause, &mut clause);
+        let bound_args = args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect::<_, Vec<_>, RwError>();
+        self.context.clause = clause;
+        self.pop_context()?;
+        let [arg] = <[ExprImpl; 1]>::try_from(bound_args?).map_err(|args| {
+            RwError::from(ErrorCode::BindError(format!(
+                "function jsonb_to_recordset expects 1 argument, but {} were given",
+                args.len()
+            )))
+        })?;
+        if matches!(arg, ExprImpl::Subquery(_)) {
+            // Same error reports as DuckDB, see `bind_table_function` above.
+            return Err(ErrorCode::InvalidInputSyntax(
+                "Only table-in-out functions can have subquery parameters, jsonb_to_recordset only accepts constant parameters".to_string(),
+            )
+            .into());
+        }
+        let arg = arg.cast_implicit(DataType::Jsonb)?;
+
+        let func = TableFunction {
+            args: vec![arg],
+            return_type: DataType::Struct(struct_type.clone()),
+            function_type: TableFunctionType::JsonbToRecordset,
+            udtf_catalog: None,
+        };
+
+        // The record is flattened into multiple columns, in the same way as other
+        // struct-returning table functions above.
+        let schema = Schema::from(&struct_type);
+        let mut columns = schema.fields.into_iter().map(|f| (false, f)).collect_vec();
+        if with_ordinality {
+            columns.push((false, Field::with_name(DataType::Int64, "ordinality")));
+        }
+        self.bind_table_to_context(columns, "jsonb_to_recordset".to_string(), alias)?;
+
+        Ok(Relation::TableFunction {
+            expr: func.into(),
+            with_ordinality,
+        })
+    }
 }
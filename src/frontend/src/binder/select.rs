@@ -48,6 +48,8 @@ use crate::utils::Condition;
 #[derive(Debug, Clone)]
 pub struct BoundSelect {
     pub distinct: BoundDistinct,
+    /// Optimizer hints from the `/*+ ... */` comment, to be interpreted by the planner.
+    pub hints: Vec<String>,
     pub select_items: Vec<ExprImpl>,
     pub aliases: Vec<Option<String>>,
     pub from: Option<Relation>,
@@ -297,6 +299,7 @@ impl Binder {
 
         Ok(BoundSelect {
             distinct,
+            hints: select.hints,
             select_items,
             aliases,
             from,
@@ -573,6 +576,7 @@ impl Binder {
 
         Ok(BoundSelect {
             distinct: BoundDistinct::All,
+            hints: vec![],
             select_items,
             aliases: vec![None],
             from,
@@ -660,6 +664,7 @@ impl Binder {
 
         Ok(BoundSelect {
             distinct: BoundDistinct::All,
+            hints: vec![],
             select_items,
             aliases: vec![None],
             from: Some(indexes_with_stats),
@@ -716,6 +721,7 @@ impl Binder {
 
         Ok(BoundSelect {
             distinct: BoundDistinct::All,
+            hints: vec![],
             select_items,
             aliases: vec![None],
             from,
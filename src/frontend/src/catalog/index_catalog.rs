@@ -25,8 +25,9 @@ use risingwave_pb::catalog::{PbIndex, PbStreamJobStatus};
 
 use super::ColumnId;
 use crate::catalog::{DatabaseId, OwnedByUserCatalog, SchemaId, TableCatalog};
-use crate::expr::{Expr, ExprImpl, FunctionCall};
+use crate::expr::{to_conjunctions, Expr, ExprImpl, FunctionCall};
 use crate::user::UserId;
+use crate::utils::Condition;
 
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
@@ -60,6 +61,10 @@ pub struct IndexCatalog {
 
     pub original_columns: Vec<ColumnId>,
 
+    /// The `WHERE` predicate of a partial index, referring to the columns of the primary table.
+    /// `None` for a total index.
+    pub predicate: Option<ExprImpl>,
+
     pub created_at_epoch: Option<Epoch>,
 
     pub initialized_at_epoch: Option<Epoch>,
@@ -112,6 +117,11 @@ impl IndexCatalog {
             .map(Into::into)
             .collect();
 
+        let predicate = index_prost
+            .predicate
+            .as_ref()
+            .map(|predicate| ExprImpl::from_expr_proto(predicate).unwrap());
+
         IndexCatalog {
             id: index_prost.id.into(),
             name: index_prost.name.clone(),
@@ -122,11 +132,32 @@ impl IndexCatalog {
             secondary_to_primary_mapping,
             function_mapping,
             original_columns,
+            predicate,
             created_at_epoch: index_prost.created_at_epoch.map(Epoch::from),
             initialized_at_epoch: index_prost.initialized_at_epoch.map(Epoch::from),
         }
     }
 
+    /// Whether the index only maintains a subset of the primary table's rows, i.e. it is created
+    /// with a `WHERE` predicate.
+    pub fn is_partial(&self) -> bool {
+        self.predicate.is_some()
+    }
+
+    /// Whether the index contains all rows a scan with `condition` may return, i.e. the index is
+    /// a total index, or its predicate is implied by `condition`.
+    ///
+    /// The implication check is conservative: each conjunct of the index predicate must literally
+    /// appear among the conjunctions of `condition`.
+    pub fn predicate_implied_by(&self, condition: &Condition) -> bool {
+        let Some(predicate) = &self.predicate else {
+            return true;
+        };
+        to_conjunctions(predicate.clone())
+            .iter()
+            .all(|conjunct| condition.conjunctions.contains(conjunct))
+    }
+
     pub fn primary_table_pk_ref_to_index_table(&self) -> Vec<ColumnOrder> {
         let mapping = self.primary_to_secondary_mapping();
 
@@ -185,6 +216,7 @@ impl IndexCatalog {
             initialized_at_epoch: self.initialized_at_epoch.map(|e| e.0),
             created_at_epoch: self.created_at_epoch.map(|e| e.0),
             stream_job_status: PbStreamJobStatus::Creating.into(),
+            predicate: self.predicate.as_ref().map(|expr| expr.to_expr_proto()),
         }
     }
 
@@ -31,6 +31,7 @@ use risingwave_common::row::OwnedRow;
 use risingwave_common::types::DataType;
 use risingwave_pb::user::grant_privilege::Object;
 
+use crate::audit_log::AuditLogManagerRef;
 use crate::catalog::catalog_service::CatalogReader;
 use crate::catalog::system_catalog::information_schema::*;
 use crate::catalog::system_catalog::pg_catalog::*;
@@ -105,6 +106,8 @@ pub struct SysCatalogReaderImpl {
     // Read from meta.
     meta_client: Arc<dyn FrontendMetaClient>,
     auth_context: Arc<AuthContext>,
+    // Read the audited statements of this frontend node.
+    audit_log_manager: AuditLogManagerRef,
 }
 
 impl SysCatalogReaderImpl {
@@ -114,6 +117,7 @@ impl SysCatalogReaderImpl {
         worker_node_manager: WorkerNodeManagerRef,
         meta_client: Arc<dyn FrontendMetaClient>,
         auth_context: Arc<AuthContext>,
+        audit_log_manager: AuditLogManagerRef,
     ) -> Self {
         Self {
             catalog_reader,
@@ -121,6 +125,7 @@ impl SysCatalogReaderImpl {
             worker_node_manager,
             meta_client,
             auth_context,
+            audit_log_manager,
         }
     }
 }
@@ -378,15 +383,18 @@ prepare_sys_catalog! {
     { BuiltinCatalog::Table(&RW_MATERIALIZED_VIEWS), read_rw_mview_info },
     { BuiltinCatalog::Table(&RW_INDEXES), read_rw_indexes_info },
     { BuiltinCatalog::Table(&RW_SOURCES), read_rw_sources_info },
+    { BuiltinCatalog::Table(&RW_SOURCE_STATUS), read_rw_source_status_info await },
     { BuiltinCatalog::Table(&RW_SINKS), read_rw_sinks_info },
     { BuiltinCatalog::Table(&RW_CONNECTIONS), read_rw_connections_info },
     { BuiltinCatalog::Table(&RW_FUNCTIONS), read_rw_functions_info },
     { BuiltinCatalog::Table(&RW_VIEWS), read_rw_views_info },
     { BuiltinCatalog::Table(&RW_WORKER_NODES), read_rw_worker_nodes_info },
     { BuiltinCatalog::Table(&RW_PARALLEL_UNITS), read_rw_parallel_units_info },
+    { BuiltinCatalog::Table(&RW_VNODE_MAPPINGS), read_rw_vnode_mappings },
     { BuiltinCatalog::Table(&RW_TABLE_FRAGMENTS), read_rw_table_fragments_info await },
     { BuiltinCatalog::Table(&RW_FRAGMENTS), read_rw_fragment_distributions_info await },
     { BuiltinCatalog::Table(&RW_ACTORS), read_rw_actor_states_info await },
+    { BuiltinCatalog::Table(&RW_ACTOR_TRACES), read_rw_actor_traces_info await },
     { BuiltinCatalog::Table(&RW_META_SNAPSHOT), read_meta_snapshot await },
     { BuiltinCatalog::Table(&RW_DDL_PROGRESS), read_ddl_progress await },
     { BuiltinCatalog::Table(&RW_TABLE_STATS), read_table_stats },
@@ -400,11 +408,16 @@ prepare_sys_catalog! {
     { BuiltinCatalog::Table(&RW_HUMMOCK_CURRENT_VERSION), read_hummock_current_version await },
     { BuiltinCatalog::Table(&RW_HUMMOCK_CHECKPOINT_VERSION), read_hummock_checkpoint_version await },
     { BuiltinCatalog::Table(&RW_HUMMOCK_SSTABLES), read_hummock_sstables await },
+    { BuiltinCatalog::Table(&RW_HUMMOCK_VERSION_LEVELS), read_hummock_version_levels await },
     { BuiltinCatalog::Table(&RW_HUMMOCK_VERSION_DELTAS), read_hummock_version_deltas await },
     { BuiltinCatalog::Table(&RW_HUMMOCK_BRANCHED_OBJECTS), read_hummock_branched_objects await },
     { BuiltinCatalog::Table(&RW_HUMMOCK_COMPACTION_GROUP_CONFIGS), read_hummock_compaction_group_configs await },
+    { BuiltinCatalog::Table(&RW_HUMMOCK_COMPACT_TASK_PROGRESS), read_hummock_compact_task_progress await },
+    { BuiltinCatalog::Table(&RW_HUMMOCK_COMPACTION_STATUS), read_hummock_compaction_status await },
     { BuiltinCatalog::Table(&RW_HUMMOCK_META_CONFIGS), read_hummock_meta_configs await},
     { BuiltinCatalog::Table(&RW_DESCRIPTION), read_rw_description },
+    { BuiltinCatalog::Table(&RW_EVENT_LOG), read_event_logs await },
+    { BuiltinCatalog::Table(&RW_AUDIT_LOG), read_audit_log },
 }
 
 #[cfg(test)]
@@ -12,16 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod rw_actor_traces;
 mod rw_actors;
+mod rw_audit_log;
 mod rw_columns;
 mod rw_connections;
 mod rw_databases;
 mod rw_ddl_progress;
 mod rw_description;
+mod rw_event_log;
 mod rw_fragments;
 mod rw_functions;
 mod rw_hummock_branched_objects;
+mod rw_hummock_compact_task_progress;
 mod rw_hummock_compaction_group_configs;
+mod rw_hummock_compaction_status;
 mod rw_hummock_meta_configs;
 mod rw_hummock_pinned_snapshots;
 mod rw_hummock_pinned_versions;
@@ -36,6 +41,7 @@ mod rw_relation_info;
 mod rw_relations;
 mod rw_schemas;
 mod rw_sinks;
+mod rw_source_status;
 mod rw_sources;
 mod rw_system_tables;
 mod rw_table_fragments;
@@ -45,18 +51,24 @@ mod rw_types;
 mod rw_user_secrets;
 mod rw_users;
 mod rw_views;
+mod rw_vnode_mappings;
 mod rw_worker_nodes;
 
+pub use rw_actor_traces::*;
 pub use rw_actors::*;
+pub use rw_audit_log::*;
 pub use rw_columns::*;
 pub use rw_connections::*;
 pub use rw_databases::*;
 pub use rw_ddl_progress::*;
 pub use rw_description::*;
+pub use rw_event_log::*;
 pub use rw_fragments::*;
 pub use rw_functions::*;
 pub use rw_hummock_branched_objects::*;
+pub use rw_hummock_compact_task_progress::*;
 pub use rw_hummock_compaction_group_configs::*;
+pub use rw_hummock_compaction_status::*;
 pub use rw_hummock_meta_configs::*;
 pub use rw_hummock_pinned_snapshots::*;
 pub use rw_hummock_pinned_versions::*;
@@ -71,6 +83,7 @@ pub use rw_relation_info::*;
 pub use rw_relations::*;
 pub use rw_schemas::*;
 pub use rw_sinks::*;
+pub use rw_source_status::*;
 pub use rw_sources::*;
 pub use rw_system_tables::*;
 pub use rw_table_fragments::*;
@@ -80,4 +93,5 @@ pub use rw_types::*;
 pub use rw_user_secrets::*;
 pub use rw_users::*;
 pub use rw_views::*;
+pub use rw_vnode_mappings::*;
 pub use rw_worker_nodes::*;
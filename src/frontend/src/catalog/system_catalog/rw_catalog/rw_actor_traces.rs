@@ -0,0 +1,52 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::RW_CATALOG_SCHEMA_NAME;
+use risingwave_common::error::Result;
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{BuiltinTable, SysCatalogReaderImpl};
+
+/// `rw_actor_traces` dumps the await-tree of each running actor, which is helpful for
+/// diagnosing stuck actors without shell access to the compute nodes. The trace is
+/// collected on demand when this table is queried.
+pub const RW_ACTOR_TRACES: BuiltinTable = BuiltinTable {
+    name: "rw_actor_traces",
+    schema: RW_CATALOG_SCHEMA_NAME,
+    columns: &[
+        (DataType::Int32, "actor_id"),
+        (DataType::Int32, "worker_id"),
+        (DataType::Varchar, "trace"),
+    ],
+    pk: &[0],
+};
+
+impl SysCatalogReaderImpl {
+    pub async fn read_rw_actor_traces_info(&self) -> Result<Vec<OwnedRow>> {
+        let traces = self.meta_client.list_actor_traces().await?;
+
+        Ok(traces
+            .into_iter()
+            .map(|trace| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int32(trace.actor_id as i32)),
+                    Some(ScalarImpl::Int32(trace.worker_id as i32)),
+                    Some(ScalarImpl::Utf8(trace.trace.into())),
+                ])
+            })
+            .collect_vec())
+    }
+}
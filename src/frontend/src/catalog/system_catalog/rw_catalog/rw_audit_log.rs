@@ -0,0 +1,63 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::RW_CATALOG_SCHEMA_NAME;
+use risingwave_common::error::Result;
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, ScalarImpl, Timestamptz};
+
+use crate::catalog::system_catalog::{BuiltinTable, SysCatalogReaderImpl};
+
+/// The statements recently audited on the frontend node serving the query, see the
+/// `audit_log_level` session variable. The retention is bounded; route the `audit_log`
+/// tracing target to an external collector for durable retention.
+pub const RW_AUDIT_LOG: BuiltinTable = BuiltinTable {
+    name: "rw_audit_log",
+    schema: RW_CATALOG_SCHEMA_NAME,
+    columns: &[
+        (DataType::Timestamptz, "timestamp"),
+        (DataType::Varchar, "user_name"),
+        (DataType::Int32, "user_id"),
+        (DataType::Int32, "session_id"),
+        // class of the statement: ddl or dml
+        (DataType::Varchar, "statement_kind"),
+        // name of the object the statement operates on, if derivable
+        (DataType::Varchar, "object_name"),
+        (DataType::Varchar, "sql"),
+    ],
+    pk: &[],
+};
+
+impl SysCatalogReaderImpl {
+    pub fn read_audit_log(&self) -> Result<Vec<OwnedRow>> {
+        let audit_logs = self
+            .audit_log_manager
+            .list()
+            .into_iter()
+            .map(|e| {
+                OwnedRow::new(vec![
+                    Timestamptz::from_millis(e.timestamp as i64).map(ScalarImpl::Timestamptz),
+                    Some(ScalarImpl::Utf8(e.user_name.into())),
+                    Some(ScalarImpl::Int32(e.user_id as i32)),
+                    Some(ScalarImpl::Int32(e.session_id)),
+                    Some(ScalarImpl::Utf8(e.statement_kind.into())),
+                    e.object_name.map(|name| ScalarImpl::Utf8(name.into())),
+                    Some(ScalarImpl::Utf8(e.sql.into())),
+                ])
+            })
+            .collect_vec();
+        Ok(audit_logs)
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::RW_CATALOG_SCHEMA_NAME;
+use risingwave_common::error::Result;
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{BuiltinTable, SysCatalogReaderImpl};
+
+/// Per-level compaction backlog of each compaction group: how many compact tasks are
+/// running with the level as input, and how many bytes they are going to rewrite.
+pub const RW_HUMMOCK_COMPACTION_STATUS: BuiltinTable = BuiltinTable {
+    name: "rw_hummock_compaction_status",
+    schema: RW_CATALOG_SCHEMA_NAME,
+    columns: &[
+        (DataType::Int64, "compaction_group_id"),
+        (DataType::Int32, "level_id"),
+        (DataType::Int32, "pending_task_count"),
+        (DataType::Int64, "pending_compaction_bytes"),
+    ],
+    pk: &[0, 1],
+};
+
+impl SysCatalogReaderImpl {
+    pub async fn read_hummock_compaction_status(&self) -> Result<Vec<OwnedRow>> {
+        let statuses = self.meta_client.list_hummock_compaction_statuses().await?;
+        let rows = statuses
+            .into_iter()
+            .flat_map(|s| {
+                s.level_handlers.into_iter().map(move |h| {
+                    let pending_compaction_bytes: u64 =
+                        h.tasks.iter().map(|t| t.total_file_size).sum();
+                    OwnedRow::new(vec![
+                        Some(ScalarImpl::Int64(s.compaction_group_id as _)),
+                        Some(ScalarImpl::Int32(h.level as _)),
+                        Some(ScalarImpl::Int32(h.tasks.len() as _)),
+                        Some(ScalarImpl::Int64(pending_compaction_bytes as _)),
+                    ])
+                })
+            })
+            .collect_vec();
+        Ok(rows)
+    }
+}
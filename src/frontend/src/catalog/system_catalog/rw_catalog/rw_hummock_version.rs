@@ -72,6 +72,27 @@ pub const RW_HUMMOCK_SSTABLES: BuiltinTable = BuiltinTable {
     pk: &[0],
 };
 
+/// One row per level (and per sub-level for L0) of each compaction group in the current
+/// version, so that SST counts and sizes can be aggregated with plain SQL instead of
+/// decoding the `compaction_group` json of `rw_hummock_current_version`.
+pub const RW_HUMMOCK_VERSION_LEVELS: BuiltinTable = BuiltinTable {
+    name: "rw_hummock_version_levels",
+    schema: RW_CATALOG_SCHEMA_NAME,
+    columns: &[
+        (DataType::Int64, "version_id"),
+        (DataType::Int64, "compaction_group_id"),
+        (DataType::Int32, "level_id"),
+        (DataType::Int64, "sub_level_id"),
+        (DataType::Int32, "level_type"),
+        (DataType::Int32, "sstable_count"),
+        (DataType::Int64, "total_file_size"),
+        (DataType::Int64, "uncompressed_file_size"),
+        (DataType::Int64, "total_key_count"),
+        (DataType::Int64, "stale_key_count"),
+    ],
+    pk: &[],
+};
+
 impl SysCatalogReaderImpl {
     pub async fn read_hummock_current_version(&self) -> Result<Vec<OwnedRow>> {
         let version = self.meta_client.get_hummock_current_version().await?;
@@ -91,6 +112,11 @@ impl SysCatalogReaderImpl {
         let version = self.meta_client.get_hummock_current_version().await?;
         Ok(version_to_sstable_rows(version))
     }
+
+    pub async fn read_hummock_version_levels(&self) -> Result<Vec<OwnedRow>> {
+        let version = self.meta_client.get_hummock_current_version().await?;
+        Ok(version_to_level_rows(&version))
+    }
 }
 
 fn remove_key_range_from_version(mut version: HummockVersion) -> HummockVersion {
@@ -124,6 +150,38 @@ fn version_to_compaction_group_rows(version: &HummockVersion) -> Vec<OwnedRow> {
         .collect()
 }
 
+fn version_to_level_rows(version: &HummockVersion) -> Vec<OwnedRow> {
+    let mut rows = vec![];
+    for cg in version.levels.values() {
+        for level in cg
+            .levels
+            .iter()
+            .chain(cg.l0.as_ref().unwrap().sub_levels.iter())
+        {
+            let sub_level_id = if level.level_idx > 0 {
+                None
+            } else {
+                Some(ScalarImpl::Int64(level.sub_level_id as _))
+            };
+            let total_key_count: u64 = level.table_infos.iter().map(|sst| sst.total_key_count).sum();
+            let stale_key_count: u64 = level.table_infos.iter().map(|sst| sst.stale_key_count).sum();
+            rows.push(OwnedRow::new(vec![
+                Some(ScalarImpl::Int64(version.id as _)),
+                Some(ScalarImpl::Int64(cg.group_id as _)),
+                Some(ScalarImpl::Int32(level.level_idx as _)),
+                sub_level_id,
+                Some(ScalarImpl::Int32(level.level_type as _)),
+                Some(ScalarImpl::Int32(level.table_infos.len() as _)),
+                Some(ScalarImpl::Int64(level.total_file_size as _)),
+                Some(ScalarImpl::Int64(level.uncompressed_file_size as _)),
+                Some(ScalarImpl::Int64(total_key_count as _)),
+                Some(ScalarImpl::Int64(stale_key_count as _)),
+            ]));
+        }
+    }
+    rows
+}
+
 fn version_to_sstable_rows(version: HummockVersion) -> Vec<OwnedRow> {
     let mut sstables = vec![];
     for cg in version.levels.into_values() {
@@ -0,0 +1,82 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use risingwave_common::catalog::RW_CATALOG_SCHEMA_NAME;
+use risingwave_common::error::Result;
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{BuiltinTable, SysCatalogReaderImpl};
+use crate::handler::create_source::UPSTREAM_SOURCE_KEY;
+
+/// `rw_source_status` lists the current split assignment of each source, one row per split
+/// assigned to an actor.
+pub const RW_SOURCE_STATUS: BuiltinTable = BuiltinTable {
+    name: "rw_source_status",
+    schema: RW_CATALOG_SCHEMA_NAME,
+    columns: &[
+        (DataType::Int32, "source_id"),
+        (DataType::Varchar, "source_name"),
+        (DataType::Int32, "schema_id"),
+        (DataType::Varchar, "connector"),
+        (DataType::Int32, "fragment_id"),
+        (DataType::Int32, "actor_id"),
+        (DataType::Varchar, "split_id"),
+    ],
+    pk: &[5, 6],
+};
+
+impl SysCatalogReaderImpl {
+    pub async fn read_rw_source_status_info(&self) -> Result<Vec<OwnedRow>> {
+        let actor_splits = self.meta_client.list_actor_splits().await?;
+
+        let reader = self.catalog_reader.read_guard();
+        let schemas = reader.iter_schemas(&self.auth_context.database)?;
+        let sources: HashMap<_, _> = schemas
+            .flat_map(|schema| {
+                schema
+                    .iter_source()
+                    .map(|source| (source.id, (schema.id(), source)))
+            })
+            .collect();
+
+        Ok(actor_splits
+            .into_iter()
+            .filter_map(|actor_split| {
+                // The source may belong to another database, or has just been dropped.
+                let (schema_id, source) = sources.get(&actor_split.source_id)?;
+                Some(OwnedRow::new(vec![
+                    Some(ScalarImpl::Int32(source.id as i32)),
+                    Some(ScalarImpl::Utf8(source.name.clone().into())),
+                    Some(ScalarImpl::Int32(*schema_id as i32)),
+                    Some(ScalarImpl::Utf8(
+                        source
+                            .properties
+                            .get(UPSTREAM_SOURCE_KEY)
+                            .cloned()
+                            .unwrap_or("".to_string())
+                            .to_uppercase()
+                            .into(),
+                    )),
+                    Some(ScalarImpl::Int32(actor_split.fragment_id as i32)),
+                    Some(ScalarImpl::Int32(actor_split.actor_id as i32)),
+                    Some(ScalarImpl::Utf8(actor_split.split_id.into())),
+                ]))
+            })
+            .collect_vec())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap};
+
+use itertools::Itertools;
+use risingwave_common::array::ListValue;
+use risingwave_common::catalog::RW_CATALOG_SCHEMA_NAME;
+use risingwave_common::error::Result;
+use risingwave_common::hash::{ParallelUnitId, ParallelUnitMapping};
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{BuiltinTable, SysCatalogReaderImpl};
+use crate::catalog::FragmentId;
+
+/// Exposes the vnode-to-parallel-unit mapping of each fragment, so that data distribution can be
+/// inspected with plain SQL instead of decoding the compressed protobuf mappings by hand.
+pub const RW_VNODE_MAPPINGS: BuiltinTable = BuiltinTable {
+    name: "rw_vnode_mappings",
+    schema: RW_CATALOG_SCHEMA_NAME,
+    columns: &[
+        (DataType::Int32, "fragment_id"),
+        (DataType::Varchar, "mapping_type"),
+        (DataType::Int32, "parallel_unit_id"),
+        (DataType::List(Box::new(DataType::Int32)), "vnodes"),
+    ],
+    pk: &[0, 1, 2],
+};
+
+fn mapping_rows(
+    mappings: HashMap<FragmentId, ParallelUnitMapping>,
+    mapping_type: &str,
+) -> Vec<OwnedRow> {
+    mappings
+        .into_iter()
+        .sorted_by_key(|(fragment_id, _)| *fragment_id)
+        .flat_map(|(fragment_id, mapping)| {
+            let mut vnodes_by_parallel_unit: BTreeMap<ParallelUnitId, Vec<_>> = BTreeMap::new();
+            for (vnode, parallel_unit_id) in mapping.iter_with_vnode() {
+                vnodes_by_parallel_unit
+                    .entry(parallel_unit_id)
+                    .or_default()
+                    .push(Some(ScalarImpl::Int32(vnode.to_index() as i32)));
+            }
+            vnodes_by_parallel_unit
+                .into_iter()
+                .map(move |(parallel_unit_id, vnodes)| {
+                    OwnedRow::new(vec![
+                        Some(ScalarImpl::Int32(fragment_id as i32)),
+                        Some(ScalarImpl::Utf8(mapping_type.into())),
+                        Some(ScalarImpl::Int32(parallel_unit_id as i32)),
+                        Some(ScalarImpl::List(ListValue::new(vnodes))),
+                    ])
+                })
+        })
+        .collect_vec()
+}
+
+impl SysCatalogReaderImpl {
+    pub fn read_rw_vnode_mappings(&self) -> Result<Vec<OwnedRow>> {
+        let mut rows = mapping_rows(
+            self.worker_node_manager.streaming_fragment_mappings(),
+            "STREAMING",
+        );
+        rows.extend(mapping_rows(
+            self.worker_node_manager.serving_fragment_mappings(),
+            "SERVING",
+        ));
+        Ok(rows)
+    }
+}
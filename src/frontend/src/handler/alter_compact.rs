@@ -0,0 +1,82 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::ObjectName;
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::Binder;
+
+/// Handles `ALTER MATERIALIZED VIEW <name> COMPACT`, which triggers a manual compaction of the
+/// compaction group(s) holding the state of the materialized view, filtered by its state
+/// tables. The progress of the resulting compaction tasks can be observed through
+/// `rw_catalog.rw_hummock_compact_task_progress`.
+pub async fn handle_alter_compact(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        if table.table_type != TableType::MaterializedView {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{table_name}\" is not a materialized view",
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+        table.id
+    };
+
+    // The state of the materialized view may live in a dedicated compaction group, so look up
+    // the group(s) it belongs to instead of assuming the static one.
+    let meta_client = session.env().meta_client();
+    let group_ids = meta_client
+        .list_hummock_compaction_group_configs()
+        .await?
+        .into_iter()
+        .filter(|group| group.member_table_ids.contains(&table_id.table_id))
+        .map(|group| group.id)
+        .collect::<Vec<_>>();
+    if group_ids.is_empty() {
+        return Err(ErrorCode::InternalError(format!(
+            "no compaction group found for materialized view \"{table_name}\", it may not have been committed yet",
+        ))
+        .into());
+    }
+
+    for group_id in group_ids {
+        meta_client
+            .trigger_manual_compaction(group_id, table_id.table_id)
+            .await?;
+    }
+
+    Ok(PgResponse::empty_result(
+        StatementType::ALTER_MATERIALIZED_VIEW,
+    ))
+}
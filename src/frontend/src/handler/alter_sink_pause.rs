@@ -0,0 +1,55 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::Result;
+use risingwave_sqlparser::ast::ObjectName;
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::Binder;
+
+/// Handles `ALTER SINK <name> PAUSE/RESUME`, which stops or resumes delivery to the external
+/// system without dropping the sink, e.g. for a downstream maintenance window. While paused,
+/// upstream changes keep being buffered in the sink's log store, bounded by its capacity. The
+/// pause state is visible in `SHOW SINKS`.
+pub async fn handle_alter_sink_pause(
+    handler_args: HandlerArgs,
+    sink_name: ObjectName,
+    pause: bool,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_sink_name) =
+        Binder::resolve_schema_qualified_name(db_name, sink_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let sink_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (sink, schema_name) = reader.get_sink_by_name(db_name, schema_path, &real_sink_name)?;
+        session.check_privilege_for_drop_alter(schema_name, &**sink)?;
+        sink.id
+    };
+
+    session
+        .env()
+        .meta_client()
+        .alter_sink_pause(sink_id.sink_id, pause)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_SINK))
+}
@@ -19,6 +19,9 @@ use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
+use risingwave_connector::sink::{
+    SinkSchemaChangePolicy, SINK_SCHEMA_CHANGE_BLOCK, SINK_SCHEMA_CHANGE_OPTION,
+};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::Table;
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
@@ -181,6 +184,7 @@ pub async fn handle_alter_table_column(
         constraints,
         source_watermarks,
         append_only,
+        on_conflict,
         ..
     } = definition
     else {
@@ -200,6 +204,7 @@ pub async fn handle_alter_table_column(
                     source_watermarks,
                     col_id_gen,
                     append_only,
+                    on_conflict,
                 )
                 .await?
             }
@@ -211,6 +216,7 @@ pub async fn handle_alter_table_column(
                 col_id_gen,
                 source_watermarks,
                 append_only,
+                on_conflict,
             )?,
         };
 
@@ -255,6 +261,50 @@ pub async fn handle_alter_table_column(
         table.columns.len(),
     );
 
+    // Sinks created from this table keep the column set captured at `CREATE SINK` time,
+    // so the schema change is not propagated to their downstream systems. Each sink
+    // declares through the `schema.change.policy` WITH option whether such a change is
+    // acceptable while it exists.
+    let mut blocking_sinks = vec![];
+    let mut permitting_sinks = vec![];
+    {
+        let reader = session.env().catalog_reader().read_guard();
+        for sink in reader
+            .get_database_by_name(db_name)?
+            .iter_schemas()
+            .flat_map(|schema| schema.iter_sink())
+            .filter(|sink| sink.dependent_relations.contains(&original_catalog.id()))
+        {
+            match SinkSchemaChangePolicy::from_properties(&sink.properties)? {
+                SinkSchemaChangePolicy::Block => blocking_sinks.push(sink.name.clone()),
+                SinkSchemaChangePolicy::Permit => permitting_sinks.push(sink.name.clone()),
+            }
+        }
+    }
+    if !blocking_sinks.is_empty() {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "sink(s) {} on table \"{}\" are created with `{} = '{}'`. Drop and recreate the sink(s) to proceed with the schema change.",
+            blocking_sinks
+                .iter()
+                .map(|name| format!("\"{name}\""))
+                .join(", "),
+            real_table_name,
+            SINK_SCHEMA_CHANGE_OPTION,
+            SINK_SCHEMA_CHANGE_BLOCK,
+        ))
+        .into());
+    }
+    if !permitting_sinks.is_empty() {
+        session.notice_to_user(format!(
+            "sink(s) {} will keep emitting the original schema of table \"{}\". Recreate the sink(s) to propagate the schema change downstream.",
+            permitting_sinks
+                .iter()
+                .map(|name| format!("\"{name}\""))
+                .join(", "),
+            real_table_name,
+        ));
+    }
+
     let catalog_writer = session.catalog_writer()?;
 
     catalog_writer
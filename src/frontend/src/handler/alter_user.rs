@@ -111,6 +111,10 @@ fn alter_prost_user_info(
                 }
                 update_fields.push(UpdateField::AuthInfo);
             }
+            UserOption::QueryLimit(limit) => {
+                user_info.query_limit = *limit;
+                update_fields.push(UpdateField::QueryLimit);
+            }
         }
     }
     Ok((user_info, update_fields))
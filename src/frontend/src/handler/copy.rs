@@ -0,0 +1,307 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use itertools::Itertools;
+use parquet::arrow::ArrowWriter;
+use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::pg_server::BoxedError;
+use risingwave_common::array::{to_record_batch_with_schema, DataChunk};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::session_config::QueryMode;
+use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
+use risingwave_object_store::object::{parse_remote_object_store, ObjectStoreImpl};
+use risingwave_sqlparser::ast::{
+    Distinct, Expr, Ident, ObjectName, Query, Select, SelectItem, SetExpr, Statement, TableFactor,
+    TableWithJoins, Value, Values,
+};
+
+use super::query::{
+    distribute_execute, gen_batch_plan_by_statement, gen_batch_plan_fragmenter, local_execute,
+    BatchPlanFragmenterResult,
+};
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::optimizer::OptimizerContext;
+use crate::Binder;
+
+/// The maximum number of rows in one exported Parquet file. A new file is started once the
+/// current one reaches this count, so that large exports are split into parts that downstream
+/// systems can load in parallel.
+const MAX_ROWS_PER_FILE: usize = 1 << 20;
+
+/// Handles `COPY <table> FROM STDIN`, whose payload has already been materialized as a flat list
+/// of tab-separated values by the parser. The values are rewritten into a single batched `INSERT
+/// ... VALUES` statement so that they go through the regular DML path to the table source,
+/// avoiding the per-statement overhead of row-by-row inserts.
+pub async fn handle_copy(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    columns: Vec<Ident>,
+    values: Vec<Option<String>>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+
+    // The number of columns each row of the payload is expected to have.
+    let arity = if columns.is_empty() {
+        let db_name = session.database();
+        let (schema_name, real_table_name) =
+            Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+        let search_path = session.config().get_search_path();
+        let user_name = &session.auth_context().user_name;
+        let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, _) = reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        table.columns.iter().filter(|c| !c.is_hidden()).count()
+    } else {
+        columns.len()
+    };
+
+    if values.is_empty() {
+        return Ok(PgResponse::builder(StatementType::COPY).row_cnt(0).into());
+    }
+    if arity == 0 || values.len() % arity != 0 {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "COPY payload of {} values cannot be divided into rows of {} columns",
+            values.len(),
+            arity
+        ))
+        .into());
+    }
+
+    // Rewrite the payload into `INSERT INTO <table> [(columns)] VALUES ...`. Each value is passed
+    // as a string literal and implicitly cast to the column type by the binder, consistent with
+    // the text format of the COPY protocol.
+    let rows = values
+        .chunks(arity)
+        .map(|row| {
+            row.iter()
+                .map(|v| match v {
+                    Some(v) => Expr::Value(Value::SingleQuotedString(v.clone())),
+                    None => Expr::Value(Value::Null),
+                })
+                .collect()
+        })
+        .collect();
+    let insert = Statement::Insert {
+        table_name,
+        columns,
+        source: Box::new(Query {
+            with: None,
+            body: SetExpr::Values(Values(rows)),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+            fetch: None,
+        }),
+        returning: vec![],
+    };
+
+    let response = super::query::handle_query(handler_args, insert, vec![]).await?;
+    Ok(PgResponse::builder(StatementType::COPY)
+        .row_cnt_opt(response.affected_rows_cnt())
+        .into())
+}
+
+/// Handles `COPY <table> TO '<target>' FORMAT PARQUET`, which exports a consistent snapshot of
+/// a table or materialized view to an object store.
+///
+/// The export is rewritten into a `SELECT` over the relation and goes through the regular batch
+/// planning and scheduling path, so all scans read at the epoch pinned for this statement. The
+/// result is written as one or more Parquet files under the target prefix, together with a
+/// `manifest.json` that records the epoch and the produced files.
+pub async fn handle_copy_to(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    columns: Vec<Ident>,
+    target: String,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+
+    let Some(path) = target.strip_prefix("s3://") else {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "COPY TO only supports s3:// targets, got: {}",
+            target
+        ))
+        .into());
+    };
+    let (bucket, prefix) = path.split_once('/').unwrap_or((path, ""));
+    let prefix = prefix.trim_end_matches('/');
+    if bucket.is_empty() {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "COPY TO target misses the bucket name: {}",
+            target
+        ))
+        .into());
+    }
+
+    // Rewrite the export into `SELECT [columns | *] FROM <table>` so that it is planned,
+    // privilege-checked and scheduled like a regular batch query.
+    let projection = if columns.is_empty() {
+        vec![SelectItem::Wildcard(None)]
+    } else {
+        columns
+            .into_iter()
+            .map(|column| SelectItem::UnnamedExpr(Expr::Identifier(column)))
+            .collect()
+    };
+    let select = Statement::Query(Box::new(Query {
+        with: None,
+        body: SetExpr::Select(Box::new(Select {
+            distinct: Distinct::All,
+            hints: vec![],
+            projection,
+            from: vec![TableWithJoins {
+                relation: TableFactor::Table {
+                    name: table_name.clone(),
+                    alias: None,
+                    for_system_time_as_of_proctime: false,
+                },
+                joins: vec![],
+            }],
+            lateral_views: vec![],
+            selection: None,
+            group_by: vec![],
+            having: None,
+        })),
+        order_by: vec![],
+        limit: None,
+        offset: None,
+        fetch: None,
+    }));
+
+    let BatchPlanFragmenterResult {
+        plan_fragmenter,
+        query_mode,
+        schema,
+        ..
+    } = {
+        let context = OptimizerContext::from_handler_args(handler_args);
+        let plan_result = gen_batch_plan_by_statement(&session, context.into(), select)?;
+        gen_batch_plan_fragmenter(&session, plan_result)?
+    };
+    let query = plan_fragmenter.generate_complete_query().await?;
+
+    // The epoch all scans of this statement read at. It is recorded in the manifest so that the
+    // exported files can be related to a checkpoint.
+    let epoch = session.pinned_snapshot().epoch().0;
+
+    let mut chunk_stream: BoxStream<'static, std::result::Result<DataChunk, BoxedError>> =
+        match query_mode {
+            QueryMode::Auto => unreachable!(),
+            QueryMode::Local => local_execute(session.clone(), query).await?.boxed(),
+            QueryMode::Distributed => distribute_execute(session.clone(), query).await?.boxed(),
+        };
+
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            Ok(arrow_schema::Field::new(
+                field.name.clone(),
+                (&field.data_type).try_into().map_err(ErrorCode::ArrayError)?,
+                true,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let arrow_schema = Arc::new(arrow_schema::Schema::new(fields));
+
+    let store = parse_remote_object_store(
+        &format!("s3://{}", bucket),
+        Arc::new(ObjectStoreMetrics::unused()),
+        "COPY TO",
+    )
+    .await;
+
+    let mut files = Vec::new();
+    let mut writer = new_parquet_writer(&arrow_schema)?;
+    let mut rows_in_file = 0;
+    let mut total_rows = 0;
+
+    while let Some(chunk) = chunk_stream.next().await {
+        let chunk =
+            chunk.map_err(|err| RwError::from(ErrorCode::InternalError(format!("{}", err))))?;
+        rows_in_file += chunk.cardinality();
+        total_rows += chunk.cardinality();
+        let record_batch = to_record_batch_with_schema(arrow_schema.clone(), &chunk)
+            .map_err(ErrorCode::ArrayError)?;
+        writer.write(&record_batch).map_err(|err| {
+            ErrorCode::InternalError(format!("failed to write the parquet file: {}", err))
+        })?;
+
+        if rows_in_file >= MAX_ROWS_PER_FILE {
+            let full = std::mem::replace(&mut writer, new_parquet_writer(&arrow_schema)?);
+            files.push(finish_file(&store, prefix, files.len(), rows_in_file, full).await?);
+            rows_in_file = 0;
+        }
+    }
+    if rows_in_file > 0 || files.is_empty() {
+        // Always produce at least one (possibly empty) file so that the target is well-formed
+        // even for an empty relation.
+        files.push(finish_file(&store, prefix, files.len(), rows_in_file, writer).await?);
+    }
+
+    let manifest = serde_json::json!({
+        "table": table_name.to_string(),
+        "epoch": epoch,
+        "format": "parquet",
+        "columns": schema.fields().iter().map(|field| field.name.clone()).collect_vec(),
+        "files": files,
+    });
+    let manifest = serde_json::to_vec_pretty(&manifest).expect("failed to serialize the manifest");
+    upload(&store, prefix, "manifest.json", manifest).await?;
+
+    Ok(PgResponse::builder(StatementType::COPY)
+        .row_cnt(total_rows as i32)
+        .into())
+}
+
+fn new_parquet_writer(schema: &Arc<arrow_schema::Schema>) -> Result<ArrowWriter<Vec<u8>>> {
+    ArrowWriter::try_new(Vec::new(), schema.clone(), None).map_err(|err| {
+        ErrorCode::InternalError(format!("failed to create the parquet writer: {}", err)).into()
+    })
+}
+
+/// Finishes and uploads one Parquet file, returning its manifest entry.
+async fn finish_file(
+    store: &ObjectStoreImpl,
+    prefix: &str,
+    seq: usize,
+    rows: usize,
+    writer: ArrowWriter<Vec<u8>>,
+) -> Result<serde_json::Value> {
+    let data = writer.into_inner().map_err(|err| {
+        ErrorCode::InternalError(format!("failed to finish the parquet file: {}", err))
+    })?;
+    let name = format!("part-{:05}.parquet", seq);
+    upload(store, prefix, &name, data).await?;
+    Ok(serde_json::json!({ "path": name, "rows": rows }))
+}
+
+async fn upload(store: &ObjectStoreImpl, prefix: &str, name: &str, data: Vec<u8>) -> Result<()> {
+    let path = if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", prefix, name)
+    };
+    store.upload(&path, Bytes::from(data)).await.map_err(|err| {
+        ErrorCode::InternalError(format!("failed to upload {} to the target: {}", path, err))
+            .into()
+    })
+}
@@ -36,12 +36,15 @@ use crate::expr::{Expr, ExprImpl, InputRef};
 use crate::handler::privilege::ObjectCheckItem;
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::generic::ScanTableType;
-use crate::optimizer::plan_node::{Explain, LogicalProject, LogicalScan, StreamMaterialize};
+use crate::optimizer::plan_node::{
+    Explain, LogicalFilter, LogicalProject, LogicalScan, StreamMaterialize,
+};
 use crate::optimizer::property::{Cardinality, Distribution, Order, RequiredDist};
 use crate::optimizer::{OptimizerContext, OptimizerContextRef, PlanRef, PlanRoot};
 use crate::scheduler::streaming_manager::CreatingStreamingJobInfo;
 use crate::session::SessionImpl;
 use crate::stream_fragmenter::build_graph;
+use crate::utils::Condition;
 
 pub(crate) fn gen_create_index_plan(
     session: &SessionImpl,
@@ -51,6 +54,7 @@ pub(crate) fn gen_create_index_plan(
     columns: Vec<OrderByExpr>,
     include: Vec<Ident>,
     distributed_by: Vec<ast::Expr>,
+    predicate: Option<ast::Expr>,
 ) -> Result<(PlanRef, PbTable, PbIndex)> {
     let db_name = session.database();
     let (schema_name, table_name) = Binder::resolve_schema_qualified_name(db_name, table_name)?;
@@ -135,6 +139,28 @@ pub(crate) fn gen_create_index_plan(
         distributed_columns_expr.push(expr_impl);
     }
 
+    // Bind the predicate of a partial index, referring to the columns of the primary table.
+    let index_predicate = predicate
+        .map(|predicate| -> Result<ExprImpl> {
+            let expr_impl = binder.bind_expr(predicate)?.enforce_bool_clause("WHERE")?;
+            if expr_impl.is_impure() {
+                return Err(ErrorCode::NotSupported(
+                    "this expression is impure".into(),
+                    "use a pure expression instead".into(),
+                )
+                .into());
+            }
+            if expr_impl.has_subquery() || expr_impl.has_agg_call() {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "subqueries and aggregate functions are not allowed in index predicate"
+                        .to_string(),
+                )
+                .into());
+            }
+            Ok(expr_impl)
+        })
+        .transpose()?;
+
     let table_desc = Rc::new(table.table_desc());
 
     // Remove duplicate column of index columns
@@ -188,6 +214,7 @@ pub(crate) fn gen_create_index_plan(
         index_table_name.clone(),
         &index_columns_ordered_expr,
         &include_columns_expr,
+        index_predicate.clone(),
         // We use the first index column as distributed key by default if users
         // haven't specify the distributed by columns.
         if distributed_columns_expr.is_empty() {
@@ -245,6 +272,7 @@ pub(crate) fn gen_create_index_plan(
         initialized_at_epoch: None,
         created_at_epoch: None,
         stream_job_status: PbStreamJobStatus::Creating.into(),
+        predicate: index_predicate.as_ref().map(|expr| expr.to_expr_proto()),
     };
 
     let plan: PlanRef = materialize.into();
@@ -314,12 +342,14 @@ fn assemble_materialize(
     index_name: String,
     index_columns: &[(ExprImpl, OrderType)],
     include_columns: &[ExprImpl],
+    index_predicate: Option<ExprImpl>,
     distributed_by_columns_len: usize,
     cardinality: Cardinality,
 ) -> Result<StreamMaterialize> {
     // Build logical plan and then call gen_create_index_plan
     // LogicalProject(index_columns, include_columns)
-    //   LogicalScan(table_desc)
+    //   LogicalFilter(index_predicate) (partial index only)
+    //     LogicalScan(table_desc)
 
     let definition = context.normalized_sql().to_owned();
 
@@ -334,13 +364,21 @@ fn assemble_materialize(
         cardinality,
     );
 
+    // A partial index only materializes the rows satisfying its predicate.
+    let input = match index_predicate {
+        Some(predicate) => {
+            LogicalFilter::create(logical_scan.into(), Condition::with_expr(predicate))
+        }
+        None => logical_scan.into(),
+    };
+
     let exprs = index_columns
         .iter()
         .map(|(expr, _)| expr.clone())
         .chain(include_columns.iter().cloned())
         .collect_vec();
 
-    let logical_project = LogicalProject::create(logical_scan.into(), exprs);
+    let logical_project = LogicalProject::create(input, exprs);
     let mut project_required_cols = FixedBitSet::with_capacity(logical_project.schema().len());
     project_required_cols.toggle_range(0..logical_project.schema().len());
 
@@ -406,6 +444,7 @@ pub async fn handle_create_index(
     columns: Vec<OrderByExpr>,
     include: Vec<Ident>,
     distributed_by: Vec<ast::Expr>,
+    predicate: Option<ast::Expr>,
 ) -> Result<RwPgResponse> {
     let session = handler_args.session.clone();
 
@@ -429,6 +468,7 @@ pub async fn handle_create_index(
             columns,
             include,
             distributed_by,
+            predicate,
         )?;
         let mut graph = build_graph(plan);
         graph.parallelism = session
@@ -17,9 +17,10 @@ use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::acl::AclMode;
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::Interval;
 use risingwave_pb::catalog::{CreateType, PbTable};
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
-use risingwave_sqlparser::ast::{EmitMode, Ident, ObjectName, Query};
+use risingwave_sqlparser::ast::{EmitMode, Ident, ObjectName, Query, Value};
 
 use super::privilege::resolve_relation_privileges;
 use super::RwPgResponse;
@@ -75,6 +76,52 @@ pub(super) fn get_column_names(
     Ok(col_names)
 }
 
+/// Extract the `EMIT` clause into whether to use Emit-On-Window-Close mode, and the allowed
+/// lateness if one is specified.
+pub fn bind_emit_mode(emit_mode: &Option<EmitMode>) -> Result<(bool, Option<Interval>)> {
+    match emit_mode {
+        Some(EmitMode::OnWindowClose { allowed_lateness }) => {
+            let allowed_lateness = allowed_lateness
+                .as_ref()
+                .map(bind_allowed_lateness)
+                .transpose()?;
+            Ok((true, allowed_lateness))
+        }
+        _ => Ok((false, None)),
+    }
+}
+
+fn bind_allowed_lateness(value: &Value) -> Result<Interval> {
+    let Value::Interval {
+        value,
+        leading_field,
+        ..
+    } = value
+    else {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "`ALLOWED LATENESS` must be an interval literal".to_string(),
+        )
+        .into());
+    };
+    let interval = Interval::parse_with_fields(
+        value,
+        leading_field.clone().map(Binder::bind_date_time_field),
+    )?;
+    if interval.months() != 0 {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "`ALLOWED LATENESS` must not contain month or larger fields".to_string(),
+        )
+        .into());
+    }
+    if interval.days() < 0 || interval.usecs() < 0 {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "`ALLOWED LATENESS` must be a non-negative interval".to_string(),
+        )
+        .into());
+    }
+    Ok(interval)
+}
+
 /// Generate create MV plan, return plan and mv table info.
 pub fn gen_create_mv_plan(
     session: &SessionImpl,
@@ -102,7 +149,7 @@ pub fn gen_create_mv_plan(
 
     let col_names = get_column_names(&bound, session, columns)?;
 
-    let emit_on_window_close = emit_mode == Some(EmitMode::OnWindowClose);
+    let (emit_on_window_close, allowed_lateness) = bind_emit_mode(&emit_mode)?;
     if emit_on_window_close {
         context.warn_to_user("EMIT ON WINDOW CLOSE is currently an experimental feature. Please use it with caution.");
     }
@@ -114,8 +161,12 @@ pub fn gen_create_mv_plan(
         }
         plan_root.set_out_names(col_names)?;
     }
-    let materialize =
-        plan_root.gen_materialize_plan(table_name, definition, emit_on_window_close)?;
+    let materialize = plan_root.gen_materialize_plan(
+        table_name,
+        definition,
+        emit_on_window_close,
+        allowed_lateness,
+    )?;
     let mut table = materialize.table().to_prost(schema_id, database_id);
     if session.config().get_create_compaction_group_for_mv() {
         table.properties.insert(
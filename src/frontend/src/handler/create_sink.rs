@@ -28,11 +28,11 @@ use risingwave_connector::sink::{
 };
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
 use risingwave_sqlparser::ast::{
-    ConnectorSchema, CreateSink, CreateSinkStatement, EmitMode, Encode, Format, ObjectName, Query,
+    ConnectorSchema, CreateSink, CreateSinkStatement, Encode, Format, ObjectName, Query,
     Select, SelectItem, SetExpr, TableFactor, TableWithJoins,
 };
 
-use super::create_mv::get_column_names;
+use super::create_mv::{bind_emit_mode, get_column_names};
 use super::RwPgResponse;
 use crate::binder::Binder;
 use crate::handler::privilege::resolve_query_privileges;
@@ -117,7 +117,7 @@ pub fn gen_sink_plan(
         conn_id.map(ConnectionId)
     };
 
-    let emit_on_window_close = stmt.emit_mode == Some(EmitMode::OnWindowClose);
+    let (emit_on_window_close, allowed_lateness) = bind_emit_mode(&stmt.emit_mode)?;
     if emit_on_window_close {
         context.warn_to_user("EMIT ON WINDOW CLOSE is currently an experimental feature. Please use it with caution.");
     }
@@ -155,6 +155,7 @@ pub fn gen_sink_plan(
         definition,
         with_options,
         emit_on_window_close,
+        allowed_lateness,
         db_name.to_owned(),
         sink_from_table_name,
         format_desc,
@@ -285,12 +286,12 @@ static CONNECTORS_COMPATIBLE_FORMATS: LazyLock<HashMap<String, HashMap<Format, V
                     Format::Debezium => vec![Encode::Json],
                 ),
                 KinesisSink::SINK_NAME => hashmap!(
-                    Format::Plain => vec![Encode::Json],
+                    Format::Plain => vec![Encode::Json, Encode::Protobuf],
                     Format::Upsert => vec![Encode::Json],
                     Format::Debezium => vec![Encode::Json],
                 ),
                 PulsarSink::SINK_NAME => hashmap!(
-                    Format::Plain => vec![Encode::Json],
+                    Format::Plain => vec![Encode::Json, Encode::Protobuf],
                     Format::Upsert => vec![Encode::Json],
                     Format::Debezium => vec![Encode::Json],
                 ),
@@ -22,7 +22,8 @@ use maplit::{convert_args, hashmap};
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{
     is_column_ids_dedup, ColumnCatalog, ColumnDesc, TableId, DEFAULT_KEY_COLUMN_NAME,
-    INITIAL_SOURCE_VERSION_ID, KAFKA_TIMESTAMP_COLUMN_NAME,
+    ICEBERG_FILE_PATH_COLUMN_NAME, ICEBERG_SNAPSHOT_ID_COLUMN_NAME, INITIAL_SOURCE_VERSION_ID,
+    KAFKA_TIMESTAMP_COLUMN_NAME,
 };
 use risingwave_common::error::ErrorCode::{self, InvalidInputSyntax, ProtocolError};
 use risingwave_common::error::{Result, RwError};
@@ -42,8 +43,8 @@ use risingwave_connector::source::datagen::DATAGEN_CONNECTOR;
 use risingwave_connector::source::nexmark::source::{get_event_data_types_with_names, EventType};
 use risingwave_connector::source::test_source::TEST_CONNECTOR;
 use risingwave_connector::source::{
-    GOOGLE_PUBSUB_CONNECTOR, KAFKA_CONNECTOR, KINESIS_CONNECTOR, NATS_CONNECTOR, NEXMARK_CONNECTOR,
-    PULSAR_CONNECTOR, S3_CONNECTOR, S3_V2_CONNECTOR,
+    GOOGLE_PUBSUB_CONNECTOR, ICEBERG_CONNECTOR, KAFKA_CONNECTOR, KINESIS_CONNECTOR, MQTT_CONNECTOR,
+    NATS_CONNECTOR, NEXMARK_CONNECTOR, PULSAR_CONNECTOR, S3_CONNECTOR, S3_V2_CONNECTOR,
 };
 use risingwave_pb::catalog::{
     PbSchemaRegistryNameStrategy, PbSource, StreamSourceInfo, WatermarkDesc,
@@ -65,7 +66,7 @@ use crate::handler::create_table::{
     ensure_table_constraints_supported, ColumnIdGenerator,
 };
 use crate::handler::util::{
-    get_connector, is_cdc_connector, is_kafka_connector, is_key_mq_connector,
+    get_connector, is_cdc_connector, is_iceberg_connector, is_kafka_connector, is_key_mq_connector,
 };
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::{LogicalSource, ToStream, ToStreamContext};
@@ -842,6 +843,33 @@ fn check_and_add_timestamp_column(
     }
 }
 
+// Add hidden columns `_rw_iceberg_snapshot_id` and `_rw_iceberg_file_path` to each row from
+// the iceberg source, recording the snapshot and the data file it was read from.
+fn check_and_add_iceberg_columns(
+    with_properties: &HashMap<String, String>,
+    columns: &mut Vec<ColumnCatalog>,
+) {
+    if is_iceberg_connector(with_properties) {
+        for (name, data_type) in [
+            (ICEBERG_SNAPSHOT_ID_COLUMN_NAME, DataType::Int64),
+            (ICEBERG_FILE_PATH_COLUMN_NAME, DataType::Varchar),
+        ] {
+            columns.push(ColumnCatalog {
+                column_desc: ColumnDesc {
+                    data_type,
+                    column_id: ColumnId::placeholder(),
+                    name: name.to_string(),
+                    field_descs: vec![],
+                    type_name: "".to_string(),
+                    generated_or_default_column: None,
+                    description: None,
+                },
+                is_hidden: true,
+            });
+        }
+    }
+}
+
 fn add_default_key_column(columns: &mut Vec<ColumnCatalog>) {
     let column = ColumnCatalog {
         column_desc: ColumnDesc {
@@ -945,16 +973,23 @@ static CONNECTORS_COMPATIBLE_FORMATS: LazyLock<HashMap<String, HashMap<Format, V
                     Format::Plain => vec![Encode::Json],
                 ),
                 POSTGRES_CDC_CONNECTOR => hashmap!(
-                    Format::Plain => vec![Encode::Bytes],
+                    // `Encode::Json` supports source stream job
+                    Format::Plain => vec![Encode::Bytes, Encode::Json],
                     Format::Debezium => vec![Encode::Json],
                 ),
                 CITUS_CDC_CONNECTOR => hashmap!(
                     Format::Plain => vec![Encode::Bytes],
                     Format::Debezium => vec![Encode::Json],
                 ),
+                MQTT_CONNECTOR => hashmap!(
+                    Format::Plain => vec![Encode::Json, Encode::Bytes],
+                ),
                 NATS_CONNECTOR => hashmap!(
                     Format::Plain => vec![Encode::Json],
                 ),
+                ICEBERG_CONNECTOR => hashmap!(
+                    Format::Native => vec![Encode::Native],
+                ),
                 TEST_CONNECTOR => hashmap!(
                     Format::Plain => vec![Encode::Json],
                 )
@@ -1178,6 +1213,7 @@ pub async fn handle_create_source(
     }
 
     check_and_add_timestamp_column(&with_properties, &mut columns);
+    check_and_add_iceberg_columns(&with_properties, &mut columns);
 
     let mut col_id_gen = ColumnIdGenerator::new_initial();
     for c in &mut columns {
@@ -40,7 +40,7 @@ use risingwave_pb::plan_common::{DefaultColumnDesc, GeneratedColumnDesc};
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
 use risingwave_sqlparser::ast::{
     CdcTableInfo, ColumnDef, ColumnOption, ConnectorSchema, DataType as AstDataType, Format,
-    ObjectName, SourceWatermark, TableConstraint,
+    ObjectName, OnConflict, SourceWatermark, TableConstraint,
 };
 
 use super::RwPgResponse;
@@ -465,6 +465,7 @@ pub(crate) async fn gen_create_table_plan_with_source(
     source_watermarks: Vec<SourceWatermark>,
     mut col_id_gen: ColumnIdGenerator,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
     if append_only
         && source_schema.format != Format::Plain
@@ -587,12 +588,14 @@ pub(crate) async fn gen_create_table_plan_with_source(
         watermark_descs,
         Some(cdc_table_type),
         append_only,
+        on_conflict,
         Some(col_id_gen.into_version()),
     )
 }
 
 /// `gen_create_table_plan` generates the plan for creating a table without an external stream
 /// source.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn gen_create_table_plan(
     context: OptimizerContext,
     table_name: ObjectName,
@@ -601,6 +604,7 @@ pub(crate) fn gen_create_table_plan(
     mut col_id_gen: ColumnIdGenerator,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
     let definition = context.normalized_sql().to_owned();
     let mut columns = bind_sql_columns(&column_defs)?;
@@ -618,6 +622,7 @@ pub(crate) fn gen_create_table_plan(
         definition,
         source_watermarks,
         append_only,
+        on_conflict,
         Some(col_id_gen.into_version()),
     )
 }
@@ -633,6 +638,7 @@ pub(crate) fn gen_create_table_plan_without_bind(
     definition: String,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     version: Option<TableVersion>,
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
     ensure_table_constraints_supported(&constraints)?;
@@ -666,6 +672,7 @@ pub(crate) fn gen_create_table_plan_without_bind(
         watermark_descs,
         None,
         append_only,
+        on_conflict,
         version,
     )
 }
@@ -683,6 +690,7 @@ fn gen_table_plan_inner(
     watermark_descs: Vec<WatermarkDesc>,
     cdc_table_type: Option<CdcTableType>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     version: Option<TableVersion>, /* TODO: this should always be `Some` if we support `ALTER
                                     * TABLE` for `CREATE TABLE AS`. */
 ) -> Result<(PlanRef, Option<PbSource>, PbTable)> {
@@ -773,6 +781,7 @@ fn gen_table_plan_inner(
         pk_column_ids,
         row_id_index,
         append_only,
+        on_conflict,
         watermark_descs,
         version,
     )?;
@@ -882,6 +891,7 @@ pub(crate) fn gen_create_table_plan_for_cdc_source(
         pk_column_ids,
         None,
         append_only,
+        None, // no on conflict clause
         vec![], // no watermarks
         Some(col_id_gen.into_version()),
     )?;
@@ -942,6 +952,7 @@ pub async fn handle_create_table(
     source_schema: Option<ConnectorSchema>,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     notice: Option<String>,
     cdc_table_info: Option<CdcTableInfo>,
 ) -> Result<RwPgResponse> {
@@ -979,6 +990,7 @@ pub async fn handle_create_table(
                     source_watermarks,
                     col_id_gen,
                     append_only,
+                    on_conflict,
                 )
                 .await?,
                 TableJobType::General,
@@ -992,6 +1004,7 @@ pub async fn handle_create_table(
                     col_id_gen,
                     source_watermarks,
                     append_only,
+                    on_conflict,
                 )?,
                 TableJobType::General,
             ),
@@ -105,6 +105,7 @@ pub async fn handle_create_as(
             "".to_owned(), // TODO: support `SHOW CREATE TABLE` for `CREATE TABLE AS`
             vec![],        // No watermark should be defined in for `CREATE TABLE AS`
             append_only,
+            None, // No conflict behavior can be defined for `CREATE TABLE AS`
             Some(col_id_gen.into_version()),
         )?;
         let mut graph = build_graph(plan);
@@ -91,6 +91,7 @@ fn make_prost_user_info(
                     user_info.auth_info = encrypted_password(&user_info.name, &password.0);
                 }
             }
+            UserOption::QueryLimit(limit) => user_info.query_limit = *limit,
         }
     }
 
@@ -18,6 +18,7 @@ use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
+use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{display_comma_separated, ObjectName};
@@ -25,6 +26,7 @@ use risingwave_sqlparser::ast::{display_comma_separated, ObjectName};
 use super::RwPgResponse;
 use crate::binder::{Binder, Relation};
 use crate::catalog::CatalogError;
+use crate::expr::{ExprDisplay, ExprImpl};
 use crate::handler::util::col_descs_to_rows;
 use crate::handler::HandlerArgs;
 
@@ -34,74 +36,113 @@ pub fn handle_describe(handler_args: HandlerArgs, table_name: ObjectName) -> Res
     let relation = binder.bind_relation_by_name(table_name.clone(), None, false)?;
     // For Source, it doesn't have table catalog so use get source to get column descs.
 
-    // Vec<ColumnCatalog>, Vec<ColumnDesc>, Vec<ColumnDesc>, Vec<Arc<IndexCatalog>>, String, Option<String>
-    let (columns, pk_columns, dist_columns, indices, relname, description) = match relation {
-        Relation::Source(s) => {
-            let pk_column_catalogs = s
-                .catalog
-                .pk_col_ids
-                .iter()
-                .map(|&column_id| {
+    // Vec<ColumnCatalog>, Vec<ColumnDesc>, Vec<ColumnDesc>, Vec<String>, Vec<Arc<IndexCatalog>>, String, Option<String>
+    let (columns, pk_columns, dist_columns, watermarks, indices, relname, description) =
+        match relation {
+            Relation::Source(s) => {
+                let pk_column_catalogs = s
+                    .catalog
+                    .pk_col_ids
+                    .iter()
+                    .map(|&column_id| {
+                        s.catalog
+                            .columns
+                            .iter()
+                            .filter(|x| x.column_id() == column_id)
+                            .map(|x| x.column_desc.clone())
+                            .exactly_one()
+                            .unwrap()
+                    })
+                    .collect_vec();
+                // The watermark expression is bound against the source columns.
+                let input_schema = Schema::new(
                     s.catalog
                         .columns
                         .iter()
-                        .filter(|x| x.column_id() == column_id)
-                        .map(|x| x.column_desc.clone())
-                        .exactly_one()
-                        .unwrap()
-                })
-                .collect_vec();
-            (
-                s.catalog.columns,
-                pk_column_catalogs,
-                vec![],
-                vec![],
-                s.catalog.name,
-                None, // Description
-            )
-        }
-        Relation::BaseTable(t) => {
-            let pk_column_catalogs = t
-                .table_catalog
-                .pk()
-                .iter()
-                .map(|x| t.table_catalog.columns[x.column_index].column_desc.clone())
-                .collect_vec();
-            let dist_columns = t
-                .table_catalog
-                .distribution_key()
-                .iter()
-                .map(|idx| t.table_catalog.columns[*idx].column_desc.clone())
-                .collect_vec();
-            (
-                t.table_catalog.columns,
-                pk_column_catalogs,
-                dist_columns,
-                t.table_indexes,
-                t.table_catalog.name,
-                t.table_catalog.description,
-            )
-        }
-        Relation::SystemTable(t) => {
-            let pk_column_catalogs = t
-                .sys_table_catalog
-                .pk
-                .iter()
-                .map(|idx| t.sys_table_catalog.columns[*idx].column_desc.clone())
-                .collect_vec();
-            (
-                t.sys_table_catalog.columns.clone(),
-                pk_column_catalogs,
-                vec![],
-                vec![],
-                t.sys_table_catalog.name.clone(),
-                None, // Description
-            )
-        }
-        _ => {
-            return Err(CatalogError::NotFound("table or source", table_name.to_string()).into());
-        }
-    };
+                        .map(|col| Field::from(&col.column_desc))
+                        .collect(),
+                );
+                let watermarks = s
+                    .catalog
+                    .watermark_descs
+                    .iter()
+                    .map(|desc| {
+                        let expr = ExprImpl::from_expr_proto(desc.expr.as_ref().unwrap())
+                            .expect("expr in watermark descs corrupted");
+                        format!(
+                            "{} AS {}",
+                            s.catalog.columns[desc.watermark_idx as usize].name(),
+                            ExprDisplay {
+                                expr: &expr,
+                                input_schema: &input_schema
+                            }
+                        )
+                    })
+                    .collect_vec();
+                (
+                    s.catalog.columns,
+                    pk_column_catalogs,
+                    vec![],
+                    watermarks,
+                    vec![],
+                    s.catalog.name,
+                    None, // Description
+                )
+            }
+            Relation::BaseTable(t) => {
+                let pk_column_catalogs = t
+                    .table_catalog
+                    .pk()
+                    .iter()
+                    .map(|x| t.table_catalog.columns[x.column_index].column_desc.clone())
+                    .collect_vec();
+                let dist_columns = t
+                    .table_catalog
+                    .distribution_key()
+                    .iter()
+                    .map(|idx| t.table_catalog.columns[*idx].column_desc.clone())
+                    .collect_vec();
+                // Only the columns a watermark is declared on are recorded in the table
+                // catalog, not the watermark expression itself.
+                let watermarks = t
+                    .table_catalog
+                    .watermark_columns
+                    .ones()
+                    .map(|idx| t.table_catalog.columns[idx].name().to_string())
+                    .collect_vec();
+                (
+                    t.table_catalog.columns,
+                    pk_column_catalogs,
+                    dist_columns,
+                    watermarks,
+                    t.table_indexes,
+                    t.table_catalog.name,
+                    t.table_catalog.description,
+                )
+            }
+            Relation::SystemTable(t) => {
+                let pk_column_catalogs = t
+                    .sys_table_catalog
+                    .pk
+                    .iter()
+                    .map(|idx| t.sys_table_catalog.columns[*idx].column_desc.clone())
+                    .collect_vec();
+                (
+                    t.sys_table_catalog.columns.clone(),
+                    pk_column_catalogs,
+                    vec![],
+                    vec![],
+                    vec![],
+                    t.sys_table_catalog.name.clone(),
+                    None, // Description
+                )
+            }
+            _ => {
+                return Err(
+                    CatalogError::NotFound("table or source", table_name.to_string()).into()
+                );
+            }
+        };
 
     // Convert all column descs to rows
     let mut rows = col_descs_to_rows(columns);
@@ -136,6 +177,16 @@ pub fn handle_describe(handler_args: HandlerArgs, table_name: ObjectName) -> Res
         ]));
     }
 
+    // Convert watermark definitions to rows
+    if !watermarks.is_empty() {
+        rows.push(Row::new(vec![
+            Some("watermark".into()),
+            Some(concat(watermarks.iter()).into()),
+            None, // Is Hidden
+            None, // Description
+        ]));
+    }
+
     // Convert all indexes to rows
     rows.extend(indices.iter().map(|index| {
         let index_display = index.display();
@@ -221,12 +272,15 @@ mod tests {
     async fn test_describe_handler() {
         let frontend = LocalFrontend::new(Default::default()).await;
         frontend
-            .run_sql("create table t (v1 int, v2 int, v3 int primary key, v4 int);")
+            .run_sql(
+                "create table t (v1 int, v2 int, v3 int primary key, v4 int, \
+                v5 int as v1 + 1, v6 int default 42);",
+            )
             .await
             .unwrap();
 
         frontend
-            .run_sql("create index idx1 on t (v1 DESC, v2);")
+            .run_sql("create index idx1 on t (v1 DESC, v2) include (v4);")
             .await
             .unwrap();
 
@@ -254,6 +308,8 @@ mod tests {
             "v2".into() => "integer".into(),
             "v3".into() => "integer".into(),
             "v4".into() => "integer".into(),
+            "v5".into() => "integer generated as (v1 + 1)".into(),
+            "v6".into() => "integer default 42".into(),
             "primary key".into() => "v3".into(),
             "distribution key".into() => "v3".into(),
             "idx1".into() => "index(v1 DESC, v2 ASC, v3 ASC) include(v4) distributed by(v1)".into(),
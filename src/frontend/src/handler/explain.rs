@@ -18,7 +18,7 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{ExplainOptions, ExplainType, Statement};
+use risingwave_sqlparser::ast::{ExplainFormat, ExplainOptions, ExplainType, Statement};
 
 use super::create_index::gen_create_index_plan;
 use super::create_mv::gen_create_mv_plan;
@@ -37,7 +37,7 @@ use crate::optimizer::OptimizerContext;
 use crate::scheduler::worker_node_manager::WorkerNodeSelector;
 use crate::scheduler::BatchPlanFragmenter;
 use crate::stream_fragmenter::build_graph;
-use crate::utils::explain_stream_graph;
+use crate::utils::{explain_stream_graph, explain_stream_graph_as_dot, explain_stream_graph_as_json};
 use crate::OptimizerContextRef;
 
 async fn do_handle_explain(
@@ -62,6 +62,7 @@ async fn do_handle_explain(
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 cdc_table_info,
                 ..
             } => {
@@ -86,6 +87,7 @@ async fn do_handle_explain(
                             source_watermarks,
                             ColumnIdGenerator::new_initial(),
                             append_only,
+                            on_conflict,
                         )
                         .await?
                         .0
@@ -99,6 +101,7 @@ async fn do_handle_explain(
                             ColumnIdGenerator::new_initial(),
                             source_watermarks,
                             append_only,
+                            on_conflict,
                         )?
                         .0
                     }
@@ -164,6 +167,7 @@ async fn do_handle_explain(
                         columns,
                         include,
                         distributed_by,
+                        predicate,
                         ..
                     } => gen_create_index_plan(
                         &session,
@@ -173,6 +177,7 @@ async fn do_handle_explain(
                         columns,
                         include,
                         distributed_by,
+                        predicate,
                     )
                     .map(|x| x.0),
 
@@ -200,10 +205,17 @@ async fn do_handle_explain(
         let explain_trace = context.is_explain_trace();
         let explain_verbose = context.is_explain_verbose();
         let explain_type = context.explain_type();
+        let explain_format = context.explain_format();
 
         if explain_trace {
-            let trace = context.take_trace();
-            blocks.extend(trace);
+            if explain_format == ExplainFormat::Json && explain_type != ExplainType::DistSql {
+                // Render the rule-by-rule trace as a JSON document instead of the flat text
+                // dump of intermediate plans.
+                blocks.push(context.take_rule_trace_as_json());
+            } else {
+                let trace = context.take_trace();
+                blocks.extend(trace);
+            }
         }
 
         match explain_type {
@@ -225,7 +237,13 @@ async fn do_handle_explain(
                         }
                         Convention::Stream => {
                             let graph = build_graph(plan.clone());
-                            blocks.push(explain_stream_graph(&graph, explain_verbose));
+                            blocks.push(match explain_format {
+                                ExplainFormat::Text => {
+                                    explain_stream_graph(&graph, explain_verbose)
+                                }
+                                ExplainFormat::Json => explain_stream_graph_as_json(&graph),
+                                ExplainFormat::Dot => explain_stream_graph_as_dot(&graph),
+                            });
                         }
                     }
                 }
@@ -270,6 +288,20 @@ pub async fn handle_explain(
         return Err(ErrorCode::NotImplemented("explain analyze".to_string(), 4856.into()).into());
     }
 
+    if options.explain_format != ExplainFormat::Text
+        && options.explain_type != ExplainType::DistSql
+        && !(options.explain_format == ExplainFormat::Json && options.trace)
+    {
+        return Err(ErrorCode::NotImplemented(
+            format!(
+                "EXPLAIN (FORMAT {}) is only supported with (DISTSQL) or (TRACE, FORMAT JSON)",
+                options.explain_format
+            ),
+            None.into(),
+        )
+        .into());
+    }
+
     let context = OptimizerContext::new(handler_args.clone(), options.clone());
 
     let mut blocks = Vec::new();
@@ -19,6 +19,7 @@ use risingwave_pb::user::PbGrantPrivilege;
 use risingwave_sqlparser::ast::{GrantObjects, Privileges, Statement};
 
 use super::RwPgResponse;
+use crate::bind_data_type;
 use crate::binder::Binder;
 use crate::catalog::root_catalog::SchemaPath;
 use crate::catalog::table_catalog::TableType;
@@ -120,6 +121,58 @@ fn make_prost_privilege(
                 grant_objs.push(PbObject::SourceId(source.id));
             }
         }
+        GrantObjects::Sinks(sinks) => {
+            let db_name = session.database();
+            let search_path = session.config().get_search_path();
+            let user_name = &session.auth_context().user_name;
+
+            for name in sinks {
+                let (schema_name, sink_name) =
+                    Binder::resolve_schema_qualified_name(db_name, name)?;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let (sink, _) = reader.get_sink_by_name(db_name, schema_path, &sink_name)?;
+                grant_objs.push(PbObject::SinkId(sink.id.sink_id));
+            }
+        }
+        GrantObjects::Functions(func_descs) => {
+            let db_name = session.database();
+            let search_path = session.config().get_search_path();
+            let user_name = &session.auth_context().user_name;
+
+            for func_desc in func_descs {
+                let (schema_name, function_name) =
+                    Binder::resolve_schema_qualified_name(db_name, func_desc.name)?;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let (function, _) = match func_desc.args {
+                    Some(args) => {
+                        let mut arg_types = vec![];
+                        for arg in args {
+                            arg_types.push(bind_data_type(&arg.data_type)?);
+                        }
+                        reader.get_function_by_name_args(
+                            db_name,
+                            schema_path,
+                            &function_name,
+                            &arg_types,
+                        )?
+                    }
+                    None => {
+                        let (functions, schema_name) =
+                            reader.get_functions_by_name(db_name, schema_path, &function_name)?;
+                        if functions.len() > 1 {
+                            return Err(ErrorCode::CatalogError(format!("function name {function_name:?} is not unique\nHINT: Specify the argument list to select the function unambiguously.").into()).into());
+                        }
+                        (
+                            functions.into_iter().next().expect("no functions"),
+                            schema_name,
+                        )
+                    }
+                };
+                grant_objs.push(PbObject::FunctionId(function.id.function_id()));
+            }
+        }
         GrantObjects::AllSourcesInSchema { schemas } => {
             for schema in schemas {
                 let schema_name = Binder::resolve_schema_name(schema)?;
@@ -141,6 +194,13 @@ fn make_prost_privilege(
                 grant_objs.push(PbObject::AllDmlTablesSchemaId(schema.id()));
             }
         }
+        GrantObjects::AllSinksInSchema { schemas } => {
+            for schema in schemas {
+                let schema_name = Binder::resolve_schema_name(schema)?;
+                let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
+                grant_objs.push(PbObject::AllSinksSchemaId(schema.id()));
+            }
+        }
         o => {
             return Err(ErrorCode::BindError(format!(
                 "GRANT statement does not support object type: {:?}",
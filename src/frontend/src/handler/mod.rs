@@ -18,6 +18,7 @@ use std::task::{Context, Poll};
 
 use futures::stream::{self, BoxStream};
 use futures::{Stream, StreamExt};
+use itertools::Itertools;
 use pgwire::pg_response::StatementType::{self, ABORT, BEGIN, COMMIT, ROLLBACK, START_TRANSACTION};
 use pgwire::pg_response::{PgResponse, PgResponseBuilder, RowSetResult};
 use pgwire::pg_server::BoxedError;
@@ -33,14 +34,18 @@ use crate::scheduler::{DistributedQueryStream, LocalQueryStream};
 use crate::session::SessionImpl;
 use crate::utils::WithOptions;
 
+mod alter_compact;
 mod alter_owner;
+mod alter_parallelism;
 mod alter_relation_rename;
+mod alter_sink_pause;
 mod alter_source_column;
 mod alter_system;
 mod alter_table_column;
 pub mod alter_user;
 pub mod cancel_job;
 mod comment;
+mod copy;
 pub mod create_connection;
 mod create_database;
 pub mod create_function;
@@ -169,6 +174,113 @@ impl HandlerArgs {
     }
 }
 
+/// Record the statement in the audit log, if its statement class is enabled by the
+/// `audit_log_level` session config.
+fn record_audit_log(handler_args: &HandlerArgs, stmt: &Statement) {
+    let audit_log_level = handler_args.session.config().get_audit_log_level();
+
+    let is_ddl = matches!(
+        stmt,
+        Statement::CreateView { .. }
+            | Statement::CreateTable { .. }
+            | Statement::CreateIndex { .. }
+            | Statement::CreateSource { .. }
+            | Statement::CreateSink { .. }
+            | Statement::CreateConnection { .. }
+            | Statement::CreateFunction { .. }
+            | Statement::CreateSchema { .. }
+            | Statement::CreateDatabase { .. }
+            | Statement::CreateUser(_)
+            | Statement::AlterDatabase { .. }
+            | Statement::AlterSchema { .. }
+            | Statement::AlterTable { .. }
+            | Statement::AlterIndex { .. }
+            | Statement::AlterView { .. }
+            | Statement::AlterSink { .. }
+            | Statement::AlterSource { .. }
+            | Statement::AlterUser(_)
+            | Statement::AlterSystem { .. }
+            | Statement::Drop(_)
+            | Statement::DropFunction { .. }
+            | Statement::Comment { .. }
+    );
+    let is_dml = matches!(
+        stmt,
+        Statement::Insert { .. } | Statement::Update { .. } | Statement::Delete { .. }
+    );
+
+    if (is_ddl && audit_log_level.log_ddl()) || (is_dml && audit_log_level.log_dml()) {
+        let session = &handler_args.session;
+        let statement_kind = if is_ddl { "ddl" } else { "dml" };
+        let object_name = audited_object_name(stmt);
+        tracing::info!(
+            target: "audit_log",
+            user = %session.user_name(),
+            user_id = session.user_id(),
+            session_id = session.session_id().0,
+            statement_kind,
+            object_name,
+            sql = %handler_args.normalized_sql,
+        );
+        session.env().audit_log_manager().record(
+            session.user_name().to_owned(),
+            session.user_id(),
+            session.session_id().0,
+            statement_kind,
+            object_name,
+            handler_args.normalized_sql.clone(),
+        );
+    }
+}
+
+/// Best-effort name of the object an audited statement operates on. `None` for statements
+/// without a single obvious target, e.g. `ALTER SYSTEM`.
+fn audited_object_name(stmt: &Statement) -> Option<String> {
+    let name = match stmt {
+        Statement::CreateView { name, .. }
+        | Statement::CreateTable { name, .. }
+        | Statement::CreateIndex { name, .. }
+        | Statement::CreateFunction { name, .. }
+        | Statement::AlterDatabase { name, .. }
+        | Statement::AlterSchema { name, .. }
+        | Statement::AlterTable { name, .. }
+        | Statement::AlterIndex { name, .. }
+        | Statement::AlterView { name, .. }
+        | Statement::AlterSink { name, .. }
+        | Statement::AlterSource { name, .. }
+        | Statement::Insert {
+            table_name: name, ..
+        }
+        | Statement::Update {
+            table_name: name, ..
+        }
+        | Statement::Delete {
+            table_name: name, ..
+        }
+        | Statement::Comment {
+            object_name: name, ..
+        } => name,
+        Statement::CreateSource { stmt } => &stmt.source_name,
+        Statement::CreateSink { stmt } => &stmt.sink_name,
+        Statement::CreateConnection { stmt } => &stmt.connection_name,
+        Statement::CreateSchema { schema_name, .. } => schema_name,
+        Statement::CreateDatabase { db_name, .. } => db_name,
+        Statement::CreateUser(stmt) => &stmt.user_name,
+        Statement::AlterUser(stmt) => &stmt.user_name,
+        Statement::Drop(stmt) => &stmt.object_name,
+        Statement::DropFunction { func_desc, .. } => {
+            return Some(
+                func_desc
+                    .iter()
+                    .map(|desc| desc.name.to_string())
+                    .join(", "),
+            )
+        }
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
 pub async fn handle(
     session: Arc<SessionImpl>,
     stmt: Statement,
@@ -178,6 +290,16 @@ pub async fn handle(
     session.clear_cancel_query_flag();
     let _guard = session.txn_begin_implicit();
     let handler_args = HandlerArgs::new(session, &stmt, sql)?;
+    record_audit_log(&handler_args, &stmt);
+
+    // In a DDL transaction, `CREATE` statements are buffered and applied at `COMMIT DDL`.
+    if let Some(response) = transaction::try_buffer_ddl(&handler_args, &stmt)? {
+        return Ok(response);
+    }
+    // In an explicit read-write transaction, DML statements are buffered and applied at `COMMIT`.
+    if let Some(response) = transaction::try_buffer_dml(&handler_args, &stmt)? {
+        return Ok(response);
+    }
 
     match stmt {
         Statement::Explain {
@@ -224,6 +346,7 @@ pub async fn handle(
             source_schema,
             source_watermarks,
             append_only,
+            on_conflict,
             cdc_table_info,
         } => {
             if or_replace {
@@ -241,6 +364,12 @@ pub async fn handle(
                 .into());
             }
             if let Some(query) = query {
+                if on_conflict.is_some() {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "ON CONFLICT is not supported for CREATE TABLE AS".to_string(),
+                    )
+                    .into());
+                }
                 return create_table_as::handle_create_as(
                     handler_args,
                     name,
@@ -267,6 +396,7 @@ pub async fn handle(
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 notice,
                 cdc_table_info,
             )
@@ -390,6 +520,16 @@ pub async fn handle(
         | Statement::Insert { .. }
         | Statement::Delete { .. }
         | Statement::Update { .. } => query::handle_query(handler_args, stmt, formats).await,
+        Statement::Copy {
+            table_name,
+            columns,
+            values,
+        } => copy::handle_copy(handler_args, table_name, columns, values).await,
+        Statement::CopyTo {
+            table_name,
+            columns,
+            target,
+        } => copy::handle_copy_to(handler_args, table_name, columns, target).await,
         Statement::CreateView {
             materialized,
             if_not_exists,
@@ -431,12 +571,14 @@ pub async fn handle(
         } => variable::handle_set(handler_args, variable, value),
         Statement::SetTimeZone { local: _, value } => handle_set_time_zone(handler_args, value),
         Statement::ShowVariable { variable } => variable::handle_show(handler_args, variable).await,
+        Statement::Discard => variable::handle_discard(handler_args),
         Statement::CreateIndex {
             name,
             table_name,
             columns,
             include,
             distributed_by,
+            predicate,
             unique,
             if_not_exists,
         } => {
@@ -454,6 +596,7 @@ pub async fn handle(
                 columns.to_vec(),
                 include,
                 distributed_by,
+                predicate,
             )
             .await
         }
@@ -532,6 +675,34 @@ pub async fn handle(
                 alter_relation_rename::handle_rename_view(handler_args, name, view_name).await
             }
         }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::SetParallelism { parallelism },
+        } => {
+            if materialized {
+                alter_parallelism::handle_alter_parallelism(handler_args, name, parallelism).await
+            } else {
+                Err(ErrorCode::InvalidInputSyntax(
+                    "SET PARALLELISM is only supported for materialized views".to_string(),
+                )
+                .into())
+            }
+        }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::Compact,
+        } => {
+            if materialized {
+                alter_compact::handle_alter_compact(handler_args, name).await
+            } else {
+                Err(ErrorCode::InvalidInputSyntax(
+                    "COMPACT is only supported for materialized views".to_string(),
+                )
+                .into())
+            }
+        }
         Statement::AlterView {
             materialized,
             name,
@@ -571,6 +742,13 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterSink {
+            name,
+            operation: operation @ (AlterSinkOperation::Pause | AlterSinkOperation::Resume),
+        } => {
+            let pause = matches!(operation, AlterSinkOperation::Pause);
+            alter_sink_pause::handle_alter_sink_pause(handler_args, name, pause).await
+        }
         Statement::AlterSource {
             name,
             operation: AlterSourceOperation::RenameSource { source_name },
@@ -598,6 +776,9 @@ pub async fn handle(
             transaction::handle_begin(handler_args, START_TRANSACTION, modes).await
         }
         Statement::Begin { modes } => transaction::handle_begin(handler_args, BEGIN, modes).await,
+        Statement::BeginDdl => transaction::handle_begin_ddl(handler_args).await,
+        Statement::CommitDdl => transaction::handle_commit_ddl(handler_args).await,
+        Statement::RollbackDdl => transaction::handle_rollback_ddl(handler_args).await,
         Statement::Commit { chain } => {
             transaction::handle_commit(handler_args, COMMIT, chain).await
         }
@@ -38,15 +38,16 @@ use crate::handler::util::{to_pg_field, DataChunkToRowSetAdapter};
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::Explain;
 use crate::optimizer::{
-    ExecutionModeDecider, OptimizerContext, OptimizerContextRef, RelationCollectorVisitor,
-    SysTableVisitor,
+    ExecutionModeDecider, FrontendOnlyVisitor, OptimizerContext, OptimizerContextRef,
+    RelationCollectorVisitor, SysTableVisitor,
 };
 use crate::planner::Planner;
 use crate::scheduler::plan_fragmenter::Query;
 use crate::scheduler::worker_node_manager::WorkerNodeSelector;
 use crate::scheduler::{
     BatchPlanFragmenter, DistributedQueryStream, ExecutionContext, ExecutionContextRef,
-    LocalQueryExecution, LocalQueryStream,
+    FastPathQueryExecution, LocalQueryExecution, LocalQueryStream, QueryResultCache,
+    QueryResultCacheKey,
 };
 use crate::session::SessionImpl;
 use crate::PlanRef;
@@ -253,15 +254,22 @@ fn determine_query_mode(batch_plan: PlanRef) -> QueryMode {
     }
 }
 
-struct BatchPlanFragmenterResult {
+pub(crate) struct BatchPlanFragmenterResult {
     pub(crate) plan_fragmenter: BatchPlanFragmenter,
     pub(crate) query_mode: QueryMode,
     pub(crate) schema: Schema,
     pub(crate) stmt_type: StatementType,
+    /// Set when the plan only reads frontend-local data and the session enables the fast
+    /// path. Such a plan is executed directly, bypassing `plan_fragmenter`.
+    pub(crate) fast_path_plan: Option<PlanRef>,
+    /// Textual form of the resolved plan, set when the session enables the result cache.
+    /// Unlike the raw SQL text, it identifies the query regardless of the database and
+    /// search path it was resolved under.
+    pub(crate) result_cache_plan: Option<String>,
     pub(crate) _dependent_relations: Vec<TableId>,
 }
 
-fn gen_batch_plan_fragmenter(
+pub(crate) fn gen_batch_plan_fragmenter(
     session: &SessionImpl,
     plan_result: BatchQueryPlanResult,
 ) -> Result<BatchPlanFragmenterResult> {
@@ -278,6 +286,19 @@ fn gen_batch_plan_fragmenter(
         plan.explain_to_string(),
         query_mode
     );
+
+    // Queries that only read data kept in the frontend itself, i.e. system catalog tables
+    // and constant values, can be executed right here without spawning batch tasks.
+    let fast_path_plan = (query_mode == QueryMode::Local
+        && stmt_type == StatementType::SELECT
+        && session.config().get_batch_enable_fast_path()
+        && FrontendOnlyVisitor::frontend_only(plan.clone()))
+    .then(|| plan.clone());
+
+    let result_cache_plan = (stmt_type == StatementType::SELECT
+        && session.config().get_enable_query_result_cache())
+    .then(|| plan.explain_to_string());
+
     let worker_node_manager_reader = WorkerNodeSelector::new(
         session.env().worker_node_manager_ref(),
         session.is_barrier_read(),
@@ -294,6 +315,8 @@ fn gen_batch_plan_fragmenter(
         query_mode,
         schema,
         stmt_type,
+        fast_path_plan,
+        result_cache_plan,
         _dependent_relations: dependent_relations,
     })
 }
@@ -308,6 +331,8 @@ async fn execute(
         query_mode,
         schema,
         stmt_type,
+        fast_path_plan,
+        result_cache_plan,
         ..
     } = plan_fragmenter_result;
 
@@ -325,8 +350,15 @@ async fn execute(
     }
 
     let query_start_time = Instant::now();
-    let query = plan_fragmenter.generate_complete_query().await?;
-    tracing::trace!("Generated query after plan fragmenter: {:?}", &query);
+    // Fast path queries are executed from the plan directly, without generating the
+    // complete fragmented query.
+    let query = if fast_path_plan.is_none() {
+        let query = plan_fragmenter.generate_complete_query().await?;
+        tracing::trace!("Generated query after plan fragmenter: {:?}", &query);
+        Some(query)
+    } else {
+        None
+    };
 
     let pg_descs = schema
         .fields()
@@ -335,13 +367,46 @@ async fn execute(
         .collect::<Vec<PgFieldDescriptor>>();
     let column_types = schema.fields().iter().map(|f| f.data_type()).collect_vec();
 
+    // The result cache is only applicable to read-only queries on a consistent committed
+    // snapshot with the default text format. Entries are keyed by the resolved plan, the
+    // committed epoch and the session context, so that a checkpoint advancing the epoch
+    // invalidates them and sessions never share entries they should not.
+    let result_cache_key = result_cache_plan
+        .filter(|_| {
+            !session.is_barrier_read()
+                && session.config().get_query_epoch().is_none()
+                && !formats.contains(&Format::Binary)
+        })
+        .map(|plan| QueryResultCacheKey {
+            plan,
+            epoch: session.pinned_snapshot().epoch().0,
+            database: session.database().to_string(),
+            search_path: session.config().get_search_path().to_string(),
+            user_id: session.auth_context().user_id,
+            timezone: session.config().get_timezone().to_owned(),
+        });
+
+    if let Some(key) = &result_cache_key {
+        if let Some(row_sets) = session.env().query_result_cache().get(key) {
+            let row_stream = PgResponseStream::Rows(
+                futures::stream::iter(row_sets.iter().cloned().map(Ok)).boxed(),
+            );
+            return Ok(PgResponse::builder(stmt_type)
+                .values(row_stream, pg_descs)
+                .into());
+        }
+    }
+
     // Used in counting row count.
     let first_field_format = formats.first().copied().unwrap_or(Format::Text);
 
     let mut row_stream = match query_mode {
         QueryMode::Auto => unreachable!(),
         QueryMode::Local => PgResponseStream::LocalQuery(DataChunkToRowSetAdapter::new(
-            local_execute(session.clone(), query).await?,
+            match fast_path_plan {
+                Some(plan) => fast_path_execute(session.clone(), plan)?,
+                None => local_execute(session.clone(), query.unwrap()).await?,
+            },
             column_types,
             formats,
             session.clone(),
@@ -349,7 +414,7 @@ async fn execute(
         // Local mode do not support cancel tasks.
         QueryMode::Distributed => {
             PgResponseStream::DistributedQuery(DataChunkToRowSetAdapter::new(
-                distribute_execute(session.clone(), query).await?,
+                distribute_execute(session.clone(), query.unwrap()).await?,
                 column_types,
                 formats,
                 session.clone(),
@@ -397,6 +462,37 @@ async fn execute(
         _ => unreachable!(),
     };
 
+    if let Some(key) = result_cache_key {
+        // Buffer the result up to the cache row limit so that it can be replayed on the next
+        // cache hit. Once the limit is exceeded, give up caching and fall back to streaming:
+        // the buffered prefix is chained with the rest of the untouched stream.
+        let mut row_sets = Vec::new();
+        let mut num_rows = 0;
+        while num_rows <= QueryResultCache::MAX_ROWS_PER_ENTRY {
+            let Some(row_set) = row_stream.next().await else {
+                break;
+            };
+            let row_set = row_set
+                .map_err(|err| RwError::from(ErrorCode::InternalError(format!("{}", err))))?;
+            num_rows += row_set.len();
+            row_sets.push(row_set);
+        }
+        if num_rows <= QueryResultCache::MAX_ROWS_PER_ENTRY {
+            session
+                .env()
+                .query_result_cache()
+                .insert(key, row_sets.clone());
+            row_stream =
+                PgResponseStream::Rows(futures::stream::iter(row_sets.into_iter().map(Ok)).boxed());
+        } else {
+            row_stream = PgResponseStream::Rows(
+                futures::stream::iter(row_sets.into_iter().map(Ok))
+                    .chain(row_stream)
+                    .boxed(),
+            );
+        }
+    }
+
     // We need to do some post work after the query is finished and before the `Complete` response
     // it sent. This is achieved by the `callback` in `PgResponse`.
     let callback = async move {
@@ -448,7 +544,7 @@ async fn execute(
         .into())
 }
 
-async fn distribute_execute(
+pub(crate) async fn distribute_execute(
     session: Arc<SessionImpl>,
     query: Query,
 ) -> Result<DistributedQueryStream> {
@@ -462,7 +558,10 @@ async fn distribute_execute(
 }
 
 #[expect(clippy::unused_async)]
-async fn local_execute(session: Arc<SessionImpl>, query: Query) -> Result<LocalQueryStream> {
+pub(crate) async fn local_execute(
+    session: Arc<SessionImpl>,
+    query: Query,
+) -> Result<LocalQueryStream> {
     let front_env = session.env();
 
     // TODO: if there's no table scan, we don't need to acquire snapshot.
@@ -473,3 +572,14 @@ async fn local_execute(session: Arc<SessionImpl>, query: Query) -> Result<LocalQ
 
     Ok(execution.stream_rows())
 }
+
+pub(crate) fn fast_path_execute(
+    session: Arc<SessionImpl>,
+    plan: PlanRef,
+) -> Result<LocalQueryStream> {
+    let front_env = session.env().clone();
+
+    let execution = FastPathQueryExecution::new(plan, front_env, session);
+
+    Ok(execution.stream_rows())
+}
@@ -28,8 +28,10 @@ use risingwave_connector::source::kafka::PRIVATELINK_CONNECTION;
 use risingwave_expr::scalar::like::{i_like_default, like_default};
 use risingwave_pb::catalog::connection;
 use risingwave_sqlparser::ast::{
-    Ident, ObjectName, ShowCreateType, ShowObject, ShowStatementFilter,
+    CompatibleSourceSchema, Ident, ObjectName, ShowCreateType, ShowObject, ShowStatementFilter,
+    SqlOption, Statement, Value,
 };
+use risingwave_sqlparser::parser::Parser;
 use serde_json;
 
 use super::RwPgResponse;
@@ -135,12 +137,23 @@ pub async fn handle_show_object(
             .filter(|t| t.associated_table_id.is_none())
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::Sink { schema } => catalog_reader
-            .read_guard()
-            .get_schema_by_name(session.database(), &schema_or_default(&schema))?
-            .iter_sink()
-            .map(|t| t.name.clone())
-            .collect(),
+        ShowObject::Sink { schema } => {
+            let rows = catalog_reader
+                .read_guard()
+                .get_schema_by_name(session.database(), &schema_or_default(&schema))?
+                .iter_sink()
+                .map(|t| {
+                    Row::new(vec![
+                        Some(t.name.clone().into()),
+                        Some(t.is_paused.to_string().into()),
+                    ])
+                })
+                .collect_vec();
+
+            return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
+                .values(rows.into(), row_desc)
+                .into());
+        }
         ShowObject::Columns { table } => {
             let columns = get_columns_from_table(&session, table)?;
             let rows = col_descs_to_rows(columns);
@@ -253,6 +266,24 @@ pub async fn handle_show_object(
                 .values(rows.into(), row_desc)
                 .into());
         }
+        ShowObject::FrontendNodes => {
+            // Expose all alive frontend nodes so that clients with multi-host connection
+            // strings can discover and balance over them.
+            let nodes = session.env().meta_client().list_frontend_nodes().await?;
+            let rows = nodes
+                .into_iter()
+                .map(|node| {
+                    let addr: HostAddr = node.host.as_ref().unwrap().into();
+                    Row::new(vec![
+                        Some(addr.to_string().into()),
+                        Some(node.get_state().unwrap().as_str_name().into()),
+                    ])
+                })
+                .collect_vec();
+            return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
+                .values(rows.into(), row_desc)
+                .into());
+        }
         ShowObject::Jobs => {
             let resp = session.env().meta_client().list_ddl_progress().await?;
             let rows = resp
@@ -320,6 +351,70 @@ pub async fn handle_show_object(
         .into())
 }
 
+/// Options in the `WITH` clause or the schema registry part whose values are sensitive and
+/// should be redacted in the output of `SHOW CREATE`.
+fn is_sensitive_option(name: &str) -> bool {
+    const SENSITIVE_WORDS: &[&str] = &[
+        "password",
+        "secret",
+        "token",
+        "credential",
+        "private.key",
+        "jwt",
+        "nkey",
+    ];
+    let name = name.to_lowercase();
+    SENSITIVE_WORDS.iter().any(|word| name.contains(word))
+}
+
+/// Redact the values of sensitive options (e.g. credentials) in the `WITH` clause and the
+/// schema registry part of the given DDL, so that the output of `SHOW CREATE` can be shared
+/// and re-executed without leaking secrets.
+///
+/// Returns `None` if the definition is not a statement with such options.
+fn redact_definition(definition: &str) -> Option<String> {
+    let mut stmt: Statement = {
+        let [stmt]: [_; 1] = Parser::parse_sql(definition).ok()?.try_into().ok()?;
+        stmt
+    };
+
+    fn redact_options(options: &mut [SqlOption]) {
+        for option in options {
+            if is_sensitive_option(&option.name.real_value()) {
+                option.value = Value::SingleQuotedString("[REDACTED]".to_string());
+            }
+        }
+    }
+
+    match &mut stmt {
+        Statement::CreateTable {
+            with_options,
+            source_schema,
+            ..
+        } => {
+            redact_options(with_options);
+            if let Some(CompatibleSourceSchema::V2(schema)) = source_schema {
+                redact_options(&mut schema.row_options);
+            }
+        }
+        Statement::CreateSource { stmt } => {
+            redact_options(&mut stmt.with_properties.0);
+            if let CompatibleSourceSchema::V2(schema) = &mut stmt.source_schema {
+                redact_options(&mut schema.row_options);
+            }
+        }
+        Statement::CreateSink { stmt } => {
+            redact_options(&mut stmt.with_properties.0);
+            if let Some(schema) = &mut stmt.sink_schema {
+                redact_options(&mut schema.row_options);
+            }
+        }
+        _ => return None,
+    }
+
+    Some(stmt.to_string())
+}
+
 pub fn handle_show_create_object(
     handle_args: HandlerArgs,
     show_create_type: ShowCreateType,
@@ -380,6 +475,8 @@ pub fn handle_show_create_object(
             .into());
         }
     };
+    // Tables, sources and sinks may contain credentials in their `WITH` options.
+    let sql = redact_definition(&sql).unwrap_or(sql);
     let name = format!("{}.{}", schema_name, object_name);
 
     Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
@@ -410,6 +507,24 @@ mod tests {
 
     use crate::test_utils::{create_proto_file, LocalFrontend, PROTO_FILE_DATA};
 
+    #[test]
+    fn test_redact_definition() {
+        let sql = super::redact_definition(
+            "CREATE SOURCE s (v int) \
+             WITH (connector = 'kafka', properties.sasl.password = 'sensitive') \
+             FORMAT PLAIN ENCODE JSON",
+        )
+        .unwrap();
+        assert!(!sql.contains("sensitive"));
+        assert!(sql.contains("[REDACTED]"));
+
+        // Statements without options are left as-is.
+        assert_eq!(
+            super::redact_definition("CREATE VIEW v AS SELECT 1"),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn test_show_source() {
         let frontend = LocalFrontend::new(Default::default()).await;
@@ -14,7 +14,9 @@
 
 use pgwire::pg_response::StatementType;
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_sqlparser::ast::{TransactionAccessMode, TransactionMode, Value};
+use risingwave_sqlparser::ast::{
+    AstOption, DropStatement, ObjectType, Statement, TransactionAccessMode, TransactionMode, Value,
+};
 
 use super::{HandlerArgs, RwPgResponse};
 use crate::session::transaction::AccessMode;
@@ -47,15 +49,19 @@ pub async fn handle_begin(
         match access_mode {
             Some(TransactionAccessMode::ReadOnly) => AccessMode::ReadOnly,
             Some(TransactionAccessMode::ReadWrite) | None => {
-                // Note: This is for compatibility with some external drivers (like psycopg2) that
-                // issue `BEGIN` implicitly for users. Not actually starting a transaction is okay
-                // since `COMMIT` and `ROLLBACK` are no-ops (except for warnings) when there is no
-                // active transaction.
-                const MESSAGE: &str = "\
-                    Read-write transaction is not supported yet. Please specify `READ ONLY` to start a read-only transaction.\n\
-                    For compatibility, this statement will still succeed but no transaction is actually started.";
-
-                return Ok(RwPgResponse::builder(stmt_type).notice(MESSAGE).into());
+                if session.config().get_enable_dml_transaction() {
+                    AccessMode::ReadWrite
+                } else {
+                    // Note: This is for compatibility with some external drivers (like psycopg2)
+                    // that issue `BEGIN` implicitly for users. Not actually starting a transaction
+                    // is okay since `COMMIT` and `ROLLBACK` are no-ops (except for warnings) when
+                    // there is no active transaction.
+                    const MESSAGE: &str = "\
+                        Read-write transaction is not supported yet. Please specify `READ ONLY` to start a read-only transaction.\n\
+                        For compatibility, this statement will still succeed but no transaction is actually started.";
+
+                    return Ok(RwPgResponse::builder(stmt_type).notice(MESSAGE).into());
+                }
             }
         }
     };
@@ -77,6 +83,23 @@ pub async fn handle_commit(
         not_impl!("COMMIT AND CHAIN")?;
     }
 
+    // If there's an explicit read-write transaction in progress, apply the buffered DML
+    // statements back-to-back, so that their changes become visible together (best-effort).
+    if let Some(statements) = session.txn_end_dml() {
+        for stmt in statements {
+            let sql: std::sync::Arc<str> = stmt.to_string().into();
+            if let Err(e) = Box::pin(super::handle(session.clone(), stmt.clone(), sql, vec![])).await
+            {
+                return Err(ErrorCode::InternalError(format!(
+                    "failed to apply DML transaction at statement `{}`, the remaining statements are discarded: {}",
+                    stmt, e
+                ))
+                .into());
+            }
+        }
+        return Ok(RwPgResponse::empty_result(stmt_type));
+    }
+
     session.txn_commit_explicit();
 
     Ok(RwPgResponse::empty_result(stmt_type))
@@ -94,11 +117,164 @@ pub async fn handle_rollback(
         not_impl!("ROLLBACK AND CHAIN")?;
     }
 
+    // Discard the DML statements buffered in the explicit read-write transaction, if any.
+    if session.txn_end_dml().is_some() {
+        return Ok(RwPgResponse::empty_result(stmt_type));
+    }
+
     session.txn_rollback_explicit();
 
     Ok(RwPgResponse::empty_result(stmt_type))
 }
 
+#[expect(clippy::unused_async)]
+pub async fn handle_begin_ddl(handler_args: HandlerArgs) -> Result<RwPgResponse> {
+    let HandlerArgs { session, .. } = handler_args;
+    session.txn_begin_explicit(AccessMode::DdlOnly);
+    Ok(RwPgResponse::empty_result(StatementType::BEGIN))
+}
+
+/// Buffers the DML statement if there's an explicit read-write transaction in progress,
+/// returning an early response. Returns `None` if the statement should be handled as usual.
+pub fn try_buffer_dml(
+    handler_args: &HandlerArgs,
+    stmt: &Statement,
+) -> Result<Option<RwPgResponse>> {
+    let returning = match stmt {
+        Statement::Insert { returning, .. }
+        | Statement::Update { returning, .. }
+        | Statement::Delete { returning, .. } => returning,
+        _ => return Ok(None),
+    };
+    if !handler_args.session.txn_buffer_dml_statement(stmt) {
+        return Ok(None);
+    }
+    if !returning.is_empty() {
+        // The statement has been buffered, but we cannot return rows at `COMMIT` time.
+        let _discarded = handler_args.session.txn_end_dml();
+        return Err(ErrorCode::NotImplemented(
+            "`RETURNING` in a DML transaction".into(),
+            None.into(),
+        )
+        .into());
+    }
+
+    let stmt_type = StatementType::infer_from_statement(stmt)
+        .map_err(ErrorCode::InvalidInputSyntax)?;
+    Ok(Some(
+        RwPgResponse::builder(stmt_type)
+            .notice("buffered in the transaction, to be applied at `COMMIT`")
+            .into(),
+    ))
+}
+
+/// Whether the statement may be buffered in a DDL transaction and applied at `COMMIT DDL`.
+pub fn is_bufferable_ddl(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::CreateTable { .. }
+            | Statement::CreateView { .. }
+            | Statement::CreateSource { .. }
+            | Statement::CreateSink { .. }
+            | Statement::CreateIndex { .. }
+            | Statement::CreateSchema { .. }
+            | Statement::CreateConnection { .. }
+    )
+}
+
+/// Buffers the statement if there's a DDL transaction in progress, returning an early response.
+/// Returns `None` if the statement should be handled as usual.
+pub fn try_buffer_ddl(
+    handler_args: &HandlerArgs,
+    stmt: &Statement,
+) -> Result<Option<RwPgResponse>> {
+    if !is_bufferable_ddl(stmt) {
+        return Ok(None);
+    }
+    if !handler_args.session.txn_buffer_ddl_statement(stmt) {
+        return Ok(None);
+    }
+
+    let stmt_type = StatementType::infer_from_statement(stmt)
+        .map_err(ErrorCode::InvalidInputSyntax)?;
+    Ok(Some(
+        RwPgResponse::builder(stmt_type)
+            .notice("buffered in the DDL transaction, to be applied at `COMMIT DDL`")
+            .into(),
+    ))
+}
+
+/// Returns the `DROP` statement that best-effort reverts the given `CREATE` statement, used to
+/// clean up partially applied DDL transactions.
+fn revert_ddl(stmt: &Statement) -> Option<Statement> {
+    let (object_type, object_name) = match stmt {
+        Statement::CreateTable { name, .. } => (ObjectType::Table, name.clone()),
+        Statement::CreateView {
+            materialized, name, ..
+        } => (
+            if *materialized {
+                ObjectType::MaterializedView
+            } else {
+                ObjectType::View
+            },
+            name.clone(),
+        ),
+        Statement::CreateSource { stmt } => (ObjectType::Source, stmt.source_name.clone()),
+        Statement::CreateSink { stmt } => (ObjectType::Sink, stmt.sink_name.clone()),
+        Statement::CreateIndex { name, .. } => (ObjectType::Index, name.clone()),
+        Statement::CreateSchema { schema_name, .. } => (ObjectType::Schema, schema_name.clone()),
+        Statement::CreateConnection { stmt } => {
+            (ObjectType::Connection, stmt.connection_name.clone())
+        }
+        _ => return None,
+    };
+    Some(Statement::Drop(DropStatement {
+        object_type,
+        if_exists: true,
+        object_name,
+        drop_mode: AstOption::None,
+    }))
+}
+
+pub async fn handle_commit_ddl(handler_args: HandlerArgs) -> Result<RwPgResponse> {
+    let HandlerArgs { session, .. } = handler_args;
+
+    let statements = session.txn_end_ddl()?;
+    for (i, stmt) in statements.iter().enumerate() {
+        let sql: std::sync::Arc<str> = stmt.to_string().into();
+        if let Err(e) =
+            Box::pin(super::handle(session.clone(), stmt.clone(), sql, vec![])).await
+        {
+            // Best-effort revert the statements that have been applied, in reverse order.
+            for applied in statements[..i].iter().rev() {
+                let Some(revert) = revert_ddl(applied) else {
+                    continue;
+                };
+                let sql: std::sync::Arc<str> = revert.to_string().into();
+                if let Err(e) =
+                    Box::pin(super::handle(session.clone(), revert, sql, vec![])).await
+                {
+                    tracing::warn!("failed to revert DDL statement when rolling back: {}", e);
+                }
+            }
+            return Err(ErrorCode::InternalError(format!(
+                "failed to apply DDL transaction at statement `{}`, all statements are reverted: {}",
+                stmt, e
+            ))
+            .into());
+        }
+    }
+
+    Ok(RwPgResponse::empty_result(StatementType::COMMIT))
+}
+
+#[expect(clippy::unused_async)]
+pub async fn handle_rollback_ddl(handler_args: HandlerArgs) -> Result<RwPgResponse> {
+    let HandlerArgs { session, .. } = handler_args;
+    let _discarded = session.txn_end_ddl()?;
+    Ok(RwPgResponse::empty_result(StatementType::ROLLBACK))
+}
+
 #[expect(clippy::unused_async)]
 pub async fn handle_set(
     _handler_args: HandlerArgs,
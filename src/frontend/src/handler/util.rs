@@ -26,15 +26,19 @@ use pgwire::pg_server::BoxedError;
 use pgwire::types::{Format, FormatIterator, Row};
 use pin_project_lite::pin_project;
 use risingwave_common::array::DataChunk;
-use risingwave_common::catalog::{ColumnCatalog, Field};
+use risingwave_common::catalog::{ColumnCatalog, Field, Schema};
 use risingwave_common::error::{ErrorCode, Result as RwResult};
 use risingwave_common::row::Row as _;
 use risingwave_common::types::{DataType, ScalarRefImpl, Timestamptz};
 use risingwave_common::util::iter_util::ZipEqFast;
-use risingwave_connector::source::{KAFKA_CONNECTOR, KINESIS_CONNECTOR, PULSAR_CONNECTOR};
+use risingwave_connector::source::{
+    ICEBERG_CONNECTOR, KAFKA_CONNECTOR, KINESIS_CONNECTOR, PULSAR_CONNECTOR,
+};
+use risingwave_pb::plan_common::column_desc::GeneratedOrDefaultColumn;
 use risingwave_sqlparser::ast::display_comma_separated;
 
 use crate::catalog::IndexCatalog;
+use crate::expr::{ExprDisplay, ExprImpl};
 use crate::handler::create_source::{CONNECTION_NAME_KEY, UPSTREAM_SOURCE_KEY};
 use crate::session::SessionImpl;
 
@@ -171,18 +175,55 @@ fn to_pg_rows(
 
 /// Convert column descs to rows which conclude name and type
 pub fn col_descs_to_rows(columns: Vec<ColumnCatalog>) -> Vec<Row> {
+    let input_schema = Schema::new(
+        columns
+            .iter()
+            .map(|col| Field::from(&col.column_desc))
+            .collect(),
+    );
     columns
         .iter()
         .flat_map(|col| {
+            // Show the expression of a generated or default column along with its type.
+            let expr_suffix = match col.column_desc.generated_or_default_column.as_ref() {
+                Some(GeneratedOrDefaultColumn::GeneratedColumn(desc)) => {
+                    let expr = ExprImpl::from_expr_proto(desc.expr.as_ref().unwrap())
+                        .expect("expr in generated columns corrupted");
+                    format!(
+                        " generated as {}",
+                        ExprDisplay {
+                            expr: &expr,
+                            input_schema: &input_schema
+                        }
+                    )
+                }
+                Some(GeneratedOrDefaultColumn::DefaultColumn(desc)) => {
+                    let expr = ExprImpl::from_expr_proto(desc.expr.as_ref().unwrap())
+                        .expect("expr in default columns corrupted");
+                    format!(
+                        " default {}",
+                        ExprDisplay {
+                            expr: &expr,
+                            input_schema: &input_schema
+                        }
+                    )
+                }
+                None => String::new(),
+            };
             col.column_desc
                 .flatten()
                 .into_iter()
                 .map(|c| {
-                    let type_name = if let DataType::Struct { .. } = c.data_type {
+                    let mut type_name = if let DataType::Struct { .. } = c.data_type {
                         c.type_name.clone()
                     } else {
                         c.data_type.to_string()
                     };
+                    // The expression only applies to the column itself, not to the flattened
+                    // fields of a struct column.
+                    if c.name == col.column_desc.name {
+                        type_name.push_str(&expr_suffix);
+                    }
                     Row::new(vec![
                         Some(c.name.into()),
                         Some(type_name.into()),
@@ -254,6 +295,15 @@ pub fn is_kafka_connector(with_properties: &HashMap<String, String>) -> bool {
     connector == KAFKA_CONNECTOR
 }
 
+#[inline(always)]
+pub fn is_iceberg_connector(with_properties: &HashMap<String, String>) -> bool {
+    let Some(connector) = get_connector(with_properties) else {
+        return false;
+    };
+
+    connector == ICEBERG_CONNECTOR
+}
+
 #[inline(always)]
 pub fn is_key_mq_connector(with_properties: &HashMap<String, String>) -> bool {
     let Some(connector) = get_connector(with_properties) else {
@@ -26,6 +26,22 @@ use super::RwPgResponse;
 use crate::handler::HandlerArgs;
 use crate::utils::infer_stmt_row_desc::infer_show_variable;
 
+/// Reports changes of session parameters that clients keep track of
+/// (via the `ParameterStatus` protocol message).
+struct Reporter<'a> {
+    status: &'a mut ParameterStatus,
+}
+
+impl<'a> ConfigReporter for Reporter<'a> {
+    fn report_status(&mut self, key: &str, new_val: String) {
+        if key == "APPLICATION_NAME" {
+            self.status.application_name = Some(new_val);
+        } else if key == "TIMEZONE" {
+            self.status.timezone = Some(new_val);
+        }
+    }
+}
+
 pub fn handle_set(
     handler_args: HandlerArgs,
     name: Ident,
@@ -43,18 +59,6 @@ pub fn handle_set(
 
     let mut status = ParameterStatus::default();
 
-    struct Reporter<'a> {
-        status: &'a mut ParameterStatus,
-    }
-
-    impl<'a> ConfigReporter for Reporter<'a> {
-        fn report_status(&mut self, key: &str, new_val: String) {
-            if key == "APPLICATION_NAME" {
-                self.status.application_name = Some(new_val);
-            }
-        }
-    }
-
     // Currently store the config variable simply as String -> ConfigEntry(String).
     // In future we can add converter/parser to make the API more robust.
     // We remark that the name of session parameter is always case-insensitive.
@@ -71,6 +75,21 @@ pub fn handle_set(
         .into())
 }
 
+/// Handle `DISCARD ALL` by resetting the session configurations to their defaults, so that
+/// transaction-pooled clients (e.g. behind PgBouncer) can reuse the connection without
+/// observing the previous client's state. Prepared statements and portals are additionally
+/// dropped by the pgwire layer.
+pub(super) fn handle_discard(handler_args: HandlerArgs) -> Result<RwPgResponse> {
+    let mut status = ParameterStatus::default();
+    handler_args.session.reset_config_report(Reporter {
+        status: &mut status,
+    });
+
+    Ok(PgResponse::builder(StatementType::DISCARD)
+        .status(status)
+        .into())
+}
+
 pub(super) fn handle_set_time_zone(
     handler_args: HandlerArgs,
     value: SetTimeZoneValue,
@@ -85,9 +104,18 @@ pub(super) fn handle_set_time_zone(
         _ => Ok(value.to_string()),
     }?;
 
-    handler_args.session.set_config("timezone", vec![tz_info])?;
+    let mut status = ParameterStatus::default();
+    handler_args.session.set_config_report(
+        "timezone",
+        vec![tz_info],
+        Reporter {
+            status: &mut status,
+        },
+    )?;
 
-    Ok(PgResponse::empty_result(StatementType::SET_VARIABLE))
+    Ok(PgResponse::builder(StatementType::SET_VARIABLE)
+        .status(status)
+        .into())
 }
 
 pub(super) async fn handle_show(
@@ -103,7 +131,13 @@ pub(super) async fn handle_show(
         handle_show_all(handler_args.clone())?
     } else {
         let config_reader = handler_args.session.config();
-        vec![Row::new(vec![Some(config_reader.get(&name)?.into())])]
+        // `SHOW TIME ZONE` is an alias for `SHOW timezone`, for compatibility with PostgreSQL.
+        let name = if name.eq_ignore_ascii_case("TIME ZONE") {
+            "timezone"
+        } else {
+            name.as_str()
+        };
+        vec![Row::new(vec![Some(config_reader.get(name)?.into())])]
     };
 
     Ok(PgResponse::builder(StatementType::SHOW_VARIABLE)
@@ -39,6 +39,7 @@
 #[cfg(test)]
 risingwave_expr_impl::enable!();
 
+mod audit_log;
 #[macro_use]
 mod catalog;
 pub use catalog::TableCatalog;
@@ -137,6 +138,12 @@ pub struct FrontendOpts {
     #[clap(long, env = "RW_ENABLE_BARRIER_READ")]
     #[override_opts(path = batch.enable_barrier_read)]
     pub enable_barrier_read: Option<bool>,
+
+    /// The maximum number of client connections served at the same time. Further connection
+    /// attempts are queued until an existing connection is closed, instead of being rejected.
+    /// Unlimited if not specified.
+    #[clap(long, env = "RW_MAX_CLIENT_CONNECTIONS")]
+    pub max_client_connections: Option<usize>,
 }
 
 impl Default for FrontendOpts {
@@ -156,9 +163,15 @@ pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
     // slow compile in release mode.
     Box::pin(async move {
         let listen_addr = opts.listen_addr.clone();
+        let max_client_connections = opts.max_client_connections;
         let session_mgr = Arc::new(SessionManagerImpl::new(opts).await.unwrap());
-        pg_serve(&listen_addr, session_mgr, Some(TlsConfig::new_default()))
-            .await
-            .unwrap();
+        pg_serve(
+            &listen_addr,
+            session_mgr,
+            Some(TlsConfig::new_default()),
+            max_client_connections,
+        )
+        .await
+        .unwrap();
     })
 }
@@ -17,17 +17,22 @@ use std::collections::HashMap;
 use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_common::util::epoch::MAX_EPOCH;
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
+use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::catalog::Table;
 use risingwave_pb::ddl_service::DdlProgress;
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
-    BranchedObject, CompactionGroupInfo, HummockSnapshot, HummockVersion, HummockVersionDelta,
+    BranchedObject, CompactStatus, CompactTaskProgress, CompactionGroupInfo, HummockSnapshot,
+    HummockVersion, HummockVersionDelta,
 };
 use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
+use risingwave_pb::meta::list_actor_splits_response::ActorSplit;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
+use risingwave_pb::meta::list_actor_traces_response::ActorTrace;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
+use risingwave_pb::meta::EventLog;
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{HummockMetaClient, MetaClient};
 
@@ -59,6 +64,16 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn list_actor_states(&self) -> Result<Vec<ActorState>>;
 
+    async fn list_actor_splits(&self) -> Result<Vec<ActorSplit>>;
+
+    async fn list_actor_traces(&self) -> Result<Vec<ActorTrace>>;
+
+    async fn alter_parallelism(&self, table_id: u32, parallelism: u32) -> Result<()>;
+
+    async fn alter_sink_pause(&self, sink_id: u32, pause: bool) -> Result<()>;
+
+    async fn list_frontend_nodes(&self) -> Result<Vec<WorkerNode>>;
+
     async fn unpin_snapshot(&self) -> Result<()>;
 
     async fn unpin_snapshot_before(&self, epoch: u64) -> Result<()>;
@@ -96,6 +111,18 @@ pub trait FrontendMetaClient: Send + Sync {
     async fn list_hummock_active_write_limits(&self) -> Result<HashMap<u64, WriteLimit>>;
 
     async fn list_hummock_meta_configs(&self) -> Result<HashMap<String, String>>;
+
+    async fn list_compact_task_progress(&self) -> Result<Vec<CompactTaskProgress>>;
+
+    async fn list_hummock_compaction_statuses(&self) -> Result<Vec<CompactStatus>>;
+
+    async fn trigger_manual_compaction(
+        &self,
+        compaction_group_id: u64,
+        table_id: u32,
+    ) -> Result<()>;
+
+    async fn list_event_log(&self) -> Result<Vec<EventLog>>;
 }
 
 pub struct FrontendMetaClientImpl(pub MetaClient);
@@ -141,6 +168,26 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.list_actor_states().await
     }
 
+    async fn list_actor_splits(&self) -> Result<Vec<ActorSplit>> {
+        self.0.list_actor_splits().await
+    }
+
+    async fn list_actor_traces(&self) -> Result<Vec<ActorTrace>> {
+        self.0.list_actor_traces().await
+    }
+
+    async fn alter_parallelism(&self, table_id: u32, parallelism: u32) -> Result<()> {
+        self.0.alter_parallelism(table_id, parallelism).await
+    }
+
+    async fn alter_sink_pause(&self, sink_id: u32, pause: bool) -> Result<()> {
+        self.0.alter_sink_pause(sink_id, pause).await
+    }
+
+    async fn list_frontend_nodes(&self) -> Result<Vec<WorkerNode>> {
+        self.0.list_worker_nodes(WorkerType::Frontend).await
+    }
+
     async fn unpin_snapshot(&self) -> Result<()> {
         self.0.unpin_snapshot().await
     }
@@ -240,4 +287,33 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
     async fn list_hummock_meta_configs(&self) -> Result<HashMap<String, String>> {
         self.0.list_hummock_meta_config().await
     }
+
+    async fn list_compact_task_progress(&self) -> Result<Vec<CompactTaskProgress>> {
+        self.0
+            .risectl_list_compaction_status()
+            .await
+            .map(|(_, _, progress)| progress)
+    }
+
+    async fn list_hummock_compaction_statuses(&self) -> Result<Vec<CompactStatus>> {
+        self.0
+            .risectl_list_compaction_status()
+            .await
+            .map(|(status, _, _)| status)
+    }
+
+    async fn trigger_manual_compaction(
+        &self,
+        compaction_group_id: u64,
+        table_id: u32,
+    ) -> Result<()> {
+        // Compact all levels of the group, filtered by the state tables of the job.
+        self.0
+            .trigger_manual_compaction(compaction_group_id, table_id, 0, vec![])
+            .await
+    }
+
+    async fn list_event_log(&self) -> Result<Vec<EventLog>> {
+        self.0.list_event_log().await
+    }
 }
@@ -96,6 +96,9 @@ impl ObserverState for FrontendObserverNode {
             Info::ServingParallelUnitMappings(m) => {
                 self.handle_fragment_serving_mapping_notification(m.mappings, resp.operation());
             }
+            Info::Heartbeat(_) => {
+                unreachable!("heartbeats should be consumed by the observer manager");
+            }
         }
     }
 
@@ -15,13 +15,13 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::fmt;
+use std::time::{Duration, Instant};
 
 use itertools::Itertools;
 
 use crate::optimizer::plan_node::PlanTreeNode;
 use crate::optimizer::rule::BoxedRule;
 use crate::optimizer::PlanRef;
-#[cfg(debug_assertions)]
 use crate::Explain;
 
 /// Traverse order of [`HeuristicOptimizer`]
@@ -37,25 +37,46 @@ pub struct HeuristicOptimizer<'a> {
     apply_order: &'a ApplyOrder,
     rules: &'a [BoxedRule],
     stats: Stats,
+    /// Whether to record each rule application in `applications`, for `EXPLAIN (TRACE)`.
+    trace: bool,
+    applications: Vec<AppliedRule>,
+}
+
+/// One rule application recorded by the [`HeuristicOptimizer`] when tracing is enabled.
+#[derive(Debug, Clone)]
+pub struct AppliedRule {
+    pub rule: String,
+    pub elapsed: Duration,
+    pub plan_after: String,
 }
 
 impl<'a> HeuristicOptimizer<'a> {
-    pub fn new(apply_order: &'a ApplyOrder, rules: &'a [BoxedRule]) -> Self {
+    pub fn new(apply_order: &'a ApplyOrder, rules: &'a [BoxedRule], trace: bool) -> Self {
         Self {
             apply_order,
             rules,
             stats: Stats::new(),
+            trace,
+            applications: vec![],
         }
     }
 
     fn optimize_node(&mut self, mut plan: PlanRef) -> PlanRef {
         for rule in self.rules {
+            let started_at = self.trace.then(Instant::now);
             if let Some(applied) = rule.apply(plan.clone()) {
                 #[cfg(debug_assertions)]
                 Self::check_equivalent_plan(rule.description(), &plan, &applied);
 
                 plan = applied;
                 self.stats.count_rule(rule);
+                if let Some(started_at) = started_at {
+                    self.applications.push(AppliedRule {
+                        rule: rule.description().to_string(),
+                        elapsed: started_at.elapsed(),
+                        plan_after: plan.explain_to_string(),
+                    });
+                }
             }
         }
         plan
@@ -87,6 +108,11 @@ impl<'a> HeuristicOptimizer<'a> {
         &self.stats
     }
 
+    /// Take the rule applications recorded so far, in the order they were applied.
+    pub fn take_applications(&mut self) -> Vec<AppliedRule> {
+        std::mem::take(&mut self.applications)
+    }
+
     #[cfg(debug_assertions)]
     pub fn check_equivalent_plan(rule_desc: &str, input_plan: &PlanRef, output_plan: &PlanRef) {
         if !input_plan.schema().type_eq(output_plan.schema()) {
@@ -36,16 +36,16 @@ impl PlanRef {
             apply_order,
         } = stage;
 
-        let mut heuristic_optimizer = HeuristicOptimizer::new(apply_order, rules);
+        let explain_trace = self.ctx().is_explain_trace();
+        let mut heuristic_optimizer = HeuristicOptimizer::new(apply_order, rules, explain_trace);
         let plan = heuristic_optimizer.optimize(self);
-        let stats = heuristic_optimizer.get_stats();
 
         let ctx = plan.ctx();
-        let explain_trace = ctx.is_explain_trace();
-        if explain_trace && stats.has_applied_rule() {
+        if explain_trace && heuristic_optimizer.get_stats().has_applied_rule() {
             ctx.trace(format!("{}:", stage_name));
-            ctx.trace(format!("{}", stats));
+            ctx.trace(format!("{}", heuristic_optimizer.get_stats()));
             ctx.trace(plan.explain_to_string());
+            ctx.record_applied_rules(stage_name, heuristic_optimizer.take_applications());
         }
 
         plan
@@ -60,19 +60,19 @@ impl PlanRef {
 
         let mut output_plan = self;
         loop {
-            let mut heuristic_optimizer = HeuristicOptimizer::new(apply_order, rules);
+            let explain_trace = output_plan.ctx().is_explain_trace();
+            let mut heuristic_optimizer = HeuristicOptimizer::new(apply_order, rules, explain_trace);
             output_plan = heuristic_optimizer.optimize(output_plan);
-            let stats = heuristic_optimizer.get_stats();
 
             let ctx = output_plan.ctx();
-            let explain_trace = ctx.is_explain_trace();
-            if explain_trace && stats.has_applied_rule() {
+            if explain_trace && heuristic_optimizer.get_stats().has_applied_rule() {
                 ctx.trace(format!("{}:", stage_name));
-                ctx.trace(format!("{}", stats));
+                ctx.trace(format!("{}", heuristic_optimizer.get_stats()));
                 ctx.trace(output_plan.explain_to_string());
+                ctx.record_applied_rules(stage_name, heuristic_optimizer.take_applications());
             }
 
-            if !stats.has_applied_rule() {
+            if !heuristic_optimizer.get_stats().has_applied_rule() {
                 return output_plan;
             }
         }
@@ -221,6 +221,14 @@ static LEFT_DEEP_JOIN_ORDERING: LazyLock<OptimizationStage> = LazyLock::new(|| {
     )
 });
 
+static STATS_JOIN_ORDERING: LazyLock<OptimizationStage> = LazyLock::new(|| {
+    OptimizationStage::new(
+        "Join Ordering (Statistics)".to_string(),
+        vec![StatsJoinOrderingRule::create()],
+        ApplyOrder::TopDown,
+    )
+});
+
 static BUSHY_TREE_JOIN_ORDERING: LazyLock<OptimizationStage> = LazyLock::new(|| {
     OptimizationStage::new(
         "Join Ordering".to_string(),
@@ -229,6 +237,14 @@ static BUSHY_TREE_JOIN_ORDERING: LazyLock<OptimizationStage> = LazyLock::new(||
     )
 });
 
+static GENERATE_SERIES_WITH_NOW: LazyLock<OptimizationStage> = LazyLock::new(|| {
+    OptimizationStage::new(
+        "Convert GENERATE_SERIES Ending With NOW",
+        vec![GenerateSeriesWithNowRule::create()],
+        ApplyOrder::TopDown,
+    )
+});
+
 static FILTER_WITH_NOW_TO_JOIN: LazyLock<OptimizationStage> = LazyLock::new(|| {
     OptimizationStage::new(
         "Push down filter with now into a left semijoin",
@@ -535,6 +551,10 @@ impl LogicalOptimizer {
         }
         plan = plan.optimize_by_rules(&SET_OPERATION_MERGE);
         plan = plan.optimize_by_rules(&SET_OPERATION_TO_JOIN);
+        // Convert `generate_series` ending with `now()` to a `Now` source. Only for streaming
+        // mode. This must be done before `subquery_unnesting`, where table functions are
+        // generally converted to `project_set`.
+        plan = plan.optimize_by_rules(&GENERATE_SERIES_WITH_NOW);
 
         plan = Self::subquery_unnesting(plan, enable_share_plan, explain_trace, &ctx)?;
 
@@ -640,7 +660,10 @@ impl LogicalOptimizer {
             // their relevant joins.
             plan = plan.optimize_by_rules(&TO_MULTI_JOIN);
 
-            // Reorder multijoin into left-deep join tree.
+            // Reorder multijoin into left-deep join tree, preferring the ordering derived
+            // from the table statistics reported by storage and falling back to the
+            // heuristic ordering when no statistics are available.
+            plan = plan.optimize_by_rules(&STATS_JOIN_ORDERING);
             plan = plan.optimize_by_rules(&LEFT_DEEP_JOIN_ORDERING);
         }
 
@@ -26,7 +26,8 @@ mod plan_rewriter;
 pub use plan_rewriter::PlanRewriter;
 mod plan_visitor;
 pub use plan_visitor::{
-    ExecutionModeDecider, PlanVisitor, RelationCollectorVisitor, SysTableVisitor,
+    ExecutionModeDecider, FrontendOnlyVisitor, PlanVisitor, RelationCollectorVisitor,
+    SysTableVisitor,
 };
 mod logical_optimization;
 mod optimizer_context;
@@ -41,10 +42,12 @@ use plan_expr_rewriter::ConstEvalRewriter;
 use property::Order;
 use risingwave_common::catalog::{ColumnCatalog, ColumnId, ConflictBehavior, Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::Interval;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_common::util::iter_util::ZipEqDebug;
 use risingwave_connector::sink::catalog::SinkFormatDesc;
 use risingwave_pb::catalog::WatermarkDesc;
+use risingwave_sqlparser::ast::OnConflict;
 
 use self::heuristic_optimizer::ApplyOrder;
 use self::plan_node::generic::{self, PhysicalPlanRef};
@@ -293,11 +296,15 @@ impl PlanRoot {
     }
 
     /// Generate optimized stream plan
-    fn gen_optimized_stream_plan(&mut self, emit_on_window_close: bool) -> Result<PlanRef> {
+    fn gen_optimized_stream_plan(
+        &mut self,
+        emit_on_window_close: bool,
+        allowed_lateness: Option<Interval>,
+    ) -> Result<PlanRef> {
         let ctx = self.plan.ctx();
         let _explain_trace = ctx.is_explain_trace();
 
-        let mut plan = self.gen_stream_plan(emit_on_window_close)?;
+        let mut plan = self.gen_stream_plan(emit_on_window_close, allowed_lateness)?;
 
         plan = plan.optimize_by_rules(&OptimizationStage::new(
             "Merge StreamProject",
@@ -345,7 +352,11 @@ impl PlanRoot {
     }
 
     /// Generate create index or create materialize view plan.
-    fn gen_stream_plan(&mut self, emit_on_window_close: bool) -> Result<PlanRef> {
+    fn gen_stream_plan(
+        &mut self,
+        emit_on_window_close: bool,
+        allowed_lateness: Option<Interval>,
+    ) -> Result<PlanRef> {
         let ctx = self.plan.ctx();
         let explain_trace = ctx.is_explain_trace();
 
@@ -396,9 +407,14 @@ impl PlanRoot {
                 self.out_fields = out_col_change.rewrite_bitset(&self.out_fields);
                 let plan = plan.to_stream_with_dist_required(
                     &self.required_dist,
-                    &mut ToStreamContext::new(emit_on_window_close),
+                    &mut ToStreamContext::new_with_lateness(emit_on_window_close, allowed_lateness),
                 )?;
-                stream_enforce_eowc_requirement(ctx.clone(), plan, emit_on_window_close)
+                stream_enforce_eowc_requirement(
+                    ctx.clone(),
+                    plan,
+                    emit_on_window_close,
+                    allowed_lateness,
+                )
             }
             _ => unreachable!(),
         }?;
@@ -429,10 +445,11 @@ impl PlanRoot {
         pk_column_ids: Vec<ColumnId>,
         row_id_index: Option<usize>,
         append_only: bool,
+        on_conflict: Option<OnConflict>,
         watermark_descs: Vec<WatermarkDesc>,
         version: Option<TableVersion>,
     ) -> Result<StreamMaterialize> {
-        let mut stream_plan = self.gen_optimized_stream_plan(false)?;
+        let mut stream_plan = self.gen_optimized_stream_plan(false, None)?;
 
         // Add DML node.
         stream_plan = StreamDml::new(
@@ -464,9 +481,17 @@ impl PlanRoot {
             stream_plan = StreamRowIdGen::new(stream_plan, row_id_index).into();
         }
 
-        let conflict_behavior = match append_only {
-            true => ConflictBehavior::NoCheck,
-            false => ConflictBehavior::Overwrite,
+        let conflict_behavior = match (append_only, on_conflict) {
+            (false, Some(OnConflict::Nothing)) => ConflictBehavior::IgnoreConflict,
+            (false, _) => ConflictBehavior::Overwrite,
+            (true, None) => ConflictBehavior::NoCheck,
+            (true, Some(_)) => {
+                return Err(ErrorCode::NotSupported(
+                    "ON CONFLICT can not be applied to an append-only table.".to_owned(),
+                    "Remove the ON CONFLICT clause or the APPEND ONLY clause".to_owned(),
+                )
+                .into())
+            }
         };
 
         let pk_column_indices = {
@@ -511,9 +536,10 @@ impl PlanRoot {
         mv_name: String,
         definition: String,
         emit_on_window_close: bool,
+        allowed_lateness: Option<Interval>,
     ) -> Result<StreamMaterialize> {
         let cardinality = self.compute_cardinality();
-        let stream_plan = self.gen_optimized_stream_plan(emit_on_window_close)?;
+        let stream_plan = self.gen_optimized_stream_plan(emit_on_window_close, allowed_lateness)?;
 
         StreamMaterialize::create(
             stream_plan,
@@ -535,7 +561,7 @@ impl PlanRoot {
         definition: String,
     ) -> Result<StreamMaterialize> {
         let cardinality = self.compute_cardinality();
-        let stream_plan = self.gen_optimized_stream_plan(false)?;
+        let stream_plan = self.gen_optimized_stream_plan(false, None)?;
 
         StreamMaterialize::create(
             stream_plan,
@@ -551,17 +577,19 @@ impl PlanRoot {
     }
 
     /// Optimize and generate a create sink plan.
+    #[allow(clippy::too_many_arguments)]
     pub fn gen_sink_plan(
         &mut self,
         sink_name: String,
         definition: String,
         properties: WithOptions,
         emit_on_window_close: bool,
+        allowed_lateness: Option<Interval>,
         db_name: String,
         sink_from_table_name: String,
         format_desc: Option<SinkFormatDesc>,
     ) -> Result<StreamSink> {
-        let stream_plan = self.gen_optimized_stream_plan(emit_on_window_close)?;
+        let stream_plan = self.gen_optimized_stream_plan(emit_on_window_close, allowed_lateness)?;
 
         StreamSink::create(
             stream_plan,
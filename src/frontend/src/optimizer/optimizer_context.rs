@@ -18,16 +18,46 @@ use std::cell::{RefCell, RefMut};
 use std::rc::Rc;
 use std::sync::Arc;
 
-use risingwave_sqlparser::ast::{ExplainOptions, ExplainType};
+use risingwave_pb::hummock::HummockVersionStats;
+use risingwave_sqlparser::ast::{ExplainFormat, ExplainOptions, ExplainType};
 
 use crate::expr::{CorrelatedId, SessionTimezone};
 use crate::handler::HandlerArgs;
+use crate::optimizer::heuristic_optimizer::AppliedRule;
 use crate::optimizer::plan_node::PlanNodeId;
 use crate::session::SessionImpl;
 use crate::WithOptions;
 
 const RESERVED_ID_NUM: u16 = 10000;
 
+/// A hint from the `/*+ ... */` comment after the `SELECT` keyword, instructing the optimizer
+/// how to plan the current query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanHint {
+    /// Join the relations in the order they appear in the `FROM` clause, instead of reordering
+    /// them by the heuristics.
+    Ordered,
+    /// Broadcast the build side of batch hash joins instead of shuffling both sides.
+    BroadcastJoin,
+    /// Shuffle both sides of batch hash joins, disabling lookup join selection.
+    ShuffleJoin,
+    /// Force two-phase aggregation, as if `rw_force_two_phase_agg` was set.
+    TwoPhaseAgg,
+}
+
+impl PlanHint {
+    /// Parse a single hint item. Hint names are case-insensitive.
+    pub fn parse(hint: &str) -> Option<Self> {
+        match hint.to_lowercase().as_str() {
+            "ordered" => Some(Self::Ordered),
+            "broadcast_join" => Some(Self::BroadcastJoin),
+            "shuffle_join" => Some(Self::ShuffleJoin),
+            "two_phase_agg" => Some(Self::TwoPhaseAgg),
+            _ => None,
+        }
+    }
+}
+
 pub struct OptimizerContext {
     session_ctx: Arc<SessionImpl>,
     /// Store plan node id
@@ -40,6 +70,8 @@ pub struct OptimizerContext {
     explain_options: ExplainOptions,
     /// Store the trace of optimizer
     optimizer_trace: RefCell<Vec<String>>,
+    /// Store each rule applied by the optimizer, tagged with its stage name
+    applied_rules: RefCell<Vec<(String, AppliedRule)>>,
     /// Store the optimized logical plan of optimizer
     logical_explain: RefCell<Option<String>>,
     /// Store correlated id
@@ -50,6 +82,11 @@ pub struct OptimizerContext {
     session_timezone: RefCell<SessionTimezone>,
     /// Store expr display id.
     next_expr_display_id: RefCell<usize>,
+    /// Store the snapshot of the table statistics reported by storage, taken when the context
+    /// is created so that one optimization run sees consistent statistics.
+    table_stats: HummockVersionStats,
+    /// Store the hints from the `/*+ ... */` comment of the query being planned.
+    plan_hints: RefCell<Vec<PlanHint>>,
 }
 
 // Still not sure if we need to introduce "on_optimization_finish" or other common callback methods,
@@ -75,6 +112,13 @@ impl OptimizerContext {
         let session_timezone = RefCell::new(SessionTimezone::new(
             handler_args.session.config().get_timezone().to_owned(),
         ));
+        let table_stats = handler_args
+            .session
+            .env()
+            .catalog_reader()
+            .read_guard()
+            .table_stats()
+            .clone();
         Self {
             session_ctx: handler_args.session,
             next_plan_node_id: RefCell::new(RESERVED_ID_NUM.into()),
@@ -82,11 +126,14 @@ impl OptimizerContext {
             normalized_sql: handler_args.normalized_sql,
             explain_options,
             optimizer_trace: RefCell::new(vec![]),
+            applied_rules: RefCell::new(vec![]),
             logical_explain: RefCell::new(None),
             next_correlated_id: RefCell::new(0),
             with_options: handler_args.with_options,
             session_timezone,
             next_expr_display_id: RefCell::new(RESERVED_ID_NUM.into()),
+            table_stats,
+            plan_hints: RefCell::new(vec![]),
         }
     }
 
@@ -101,11 +148,14 @@ impl OptimizerContext {
             normalized_sql: "".to_owned(),
             explain_options: ExplainOptions::default(),
             optimizer_trace: RefCell::new(vec![]),
+            applied_rules: RefCell::new(vec![]),
             logical_explain: RefCell::new(None),
             next_correlated_id: RefCell::new(0),
             with_options: Default::default(),
             session_timezone: RefCell::new(SessionTimezone::new("UTC".into())),
             next_expr_display_id: RefCell::new(0),
+            table_stats: HummockVersionStats::default(),
+            plan_hints: RefCell::new(vec![]),
         }
         .into()
     }
@@ -149,10 +199,18 @@ impl OptimizerContext {
         self.explain_options.trace
     }
 
+    pub fn is_explain_costs(&self) -> bool {
+        self.explain_options.costs
+    }
+
     pub fn explain_type(&self) -> ExplainType {
         self.explain_options.explain_type.clone()
     }
 
+    pub fn explain_format(&self) -> ExplainFormat {
+        self.explain_options.explain_format.clone()
+    }
+
     pub fn is_explain_logical(&self) -> bool {
         self.explain_type() == ExplainType::Logical
     }
@@ -185,6 +243,50 @@ impl OptimizerContext {
         self.optimizer_trace.borrow_mut().drain(..).collect()
     }
 
+    /// Record the rule applications of one optimization stage. Like [`Self::trace`], recording
+    /// stops at the logical plan if explain type is logical.
+    pub fn record_applied_rules(&self, stage: &str, applications: Vec<AppliedRule>) {
+        if self.is_explain_logical() && self.logical_explain.borrow().is_some() {
+            return;
+        }
+        self.applied_rules
+            .borrow_mut()
+            .extend(applications.into_iter().map(|a| (stage.to_owned(), a)));
+    }
+
+    /// Take the recorded rule applications and render them as a JSON document, grouping
+    /// consecutive applications by optimization stage. Used by `EXPLAIN (TRACE, FORMAT JSON)`.
+    pub fn take_rule_trace_as_json(&self) -> String {
+        let mut stages: Vec<(String, Vec<AppliedRule>)> = vec![];
+        for (stage, applied) in self.applied_rules.borrow_mut().drain(..) {
+            match stages.last_mut() {
+                Some((name, applications)) if *name == stage => applications.push(applied),
+                _ => stages.push((stage, vec![applied])),
+            }
+        }
+
+        let stages = stages
+            .into_iter()
+            .map(|(name, applications)| {
+                serde_json::json!({
+                    "stage": name,
+                    "applied_rules": applications
+                        .iter()
+                        .map(|a| {
+                            serde_json::json!({
+                                "rule": a.rule,
+                                "elapsed_us": a.elapsed.as_micros() as u64,
+                                "plan": a.plan_after,
+                            })
+                        })
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&serde_json::json!({ "stages": stages }))
+            .expect("failed to serialize the rule trace")
+    }
+
     pub fn with_options(&self) -> &WithOptions {
         &self.with_options
     }
@@ -203,6 +305,24 @@ impl OptimizerContext {
         &self.normalized_sql
     }
 
+    /// Return the snapshot of the table statistics taken when this context was created.
+    pub fn table_stats(&self) -> &HummockVersionStats {
+        &self.table_stats
+    }
+
+    /// Record a hint from the `/*+ ... */` comment of the query.
+    pub fn add_plan_hint(&self, hint: PlanHint) {
+        let mut plan_hints = self.plan_hints.borrow_mut();
+        if !plan_hints.contains(&hint) {
+            plan_hints.push(hint);
+        }
+    }
+
+    /// Return whether the given hint was specified for the query.
+    pub fn has_plan_hint(&self, hint: PlanHint) -> bool {
+        self.plan_hints.borrow().contains(&hint)
+    }
+
     pub fn session_timezone(&self) -> RefMut<'_, SessionTimezone> {
         self.session_timezone.borrow_mut()
     }
@@ -29,6 +29,7 @@ use crate::expr::{Expr, ExprRewriter};
 use crate::optimizer::plan_node::utils::IndicesDisplay;
 use crate::optimizer::plan_node::{EqJoinPredicateDisplay, ToLocalBatch};
 use crate::optimizer::property::{Distribution, Order, RequiredDist};
+use crate::optimizer::PlanHint;
 use crate::utils::ColIndexMappingRewriteExt;
 
 /// `BatchHashJoin` implements [`super::LogicalJoin`] with hash table. It builds a hash table
@@ -77,6 +78,18 @@ impl BatchHashJoin {
                     r2o.rewrite_provided_distribution(right)
                 }
             },
+            // the build side is broadcast to every partition of the probe side, so the
+            // distribution of the probe side is preserved
+            (_, Distribution::Broadcast) => match join.join_type {
+                JoinType::Inner | JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti => {
+                    let l2o = join.l2i_col_mapping().composite(&join.i2o_col_mapping());
+                    l2o.rewrite_provided_distribution(left)
+                }
+                _ => unreachable!(
+                    "broadcasting the build side is not supported for join type {:?}",
+                    join.join_type
+                ),
+            },
             (_, _) => unreachable!(
                 "suspicious distribution: left: {:?}, right: {:?}",
                 left, right
@@ -133,6 +146,23 @@ impl_plan_tree_node_for_binary! { BatchHashJoin }
 
 impl ToDistributedBatch for BatchHashJoin {
     fn to_distributed(&self) -> Result<PlanRef> {
+        // With the `broadcast_join` hint, broadcast the build (right) side to every partition
+        // of the probe side instead of shuffling both sides. This only works for join types
+        // that preserve the probe side.
+        if self.base.ctx().has_plan_hint(PlanHint::BroadcastJoin)
+            && matches!(
+                self.core.join_type,
+                JoinType::Inner | JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti
+            )
+        {
+            let left = self.left().to_distributed()?;
+            let right = self.right().to_distributed_with_required(
+                &Order::any(),
+                &RequiredDist::PhysicalDist(Distribution::Broadcast),
+            )?;
+            return Ok(self.clone_with_left_right(left, right).into());
+        }
+
         let mut right = self.right().to_distributed_with_required(
             &Order::any(),
             &RequiredDist::shard_by_key(
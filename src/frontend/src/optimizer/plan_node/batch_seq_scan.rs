@@ -17,6 +17,7 @@ use std::ops::Bound;
 use itertools::Itertools;
 use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::error::Result;
+use risingwave_common::hash::VirtualNode;
 use risingwave_common::types::ScalarImpl;
 use risingwave_common::util::scan_range::{is_full_range, ScanRange};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
@@ -38,10 +39,19 @@ pub struct BatchSeqScan {
     pub base: PlanBase<Batch>,
     core: generic::Scan,
     scan_ranges: Vec<ScanRange>,
+    /// The vnode that the predicate pins the distribution key to, if any. Used by the
+    /// scheduler to prune the partitions to scan even when it cannot be derived from the
+    /// scan ranges.
+    dist_key_vnode: Option<VirtualNode>,
 }
 
 impl BatchSeqScan {
-    fn new_inner(core: generic::Scan, dist: Distribution, scan_ranges: Vec<ScanRange>) -> Self {
+    fn new_inner(
+        core: generic::Scan,
+        dist: Distribution,
+        scan_ranges: Vec<ScanRange>,
+        dist_key_vnode: Option<VirtualNode>,
+    ) -> Self {
         let order = if scan_ranges.len() > 1 {
             Order::any()
         } else {
@@ -67,12 +77,17 @@ impl BatchSeqScan {
             base,
             core,
             scan_ranges,
+            dist_key_vnode,
         }
     }
 
-    pub fn new(core: generic::Scan, scan_ranges: Vec<ScanRange>) -> Self {
+    pub fn new(
+        core: generic::Scan,
+        scan_ranges: Vec<ScanRange>,
+        dist_key_vnode: Option<VirtualNode>,
+    ) -> Self {
         // Use `Single` by default, will be updated later with `clone_with_dist`.
-        Self::new_inner(core, Distribution::Single, scan_ranges)
+        Self::new_inner(core, Distribution::Single, scan_ranges, dist_key_vnode)
     }
 
     fn clone_with_dist(&self) -> Self {
@@ -105,6 +120,7 @@ impl BatchSeqScan {
                 }
             },
             self.scan_ranges.clone(),
+            self.dist_key_vnode,
         )
     }
 
@@ -118,6 +134,10 @@ impl BatchSeqScan {
         &self.scan_ranges
     }
 
+    pub fn dist_key_vnode(&self) -> Option<VirtualNode> {
+        self.dist_key_vnode
+    }
+
     fn scan_ranges_as_strs(&self, verbose: bool) -> Vec<String> {
         let order_names = match verbose {
             true => self.core.order_names_with_table_prefix(),
@@ -261,7 +281,13 @@ impl ToLocalBatch for BatchSeqScan {
             // scan.
             Distribution::SomeShard
         };
-        Ok(Self::new_inner(self.core.clone(), dist, self.scan_ranges.clone()).into())
+        Ok(Self::new_inner(
+            self.core.clone(),
+            dist,
+            self.scan_ranges.clone(),
+            self.dist_key_vnode,
+        )
+        .into())
     }
 }
 
@@ -273,6 +299,6 @@ impl ExprRewritable for BatchSeqScan {
     fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
         let mut core = self.core.clone();
         core.rewrite_exprs(r);
-        Self::new(core, self.scan_ranges.clone()).into()
+        Self::new(core, self.scan_ranges.clone(), self.dist_key_vnode).into()
     }
 }
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 use paste::paste;
 use risingwave_common::catalog::FieldDisplay;
+use risingwave_common::types::Interval;
 
 use super::*;
 use crate::optimizer::property::{Order, RequiredDist};
@@ -63,6 +64,7 @@ pub fn stream_enforce_eowc_requirement(
     ctx: OptimizerContextRef,
     plan: PlanRef,
     emit_on_window_close: bool,
+    allowed_lateness: Option<Interval>,
 ) -> Result<PlanRef> {
     if emit_on_window_close && !plan.emit_on_window_close() {
         let watermark_cols = plan.watermark_columns();
@@ -82,7 +84,7 @@ pub fn stream_enforce_eowc_requirement(
                     FieldDisplay(&plan.schema()[watermark_col_idx])
                 ));
             }
-            Ok(StreamEowcSort::new(plan, watermark_col_idx).into())
+            Ok(StreamEowcSort::new(plan, watermark_col_idx, allowed_lateness)?.into())
         }
     } else if !emit_on_window_close && plan.emit_on_window_close() {
         Err(ErrorCode::InternalError(
@@ -124,13 +126,22 @@ impl RewriteStreamContext {
 pub struct ToStreamContext {
     share_to_stream_map: HashMap<PlanNodeId, PlanRef>,
     emit_on_window_close: bool,
+    allowed_lateness: Option<Interval>,
 }
 
 impl ToStreamContext {
     pub fn new(emit_on_window_close: bool) -> Self {
+        Self::new_with_lateness(emit_on_window_close, None)
+    }
+
+    pub fn new_with_lateness(
+        emit_on_window_close: bool,
+        allowed_lateness: Option<Interval>,
+    ) -> Self {
         Self {
             share_to_stream_map: HashMap::new(),
             emit_on_window_close,
+            allowed_lateness,
         }
     }
 
@@ -147,6 +158,10 @@ impl ToStreamContext {
     pub fn emit_on_window_close(&self) -> bool {
         self.emit_on_window_close
     }
+
+    pub fn allowed_lateness(&self) -> Option<Interval> {
+        self.allowed_lateness
+    }
 }
 
 /// `ToBatch` allows to convert a logical plan node to batch physical node
@@ -31,7 +31,7 @@ use risingwave_pb::stream_plan::{agg_call_state, AggCallState as AggCallStatePb}
 use super::super::utils::TableCatalogBuilder;
 use super::{impl_distill_unit_from_fields, stream, GenericPlanNode, GenericPlanRef};
 use crate::expr::{Expr, ExprRewriter, InputRef, InputRefDisplay, Literal};
-use crate::optimizer::optimizer_context::OptimizerContextRef;
+use crate::optimizer::optimizer_context::{OptimizerContextRef, PlanHint};
 use crate::optimizer::plan_node::batch::BatchPlanRef;
 use crate::optimizer::property::{Distribution, FunctionalDependencySet, RequiredDist};
 use crate::stream_fragmenter::BuildFragmentGraphState;
@@ -88,6 +88,7 @@ impl<PlanRef: GenericPlanRef> Agg<PlanRef> {
 
     fn two_phase_agg_forced(&self) -> bool {
         self.ctx().session_ctx().config().get_force_two_phase_agg()
+            || self.ctx().has_plan_hint(PlanHint::TwoPhaseAgg)
     }
 
     pub fn two_phase_agg_enabled(&self) -> bool {
@@ -113,6 +114,33 @@ impl<PlanRef: GenericPlanRef> Agg<PlanRef> {
         self.two_phase_agg_forced() && self.can_two_phase_agg()
     }
 
+    fn adaptive_two_phase_agg_enabled(&self) -> bool {
+        self.ctx()
+            .session_ctx()
+            .config()
+            .get_streaming_enable_adaptive_two_phase_agg()
+    }
+
+    /// Adaptively try two phase agg for grouped aggregation, if the local aggregation is
+    /// stateless so the extra phase is cheap, and the input already has a hash distribution
+    /// to perform it on. This mitigates hotspots on skewed group keys without forcing two
+    /// phase agg globally. The heuristic stands in until plan-time statistics are available.
+    pub(crate) fn should_two_phase_agg_adaptively(
+        &self,
+        input_dist: &Distribution,
+        input_append_only: bool,
+    ) -> bool {
+        self.adaptive_two_phase_agg_enabled()
+            && !self.group_key.is_empty()
+            && self.can_two_phase_agg()
+            && self.all_local_aggs_are_stateless(input_append_only)
+            && matches!(
+                input_dist,
+                Distribution::HashShard(_) | Distribution::UpstreamHashShard(..)
+            )
+            && !self.hash_agg_dist_satisfied_by_input_dist(input_dist)
+    }
+
     /// Generally used by two phase hash agg.
     /// If input dist already satisfies hash agg distribution,
     /// it will be more expensive to do two phase agg, should just do shuffle agg.
@@ -19,7 +19,7 @@ use risingwave_common::util::sort_util::OrderType;
 pub use risingwave_pb::expr::expr_node::Type as ExprType;
 
 use super::{GenericPlanNode, GenericPlanRef};
-use crate::expr::{ExprImpl, FunctionCall, InputRef};
+use crate::expr::{ExprImpl, ExprRewriter, FunctionCall, InputRef};
 use crate::optimizer::plan_node::stream;
 use crate::optimizer::plan_node::utils::TableCatalogBuilder;
 use crate::optimizer::property::FunctionalDependencySet;
@@ -32,8 +32,12 @@ pub struct DynamicFilter<PlanRef> {
     comparator: ExprType,
     left_index: usize,
     pub left: PlanRef,
-    /// The right input can only have one column.
+    /// The right input is a single row, whose first column is compared against the left
+    /// key by `comparator`.
     pub right: PlanRef,
+    /// Residual conjuncts of a composite condition, as expressions over the
+    /// concatenation of the left schema and the right schema.
+    residual: Condition,
 }
 impl<PlanRef> DynamicFilter<PlanRef> {
     pub fn comparator(&self) -> ExprType {
@@ -51,6 +55,14 @@ impl<PlanRef> DynamicFilter<PlanRef> {
     pub fn right(&self) -> &PlanRef {
         &self.right
     }
+
+    pub fn residual(&self) -> &Condition {
+        &self.residual
+    }
+
+    pub(crate) fn rewrite_exprs(&mut self, r: &mut dyn ExprRewriter) {
+        self.residual = self.residual.clone().rewrite_expr(r);
+    }
 }
 
 impl<PlanRef: GenericPlanRef> GenericPlanNode for DynamicFilter<PlanRef> {
@@ -74,20 +86,38 @@ impl<PlanRef: GenericPlanRef> GenericPlanNode for DynamicFilter<PlanRef> {
 impl<PlanRef: GenericPlanRef> DynamicFilter<PlanRef> {
     pub fn new(comparator: ExprType, left_index: usize, left: PlanRef, right: PlanRef) -> Self {
         assert_eq!(right.schema().len(), 1);
+        Self::new_with_residual(comparator, left_index, left, right, Condition::true_cond())
+    }
+
+    pub fn new_with_residual(
+        comparator: ExprType,
+        left_index: usize,
+        left: PlanRef,
+        right: PlanRef,
+        residual: Condition,
+    ) -> Self {
+        assert!(!right.schema().is_empty());
         Self {
             comparator,
             left_index,
             left,
             right,
+            residual,
         }
     }
 
     pub fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
-        Self::new(self.comparator, self.left_index, left, right)
+        Self::new_with_residual(
+            self.comparator,
+            self.left_index,
+            left,
+            right,
+            self.residual.clone(),
+        )
     }
 
-    /// normalize to the join predicate
-    pub fn predicate(&self) -> Condition {
+    /// The driving comparison between the left key and the first column of the right row.
+    pub fn comparison_condition(&self) -> Condition {
         Condition {
             conjunctions: vec![ExprImpl::from(
                 FunctionCall::new(
@@ -108,6 +138,11 @@ impl<PlanRef: GenericPlanRef> DynamicFilter<PlanRef> {
         }
     }
 
+    /// normalize to the join predicate
+    pub fn predicate(&self) -> Condition {
+        self.comparison_condition().and(self.residual.clone())
+    }
+
     pub fn watermark_columns(&self, right_watermark: bool) -> FixedBitSet {
         let mut watermark_columns = FixedBitSet::with_capacity(self.left.schema().len());
         if right_watermark {
@@ -26,9 +26,10 @@ use super::{
     PlanTreeNodeUnary, PredicatePushdown, StreamHashAgg, StreamProject, StreamSimpleAgg,
     StreamStatelessSimpleAgg, ToBatch, ToStream,
 };
+use crate::binder::{BoundQuery, BoundSetExpr, Relation};
 use crate::expr::{
-    AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal, OrderBy,
-    WindowFunction,
+    AggCall, CorrelatedInputRef, Depth, Expr, ExprImpl, ExprMutator, ExprRewriter, ExprType,
+    FunctionCall, InputRef, Literal, OrderBy, Subquery, WindowFunction,
 };
 use crate::optimizer::plan_node::generic::GenericPlanNode;
 use crate::optimizer::plan_node::{
@@ -187,6 +188,21 @@ impl LogicalAgg {
         let input_dist = stream_input.distribution();
         debug_assert!(*input_dist != Distribution::Broadcast);
 
+        // Adaptive local + global agg
+        // For skewed group keys, adaptively insert a stateless local agg before the shuffle
+        // if it is cheap, controlled by `RW_STREAMING_ENABLE_ADAPTIVE_TWO_PHASE_AGG`.
+        if self
+            .core
+            .should_two_phase_agg_adaptively(input_dist, stream_input.append_only())
+        {
+            if let Distribution::HashShard(dist_key) | Distribution::UpstreamHashShard(dist_key, _) =
+                input_dist
+            {
+                let dist_key = dist_key.clone();
+                return self.gen_vnode_two_phase_streaming_agg_plan(stream_input, &dist_key);
+            }
+        }
+
         // Shuffle agg
         // If we have group key, and we won't try two phase agg optimization at all,
         // we will always choose shuffle agg over single agg.
@@ -783,17 +799,122 @@ impl ExprRewriter for LogicalAggBuilder {
         }
     }
 
-    fn rewrite_subquery(&mut self, subquery: crate::expr::Subquery) -> ExprImpl {
+    /// When there is a correlated subquery, all the columns of this query level it references must
+    /// refer to group columns, which are redirected to their positions in the agg output so that
+    /// subquery unnesting can later decorrelate against this agg. This covers e.g. correlated
+    /// scalar aggregates and `EXISTS` in `HAVING`, including the classic COUNT bug case, which is
+    /// handled by the left outer translation in `ApplyAggTransposeRule`.
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
         if subquery.is_correlated(0) {
-            self.error = Some(ErrorCode::NotImplemented(
-                "correlated subquery in HAVING or SELECT with agg".into(),
-                2275.into(),
-            ));
+            let mut remapper = CorrelatedGroupKeyRemapper {
+                builder: self,
+                depth: 1,
+                failed: false,
+            };
+            remapper.visit_bound_query(&mut subquery.query);
+            if remapper.failed {
+                self.error = Some(ErrorCode::NotImplemented(
+                    "correlated subquery in HAVING or SELECT with agg referencing a column that is not in GROUP BY".into(),
+                    2275.into(),
+                ));
+            }
         }
         subquery.into()
     }
 }
 
+/// Redirects `CorrelatedInputRef`s referencing the query level being aggregated from their indices
+/// in the agg input to the positions of the corresponding group keys in the agg output. A reference
+/// to a column that is not a group column sets `failed`, as such a column no longer exists after
+/// aggregation.
+struct CorrelatedGroupKeyRemapper<'a> {
+    builder: &'a LogicalAggBuilder,
+    depth: Depth,
+    failed: bool,
+}
+
+impl CorrelatedGroupKeyRemapper<'_> {
+    fn visit_bound_query(&mut self, query: &mut BoundQuery) {
+        self.visit_bound_set_expr(&mut query.body);
+        query
+            .extra_order_exprs
+            .iter_mut()
+            .for_each(|expr| self.visit_expr(expr));
+    }
+
+    fn visit_bound_set_expr(&mut self, set_expr: &mut BoundSetExpr) {
+        match set_expr {
+            BoundSetExpr::Select(select) => {
+                select.exprs_mut().for_each(|expr| self.visit_expr(expr));
+                if let Some(from) = select.from.as_mut() {
+                    self.visit_relation(from);
+                }
+            }
+            BoundSetExpr::Values(values) => {
+                values.exprs_mut().for_each(|expr| self.visit_expr(expr))
+            }
+            BoundSetExpr::Query(query) => {
+                self.depth += 1;
+                self.visit_bound_query(query);
+                self.depth -= 1;
+            }
+            BoundSetExpr::SetOperation { left, right, .. } => {
+                self.visit_bound_set_expr(&mut *left);
+                self.visit_bound_set_expr(&mut *right);
+            }
+        }
+    }
+
+    fn visit_relation(&mut self, relation: &mut Relation) {
+        match relation {
+            Relation::Subquery(subquery) => {
+                self.depth += 1;
+                self.visit_bound_query(&mut subquery.query);
+                self.depth -= 1;
+            }
+            Relation::Join(join) | Relation::Apply(join) => {
+                self.visit_expr(&mut join.cond);
+                self.visit_relation(&mut join.left);
+                self.visit_relation(&mut join.right);
+            }
+            Relation::TableFunction { expr, .. } => {
+                self.depth += 1;
+                self.visit_expr(expr);
+                self.depth -= 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ExprMutator for CorrelatedGroupKeyRemapper<'_> {
+    fn visit_correlated_input_ref(&mut self, correlated_input_ref: &mut CorrelatedInputRef) {
+        if correlated_input_ref.depth() == self.depth {
+            let input_expr: ExprImpl = InputRef::new(
+                correlated_input_ref.index(),
+                correlated_input_ref.return_type(),
+            )
+            .into();
+            match self.builder.try_as_group_expr(&input_expr) {
+                Some(group_key) => {
+                    *correlated_input_ref = CorrelatedInputRef::new(
+                        group_key,
+                        input_expr.return_type(),
+                        self.depth,
+                    );
+                }
+                None => self.failed = true,
+            }
+        }
+    }
+
+    fn visit_subquery(&mut self, subquery: &mut Subquery) {
+        self.depth += 1;
+        self.visit_bound_query(&mut subquery.query);
+        self.depth -= 1;
+    }
+}
+
 impl From<Agg<PlanRef>> for LogicalAgg {
     fn from(core: Agg<PlanRef>) -> Self {
         let base = PlanBase::new_logical_with_core(&core);
@@ -1151,6 +1272,7 @@ impl ToStream for LogicalAgg {
             }
         }
         let eowc = ctx.emit_on_window_close();
+        let allowed_lateness = ctx.allowed_lateness();
         let stream_input = self.input().to_stream(ctx)?;
 
         // Use Dedup operator, if possible.
@@ -1184,7 +1306,7 @@ impl ToStream for LogicalAgg {
         } else if let Some(final_agg) = plan.as_stream_hash_agg() {
             (
                 if eowc {
-                    final_agg.to_eowc_version()?
+                    final_agg.to_eowc_version(allowed_lateness)?
                 } else {
                     plan.clone()
                 },
@@ -39,6 +39,7 @@ use crate::optimizer::plan_node::{
 };
 use crate::optimizer::plan_visitor::LogicalCardinalityExt;
 use crate::optimizer::property::{Distribution, Order, RequiredDist};
+use crate::optimizer::PlanHint;
 use crate::utils::{ColIndexMapping, ColIndexMappingRewriteExt, Condition, ConditionDisplay};
 
 /// `LogicalJoin` combines two relations according to some condition.
@@ -1187,9 +1188,11 @@ impl LogicalJoin {
     ) -> Result<Option<PlanRef>> {
         use super::stream::prelude::*;
 
-        // If there is exactly one predicate, it is a comparison (<, <=, >, >=), and the
-        // join is a `Inner` or `LeftSemi` join, we can convert the scalar subquery into a
-        // `StreamDynamicFilter`
+        // If one of the predicates is a comparison (<, <=, >, >=) between a left column
+        // and the first column of the scalar right side, and the join is a `Inner` or
+        // `LeftSemi` join, we can convert the scalar subquery into a
+        // `StreamDynamicFilter`. The remaining conjuncts, if any, become the residual
+        // condition of the dynamic filter.
 
         // Check if `Inner`/`LeftSemi`
         if !matches!(self.join_type(), JoinType::Inner | JoinType::LeftSemi) {
@@ -1200,33 +1203,35 @@ impl LogicalJoin {
         if !self.right().max_one_row() {
             return Ok(None);
         }
-        if self.right().schema().len() != 1 {
+        if self.right().schema().is_empty() {
             return Ok(None);
         }
 
-        // Check if the join condition is a correlated comparison
-        if predicate.conjunctions.len() > 1 {
-            return Ok(None);
-        }
-        let expr: ExprImpl = predicate.into();
-        let (left_ref, comparator, right_ref) = match expr.as_comparison_cond() {
-            Some(v) => v,
-            None => return Ok(None),
-        };
-
-        let condition_cross_inputs = left_ref.index < self.left().schema().len()
-            && right_ref.index == self.left().schema().len() /* right side has only one column */;
-        if !condition_cross_inputs {
-            // Maybe we should panic here because it means some predicates are not pushed down.
-            return Ok(None);
+        // Split the driving comparison against the first column of the right side from
+        // the residual conjuncts.
+        let left_schema_len = self.left().schema().len();
+        let mut driving = None;
+        let mut residual = vec![];
+        for expr in predicate.conjunctions {
+            if driving.is_none()
+                && let Some((left_ref, comparator, right_ref)) = expr.as_comparison_cond()
+                && left_ref.index < left_schema_len
+                && right_ref.index == left_schema_len
+                // We align input types on all join predicates with cmp operator
+                && self.left().schema().fields()[left_ref.index].data_type
+                    == self.right().schema().fields()[0].data_type
+            {
+                driving = Some((left_ref, comparator));
+            } else {
+                residual.push(expr);
+            }
         }
-
-        // We align input types on all join predicates with cmp operator
-        if self.left().schema().fields()[left_ref.index].data_type
-            != self.right().schema().fields()[0].data_type
-        {
+        let Some((left_ref, comparator)) = driving else {
             return Ok(None);
-        }
+        };
+        let residual = Condition {
+            conjunctions: residual,
+        };
 
         // Check if non of the columns from the inner side is required to output
         let all_output_from_left = self
@@ -1249,7 +1254,8 @@ impl LogicalJoin {
             Distribution::Single
         );
 
-        let core = DynamicFilter::new(comparator, left_ref.index, left, right);
+        let core =
+            DynamicFilter::new_with_residual(comparator, left_ref.index, left, right, residual);
         let plan = StreamDynamicFilter::new(core).into();
         // TODO: `DynamicFilterExecutor` should support `output_indices` in `ChunkBuilder`
         if self
@@ -1314,7 +1320,11 @@ impl ToBatch for LogicalJoin {
                 ))
                 .into());
             }
-            if config.get_batch_enable_lookup_join() {
+            // When a join distribution is explicitly hinted, always use a plain hash join so
+            // that the hint takes effect on its exchanges.
+            let dist_hinted = ctx.has_plan_hint(PlanHint::BroadcastJoin)
+                || ctx.has_plan_hint(PlanHint::ShuffleJoin);
+            if config.get_batch_enable_lookup_join() && !dist_hinted {
                 if let Some(lookup_join) = self.to_batch_lookup_join_with_index_selection(
                     predicate.clone(),
                     logical_join.clone(),
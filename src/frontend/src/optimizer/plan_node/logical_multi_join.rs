@@ -32,6 +32,7 @@ use crate::optimizer::plan_node::{
     ColumnPruningContext, PlanTreeNode, PredicatePushdownContext, RewriteStreamContext,
     ToStreamContext,
 };
+use crate::optimizer::plan_visitor::RowCountEstimator;
 use crate::optimizer::property::FunctionalDependencySet;
 use crate::utils::{
     ColIndexMapping, ColIndexMappingRewriteExt, Condition, ConditionDisplay,
@@ -487,6 +488,87 @@ impl LogicalMultiJoin {
         Ok(join_ordering)
     }
 
+    /// Statistics-based counterpart of [`Self::heuristic_ordering`], used by the batch
+    /// optimizer when storage has reported row counts for the involved tables.
+    ///
+    /// Within each connected component of the join graph, the left-deep chain is grown
+    /// greedily by always joining the input with the smallest estimated row count next, so
+    /// that the intermediate results stay small. Inputs without statistics are considered
+    /// larger than any input with statistics. Returns `None` if no input has a known row
+    /// count, in which case the caller should fall back to the heuristic ordering.
+    pub(crate) fn stats_ordering(&self) -> Option<Vec<usize>> {
+        let estimates: Vec<Option<f64>> = self
+            .inputs
+            .iter()
+            .map(|input| RowCountEstimator::estimate(input.clone()))
+            .collect();
+        if estimates.iter().all(Option::is_none) {
+            return None;
+        }
+        let smaller = |a: &usize, b: &usize| match (estimates[*a], estimates[*b]) {
+            (Some(x), Some(y)) => x.total_cmp(&y).then(a.cmp(b)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        };
+
+        let mut labeller = ConnectedComponentLabeller::new(self.inputs.len());
+
+        let (eq_join_conditions, _) = self.on.clone().split_by_input_col_nums(
+            &self.input_col_nums(),
+            // only_eq=
+            true,
+        );
+        for k in eq_join_conditions.keys() {
+            labeller.add_edge(k.0, k.1);
+        }
+
+        let mut edge_sets: Vec<_> = labeller.into_edge_sets();
+
+        // Sort in decreasing order of len
+        edge_sets.sort_by_key(|a| std::cmp::Reverse(a.len()));
+
+        let mut join_ordering = vec![];
+
+        for component in edge_sets {
+            let eq_cond_edges: Vec<(usize, usize)> = component.into_iter().collect();
+            if eq_cond_edges.is_empty() {
+                break;
+            }
+
+            let mut remaining: BTreeSet<usize> =
+                eq_cond_edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+            let base = remaining.iter().copied().min_by(smaller).unwrap();
+            remaining.remove(&base);
+            let mut chain = vec![base];
+
+            while !remaining.is_empty() {
+                // The component is connected, so there is always an input left that joins
+                // with the chain built so far via an eq condition.
+                let next = remaining
+                    .iter()
+                    .copied()
+                    .filter(|v| {
+                        eq_cond_edges.iter().any(|&(a, b)| {
+                            (chain.contains(&a) && b == *v) || (chain.contains(&b) && a == *v)
+                        })
+                    })
+                    .min_by(smaller)?;
+                remaining.remove(&next);
+                chain.push(next);
+            }
+            join_ordering.extend(chain);
+        }
+
+        // Deal with singleton inputs (with no eq condition joins between them whatsoever)
+        for i in 0..self.inputs.len() {
+            if !join_ordering.contains(&i) {
+                join_ordering.push(i);
+            }
+        }
+        Some(join_ordering)
+    }
+
     /// transform multijoin into bushy tree join.
     ///
     /// 1. First, use equivalent condition derivation to get derive join relation.
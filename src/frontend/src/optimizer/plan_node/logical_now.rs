@@ -16,7 +16,7 @@ use pretty_xmlish::XmlNode;
 use risingwave_common::bail;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::Result;
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, Interval, Timestamptz};
 
 use super::generic::GenericPlanRef;
 use super::utils::{childless_record, Distill};
@@ -29,36 +29,66 @@ use crate::optimizer::property::FunctionalDependencySet;
 use crate::utils::ColIndexMapping;
 use crate::OptimizerContextRef;
 
+/// The mode of the `Now` node.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct LogicalNow {
-    pub base: PlanBase<Logical>,
+pub enum NowMode {
+    /// Emit the current timestamp on each barrier, retracting the previous one.
+    UpdateCurrent,
+    /// Emit a series of timestamps starting from `start_timestamp` with `interval` step, in an
+    /// append-only manner, as long as they are no later than the current timestamp. This is
+    /// converted from `generate_series` ending with `now()`.
+    GenerateSeries {
+        start_timestamp: Timestamptz,
+        interval: Interval,
+    },
 }
 
-impl LogicalNow {
-    pub fn new(ctx: OptimizerContextRef) -> Self {
-        let schema = Schema::new(vec![Field {
+impl NowMode {
+    pub(super) fn schema(&self) -> Schema {
+        let name = match self {
+            Self::UpdateCurrent => "now",
+            Self::GenerateSeries { .. } => "ts",
+        };
+        Schema::new(vec![Field {
             data_type: DataType::Timestamptz,
-            name: String::from("now"),
+            name: name.into(),
             sub_fields: vec![],
             type_name: String::default(),
-        }]);
+        }])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogicalNow {
+    pub base: PlanBase<Logical>,
+    mode: NowMode,
+}
+
+impl LogicalNow {
+    pub fn new(ctx: OptimizerContextRef, mode: NowMode) -> Self {
         let base = PlanBase::new_logical(
             ctx,
-            schema,
+            mode.schema(),
             Some(vec![]),
             FunctionalDependencySet::default(),
         );
-        Self { base }
+        Self { base, mode }
+    }
+
+    pub fn mode(&self) -> &NowMode {
+        &self.mode
     }
 }
 
 impl Distill for LogicalNow {
     fn distill<'a>(&self) -> XmlNode<'a> {
-        let vec = if self.base.ctx().is_explain_verbose() {
-            vec![("output", column_names_pretty(self.schema()))]
-        } else {
-            vec![]
-        };
+        let mut vec = Vec::with_capacity(2);
+        if let NowMode::GenerateSeries { .. } = &self.mode {
+            vec.push(("mode", "generate_series".into()));
+        }
+        if self.base.ctx().is_explain_verbose() {
+            vec.push(("output", column_names_pretty(self.schema())));
+        }
 
         childless_record("LogicalNow", vec)
     }
@@ -86,7 +116,7 @@ impl ToStream for LogicalNow {
 
     /// `to_stream` is equivalent to `to_stream_with_dist_required(RequiredDist::Any)`
     fn to_stream(&self, _ctx: &mut ToStreamContext) -> Result<PlanRef> {
-        Ok(StreamNow::new(self.clone(), self.ctx()).into())
+        Ok(StreamNow::new(self.mode.clone(), self.ctx()).into())
     }
 }
 
@@ -790,7 +790,7 @@ impl ToStream for LogicalOverWindow {
             let sort_input =
                 RequiredDist::shard_by_key(stream_input.schema().len(), &partition_key_indices)
                     .enforce_if_not_satisfies(stream_input, &Order::any())?;
-            let sort = StreamEowcSort::new(sort_input, order_key_index);
+            let sort = StreamEowcSort::new(sort_input, order_key_index, ctx.allowed_lateness())?;
 
             let mut core = self.core.clone();
             core.input = sort.into();
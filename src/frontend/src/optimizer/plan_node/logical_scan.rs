@@ -207,6 +207,12 @@ impl LogicalScan {
 
     /// If the index can cover the scan, transform it to the index scan.
     pub fn to_index_scan_if_index_covered(&self, index: &Rc<IndexCatalog>) -> Option<LogicalScan> {
+        // A partial index only contains the rows satisfying its predicate, so it can replace the
+        // primary table scan only if the scan predicate implies the index predicate.
+        if !index.predicate_implied_by(self.predicate()) {
+            return None;
+        }
+
         let p2s_mapping = index.primary_to_secondary_mapping();
         if self
             .required_col_idx()
@@ -469,8 +475,14 @@ impl LogicalScan {
     fn to_batch_inner_with_required(&self, required_order: &Order) -> Result<PlanRef> {
         if self.predicate().always_true() {
             required_order
-                .enforce_if_not_satisfies(BatchSeqScan::new(self.core.clone(), vec![]).into())
+                .enforce_if_not_satisfies(BatchSeqScan::new(self.core.clone(), vec![], None).into())
         } else {
+            // Derive from the full predicate, as the pinning equality conditions may not
+            // be convertible to a scan range, e.g. when the distribution key is not a
+            // prefix of the primary key.
+            let dist_key_vnode = self
+                .predicate()
+                .try_derive_dist_key_vnode(&self.core.table_desc);
             let (scan_ranges, predicate) = self.predicate().clone().split_to_scan_ranges(
                 self.core.table_desc.clone(),
                 self.base
@@ -487,7 +499,8 @@ impl LogicalScan {
             } else {
                 let (scan, predicate, project_expr) = scan.predicate_pull_up();
 
-                let mut plan: PlanRef = BatchSeqScan::new(scan, scan_ranges).into();
+                let mut plan: PlanRef =
+                    BatchSeqScan::new(scan, scan_ranges, dist_key_vnode).into();
                 if !predicate.always_true() {
                     plan = BatchFilter::new(generic::Filter::new(predicate, plan)).into();
                 }
@@ -26,7 +26,7 @@ use risingwave_common::catalog::{
 use risingwave_common::error::{ErrorCode, Result, RwError, TrackingIssue};
 use risingwave_connector::source::{ConnectorProperties, DataType};
 use risingwave_pb::plan_common::column_desc::GeneratedOrDefaultColumn;
-use risingwave_pb::plan_common::GeneratedColumnDesc;
+use risingwave_pb::plan_common::{FormatType, GeneratedColumnDesc};
 
 use super::generic::GenericPlanRef;
 use super::stream_watermark_filter::StreamWatermarkFilter;
@@ -43,7 +43,7 @@ use crate::optimizer::plan_node::stream_fs_fetch::StreamFsFetch;
 use crate::optimizer::plan_node::utils::column_names_pretty;
 use crate::optimizer::plan_node::{
     ColumnPruningContext, PredicatePushdownContext, RewriteStreamContext, StreamDedup,
-    ToStreamContext,
+    StreamUpsertNormalize, ToStreamContext,
 };
 use crate::optimizer::property::{Distribution, Order, RequiredDist};
 use crate::utils::{ColIndexMapping, Condition, IndexRewriter};
@@ -576,6 +576,29 @@ impl ToStream for LogicalSource {
         {
             plan = StreamRowIdGen::new(plan, row_id_index).into();
         }
+
+        // An MV on an upsert source only sees upserts and tombstones keyed by the primary
+        // key, so normalize them into a changelog here. Tables with an upsert source handle
+        // conflicts in the `Materialize` operator instead.
+        if let Some(catalog) = self.source_catalog()
+            && !self.core.for_table
+            && catalog.info.format() == FormatType::Upsert
+        {
+            let key_indices = catalog
+                .pk_col_ids
+                .iter()
+                .map(|id| {
+                    catalog
+                        .columns
+                        .iter()
+                        .position(|c| c.column_id() == *id)
+                        .expect("pk column not found in upsert source")
+                })
+                .collect_vec();
+            plan = RequiredDist::hash_shard(&key_indices)
+                .enforce_if_not_satisfies(plan, &Order::any())?;
+            plan = StreamUpsertNormalize::new(plan, key_indices).into();
+        }
         Ok(plan)
     }
 
@@ -37,7 +37,7 @@ use dyn_clone::{self, DynClone};
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use paste::paste;
-use pretty_xmlish::{Pretty, PrettyConfig};
+use pretty_xmlish::{Pretty, PrettyConfig, Str};
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::batch_plan::PlanNode as BatchPlanPb;
@@ -588,6 +588,64 @@ pub fn reorganize_elements_id(plan: PlanRef) -> PlanRef {
     plan
 }
 
+/// Estimated cost properties of a stream plan node, displayed with `EXPLAIN (COSTS)`.
+///
+/// Without statistics on the stream inputs, the estimates are qualitative. The state size tells
+/// whether the operator keeps no state at all, a bounded amount, or state linear in the number of
+/// input rows, and the amplification factor tells how many output rows a single input row may
+/// produce at most. Together with the append-only property of the output, they allow comparing
+/// plan variants before committing to an expensive backfill.
+fn stream_cost_fields<'a>(plan: &PlanRef) -> Vec<(Str<'a>, Pretty<'a>)> {
+    use PlanNodeType::*;
+
+    let inputs_append_only = plan.inputs().iter().all(|input| input.append_only());
+
+    let state_size = match plan.node_type() {
+        StreamMaterialize
+        | StreamHashJoin
+        | StreamDeltaJoin
+        | StreamHashAgg
+        | StreamDynamicFilter
+        | StreamGroupTopN
+        | StreamDedup
+        | StreamUpsertNormalize
+        | StreamOverWindow
+        | StreamEowcOverWindow
+        | StreamEowcSort => "linear",
+        // With an append-only input the state of aggregation and `TopN` is bounded, otherwise
+        // the input has to be materialized to handle retractions.
+        StreamSimpleAgg | StreamTopN => {
+            if inputs_append_only {
+                "bounded"
+            } else {
+                "linear"
+            }
+        }
+        // Scans and sources only persist their progress, `Now` and the watermark filter a
+        // single value per vnode.
+        StreamTableScan | StreamCdcTableScan | StreamSource | StreamFsFetch | StreamNow
+        | StreamWatermarkFilter => "bounded",
+        _ => "none",
+    };
+
+    let amplification = match plan.node_type() {
+        // Each input row may match any number of rows on the other side, each input row falls
+        // into `window_size / window_slide` windows, and an update of the dynamic filter bound
+        // may re-emit a large part of the rows seen so far.
+        StreamHashJoin | StreamDeltaJoin | StreamTemporalJoin | StreamHopWindow | StreamExpand
+        | StreamProjectSet | StreamDynamicFilter => "n",
+        // Updating an existing group retracts the old value before emitting the new one.
+        StreamHashAgg | StreamSimpleAgg | StreamTopN | StreamGroupTopN | StreamOverWindow => "2",
+        _ => "1",
+    };
+
+    vec![
+        ("append_only".into(), Pretty::debug(&plan.append_only())),
+        ("state_size".into(), Pretty::from(state_size)),
+        ("amplification".into(), Pretty::from(amplification)),
+    ]
+}
+
 pub trait Explain {
     /// Write explain the whole plan tree.
     fn explain<'a>(&self) -> Pretty<'a>;
@@ -600,6 +658,9 @@ impl Explain for PlanRef {
     /// Write explain the whole plan tree.
     fn explain<'a>(&self) -> Pretty<'a> {
         let mut node = self.distill();
+        if self.plan_base().convention() == Convention::Stream && self.ctx().is_explain_costs() {
+            node.fields.extend(stream_cost_fields(self));
+        }
         let inputs = self.inputs();
         for input in inputs.iter().peekable() {
             node.children.push(input.explain());
@@ -824,6 +885,7 @@ mod stream_source;
 mod stream_stateless_simple_agg;
 mod stream_table_scan;
 mod stream_topn;
+mod stream_upsert_normalize;
 mod stream_values;
 mod stream_watermark_filter;
 
@@ -872,7 +934,7 @@ pub use logical_intersect::LogicalIntersect;
 pub use logical_join::LogicalJoin;
 pub use logical_limit::LogicalLimit;
 pub use logical_multi_join::{LogicalMultiJoin, LogicalMultiJoinBuilder};
-pub use logical_now::LogicalNow;
+pub use logical_now::{LogicalNow, NowMode};
 pub use logical_over_window::LogicalOverWindow;
 pub use logical_project::LogicalProject;
 pub use logical_project_set::LogicalProjectSet;
@@ -914,6 +976,7 @@ pub use stream_table_scan::StreamTableScan;
 pub use stream_temporal_join::StreamTemporalJoin;
 pub use stream_topn::StreamTopN;
 pub use stream_union::StreamUnion;
+pub use stream_upsert_normalize::StreamUpsertNormalize;
 pub use stream_values::StreamValues;
 pub use stream_watermark_filter::StreamWatermarkFilter;
 
@@ -1021,6 +1084,7 @@ macro_rules! for_all_plan_nodes {
             , { Stream, EowcSort }
             , { Stream, OverWindow }
             , { Stream, FsFetch }
+            , { Stream, UpsertNormalize }
         }
     };
 }
@@ -1130,6 +1194,7 @@ macro_rules! for_stream_plan_nodes {
             , { Stream, EowcSort }
             , { Stream, OverWindow }
             , { Stream, FsFetch }
+            , { Stream, UpsertNormalize }
         }
     };
 }
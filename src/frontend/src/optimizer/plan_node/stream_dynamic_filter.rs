@@ -22,7 +22,7 @@ use super::stream::prelude::*;
 use super::stream::StreamPlanRef;
 use super::utils::{childless_record, column_names_pretty, watermark_pretty, Distill};
 use super::{generic, ExprRewritable};
-use crate::expr::{Expr, ExprImpl};
+use crate::expr::{Expr, ExprRewriter};
 use crate::optimizer::plan_node::{PlanBase, PlanTreeNodeBinary, StreamNode};
 use crate::optimizer::PlanRef;
 use crate::stream_fragmenter::BuildFragmentGraphState;
@@ -60,20 +60,18 @@ impl StreamDynamicFilter {
     }
 
     /// 1. Check the comparator.
-    /// 2. RHS input should only have 1 columns, which is the watermark column.
+    /// 2. The first column of the RHS input is the watermark column.
     ///    We check that the watermark should be set.
+    ///
+    /// Note that a residual condition does not prevent state cleaning: a left row below
+    /// the watermark can never satisfy the driving comparison again, regardless of the
+    /// residual conjuncts.
     pub fn cleaned_by_watermark(core: &DynamicFilter<PlanRef>) -> bool {
-        let expr = core.predicate();
-        if let Some(ExprImpl::FunctionCall(function_call)) = expr.as_expr_unless_true() {
-            match function_call.func_type() {
-                ExprType::GreaterThan | ExprType::GreaterThanOrEqual => {
-                    let rhs_input = core.right();
-                    rhs_input.watermark_columns().contains(0)
-                }
-                _ => false,
+        match core.comparator() {
+            ExprType::GreaterThan | ExprType::GreaterThanOrEqual => {
+                core.right().watermark_columns().contains(0)
             }
-        } else {
-            false
+            _ => false,
         }
     }
 }
@@ -120,7 +118,12 @@ impl StreamNode for StreamDynamicFilter {
         let cleaned_by_watermark = self.cleaned_by_watermark;
         let condition = self
             .core
-            .predicate()
+            .comparison_condition()
+            .as_expr_unless_true()
+            .map(|x| x.to_expr_proto());
+        let residual_condition = self
+            .core
+            .residual()
             .as_expr_unless_true()
             .map(|x| x.to_expr_proto());
         let left_index = self.core.left_index();
@@ -135,8 +138,19 @@ impl StreamNode for StreamDynamicFilter {
             condition,
             left_table: Some(left_table.to_internal_table_prost()),
             right_table: Some(right_table.to_internal_table_prost()),
+            residual_condition,
         })
     }
 }
 
-impl ExprRewritable for StreamDynamicFilter {}
+impl ExprRewritable for StreamDynamicFilter {
+    fn has_rewritable_expr(&self) -> bool {
+        true
+    }
+
+    fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
+        let mut core = self.core.clone();
+        core.rewrite_exprs(r);
+        Self::new(core).into()
+    }
+}
@@ -16,12 +16,13 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pretty_xmlish::XmlNode;
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::Interval;
 use risingwave_pb::stream_plan::stream_node::PbNodeBody;
 
 use super::generic::{self, GenericPlanRef, PlanAggCall};
 use super::stream::prelude::*;
 use super::utils::{childless_record, plan_node_name, watermark_pretty, Distill};
-use super::{ExprRewritable, PlanBase, PlanRef, PlanTreeNodeUnary, StreamNode};
+use super::{ExprRewritable, PlanBase, PlanRef, PlanTreeNodeUnary, StreamEowcSort, StreamNode};
 use crate::expr::ExprRewriter;
 use crate::optimizer::plan_node::stream::StreamPlanRef;
 use crate::stream_fragmenter::BuildFragmentGraphState;
@@ -118,7 +119,7 @@ impl StreamHashAgg {
 
     // TODO(rc): It'll be better to force creation of EOWC version through `new`, especially when we
     // optimize for 2-phase EOWC aggregation later.
-    pub fn to_eowc_version(&self) -> Result<PlanRef> {
+    pub fn to_eowc_version(&self, allowed_lateness: Option<Interval>) -> Result<PlanRef> {
         let input = self.input();
         let wtmk_group_key = self.core.watermark_group_key(input.watermark_columns());
 
@@ -131,13 +132,16 @@ impl StreamHashAgg {
             .into());
         }
 
-        Ok(Self::new_with_eowc(
-            self.core.clone(),
-            self.vnode_col_idx,
-            self.row_count_idx,
-            true,
-        )
-        .into())
+        let mut core = self.core.clone();
+        if allowed_lateness.is_some() {
+            // Buffer the input rows until the watermark has exceeded them by the allowed
+            // lateness, so that rows arriving within the allowance are still aggregated
+            // before the window is finalized.
+            let sort = StreamEowcSort::new(input, wtmk_group_key[0], allowed_lateness)?;
+            core.input = sort.into();
+        }
+
+        Ok(Self::new_with_eowc(core, self.vnode_col_idx, self.row_count_idx, true).into())
     }
 }
 
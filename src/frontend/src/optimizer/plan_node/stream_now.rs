@@ -14,14 +14,16 @@
 
 use fixedbitset::FixedBitSet;
 use pretty_xmlish::XmlNode;
-use risingwave_common::catalog::{Field, Schema};
-use risingwave_common::types::DataType;
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::value_encoding::DatumToProtoExt;
+use risingwave_pb::stream_plan::now_node::PbMode as PbNowMode;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
-use risingwave_pb::stream_plan::NowNode;
+use risingwave_pb::stream_plan::{NowNode, PbNowModeGenerateSeries, PbNowModeUpdateCurrent};
 
+use super::logical_now::NowMode;
 use super::stream::prelude::*;
 use super::utils::{childless_record, Distill, TableCatalogBuilder};
-use super::{ExprRewritable, LogicalNow, PlanBase, StreamNode};
+use super::{ExprRewritable, PlanBase, StreamNode};
 use crate::optimizer::plan_node::utils::column_names_pretty;
 use crate::optimizer::property::{Distribution, FunctionalDependencySet};
 use crate::stream_fragmenter::BuildFragmentGraphState;
@@ -30,16 +32,12 @@ use crate::OptimizerContextRef;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StreamNow {
     pub base: PlanBase<Stream>,
+    mode: NowMode,
 }
 
 impl StreamNow {
-    pub fn new(_logical: LogicalNow, ctx: OptimizerContextRef) -> Self {
-        let schema = Schema::new(vec![Field {
-            data_type: DataType::Timestamptz,
-            name: String::from("now"),
-            sub_fields: vec![],
-            type_name: String::default(),
-        }]);
+    pub fn new(mode: NowMode, ctx: OptimizerContextRef) -> Self {
+        let schema = mode.schema();
         let mut watermark_columns = FixedBitSet::with_capacity(1);
         watermark_columns.set(0, true);
         let base = PlanBase::new_stream(
@@ -48,21 +46,23 @@ impl StreamNow {
             Some(vec![]),
             FunctionalDependencySet::default(),
             Distribution::Single,
-            false,
+            matches!(mode, NowMode::GenerateSeries { .. }),
             false, // TODO(rc): derive EOWC property from input
             watermark_columns,
         );
-        Self { base }
+        Self { base, mode }
     }
 }
 
 impl Distill for StreamNow {
     fn distill<'a>(&self) -> XmlNode<'a> {
-        let vec = if self.base.ctx().is_explain_verbose() {
-            vec![("output", column_names_pretty(self.schema()))]
-        } else {
-            vec![]
-        };
+        let mut vec = Vec::with_capacity(2);
+        if let NowMode::GenerateSeries { .. } = &self.mode {
+            vec.push(("mode", "generate_series".into()));
+        }
+        if self.base.ctx().is_explain_verbose() {
+            vec.push(("output", column_names_pretty(self.schema())));
+        }
 
         childless_record("StreamNow", vec)
     }
@@ -85,6 +85,18 @@ impl StreamNode for StreamNow {
             .with_id(state.gen_table_id_wrapped());
         NodeBody::Now(NowNode {
             state_table: Some(table_catalog.to_internal_table_prost()),
+            mode: Some(match &self.mode {
+                NowMode::UpdateCurrent => PbNowMode::UpdateCurrent(PbNowModeUpdateCurrent {}),
+                NowMode::GenerateSeries {
+                    start_timestamp,
+                    interval,
+                } => PbNowMode::GenerateSeries(PbNowModeGenerateSeries {
+                    start_timestamp: Some(
+                        Some(ScalarImpl::Timestamptz(*start_timestamp)).to_protobuf(),
+                    ),
+                    interval: Some(Some(ScalarImpl::Interval(*interval)).to_protobuf()),
+                }),
+            }),
         })
     }
 }
@@ -37,7 +37,14 @@ impl StreamOverWindow {
         assert!(core.funcs_have_same_partition_and_order());
 
         let input = &core.input;
-        let watermark_columns = FixedBitSet::with_capacity(core.output_len());
+        let mut watermark_columns = FixedBitSet::with_capacity(core.output_len());
+        // The executor only propagates watermark on the first partition key column, which is the
+        // first state table pk column, for state cleaning.
+        if let Some(&first_part_key) = core.partition_key_indices().first()
+            && input.watermark_columns().contains(first_part_key)
+        {
+            watermark_columns.insert(first_part_key);
+        }
 
         let base = PlanBase::new_stream_with_core(
             &core,
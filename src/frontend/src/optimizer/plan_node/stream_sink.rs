@@ -14,6 +14,7 @@
 
 use std::assert_matches::assert_matches;
 use std::io::{Error, ErrorKind};
+use std::num::{NonZeroU32, NonZeroUsize};
 
 use anyhow::anyhow;
 use fixedbitset::FixedBitSet;
@@ -30,8 +31,10 @@ use risingwave_connector::match_sink_name_str;
 use risingwave_connector::sink::catalog::desc::SinkDesc;
 use risingwave_connector::sink::catalog::{SinkFormat, SinkFormatDesc, SinkId, SinkType};
 use risingwave_connector::sink::{
-    SinkError, CONNECTOR_TYPE_KEY, SINK_TYPE_APPEND_ONLY, SINK_TYPE_DEBEZIUM, SINK_TYPE_OPTION,
-    SINK_TYPE_UPSERT, SINK_USER_FORCE_APPEND_ONLY_OPTION,
+    SinkError, CONNECTOR_TYPE_KEY, SINK_MAX_CONCURRENCY_OPTION, SINK_ON_ERROR_OPTION,
+    SINK_ON_ERROR_RETRY, SINK_ON_ERROR_SKIP, SINK_RATE_LIMIT_OPTION, SINK_SCHEMA_CHANGE_BLOCK,
+    SINK_SCHEMA_CHANGE_OPTION, SINK_SCHEMA_CHANGE_PERMIT, SINK_TYPE_APPEND_ONLY,
+    SINK_TYPE_DEBEZIUM, SINK_TYPE_OPTION, SINK_TYPE_UPSERT, SINK_USER_FORCE_APPEND_ONLY_OPTION,
 };
 use risingwave_pb::stream_plan::stream_node::PbNodeBody;
 use tracing::info;
@@ -138,6 +141,9 @@ impl StreamSink {
     ) -> Result<(PlanRef, SinkDesc)> {
         let sink_type =
             Self::derive_sink_type(input.append_only(), &properties, format_desc.as_ref())?;
+        Self::validate_error_policy(&properties)?;
+        Self::validate_throttle(&properties)?;
+        Self::validate_schema_change_policy(&properties)?;
         let (pk, _) = derive_pk(input.clone(), user_order_by, &columns);
         let downstream_pk = Self::parse_downstream_pk(&columns, properties.get(DOWNSTREAM_PK_KEY))?;
 
@@ -232,6 +238,67 @@ impl StreamSink {
         Ok(properties.value_eq_ignore_case(SINK_USER_FORCE_APPEND_ONLY_OPTION, "true"))
     }
 
+    fn validate_error_policy(properties: &WithOptions) -> Result<()> {
+        if properties.contains_key(SINK_ON_ERROR_OPTION)
+            && !properties.value_eq_ignore_case(SINK_ON_ERROR_OPTION, SINK_ON_ERROR_RETRY)
+            && !properties.value_eq_ignore_case(SINK_ON_ERROR_OPTION, SINK_ON_ERROR_SKIP)
+        {
+            return Err(ErrorCode::SinkError(Box::new(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "`{}` must be {} or {}",
+                    SINK_ON_ERROR_OPTION, SINK_ON_ERROR_RETRY, SINK_ON_ERROR_SKIP
+                ),
+            )))
+            .into());
+        }
+        Ok(())
+    }
+
+    fn validate_schema_change_policy(properties: &WithOptions) -> Result<()> {
+        if properties.contains_key(SINK_SCHEMA_CHANGE_OPTION)
+            && !properties.value_eq_ignore_case(SINK_SCHEMA_CHANGE_OPTION, SINK_SCHEMA_CHANGE_PERMIT)
+            && !properties.value_eq_ignore_case(SINK_SCHEMA_CHANGE_OPTION, SINK_SCHEMA_CHANGE_BLOCK)
+        {
+            return Err(ErrorCode::SinkError(Box::new(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "`{}` must be {} or {}",
+                    SINK_SCHEMA_CHANGE_OPTION, SINK_SCHEMA_CHANGE_PERMIT, SINK_SCHEMA_CHANGE_BLOCK
+                ),
+            )))
+            .into());
+        }
+        Ok(())
+    }
+
+    fn validate_throttle(properties: &WithOptions) -> Result<()> {
+        let invalid = [
+            (
+                SINK_RATE_LIMIT_OPTION,
+                properties
+                    .get(SINK_RATE_LIMIT_OPTION)
+                    .is_some_and(|v| v.parse::<NonZeroU32>().is_err()),
+            ),
+            (
+                SINK_MAX_CONCURRENCY_OPTION,
+                properties
+                    .get(SINK_MAX_CONCURRENCY_OPTION)
+                    .is_some_and(|v| v.parse::<NonZeroUsize>().is_err()),
+            ),
+        ];
+        for (option, invalid) in invalid {
+            if invalid {
+                return Err(ErrorCode::SinkError(Box::new(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("`{}` must be a positive integer", option),
+                )))
+                .into());
+            }
+        }
+        Ok(())
+    }
+
     fn derive_sink_type(
         input_append_only: bool,
         properties: &WithOptions,
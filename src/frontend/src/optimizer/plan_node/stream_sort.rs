@@ -17,12 +17,15 @@ use std::collections::HashSet;
 use fixedbitset::FixedBitSet;
 use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::catalog::FieldDisplay;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, Interval, ScalarImpl};
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_pb::stream_plan::stream_node::PbNodeBody;
 
 use super::stream::prelude::*;
 use super::utils::{childless_record, Distill, TableCatalogBuilder};
 use super::{ExprRewritable, PlanBase, PlanRef, PlanTreeNodeUnary, StreamNode};
+use crate::expr::{Expr, ExprImpl, ExprType, FunctionCall, InputRef, Literal};
 use crate::stream_fragmenter::BuildFragmentGraphState;
 use crate::TableCatalog;
 
@@ -32,22 +35,39 @@ pub struct StreamEowcSort {
 
     input: PlanRef,
     sort_column_index: usize,
+    /// Tolerance for late rows in Emit-On-Window-Close mode. The watermark used to release
+    /// buffered rows is delayed by this duration, so rows arriving within the allowance are
+    /// still emitted in order instead of being finalized too early.
+    allowed_lateness: Option<Interval>,
 }
 
 impl Distill for StreamEowcSort {
     fn distill<'a>(&self) -> XmlNode<'a> {
-        let fields = vec![(
+        let mut fields = vec![(
             "sort_column",
             Pretty::display(&FieldDisplay(&self.input.schema()[self.sort_column_index])),
         )];
+        if let Some(lateness) = &self.allowed_lateness {
+            fields.push(("allowed_lateness", Pretty::display(lateness)));
+        }
         childless_record("StreamEowcSort", fields)
     }
 }
 
 impl StreamEowcSort {
-    pub fn new(input: PlanRef, sort_column_index: usize) -> Self {
+    pub fn new(
+        input: PlanRef,
+        sort_column_index: usize,
+        allowed_lateness: Option<Interval>,
+    ) -> Result<Self> {
         assert!(input.watermark_columns().contains(sort_column_index));
 
+        if let Some(lateness) = allowed_lateness {
+            // Fail early if the lateness cannot be applied to the sort column.
+            let data_type = input.schema()[sort_column_index].data_type();
+            Self::derive_lateness_expr(sort_column_index, data_type, lateness)?;
+        }
+
         let schema = input.schema().clone();
         let stream_key = input.stream_key().map(|v| v.to_vec());
         let fd_set = input.functional_dependency().clone();
@@ -64,10 +84,37 @@ impl StreamEowcSort {
             true,
             watermark_columns,
         );
-        Self {
+        Ok(Self {
             base,
             input,
             sort_column_index,
+            allowed_lateness,
+        })
+    }
+
+    /// Derive the expression to delay the watermark on the sort column by the allowed lateness.
+    /// This fails if subtracting an interval from the sort column is not supported or changes
+    /// the column type.
+    fn derive_lateness_expr(
+        sort_column_index: usize,
+        data_type: DataType,
+        lateness: Interval,
+    ) -> Result<ExprImpl> {
+        let expr = FunctionCall::new(
+            ExprType::Subtract,
+            vec![
+                InputRef::new(sort_column_index, data_type.clone()).into(),
+                Literal::new(Some(ScalarImpl::Interval(lateness)), DataType::Interval).into(),
+            ],
+        );
+        match expr {
+            Ok(expr) if expr.return_type() == data_type => Ok(expr.into()),
+            _ => Err(ErrorCode::NotSupported(
+                format!("`ALLOWED LATENESS` on watermark column of type {}", data_type),
+                "Please make sure the watermark column is of type timestamp or timestamptz"
+                    .to_string(),
+            )
+            .into()),
         }
     }
 
@@ -111,7 +158,8 @@ impl PlanTreeNodeUnary for StreamEowcSort {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        Self::new(input, self.sort_column_index)
+        Self::new(input, self.sort_column_index, self.allowed_lateness)
+            .expect("the new input should be compatible with the old one")
     }
 }
 
@@ -127,6 +175,12 @@ impl StreamNode for StreamEowcSort {
                     .to_internal_table_prost(),
             ),
             sort_column_index: self.sort_column_index as _,
+            lateness_expr: self.allowed_lateness.map(|lateness| {
+                let data_type = self.input.schema()[self.sort_column_index].data_type();
+                Self::derive_lateness_expr(self.sort_column_index, data_type, lateness)
+                    .expect("lateness expr should be derivable as checked in `new`")
+                    .to_expr_proto()
+            }),
         })
     }
 }
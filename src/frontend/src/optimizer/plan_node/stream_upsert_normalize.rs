@@ -0,0 +1,111 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use pretty_xmlish::{Pretty, XmlNode};
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_pb::stream_plan::stream_node::PbNodeBody;
+use risingwave_pb::stream_plan::UpsertNormalizeNode;
+
+use super::stream::prelude::*;
+use super::utils::{childless_record, Distill, TableCatalogBuilder};
+use super::{ExprRewritable, PlanBase, PlanRef, PlanTreeNodeUnary, StreamNode};
+use crate::stream_fragmenter::BuildFragmentGraphState;
+use crate::TableCatalog;
+
+/// [`StreamUpsertNormalize`] normalizes the output of an upsert source into a proper
+/// changelog. It keeps the latest row of each key in a state table, so that an upsert
+/// overwriting an existing key can be expanded into an update, and a tombstone can be
+/// expanded into a deletion of the previously stored row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StreamUpsertNormalize {
+    pub base: PlanBase<Stream>,
+    input: PlanRef,
+    key_indices: Vec<usize>,
+}
+
+impl StreamUpsertNormalize {
+    pub fn new(input: PlanRef, key_indices: Vec<usize>) -> Self {
+        let base = PlanBase::new_stream(
+            input.ctx(),
+            input.schema().clone(),
+            Some(key_indices.clone()),
+            input.functional_dependency().clone(),
+            input.distribution().clone(),
+            // The output contains retractions for overwritten and deleted keys.
+            false,
+            input.emit_on_window_close(),
+            input.watermark_columns().clone(),
+        );
+        Self {
+            base,
+            input,
+            key_indices,
+        }
+    }
+
+    pub fn infer_internal_table_catalog(&self) -> TableCatalog {
+        let schema = self.base.schema();
+        let mut builder =
+            TableCatalogBuilder::new(self.base.ctx().with_options().internal_table_subset());
+
+        schema.fields().iter().for_each(|field| {
+            builder.add_column(field);
+        });
+
+        self.key_indices.iter().for_each(|idx| {
+            builder.add_order_column(*idx, OrderType::ascending());
+        });
+
+        let read_prefix_len_hint = builder.get_current_pk_len();
+
+        builder.build(
+            self.base.distribution().dist_column_indices().to_vec(),
+            read_prefix_len_hint,
+        )
+    }
+}
+
+impl Distill for StreamUpsertNormalize {
+    fn distill<'a>(&self) -> XmlNode<'a> {
+        let fields = vec![("key", Pretty::debug(&self.key_indices))];
+        childless_record("StreamUpsertNormalize", fields)
+    }
+}
+
+impl PlanTreeNodeUnary for StreamUpsertNormalize {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.key_indices.clone())
+    }
+}
+
+impl_plan_tree_node_for_unary! { StreamUpsertNormalize }
+
+impl StreamNode for StreamUpsertNormalize {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> PbNodeBody {
+        let table_catalog = self
+            .infer_internal_table_catalog()
+            .with_id(state.gen_table_id_wrapped());
+        PbNodeBody::UpsertNormalize(UpsertNormalizeNode {
+            state_table: Some(table_catalog.to_internal_table_prost()),
+            key_indices: self.key_indices.iter().map(|idx| *idx as _).collect_vec(),
+        })
+    }
+}
+
+impl ExprRewritable for StreamUpsertNormalize {}
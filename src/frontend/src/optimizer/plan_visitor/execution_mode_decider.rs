@@ -17,6 +17,10 @@ use crate::optimizer::plan_node::{BatchLimit, BatchSeqScan, BatchValues, PlanTre
 use crate::optimizer::plan_visitor::PlanVisitor;
 use crate::PlanRef;
 
+/// Maximum number of rows, according to the table statistics reported by storage, for a
+/// full scan of a table to still run in local mode.
+const LOCAL_MODE_MAX_SCAN_ROWS: i64 = 1000;
+
 #[derive(Debug, Clone, Default)]
 pub struct ExecutionModeDecider {}
 
@@ -26,6 +30,20 @@ impl ExecutionModeDecider {
         let mut decider = ExecutionModeDecider {};
         decider.visit(plan)
     }
+
+    /// Whether the scanned table is known from the storage-reported statistics to contain
+    /// at most [`LOCAL_MODE_MAX_SCAN_ROWS`] rows, so that even a full scan of it is cheap
+    /// enough for local execution.
+    fn is_tiny_table(batch_seq_scan: &BatchSeqScan) -> bool {
+        let core = batch_seq_scan.core();
+        core.ctx
+            .table_stats()
+            .table_stats
+            .get(&core.table_desc.table_id.table_id())
+            .map_or(false, |stats| {
+                stats.total_key_count <= LOCAL_MODE_MAX_SCAN_ROWS
+            })
+    }
 }
 
 impl PlanVisitor for ExecutionModeDecider {
@@ -37,16 +55,18 @@ impl PlanVisitor for ExecutionModeDecider {
         Merge(|a, b| a & b)
     }
 
-    /// Point select, index lookup and two side bound range scan.
+    /// Point select, index lookup and two side bound range scan, as well as scans of
+    /// tables known from the statistics to be tiny.
     /// select * from t where id = 1
     /// select * from t where k = 1
     /// select * from t where id between 1 and 5
     fn visit_batch_seq_scan(&mut self, batch_seq_scan: &BatchSeqScan) -> bool {
-        !batch_seq_scan.scan_ranges().is_empty()
+        (!batch_seq_scan.scan_ranges().is_empty()
             && batch_seq_scan
                 .scan_ranges()
                 .iter()
-                .all(|x| x.has_eq_conds() || x.two_side_bound())
+                .all(|x| x.has_eq_conds() || x.two_side_bound()))
+            || Self::is_tiny_table(batch_seq_scan)
     }
 
     /// Simple value select.
@@ -0,0 +1,79 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DefaultBehavior, Merge};
+use crate::optimizer::plan_node::{
+    BatchDelete, BatchExchange, BatchInsert, BatchLookupJoin, BatchSeqScan, BatchUpdate,
+    BatchValues,
+};
+use crate::optimizer::plan_visitor::PlanVisitor;
+use crate::PlanRef;
+
+/// Decides whether a batch plan only reads data available in the frontend itself, i.e.
+/// system catalog tables and constant values, so that it can be executed directly in the
+/// frontend without going through the batch task scheduler.
+#[derive(Debug, Clone, Default)]
+pub struct FrontendOnlyVisitor {}
+
+impl FrontendOnlyVisitor {
+    pub fn frontend_only(plan: PlanRef) -> bool {
+        let mut visitor = FrontendOnlyVisitor {};
+        visitor.visit(plan)
+    }
+}
+
+impl PlanVisitor for FrontendOnlyVisitor {
+    type Result = bool;
+
+    type DefaultBehavior = impl DefaultBehavior<Self::Result>;
+
+    fn default_behavior() -> Self::DefaultBehavior {
+        // Unhandled leaf nodes, e.g. source scans, yield `false`.
+        Merge(|a, b| a & b)
+    }
+
+    fn visit_batch_seq_scan(&mut self, batch_seq_scan: &BatchSeqScan) -> bool {
+        batch_seq_scan.core().is_sys_table()
+    }
+
+    fn visit_batch_values(&mut self, _batch_values: &BatchValues) -> bool {
+        true
+    }
+
+    /// An exchange implies the plan is split into multiple stages, so the part below it
+    /// would not run in the frontend.
+    fn visit_batch_exchange(&mut self, _batch_exchange: &BatchExchange) -> bool {
+        false
+    }
+
+    /// The inner side of a lookup join reads a user table, which is not visited as an
+    /// input of the plan node.
+    fn visit_batch_lookup_join(&mut self, _batch_lookup_join: &BatchLookupJoin) -> bool {
+        false
+    }
+
+    // DML is executed on the compute nodes, where the DML managers live.
+
+    fn visit_batch_insert(&mut self, _batch_insert: &BatchInsert) -> bool {
+        false
+    }
+
+    fn visit_batch_delete(&mut self, _batch_delete: &BatchDelete) -> bool {
+        false
+    }
+
+    fn visit_batch_update(&mut self, _batch_update: &BatchUpdate) -> bool {
+        false
+    }
+}
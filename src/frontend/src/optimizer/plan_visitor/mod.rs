@@ -27,6 +27,8 @@ pub use input_ref_validator::*;
 
 mod execution_mode_decider;
 pub use execution_mode_decider::*;
+mod frontend_only_visitor;
+pub use frontend_only_visitor::*;
 mod temporal_join_validator;
 pub use temporal_join_validator::*;
 mod relation_collector_visitor;
@@ -37,6 +39,8 @@ mod side_effect_visitor;
 pub use side_effect_visitor::*;
 mod cardinality_visitor;
 pub use cardinality_visitor::*;
+mod row_count_estimator;
+pub use row_count_estimator::*;
 
 use crate::for_all_plan_nodes;
 use crate::optimizer::plan_node::*;
@@ -0,0 +1,153 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::plan_common::JoinType;
+
+use super::{DefaultBehavior, DefaultValue, PlanVisitor};
+use crate::optimizer::plan_node::generic::TopNLimit;
+use crate::optimizer::plan_node::{self, PlanTreeNode, PlanTreeNodeBinary, PlanTreeNodeUnary};
+use crate::optimizer::plan_visitor::PlanRef;
+
+/// Assumed selectivity of a predicate when no column-level statistics are available.
+const DEFAULT_FILTER_SELECTIVITY: f64 = 0.1;
+
+/// A visitor that estimates the number of rows a plan node yields, based on the table
+/// statistics reported by storage.
+///
+/// The row count of a table is the number of distinct primary keys counted by compaction
+/// (`total_key_count` in `HummockVersionStats`), persisted on the meta node and pushed to
+/// the frontend on every epoch commit and compaction. It thus also serves as the NDV of the
+/// primary key, which [`Self::visit_logical_join`] exploits.
+///
+/// Unlike [`super::CardinalityVisitor`], the estimates here are not guarantees: the statistics
+/// may lag behind the data. They must only be used for heuristics such as join ordering or
+/// execution mode selection, never for rewrites that rely on the cardinality for correctness.
+pub struct RowCountEstimator;
+
+impl RowCountEstimator {
+    /// Estimate the number of rows yielded by the plan, or `None` if unknown.
+    pub fn estimate(plan: PlanRef) -> Option<f64> {
+        RowCountEstimator.visit(plan)
+    }
+}
+
+impl PlanVisitor for RowCountEstimator {
+    type Result = Option<f64>;
+
+    type DefaultBehavior = impl DefaultBehavior<Self::Result>;
+
+    fn default_behavior() -> Self::DefaultBehavior {
+        // returns unknown row count for default behavior, so that the callers fall back to
+        // their statistics-free heuristics
+        DefaultValue
+    }
+
+    fn visit_logical_scan(&mut self, plan: &plan_node::LogicalScan) -> Option<f64> {
+        if plan.is_sys_table() {
+            return None;
+        }
+        let ctx = plan.ctx();
+        let row_count = ctx
+            .table_stats()
+            .table_stats
+            .get(&plan.table_desc().table_id.table_id())
+            .map(|stats| stats.total_key_count as f64)?;
+        if plan.predicate().always_true() {
+            Some(row_count)
+        } else {
+            Some(row_count * DEFAULT_FILTER_SELECTIVITY)
+        }
+    }
+
+    fn visit_logical_values(&mut self, plan: &plan_node::LogicalValues) -> Option<f64> {
+        Some(plan.rows().len() as f64)
+    }
+
+    fn visit_logical_filter(&mut self, plan: &plan_node::LogicalFilter) -> Option<f64> {
+        Some(self.visit(plan.input())? * DEFAULT_FILTER_SELECTIVITY)
+    }
+
+    fn visit_logical_project(&mut self, plan: &plan_node::LogicalProject) -> Option<f64> {
+        self.visit(plan.input())
+    }
+
+    fn visit_logical_share(&mut self, plan: &plan_node::LogicalShare) -> Option<f64> {
+        self.visit(plan.input())
+    }
+
+    fn visit_logical_limit(&mut self, plan: &plan_node::LogicalLimit) -> Option<f64> {
+        let limit = plan.limit() as f64;
+        Some(self.visit(plan.input()).map_or(limit, |rows| rows.min(limit)))
+    }
+
+    fn visit_logical_top_n(&mut self, plan: &plan_node::LogicalTopN) -> Option<f64> {
+        match plan.limit_attr() {
+            TopNLimit::Simple(limit) => {
+                let limit = limit as f64;
+                Some(self.visit(plan.input()).map_or(limit, |rows| rows.min(limit)))
+            }
+            // `WITH TIES` may yield more than `limit` rows, only bound it by the input.
+            TopNLimit::WithTies(_) => self.visit(plan.input()),
+        }
+    }
+
+    fn visit_logical_agg(&mut self, plan: &plan_node::LogicalAgg) -> Option<f64> {
+        if plan.group_key().is_empty() {
+            Some(1.0)
+        } else {
+            // Without the NDV of the group key, use the input row count as an upper bound.
+            self.visit(plan.input())
+        }
+    }
+
+    fn visit_logical_union(&mut self, plan: &plan_node::LogicalUnion) -> Option<f64> {
+        let mut total = 0.0;
+        for input in plan.inputs() {
+            total += self.visit(input)?;
+        }
+        // For `UNION` without `ALL`, the sum is still a valid upper bound.
+        Some(total)
+    }
+
+    fn visit_logical_expand(&mut self, plan: &plan_node::LogicalExpand) -> Option<f64> {
+        Some(self.visit(plan.input())? * plan.column_subsets().len() as f64)
+    }
+
+    fn visit_logical_now(&mut self, _plan: &plan_node::LogicalNow) -> Option<f64> {
+        Some(1.0)
+    }
+
+    fn visit_logical_join(&mut self, plan: &plan_node::LogicalJoin) -> Option<f64> {
+        let left = self.visit(plan.left());
+        let right = self.visit(plan.right());
+
+        match plan.join_type() {
+            JoinType::Unspecified => unreachable!(),
+
+            // Take the larger side's row count as the NDV of the join key, which is exact
+            // when the join key is that side's primary key.
+            JoinType::Inner => {
+                let (left, right) = (left?, right?);
+                Some(left * right / left.max(right).max(1.0))
+            }
+
+            // Approximate with the preserved side, assuming the join key is a key of the
+            // other side.
+            JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti => left,
+            JoinType::RightOuter | JoinType::RightSemi | JoinType::RightAnti => right,
+
+            JoinType::FullOuter => Some(left? + right?),
+        }
+    }
+}
@@ -53,7 +53,7 @@ use risingwave_common::catalog::{FieldDisplay, Schema, TableId};
 use risingwave_common::error::Result;
 use risingwave_common::hash::ParallelUnitId;
 use risingwave_pb::batch_plan::exchange_info::{
-    ConsistentHashInfo, Distribution as DistributionPb, DistributionMode, HashInfo,
+    BroadcastInfo, ConsistentHashInfo, Distribution as DistributionPb, DistributionMode, HashInfo,
 };
 use risingwave_pb::batch_plan::ExchangeInfo;
 
@@ -139,7 +139,9 @@ impl Distribution {
                 }
                 // TODO: add round robin distribution
                 Distribution::SomeShard => None,
-                Distribution::Broadcast => None,
+                Distribution::Broadcast => Some(DistributionPb::BroadcastInfo(BroadcastInfo {
+                    count: output_count,
+                })),
                 Distribution::UpstreamHashShard(key, table_id) => {
                     assert!(
                         !key.is_empty(),
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 pub(crate) mod batch_project_merge_rule;
+pub(crate) mod stats_join_ordering_rule;
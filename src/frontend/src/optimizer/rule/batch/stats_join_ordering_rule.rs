@@ -0,0 +1,38 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::optimizer::{BoxedRule, PlanRef, Rule};
+
+/// Reorders a multi join into a left deep join using the table statistics reported by
+/// storage, joining the inputs with small estimated row counts first.
+///
+/// Bails out when no statistics are available, so that
+/// [`crate::optimizer::rule::LeftDeepTreeJoinOrderingRule`] applies its statistics-free
+/// heuristic ordering instead.
+pub struct StatsJoinOrderingRule {}
+
+impl Rule for StatsJoinOrderingRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let join = plan.as_logical_multi_join()?;
+        let join_ordering = join.stats_ordering()?;
+        let left_deep_join = join.as_reordered_left_deep_join(&join_ordering);
+        Some(left_deep_join)
+    }
+}
+
+impl StatsJoinOrderingRule {
+    pub fn create() -> BoxedRule {
+        Box::new(StatsJoinOrderingRule {})
+    }
+}
@@ -60,6 +60,11 @@ impl Rule for IndexDeltaJoinRule {
                     continue;
                 }
 
+                // A partial index does not contain all rows of the primary table.
+                if index.is_partial() {
+                    continue;
+                }
+
                 let p2s_mapping = index.primary_to_secondary_mapping();
 
                 // 1. Check if distribution keys are the same.
@@ -112,6 +112,12 @@ impl Rule for IndexSelectionRule {
         let mut min_cost = primary_cost.clone();
 
         for index in indexes {
+            // A partial index is only applicable when the scan predicate implies the index
+            // predicate, otherwise the index lacks some of the rows to return.
+            if !index.predicate_implied_by(logical_scan.predicate()) {
+                continue;
+            }
+
             if let Some(index_scan) = logical_scan.to_index_scan_if_index_covered(index) {
                 let index_cost = self.estimate_table_scan_cost(
                     &index_scan,
@@ -522,6 +528,14 @@ impl IndexSelectionRule {
         let mut result = vec![];
 
         for index in logical_scan.indexes() {
+            // `conjunctions` hold for every row this arm returns, so a partial index can serve
+            // the arm only if they imply the index predicate.
+            if !index.predicate_implied_by(&Condition {
+                conjunctions: conjunctions.to_vec(),
+            }) {
+                continue;
+            }
+
             if column_index.is_some() {
                 assert_eq!(conjunctions.len(), 1);
                 let p2s_mapping = index.primary_to_secondary_mapping();
@@ -15,6 +15,7 @@
 use super::super::plan_node::*;
 use super::Rule;
 use crate::optimizer::rule::BoxedRule;
+use crate::optimizer::PlanHint;
 
 /// Reorders a multi join into a left deep join via the heuristic ordering
 pub struct LeftDeepTreeJoinOrderingRule {}
@@ -22,8 +23,13 @@ pub struct LeftDeepTreeJoinOrderingRule {}
 impl Rule for LeftDeepTreeJoinOrderingRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join = plan.as_logical_multi_join()?;
-        // check if join is inner and can be merged into multijoin
-        let join_ordering = join.heuristic_ordering().ok()?; // maybe panic here instead?
+        let join_ordering = if plan.ctx().has_plan_hint(PlanHint::Ordered) {
+            // Keep the order the relations appear in the `FROM` clause.
+            (0..join.inputs().len()).collect()
+        } else {
+            // check if join is inner and can be merged into multijoin
+            join.heuristic_ordering().ok()? // maybe panic here instead?
+        };
         let left_deep_join = join.as_reordered_left_deep_join(&join_ordering);
         Some(left_deep_join)
     }
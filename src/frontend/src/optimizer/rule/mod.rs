@@ -90,6 +90,7 @@ pub use top_n_on_index_rule::*;
 mod stream;
 pub use stream::bushy_tree_join_ordering_rule::*;
 pub use stream::filter_with_now_to_join_rule::*;
+pub use stream::generate_series_with_now_rule::*;
 pub use stream::stream_project_merge_rule::*;
 mod trivial_project_to_values_rule;
 pub use trivial_project_to_values_rule::*;
@@ -138,6 +139,7 @@ mod apply_limit_transpose_rule;
 pub use apply_limit_transpose_rule::*;
 mod batch;
 pub use batch::batch_project_merge_rule::*;
+pub use batch::stats_join_ordering_rule::*;
 mod common_sub_expr_extract_rule;
 pub use common_sub_expr_extract_rule::*;
 mod apply_over_window_transpose_rule;
@@ -189,6 +191,7 @@ macro_rules! for_all_rules {
             , { UnionMergeRule }
             , { DagToTreeRule }
             , { FilterWithNowToJoinRule }
+            , { GenerateSeriesWithNowRule }
             , { TopNOnIndexRule }
             , { TrivialProjectToValuesRule }
             , { UnionInputValuesMergeRule }
@@ -221,6 +224,7 @@ macro_rules! for_all_rules {
             , { ApplyHopWindowTransposeRule }
             , { AggCallMergeRule }
             , { ValuesExtractProjectRule }
+            , { StatsJoinOrderingRule }
         }
     };
 }
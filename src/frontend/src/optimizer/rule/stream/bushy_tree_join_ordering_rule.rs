@@ -15,6 +15,7 @@
 use super::super::super::plan_node::*;
 use super::super::Rule;
 use crate::optimizer::rule::BoxedRule;
+use crate::optimizer::PlanHint;
 
 /// Reorders a multi join into a bushy tree shape join tree with a minimal height.
 pub struct BushyTreeJoinOrderingRule {}
@@ -30,6 +31,11 @@ const BUSHY_TREE_JOIN_LOWER_LIMIT: usize = 4;
 impl Rule for BushyTreeJoinOrderingRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join = plan.as_logical_multi_join()?;
+        if plan.ctx().has_plan_hint(PlanHint::Ordered) {
+            // Keep the order the relations appear in the `FROM` clause.
+            let join_ordering = (0..join.inputs().len()).collect::<Vec<_>>();
+            return Some(join.as_reordered_left_deep_join(&join_ordering));
+        }
         if join.inputs().len() >= BUSHY_TREE_JOIN_LOWER_LIMIT
             && join.inputs().len() <= BUSHY_TREE_JOIN_UPPER_LIMIT
         {
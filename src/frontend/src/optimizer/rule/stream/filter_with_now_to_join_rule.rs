@@ -20,7 +20,7 @@ use crate::expr::{
     try_derive_watermark, ExprRewriter, FunctionCall, InputRef, WatermarkDerivation,
 };
 use crate::optimizer::plan_node::generic::GenericPlanRef;
-use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalNow};
+use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalNow, NowMode};
 use crate::optimizer::rule::{BoxedRule, Rule};
 use crate::optimizer::PlanRef;
 use crate::utils::Condition;
@@ -76,7 +76,7 @@ impl Rule for FilterWithNowToJoinRule {
         for now_filter in now_filters {
             new_plan = LogicalJoin::new(
                 new_plan,
-                LogicalNow::new(plan.ctx()).into(),
+                LogicalNow::new(plan.ctx(), NowMode::UpdateCurrent).into(),
                 JoinType::LeftSemi,
                 Condition {
                     conjunctions: vec![now_filter.into()],
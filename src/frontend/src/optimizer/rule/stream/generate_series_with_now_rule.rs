@@ -0,0 +1,100 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::expr::{ExprImpl, TableFunctionType};
+use crate::optimizer::plan_node::generic::GenericPlanRef;
+use crate::optimizer::plan_node::{LogicalNow, LogicalTableFunction, NowMode};
+use crate::optimizer::rule::{BoxedRule, Rule};
+use crate::optimizer::PlanRef;
+
+/// Convert `generate_series(start, now(), interval)` to a `Now` source node, so that the series
+/// keeps growing as time goes by, instead of being evaluated only once.
+/// Only applies to stream.
+pub struct GenerateSeriesWithNowRule {}
+impl Rule for GenerateSeriesWithNowRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let ctx = plan.ctx();
+        let table_func: &LogicalTableFunction = plan.as_logical_table_function()?;
+
+        if !table_func
+            .table_function
+            .args
+            .iter()
+            .any(|arg| arg.count_nows() > 0)
+        {
+            return None;
+        }
+
+        let func = &table_func.table_function;
+        if table_func.with_ordinality
+            || !(func.function_type == TableFunctionType::GenerateSeries
+                && func.args.len() == 3
+                && func.args[0].return_type() == DataType::Timestamptz
+                && matches!(&func.args[1], ExprImpl::Now(_))
+                && func.args[2].return_type() == DataType::Interval)
+        {
+            ctx.warn_to_user(
+                "`now()` is only supported in `generate_series(timestamptz, now(), interval)` for streaming queries",
+            );
+            return None;
+        }
+
+        let Some(Ok(Some(ScalarImpl::Timestamptz(start_timestamp)))) =
+            func.args[0].try_fold_const()
+        else {
+            ctx.warn_to_user(
+                "`start` of `generate_series` ending with `now()` must be a constant `timestamptz` value",
+            );
+            return None;
+        };
+        let Some(Ok(Some(ScalarImpl::Interval(interval)))) = func.args[2].try_fold_const() else {
+            ctx.warn_to_user(
+                "`step` of `generate_series` ending with `now()` must be a constant `interval` value",
+            );
+            return None;
+        };
+
+        // Without session time zone, we cannot add month/day part of an interval to a
+        // `timestamptz` value. See #5826.
+        if interval.months() != 0 || interval.days() != 0 {
+            ctx.warn_to_user(
+                "`step` of `generate_series` ending with `now()` must not have month/day part",
+            );
+            return None;
+        }
+        if interval.usecs() <= 0 {
+            ctx.warn_to_user("`step` of `generate_series` ending with `now()` must be positive");
+            return None;
+        }
+
+        Some(
+            LogicalNow::new(
+                plan.ctx(),
+                NowMode::GenerateSeries {
+                    start_timestamp,
+                    interval,
+                },
+            )
+            .into(),
+        )
+    }
+}
+
+impl GenerateSeriesWithNowRule {
+    pub fn create() -> BoxedRule {
+        Box::new(Self {})
+    }
+}
@@ -14,4 +14,5 @@
 
 pub(crate) mod bushy_tree_join_ordering_rule;
 pub(crate) mod filter_with_now_to_join_rule;
+pub(crate) mod generate_series_with_now_rule;
 pub(crate) mod stream_project_merge_rule;
@@ -35,6 +35,7 @@ use crate::optimizer::plan_node::{
     LogicalTopN, LogicalValues, PlanAggCall, PlanRef,
 };
 use crate::optimizer::property::Order;
+use crate::optimizer::PlanHint;
 use crate::planner::Planner;
 use crate::utils::{Condition, IndexSet};
 
@@ -48,11 +49,23 @@ impl Planner {
             group_by,
             mut having,
             distinct,
+            hints,
             ..
         }: BoundSelect,
         extra_order_exprs: Vec<ExprImpl>,
         order: &[ColumnOrder],
     ) -> Result<PlanRef> {
+        // Register the optimizer hints on the context, so that they're visible to all
+        // optimization stages. Unknown hints are ignored with a warning.
+        for hint in hints {
+            match PlanHint::parse(&hint) {
+                Some(hint) => self.ctx.add_plan_hint(hint),
+                None => self
+                    .ctx
+                    .warn_to_user(format!("Unrecognized query hint \"{}\", ignored", hint)),
+            }
+        }
+
         // Append expressions in ORDER BY.
         if distinct.is_distinct() && !extra_order_exprs.is_empty() {
             return Err(ErrorCode::InvalidInputSyntax(
@@ -32,6 +32,7 @@ use crate::catalog::catalog_service::CatalogReader;
 use crate::scheduler::plan_fragmenter::{Query, QueryId};
 use crate::scheduler::worker_node_manager::{WorkerNodeManagerRef, WorkerNodeSelector};
 use crate::scheduler::{ExecutionContextRef, SchedulerResult};
+use crate::user::UserId;
 
 pub struct DistributedQueryStream {
     chunk_rx: tokio::sync::mpsc::Receiver<SchedulerResult<DataChunk>>,
@@ -107,6 +108,13 @@ impl QueryExecutionInfo {
         self.query_execution_map.insert(query_id, query_execution);
     }
 
+    pub fn query_num_of_user(&self, user_id: UserId) -> u64 {
+        self.query_execution_map
+            .values()
+            .filter(|query| query.user_id == user_id)
+            .count() as u64
+    }
+
     pub fn delete_query(&mut self, query_id: &QueryId) {
         self.query_execution_map.remove(query_id);
     }
@@ -166,8 +174,30 @@ impl QueryManager {
                 query_limit,
             ));
         }
+        let session = context.session();
+        let user_query_limit = session
+            .env()
+            .user_info_reader()
+            .read_guard()
+            .get_user_by_name(session.user_name())
+            .and_then(|user| (user.query_limit != 0).then_some(user.query_limit));
+        if let Some(query_limit) = user_query_limit
+            && self
+                .query_execution_info
+                .read()
+                .unwrap()
+                .query_num_of_user(session.user_id())
+                >= query_limit
+        {
+            self.query_metrics.rejected_query_counter.inc();
+            return Err(crate::scheduler::SchedulerError::UserQueryReachLimit(
+                session.user_name().to_string(),
+                query_limit,
+            ));
+        }
         let query_id = query.query_id.clone();
-        let query_execution = Arc::new(QueryExecution::new(query, context.session().id()));
+        let query_execution =
+            Arc::new(QueryExecution::new(query, session.id(), session.user_id()));
 
         // Add queries status when begin.
         context
@@ -55,6 +55,9 @@ pub enum SchedulerError {
     #[error("Reject query: the {0} query number reaches the limit: {1}")]
     QueryReachLimit(QueryMode, u64),
 
+    #[error("Reject query: the concurrent query number of user {0} reaches the limit: {1}")]
+    UserQueryReachLimit(String, u64),
+
     #[error(transparent)]
     Internal(
         #[from]
@@ -0,0 +1,143 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Direct execution of frontend-only batch queries.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use pgwire::pg_server::BoxedError;
+use risingwave_batch::executor::ExecutorBuilder;
+use risingwave_batch::task::{ShutdownToken, TaskId};
+use risingwave_common::array::DataChunk;
+use risingwave_common::error::RwError;
+use risingwave_pb::common::{batch_query_epoch, BatchQueryEpoch};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::debug;
+use tracing_futures::Instrument;
+
+use crate::scheduler::plan_fragmenter::QueryId;
+use crate::scheduler::task_context::FrontendBatchTaskContext;
+use crate::scheduler::{LocalQueryStream, SchedulerError};
+use crate::session::{FrontendEnv, SessionImpl};
+use crate::PlanRef;
+
+/// Executes a batch plan that only reads data available in the frontend itself, i.e.
+/// system catalog tables and constant values, directly in the frontend process.
+///
+/// Unlike [`super::LocalQueryExecution`], no snapshot is pinned and the plan is not
+/// fragmented, as such a plan is guaranteed to consist of a single stage that never
+/// touches the storage or the compute nodes. See
+/// [`crate::optimizer::FrontendOnlyVisitor`] for the eligibility check.
+pub struct FastPathQueryExecution {
+    query_id: QueryId,
+    plan: PlanRef,
+    front_env: FrontendEnv,
+    session: Arc<SessionImpl>,
+}
+
+impl FastPathQueryExecution {
+    pub fn new(plan: PlanRef, front_env: FrontendEnv, session: Arc<SessionImpl>) -> Self {
+        Self {
+            query_id: QueryId::default(),
+            plan,
+            front_env,
+            session,
+        }
+    }
+
+    fn shutdown_rx(&self) -> ShutdownToken {
+        self.session.reset_cancel_query_flag()
+    }
+
+    #[try_stream(ok = DataChunk, error = RwError)]
+    async fn run_inner(self) {
+        debug!(%self.query_id, "Starting to run query on the fast path");
+
+        let context =
+            FrontendBatchTaskContext::new(self.front_env.clone(), self.session.auth_context());
+
+        let task_id = TaskId {
+            query_id: self.query_id.id.clone(),
+            stage_id: 0,
+            task_id: 0,
+        };
+
+        let plan_node = self.plan.to_batch_prost();
+
+        // The plan never reads from the storage, so any epoch works.
+        let epoch = BatchQueryEpoch {
+            epoch: Some(batch_query_epoch::Epoch::Current(u64::MAX)),
+        };
+
+        let executor = ExecutorBuilder::new(
+            &plan_node,
+            &task_id,
+            context,
+            epoch,
+            self.shutdown_rx().clone(),
+        );
+        let executor = executor.build().await?;
+
+        #[for_await]
+        for chunk in executor.execute() {
+            yield chunk?;
+        }
+    }
+
+    pub fn stream_rows(self) -> LocalQueryStream {
+        let compute_runtime = self.front_env.compute_runtime();
+        let (sender, receiver) = mpsc::channel(10);
+        let shutdown_rx = self.shutdown_rx().clone();
+
+        let catalog_reader = self.front_env.catalog_reader().clone();
+        let auth_context = self.session.auth_context().clone();
+        let db_name = self.session.database().to_string();
+        let search_path = self.session.config().get_search_path().clone();
+
+        let span = tracing::info_span!("fast_path_execute", query_id = self.query_id.id);
+
+        let exec = async move {
+            let mut data_stream = self
+                .run_inner()
+                .instrument(span)
+                .map(|r| r.map_err(|e| Box::new(e) as BoxedError));
+            while let Some(mut r) = data_stream.next().await {
+                // append a query cancelled error if the query is cancelled.
+                if r.is_err() && shutdown_rx.is_cancelled() {
+                    r = Err(Box::new(SchedulerError::QueryCancelled) as BoxedError);
+                }
+                if sender.send(r).await.is_err() {
+                    tracing::info!("Receiver closed.");
+                    return;
+                }
+            }
+        };
+
+        use crate::expr::function_impl::context::{
+            AUTH_CONTEXT, CATALOG_READER, DB_NAME, SEARCH_PATH,
+        };
+
+        let exec = async move { CATALOG_READER::scope(catalog_reader, exec).await };
+        let exec = async move { DB_NAME::scope(db_name, exec).await };
+        let exec = async move { SEARCH_PATH::scope(search_path, exec).await };
+        let exec = async move { AUTH_CONTEXT::scope(auth_context, exec).await };
+
+        compute_runtime.spawn(exec);
+
+        ReceiverStream::new(receiver)
+    }
+}
@@ -26,8 +26,12 @@ mod distributed;
 pub use distributed::*;
 pub mod plan_fragmenter;
 pub use plan_fragmenter::BatchPlanFragmenter;
+mod result_cache;
+pub use result_cache::*;
 mod snapshot;
 pub use snapshot::*;
+mod fast_path;
+pub use fast_path::*;
 mod local;
 pub use local::*;
 
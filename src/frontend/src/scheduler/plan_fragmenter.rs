@@ -932,8 +932,12 @@ impl BatchPlanFragmenter {
                 let vnode_mapping = self
                     .worker_node_manager
                     .fragment_mapping(table_catalog.fragment_id)?;
-                let partitions =
-                    derive_partitions(scan_node.scan_ranges(), table_desc, &vnode_mapping);
+                let partitions = derive_partitions(
+                    scan_node.scan_ranges(),
+                    scan_node.dist_key_vnode(),
+                    table_desc,
+                    &vnode_mapping,
+                );
                 TableScanInfo::new(name, partitions)
             };
             Ok(Some(info))
@@ -994,9 +998,11 @@ impl BatchPlanFragmenter {
 }
 
 /// Try to derive the partition to read from the scan range.
-/// It can be derived if the value of the distribution key is already known.
+/// It can be derived if the value of the distribution key is already known, either from
+/// the scan range itself or from `dist_key_vnode` computed out of the predicate.
 fn derive_partitions(
     scan_ranges: &[ScanRange],
+    dist_key_vnode: Option<VirtualNode>,
     table_desc: &TableDesc,
     vnode_mapping: &ParallelUnitMapping,
 ) -> HashMap<ParallelUnitId, TablePartitionInfo> {
@@ -1004,6 +1010,21 @@ fn derive_partitions(
     let mut partitions: HashMap<ParallelUnitId, (BitmapBuilder, Vec<_>)> = HashMap::new();
 
     if scan_ranges.is_empty() {
+        // Even without a scan range, the predicate may pin the distribution key to a
+        // single vnode, e.g. when the distribution key is not a prefix of the primary
+        // key. Scan only the owner of that vnode then.
+        if let Some(vnode) = dist_key_vnode {
+            let parallel_unit_id = vnode_mapping[vnode];
+            let mut vnode_bitmap = BitmapBuilder::zeroed(num_vnodes);
+            vnode_bitmap.set(vnode.to_index(), true);
+            return HashMap::from([(
+                parallel_unit_id,
+                TablePartitionInfo {
+                    vnode_bitmap: vnode_bitmap.finish().to_protobuf(),
+                    scan_ranges: vec![],
+                },
+            )]);
+        }
         return vnode_mapping
             .to_bitmaps()
             .into_iter()
@@ -1020,10 +1041,12 @@ fn derive_partitions(
     }
 
     for scan_range in scan_ranges {
-        let vnode = scan_range.try_compute_vnode(
-            &table_desc.distribution_key,
-            &table_desc.order_column_indices(),
-        );
+        let vnode = scan_range
+            .try_compute_vnode(
+                &table_desc.distribution_key,
+                &table_desc.order_column_indices(),
+            )
+            .or(dist_key_vnode);
         match vnode {
             None => {
                 // put this scan_range to all partitions
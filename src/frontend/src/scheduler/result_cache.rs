@@ -0,0 +1,136 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use pgwire::types::Row;
+
+/// The maximum number of cached queries. Inserting beyond this limit first evicts all entries
+/// of stale epochs, and gives up caching if the cache is still full.
+const MAX_ENTRIES: usize = 256;
+
+/// A frontend-level cache for the results of read-only batch queries.
+///
+/// Repeated queries (e.g. from auto-refreshing dashboards) between two checkpoints can be
+/// answered without scheduling a batch job at all. Since the committed epoch is part of the
+/// key, an entry is implicitly invalidated once a checkpoint advances the epoch; stale
+/// entries are evicted lazily on insertion.
+///
+/// The cache is opt-in via the `rw_enable_query_result_cache` session variable.
+pub struct QueryResultCache {
+    inner: Mutex<HashMap<QueryResultCacheKey, Arc<Vec<Vec<Row>>>>>,
+}
+
+/// Cache key of a query result.
+///
+/// The cache is shared by all sessions of the frontend, so the query is identified by its
+/// resolved plan rather than the raw SQL text: the same SQL may resolve to different
+/// relations under another database or search path. The parts of the session context that
+/// still affect the rendered result — the current user and the timezone — are part of the
+/// key as well.
+#[derive(PartialEq, Eq, Hash)]
+pub struct QueryResultCacheKey {
+    /// Textual form of the resolved batch plan, with catalog ids bound.
+    pub plan: String,
+    /// The committed epoch the query reads from.
+    pub epoch: u64,
+    pub database: String,
+    pub search_path: String,
+    pub user_id: u32,
+    pub timezone: String,
+}
+
+impl Default for QueryResultCache {
+    fn default() -> Self {
+        Self {
+            inner: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl QueryResultCache {
+    /// The maximum number of rows a single query result may have to be cached. Large results
+    /// are not worth buffering in the frontend.
+    pub const MAX_ROWS_PER_ENTRY: usize = 10240;
+
+    /// Returns the cached row sets for the given key, if any.
+    pub fn get(&self, key: &QueryResultCacheKey) -> Option<Arc<Vec<Vec<Row>>>> {
+        self.inner.lock().get(key).cloned()
+    }
+
+    /// Caches the row sets for the given key. Results that are too large are silently
+    /// skipped.
+    pub fn insert(&self, key: QueryResultCacheKey, row_sets: Vec<Vec<Row>>) {
+        if row_sets.iter().map(|rows| rows.len()).sum::<usize>() > Self::MAX_ROWS_PER_ENTRY {
+            return;
+        }
+        let mut guard = self.inner.lock();
+        if guard.len() >= MAX_ENTRIES {
+            // Entries of earlier epochs can never be hit again, so evict them first.
+            let epoch = key.epoch;
+            guard.retain(|key, _| key.epoch >= epoch);
+            if guard.len() >= MAX_ENTRIES {
+                return;
+            }
+        }
+        guard.insert(key, Arc::new(row_sets));
+    }
+}
+
+pub type QueryResultCacheRef = Arc<QueryResultCache>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(plan: &str, epoch: u64) -> QueryResultCacheKey {
+        QueryResultCacheKey {
+            plan: plan.to_owned(),
+            epoch,
+            database: "dev".to_owned(),
+            search_path: "\"$user\", public".to_owned(),
+            user_id: 1,
+            timezone: "UTC".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_epoch_keyed_invalidation() {
+        let cache = QueryResultCache::default();
+        cache.insert(key("BatchValues { rows: [[1:Int32]] }", 1), vec![vec![]]);
+        assert!(cache.get(&key("BatchValues { rows: [[1:Int32]] }", 1)).is_some());
+        // A different epoch never hits the old entry.
+        assert!(cache.get(&key("BatchValues { rows: [[1:Int32]] }", 2)).is_none());
+        assert!(cache.get(&key("BatchValues { rows: [[2:Int32]] }", 1)).is_none());
+    }
+
+    #[test]
+    fn test_session_context_in_key() {
+        let cache = QueryResultCache::default();
+        cache.insert(key("BatchValues { rows: [[1:Int32]] }", 1), vec![vec![]]);
+        // A different session context never hits the entry.
+        let other_user = QueryResultCacheKey {
+            user_id: 2,
+            ..key("BatchValues { rows: [[1:Int32]] }", 1)
+        };
+        assert!(cache.get(&other_user).is_none());
+        let other_timezone = QueryResultCacheKey {
+            timezone: "Europe/London".to_owned(),
+            ..key("BatchValues { rows: [[1:Int32]] }", 1)
+        };
+        assert!(cache.get(&other_timezone).is_none());
+    }
+}
@@ -52,6 +52,7 @@ impl BatchTaskContext for FrontendBatchTaskContext {
             self.env.worker_node_manager_ref(),
             self.env.meta_client_ref(),
             self.auth_context.clone(),
+            self.env.audit_log_manager_ref(),
         ))
     }
 
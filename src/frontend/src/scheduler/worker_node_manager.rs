@@ -176,6 +176,15 @@ impl WorkerNodeManager {
             .ok_or_else(|| SchedulerError::StreamingVnodeMappingNotFound(*fragment_id))
     }
 
+    /// Returns a snapshot of all fragments' streaming vnode mappings, for introspection.
+    pub fn streaming_fragment_mappings(&self) -> HashMap<FragmentId, ParallelUnitMapping> {
+        self.inner
+            .read()
+            .unwrap()
+            .streaming_fragment_vnode_mapping
+            .clone()
+    }
+
     pub fn insert_streaming_fragment_mapping(
         &self,
         fragment_id: FragmentId,
@@ -221,6 +230,15 @@ impl WorkerNodeManager {
             .ok_or_else(|| SchedulerError::ServingVnodeMappingNotFound(fragment_id))
     }
 
+    /// Returns a snapshot of all fragments' serving vnode mappings, for introspection.
+    pub fn serving_fragment_mappings(&self) -> HashMap<FragmentId, ParallelUnitMapping> {
+        self.inner
+            .read()
+            .unwrap()
+            .serving_fragment_vnode_mapping
+            .clone()
+    }
+
     pub fn set_serving_fragment_mapping(&self, mappings: HashMap<FragmentId, ParallelUnitMapping>) {
         let mut guard = self.inner.write().unwrap();
         tracing::debug!(
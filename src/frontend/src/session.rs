@@ -23,6 +23,8 @@ use std::time::{Duration, Instant};
 use bytes::Bytes;
 use either::Either;
 use parking_lot::{Mutex, RwLock, RwLockReadGuard};
+use pgwire::error::WithSqlState;
+use pgwire::error_or_notice::SqlState;
 use pgwire::net::{Address, AddressRef};
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_message::TransactionStatus;
@@ -68,6 +70,7 @@ use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tracing::info;
 
+use crate::audit_log::{AuditLogManager, AuditLogManagerRef};
 use crate::binder::{Binder, BoundStatement, ResolveQualifiedNameError};
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
 use crate::catalog::connection_catalog::ConnectionCatalog;
@@ -89,7 +92,7 @@ use crate::scheduler::streaming_manager::{StreamingJobTracker, StreamingJobTrack
 use crate::scheduler::worker_node_manager::{WorkerNodeManager, WorkerNodeManagerRef};
 use crate::scheduler::{
     DistributedQueryMetrics, HummockSnapshotManager, HummockSnapshotManagerRef, QueryManager,
-    GLOBAL_DISTRIBUTED_QUERY_METRICS,
+    QueryResultCache, QueryResultCacheRef, GLOBAL_DISTRIBUTED_QUERY_METRICS,
 };
 use crate::telemetry::FrontendTelemetryCreator;
 use crate::user::user_authentication::md5_hash_with_salt;
@@ -136,6 +139,14 @@ pub struct FrontendEnv {
     /// Runtime for compute intensive tasks in frontend, e.g. executors in local mode,
     /// root stage in mpp mode.
     compute_runtime: Arc<BackgroundShutdownRuntime>,
+
+    /// Caches results of read-only batch queries, keyed by (SQL, epoch). Opt-in per session
+    /// via `rw_enable_query_result_cache`.
+    query_result_cache: QueryResultCacheRef,
+
+    /// Retains the most recent audited statements of this node, exposed through
+    /// `rw_catalog.rw_audit_log`. Opt-in per session via `audit_log_level`.
+    audit_log_manager: AuditLogManagerRef,
 }
 
 /// Session map identified by `(process_id, secret_key)`
@@ -183,6 +194,8 @@ impl FrontendEnv {
             source_metrics: Arc::new(SourceMetrics::default()),
             creating_streaming_job_tracker: Arc::new(creating_streaming_tracker),
             compute_runtime: Self::create_compute_runtime(),
+            query_result_cache: Arc::new(QueryResultCache::default()),
+            audit_log_manager: Arc::new(AuditLogManager::default()),
         }
     }
 
@@ -342,6 +355,8 @@ impl FrontendEnv {
                 source_metrics,
                 creating_streaming_job_tracker,
                 compute_runtime: Self::create_compute_runtime(),
+                query_result_cache: Arc::new(QueryResultCache::default()),
+                audit_log_manager: Arc::new(AuditLogManager::default()),
             },
             join_handles,
             shutdown_senders,
@@ -410,6 +425,18 @@ impl FrontendEnv {
         &self.batch_config
     }
 
+    pub fn query_result_cache(&self) -> &QueryResultCache {
+        &self.query_result_cache
+    }
+
+    pub fn audit_log_manager(&self) -> &AuditLogManager {
+        &self.audit_log_manager
+    }
+
+    pub fn audit_log_manager_ref(&self) -> AuditLogManagerRef {
+        self.audit_log_manager.clone()
+    }
+
     pub fn meta_config(&self) -> &MetaConfig {
         &self.meta_config
     }
@@ -593,6 +620,10 @@ impl SessionImpl {
         self.config_map.write().set(key, value, reporter)
     }
 
+    pub fn reset_config_report(&self, reporter: impl ConfigReporter) {
+        self.config_map.write().reset(reporter)
+    }
+
     pub fn session_id(&self) -> SessionId {
         self.id
     }
@@ -769,7 +800,8 @@ impl SessionImpl {
     ) -> std::result::Result<PgResponse<PgResponseStream>, BoxedError> {
         // Parse sql.
         let mut stmts = Parser::parse_sql(&sql)
-            .inspect_err(|e| tracing::error!("failed to parse sql:\n{}:\n{}", sql, e))?;
+            .inspect_err(|e| tracing::error!("failed to parse sql:\n{}:\n{}", sql, e))
+            .map_err(|e| pgwire::error::boxed_syntax_error(e, &sql))?;
         if stmts.is_empty() {
             return Ok(PgResponse::empty_result(
                 pgwire::pg_response::StatementType::EMPTY,
@@ -803,7 +835,8 @@ impl SessionImpl {
                 handle_fut.await
             }
         }
-        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))?;
+        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))
+        .map_err(to_pg_error)?;
         Ok(rsp)
     }
 
@@ -1011,7 +1044,8 @@ impl Session for SessionImpl {
                 handle_fut.await
             }
         }
-        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))?;
+        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))
+        .map_err(to_pg_error)?;
         Ok(rsp)
     }
 
@@ -1029,7 +1063,7 @@ impl Session for SessionImpl {
         params_types: Vec<Option<DataType>>,
     ) -> std::result::Result<PrepareStatement, BoxedError> {
         Ok(if let Some(statement) = statement {
-            handle_parse(self, statement, params_types)?
+            handle_parse(self, statement, params_types).map_err(to_pg_error)?
         } else {
             PrepareStatement::Empty
         })
@@ -1047,7 +1081,8 @@ impl Session for SessionImpl {
             params,
             param_formats,
             result_formats,
-        )?)
+        )
+        .map_err(to_pg_error)?)
     }
 
     async fn execute(
@@ -1071,7 +1106,8 @@ impl Session for SessionImpl {
                 handle_fut.await
             }
         }
-        .inspect_err(|e| tracing::error!("failed to handle execute:\n{}", e))?;
+        .inspect_err(|e| tracing::error!("failed to handle execute:\n{}", e))
+        .map_err(to_pg_error)?;
         Ok(rsp)
     }
 
@@ -1142,6 +1178,13 @@ impl Session for SessionImpl {
     }
 }
 
+/// Attaches the SQLSTATE of the error before handing it over to pgwire, so that it is reported
+/// to the client in the error response.
+fn to_pg_error(e: RwError) -> WithSqlState {
+    let state = SqlState::from_code(e.inner().sqlstate());
+    WithSqlState::new(state, Box::new(e))
+}
+
 /// Returns row description of the statement
 fn infer(bound: Option<BoundStatement>, stmt: Statement) -> Result<Vec<PgFieldDescriptor>> {
     match stmt {
@@ -17,6 +17,7 @@ use std::sync::{Arc, Weak};
 
 use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::Statement;
 
 use super::SessionImpl;
 use crate::catalog::catalog_service::CatalogWriter;
@@ -37,7 +38,7 @@ impl Id {
 }
 
 /// Transaction access mode.
-// TODO: WriteOnly, CreateDdlOnly
+// TODO: WriteOnly
 pub enum AccessMode {
     /// Read-write transaction. All operations are permitted.
     ///
@@ -50,6 +51,11 @@ pub enum AccessMode {
     /// All reads (except for the system table) are performed on a consistent snapshot acquired at
     /// the first read operation in the transaction.
     ReadOnly,
+
+    /// DDL-only transaction started with `BEGIN DDL`. `CREATE` statements are buffered in the
+    /// transaction context and applied together at `COMMIT DDL`, while other write operations
+    /// are rejected.
+    DdlOnly,
 }
 
 /// Transaction context.
@@ -64,6 +70,11 @@ pub struct Context {
     /// The snapshot of the transaction, acquired lazily at the first read operation in the
     /// transaction.
     snapshot: Option<ReadSnapshot>,
+
+    /// The statements buffered in the transaction, to be applied together at commit: `CREATE`
+    /// statements for [`AccessMode::DdlOnly`], DML statements for explicit
+    /// [`AccessMode::ReadWrite`]. Always empty for other access modes.
+    stmt_buffer: Vec<Statement>,
 }
 
 /// Transaction state.
@@ -117,6 +128,7 @@ impl SessionImpl {
                     id: Id::new(),
                     access_mode: AccessMode::ReadWrite,
                     snapshot: Default::default(),
+                    stmt_buffer: Default::default(),
                 })
             }
             State::Implicit(_) => unreachable!("implicit transaction is already in progress"),
@@ -140,6 +152,7 @@ impl SessionImpl {
                     id: ctx.id,
                     access_mode,
                     snapshot: ctx.snapshot.clone(),
+                    stmt_buffer: Default::default(),
                 })
             }
             State::Explicit(_) => {
@@ -163,6 +176,9 @@ impl SessionImpl {
             State::Explicit(ctx) => match ctx.access_mode {
                 AccessMode::ReadWrite => unimplemented!(),
                 AccessMode::ReadOnly => *txn = State::Initial,
+                AccessMode::DdlOnly => self.notice_to_user(
+                    "there is a DDL transaction in progress, use `COMMIT DDL` to apply it",
+                ),
             },
         }
     }
@@ -181,10 +197,79 @@ impl SessionImpl {
             State::Explicit(ctx) => match ctx.access_mode {
                 AccessMode::ReadWrite => unimplemented!(),
                 AccessMode::ReadOnly => *txn = State::Initial,
+                AccessMode::DdlOnly => self.notice_to_user(
+                    "there is a DDL transaction in progress, use `ROLLBACK DDL` to abort it",
+                ),
             },
         }
     }
 
+    /// Buffers a `CREATE` statement if there's a DDL transaction in progress.
+    ///
+    /// Returns `true` if the statement is buffered and should not be executed now.
+    pub fn txn_buffer_ddl_statement(&self, stmt: &Statement) -> bool {
+        let mut txn = self.txn.lock();
+
+        match &mut *txn {
+            State::Explicit(ctx) if matches!(ctx.access_mode, AccessMode::DdlOnly) => {
+                ctx.stmt_buffer.push(stmt.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Buffers a DML statement if there's an explicit read-write transaction in progress.
+    ///
+    /// Returns `true` if the statement is buffered and should not be executed now.
+    pub fn txn_buffer_dml_statement(&self, stmt: &Statement) -> bool {
+        let mut txn = self.txn.lock();
+
+        match &mut *txn {
+            State::Explicit(ctx) if matches!(ctx.access_mode, AccessMode::ReadWrite) => {
+                ctx.stmt_buffer.push(stmt.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Ends the explicit read-write transaction and returns the buffered DML statements for the
+    /// caller to apply (or discard, for `ROLLBACK`).
+    ///
+    /// Returns `None` if there's no explicit read-write transaction in progress.
+    pub fn txn_end_dml(&self) -> Option<Vec<Statement>> {
+        let mut txn = self.txn.lock();
+
+        match &mut *txn {
+            State::Explicit(ctx) if matches!(ctx.access_mode, AccessMode::ReadWrite) => {
+                let statements = std::mem::take(&mut ctx.stmt_buffer);
+                *txn = State::Initial;
+                Some(statements)
+            }
+            _ => None,
+        }
+    }
+
+    /// Ends the DDL transaction and returns the buffered statements for the caller to apply.
+    ///
+    /// Returns an error if there's no DDL transaction in progress.
+    pub fn txn_end_ddl(&self) -> Result<Vec<Statement>> {
+        let mut txn = self.txn.lock();
+
+        match &mut *txn {
+            State::Explicit(ctx) if matches!(ctx.access_mode, AccessMode::DdlOnly) => {
+                let statements = std::mem::take(&mut ctx.stmt_buffer);
+                *txn = State::Initial;
+                Ok(statements)
+            }
+            _ => Err(ErrorCode::InvalidInputSyntax(
+                "there is no DDL transaction in progress".to_string(),
+            )
+            .into()),
+        }
+    }
+
     /// Returns the transaction context.
     fn txn_ctx(&self) -> MappedMutexGuard<'_, Context> {
         MutexGuard::map(self.txn.lock(), |txn| match txn {
@@ -238,6 +323,9 @@ impl SessionImpl {
             AccessMode::ReadOnly => Err(ErrorCode::PermissionDenied(
                 "cannot execute in a read-only transaction".into(),
             ))?,
+            AccessMode::DdlOnly => Err(ErrorCode::PermissionDenied(
+                "only `CREATE` statements are permitted in a DDL transaction".into(),
+            ))?,
         }
     }
 
@@ -40,14 +40,18 @@ use risingwave_pb::ddl_service::alter_owner_request::Object;
 use risingwave_pb::ddl_service::{create_connection_request, DdlProgress, PbTableJobType};
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
-    BranchedObject, CompactionGroupInfo, HummockSnapshot, HummockVersion, HummockVersionDelta,
+    BranchedObject, CompactStatus, CompactTaskProgress, CompactionGroupInfo, HummockSnapshot,
+    HummockVersion, HummockVersionDelta,
 };
 use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
+use risingwave_pb::common::WorkerNode;
+use risingwave_pb::meta::list_actor_splits_response::ActorSplit;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
+use risingwave_pb::meta::list_actor_traces_response::ActorTrace;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
-use risingwave_pb::meta::SystemParams;
+use risingwave_pb::meta::{EventLog, SystemParams};
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::{GrantPrivilege, UserInfo};
@@ -65,6 +69,9 @@ use crate::user::user_service::UserInfoWriter;
 use crate::user::UserId;
 use crate::FrontendOpts;
 
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+
 /// An embedded frontend without starting meta and without starting frontend as a tcp server.
 pub struct LocalFrontend {
     pub opts: FrontendOpts,
@@ -702,6 +709,7 @@ impl UserInfoWriter for MockUserInfoWriter {
             UpdateField::CreateUser => user_info.can_create_user = update_user.can_create_user,
             UpdateField::AuthInfo => user_info.auth_info = update_user.auth_info.clone(),
             UpdateField::Rename => user_info.name = update_user.name.clone(),
+            UpdateField::QueryLimit => user_info.query_limit = update_user.query_limit,
             UpdateField::Unspecified => unreachable!(),
         });
         lock.update_user(update_user);
@@ -828,6 +836,26 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(vec![])
     }
 
+    async fn list_actor_splits(&self) -> RpcResult<Vec<ActorSplit>> {
+        Ok(vec![])
+    }
+
+    async fn list_actor_traces(&self) -> RpcResult<Vec<ActorTrace>> {
+        Ok(vec![])
+    }
+
+    async fn alter_parallelism(&self, _table_id: u32, _parallelism: u32) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn alter_sink_pause(&self, _sink_id: u32, _pause: bool) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn list_frontend_nodes(&self) -> RpcResult<Vec<WorkerNode>> {
+        Ok(vec![])
+    }
+
     async fn unpin_snapshot(&self) -> RpcResult<()> {
         Ok(())
     }
@@ -895,6 +923,26 @@ impl FrontendMetaClient for MockFrontendMetaClient {
     async fn list_hummock_meta_configs(&self) -> RpcResult<HashMap<String, String>> {
         unimplemented!()
     }
+
+    async fn list_compact_task_progress(&self) -> RpcResult<Vec<CompactTaskProgress>> {
+        unimplemented!()
+    }
+
+    async fn list_hummock_compaction_statuses(&self) -> RpcResult<Vec<CompactStatus>> {
+        unimplemented!()
+    }
+
+    async fn trigger_manual_compaction(
+        &self,
+        _compaction_group_id: u64,
+        _table_id: u32,
+    ) -> RpcResult<()> {
+        unimplemented!()
+    }
+
+    async fn list_event_log(&self) -> RpcResult<Vec<EventLog>> {
+        Ok(vec![])
+    }
 }
 
 #[cfg(test)]
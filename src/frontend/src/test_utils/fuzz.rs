@@ -0,0 +1,301 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A SQLSmith-style deterministic fuzzing harness.
+//!
+//! [`SqlFuzzer`] generates random-but-valid DDL/DML/`SELECT` workloads from the live catalog
+//! and runs them through the binder and planner in-process, without wiring up an external
+//! fuzzing tool. All randomness is derived from a caller-provided seed, so a failing workload
+//! can be reproduced by re-running with the same seed.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::Statement;
+use risingwave_sqlparser::parser::Parser;
+
+use crate::handler::query::gen_batch_plan_by_statement;
+use crate::handler::HandlerArgs;
+use crate::optimizer::OptimizerContext;
+use crate::session::SessionImpl;
+
+/// Prefix of all relations created by the fuzzer, so that it never touches relations it did not
+/// create itself.
+const FUZZ_RELATION_PREFIX: &str = "fuzz_";
+
+/// Column types the fuzzer may use in generated DDL. Literal generation in
+/// [`SqlFuzzer::gen_literal`] must cover all of them.
+const COLUMN_TYPES: &[&str] = &[
+    "smallint",
+    "int",
+    "bigint",
+    "double precision",
+    "decimal",
+    "varchar",
+    "boolean",
+    "date",
+];
+
+/// A deterministic generator of random-but-valid SQL workloads against the live catalog of a
+/// session, typically one created by [`super::LocalFrontend`].
+///
+/// DDL statements are executed through the full handler so that the catalog evolves as the
+/// workload runs, while DML and `SELECT` statements are only run through the binder and
+/// planner, since a mocked frontend has no compute nodes to execute them on.
+pub struct SqlFuzzer {
+    session: Arc<SessionImpl>,
+    rng: StdRng,
+    seed: u64,
+    /// Monotonic suffix for generated relation names.
+    next_id: u32,
+}
+
+/// A table visible to the fuzzer, snapshotted from the catalog.
+struct FuzzTable {
+    name: String,
+    /// Visible columns with their types.
+    columns: Vec<(String, DataType)>,
+}
+
+impl SqlFuzzer {
+    pub fn new(session: Arc<SessionImpl>, seed: u64) -> Self {
+        Self {
+            session,
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            next_id: 0,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate and run `count` statements, stopping at the first statement that fails to be
+    /// handled, bound or planned. The error message carries the seed and the offending SQL for
+    /// reproduction.
+    pub async fn run(&mut self, count: usize) -> Result<()> {
+        for _ in 0..count {
+            let sql = self.gen_statement();
+            self.run_one(&sql).await.map_err(|e| {
+                ErrorCode::InternalError(format!(
+                    "fuzzing failed (seed={}) on `{}`: {}",
+                    self.seed, sql, e
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    async fn run_one(&self, sql: &str) -> Result<()> {
+        let stmt = Parser::parse_sql(sql)
+            .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+            .into_iter()
+            .exactly_one()
+            .expect("fuzzer generates exactly one statement");
+        match &stmt {
+            Statement::Query(_) | Statement::Insert { .. } | Statement::Delete { .. } => {
+                let handler_args =
+                    HandlerArgs::new(self.session.clone(), &stmt, Arc::from(sql))?;
+                let context = OptimizerContext::from_handler_args(handler_args);
+                gen_batch_plan_by_statement(&self.session, context.into(), stmt)?;
+            }
+            _ => {
+                crate::handler::handle(self.session.clone(), stmt, Arc::from(sql), vec![])
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn gen_statement(&mut self) -> String {
+        let tables = self.fuzz_tables();
+        if tables.is_empty() {
+            return self.gen_create_table();
+        }
+        match self.rng.gen_range(0..10) {
+            0 => self.gen_create_table(),
+            1 => {
+                // `ORDER BY` and `LIMIT` are not generated for materialized views.
+                let query = self.gen_select_inner(&tables, false);
+                format!(
+                    "CREATE MATERIALIZED VIEW {}mv{} AS {}",
+                    FUZZ_RELATION_PREFIX,
+                    self.next_id(),
+                    query
+                )
+            }
+            2 => {
+                let table = tables.choose(&mut self.rng).unwrap();
+                let (column, _) = table.columns.choose(&mut self.rng).unwrap();
+                format!(
+                    "CREATE INDEX {}idx{} ON {}({})",
+                    FUZZ_RELATION_PREFIX,
+                    self.next_id(),
+                    table.name,
+                    column
+                )
+            }
+            3..=4 => self.gen_insert(&tables),
+            5 => {
+                let table = tables.choose(&mut self.rng).unwrap();
+                let (column, data_type) = table.columns.choose(&mut self.rng).unwrap();
+                let literal = self.gen_literal(data_type);
+                format!("DELETE FROM {} WHERE {} = {}", table.name, column, literal)
+            }
+            _ => self.gen_select(&tables),
+        }
+    }
+
+    fn gen_create_table(&mut self) -> String {
+        let column_count = self.rng.gen_range(1..=6);
+        let columns = (0..column_count)
+            .map(|i| format!("c{} {}", i, COLUMN_TYPES.choose(&mut self.rng).unwrap()))
+            .join(", ");
+        format!(
+            "CREATE TABLE {}t{} ({})",
+            FUZZ_RELATION_PREFIX,
+            self.next_id(),
+            columns
+        )
+    }
+
+    fn gen_insert(&mut self, tables: &[FuzzTable]) -> String {
+        let table = tables.choose(&mut self.rng).unwrap();
+        let row_count = self.rng.gen_range(1..=3);
+        let rows = (0..row_count)
+            .map(|_| {
+                let literals = table
+                    .columns
+                    .iter()
+                    .map(|(_, data_type)| self.gen_literal(data_type))
+                    .join(", ");
+                format!("({})", literals)
+            })
+            .join(", ");
+        let columns = table.columns.iter().map(|(name, _)| name.as_str()).join(", ");
+        format!("INSERT INTO {} ({}) VALUES {}", table.name, columns, rows)
+    }
+
+    fn gen_select(&mut self, tables: &[FuzzTable]) -> String {
+        self.gen_select_inner(tables, true)
+    }
+
+    fn gen_select_inner(&mut self, tables: &[FuzzTable], allow_order_and_limit: bool) -> String {
+        let table = tables.choose(&mut self.rng).unwrap();
+        let mut columns = table.columns.clone();
+        columns.shuffle(&mut self.rng);
+        columns.truncate(self.rng.gen_range(1..=columns.len()));
+
+        let projection = if self.rng.gen_bool(0.2) {
+            format!("{}, count(*)", columns[0].0)
+        } else {
+            columns.iter().map(|(name, _)| name.as_str()).join(", ")
+        };
+        let mut sql = format!("SELECT {} FROM {}", projection, table.name);
+        if self.rng.gen_bool(0.5) {
+            let (column, data_type) = table.columns.choose(&mut self.rng).unwrap();
+            let literal = self.gen_literal(data_type);
+            sql += &format!(" WHERE {} = {}", column, literal);
+        }
+        if projection.contains("count(*)") {
+            sql += &format!(" GROUP BY {}", columns[0].0);
+        } else if allow_order_and_limit && self.rng.gen_bool(0.3) {
+            sql += &format!(" ORDER BY {}", columns[0].0);
+        }
+        if allow_order_and_limit && self.rng.gen_bool(0.3) {
+            sql += &format!(" LIMIT {}", self.rng.gen_range(1..=100));
+        }
+        sql
+    }
+
+    fn gen_literal(&mut self, data_type: &DataType) -> String {
+        match data_type {
+            DataType::Int16 => self.rng.gen_range(-100i16..=100).to_string(),
+            DataType::Int32 => self.rng.gen_range(-10000i32..=10000).to_string(),
+            DataType::Int64 => self.rng.gen_range(-1000000i64..=1000000).to_string(),
+            DataType::Float64 | DataType::Decimal => {
+                format!("{:.2}", self.rng.gen_range(-100.0f64..=100.0))
+            }
+            DataType::Varchar => format!("'s{}'", self.rng.gen_range(0..1000)),
+            DataType::Boolean => self.rng.gen_bool(0.5).to_string(),
+            DataType::Date => format!(
+                "DATE '2023-{:02}-{:02}'",
+                self.rng.gen_range(1..=12),
+                self.rng.gen_range(1..=28)
+            ),
+            _ => "NULL".to_string(),
+        }
+    }
+
+    /// Snapshot the tables previously created by the fuzzer from the live catalog.
+    fn fuzz_tables(&self) -> Vec<FuzzTable> {
+        let catalog_reader = self.session.env().catalog_reader().read_guard();
+        let Ok(schema) =
+            catalog_reader.get_schema_by_name(self.session.database(), DEFAULT_SCHEMA_NAME)
+        else {
+            return vec![];
+        };
+        schema
+            .iter_table()
+            .filter(|t| t.name().starts_with(FUZZ_RELATION_PREFIX))
+            .map(|t| FuzzTable {
+                name: t.name().to_string(),
+                columns: t
+                    .columns()
+                    .iter()
+                    .filter(|c| !c.is_hidden())
+                    .map(|c| (c.name().to_string(), c.data_type().clone()))
+                    .collect(),
+            })
+            .filter(|t| !t.columns.is_empty())
+            .collect()
+    }
+
+    fn next_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+    use crate::FrontendOpts;
+
+    #[tokio::test]
+    async fn test_fuzzing_is_deterministic() {
+        let frontend = LocalFrontend::new(FrontendOpts::default()).await;
+        let mut fuzzer = SqlFuzzer::new(frontend.session_ref(), 42);
+        let sql = fuzzer.gen_statement();
+
+        let mut replay = SqlFuzzer::new(frontend.session_ref(), 42);
+        assert_eq!(sql, replay.gen_statement());
+    }
+
+    #[tokio::test]
+    async fn test_fuzzing_workload_passes_binder_and_planner() {
+        let frontend = LocalFrontend::new(FrontendOpts::default()).await;
+        let mut fuzzer = SqlFuzzer::new(frontend.session_ref(), 114514);
+        fuzzer.run(32).await.unwrap();
+    }
+}
@@ -33,6 +33,7 @@ pub struct UserCatalog {
     pub can_login: bool,
     pub auth_info: Option<PbAuthInfo>,
     pub grant_privileges: Vec<PbGrantPrivilege>,
+    pub query_limit: u64,
 
     // User owned acl mode set, group by object id.
     // TODO: merge it after we fully migrate to sql-backend.
@@ -52,6 +53,7 @@ impl From<PbUserInfo> for UserCatalog {
             can_login: user.can_login,
             auth_info: user.auth_info,
             grant_privileges: user.grant_privileges,
+            query_limit: user.query_limit,
             database_acls: Default::default(),
             schema_acls: Default::default(),
             object_acls: Default::default(),
@@ -73,6 +75,7 @@ impl UserCatalog {
             can_login: self.can_login,
             auth_info: self.auth_info.clone(),
             grant_privileges: self.grant_privileges.clone(),
+            query_limit: self.query_limit,
         }
     }
 
@@ -84,9 +87,7 @@ impl UserCatalog {
             Object::SourceId(id) => self.object_acls.entry(id),
             Object::SinkId(id) => self.object_acls.entry(id),
             Object::ViewId(id) => self.object_acls.entry(id),
-            Object::FunctionId(_) => {
-                unreachable!("grant privilege on function is not supported yet.")
-            }
+            Object::FunctionId(id) => self.object_acls.entry(id),
             _ => unreachable!(""),
         }
     }
@@ -99,9 +100,7 @@ impl UserCatalog {
             Object::SourceId(id) => self.object_acls.get(id),
             Object::SinkId(id) => self.object_acls.get(id),
             Object::ViewId(id) => self.object_acls.get(id),
-            Object::FunctionId(_) => {
-                unreachable!("grant privilege on function is not supported yet.")
-            }
+            Object::FunctionId(id) => self.object_acls.get(id),
             _ => unreachable!("unexpected object type."),
         }
     }
@@ -55,7 +55,10 @@ fn get_all_available_modes(object: &GrantObjects) -> Result<&AclModeSet> {
         GrantObjects::Tables(_) | GrantObjects::AllTablesInSchema { .. } => {
             Ok(&acl::ALL_AVAILABLE_TABLE_MODES)
         }
-        GrantObjects::Sinks(_) => Ok(&acl::ALL_AVAILABLE_SINK_MODES),
+        GrantObjects::Sinks(_) | GrantObjects::AllSinksInSchema { .. } => {
+            Ok(&acl::ALL_AVAILABLE_SINK_MODES)
+        }
+        GrantObjects::Functions(_) => Ok(&acl::ALL_AVAILABLE_FUNCTION_MODES),
         _ => Err(
             ErrorCode::BindError("Invalid privilege type for the given object.".to_string()).into(),
         ),
@@ -77,6 +80,7 @@ pub fn get_prost_action(action: &Action) -> PbAction {
         Action::Connect => PbAction::Connect,
         Action::Create => PbAction::Create,
         Action::Usage => PbAction::Usage,
+        Action::Execute => PbAction::Execute,
         _ => unreachable!(),
     }
 }
@@ -22,7 +22,9 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::catalog::{Schema, TableDesc};
 use risingwave_common::error::Result;
-use risingwave_common::types::{DataType, DefaultOrd, ScalarImpl};
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::row::OwnedRow;
+use risingwave_common::types::{DataType, Datum, DefaultOrd, ScalarImpl};
 use risingwave_common::util::scan_range::{is_full_range, ScanRange};
 
 use crate::expr::{
@@ -487,6 +489,47 @@ impl Condition {
         ))
     }
 
+    /// Try to compute the vnode that equality conditions pin the distribution key to.
+    ///
+    /// Returns `Some` if every distribution key column is constrained to a constant, in
+    /// which case only the owner of the returned vnode can contain matching rows. Unlike
+    /// [`ScanRange::try_compute_vnode`], this does not require the distribution key to be
+    /// covered by a prefix of the primary key.
+    pub fn try_derive_dist_key_vnode(&self, table_desc: &TableDesc) -> Option<VirtualNode> {
+        let dist_key_indices = &table_desc.distribution_key;
+        if dist_key_indices.is_empty() {
+            return None;
+        }
+
+        let mut dist_key_values: Vec<Option<Datum>> = vec![None; dist_key_indices.len()];
+        for expr in &self.conjunctions {
+            let Some((input_ref, const_expr)) = expr.as_eq_const() else {
+                continue;
+            };
+            let Some(pos) = dist_key_indices
+                .iter()
+                .position(|&idx| idx == input_ref.index)
+            else {
+                continue;
+            };
+            let Ok(const_expr) = const_expr.cast_implicit(input_ref.data_type.clone()) else {
+                continue;
+            };
+            let Ok(value) = const_expr.fold_const() else {
+                continue;
+            };
+            // `dist_key_col = NULL` never matches any row, so any single vnode is a valid
+            // superset of the result.
+            dist_key_values[pos] = Some(value);
+        }
+        let dist_key_row = OwnedRow::new(dist_key_values.into_iter().collect::<Option<Vec<_>>>()?);
+
+        Some(VirtualNode::compute_row(
+            &dist_key_row,
+            &(0..dist_key_indices.len()).collect_vec(),
+        ))
+    }
+
     /// classify conjunctions into groups:
     /// The i-th group has exprs that only reference the i-th PK column.
     /// The last group contains all the other exprs.
@@ -1096,4 +1139,40 @@ mod tests {
         assert_eq!(res.1.conjunctions, vec![right]);
         assert_eq!(res.2.conjunctions, vec![other]);
     }
+
+    #[test]
+    fn test_try_derive_dist_key_vnode() {
+        let ty = DataType::Int32;
+        let table_desc = TableDesc {
+            distribution_key: vec![1],
+            ..Default::default()
+        };
+
+        let eq_dist_key: ExprImpl = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                InputRef::new(1, ty.clone()).into(),
+                ExprImpl::literal_int(5),
+            ],
+        )
+        .unwrap()
+        .into();
+        let eq_other: ExprImpl = FunctionCall::new(
+            ExprType::Equal,
+            vec![InputRef::new(0, ty).into(), ExprImpl::literal_int(1)],
+        )
+        .unwrap()
+        .into();
+
+        let expected = VirtualNode::compute_row(
+            &OwnedRow::new(vec![Some(ScalarImpl::Int32(5))]),
+            &[0],
+        );
+        let cond = Condition::with_expr(eq_dist_key).and(Condition::with_expr(eq_other.clone()));
+        assert_eq!(cond.try_derive_dist_key_vnode(&table_desc), Some(expected));
+
+        // The distribution key is not fixed by the condition.
+        let cond = Condition::with_expr(eq_other);
+        assert_eq!(cond.try_derive_dist_key_vnode(&table_desc), None);
+    }
 }
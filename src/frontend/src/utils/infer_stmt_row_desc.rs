@@ -58,6 +58,18 @@ pub fn infer_show_object(objects: &ShowObject) -> Vec<PgFieldDescriptor> {
                 DataType::Varchar.type_len(),
             ),
         ],
+        ShowObject::Sink { .. } => vec![
+            PgFieldDescriptor::new(
+                "Name".to_owned(),
+                DataType::Varchar.to_oid(),
+                DataType::Varchar.type_len(),
+            ),
+            PgFieldDescriptor::new(
+                "Is Paused".to_owned(),
+                DataType::Varchar.to_oid(),
+                DataType::Varchar.type_len(),
+            ),
+        ],
         ShowObject::Function { .. } => vec![
             PgFieldDescriptor::new(
                 "Name".to_owned(),
@@ -144,6 +156,18 @@ pub fn infer_show_object(objects: &ShowObject) -> Vec<PgFieldDescriptor> {
                 DataType::Varchar.type_len(),
             ),
         ],
+        ShowObject::FrontendNodes => vec![
+            PgFieldDescriptor::new(
+                "Addr".to_owned(),
+                DataType::Varchar.to_oid(),
+                DataType::Varchar.type_len(),
+            ),
+            PgFieldDescriptor::new(
+                "State".to_owned(),
+                DataType::Varchar.to_oid(),
+                DataType::Varchar.type_len(),
+            ),
+        ],
         ShowObject::Jobs => vec![
             PgFieldDescriptor::new(
                 "Id".to_owned(),
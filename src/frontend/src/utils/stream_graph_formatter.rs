@@ -38,6 +38,86 @@ pub fn explain_stream_graph(graph: &StreamFragmentGraph, is_verbose: bool) -> St
     output
 }
 
+/// Explain the stream graph in DOT format, suitable for rendering with Graphviz.
+pub fn explain_stream_graph_as_dot(graph: &StreamFragmentGraph) -> String {
+    let mut output = String::with_capacity(2048);
+    output.push_str("digraph {\n");
+    for (_, fragment) in graph.fragments.iter().sorted_by_key(|(id, _)| **id) {
+        let id = fragment.get_fragment_id();
+        let operators = collect_operators(fragment.node.as_ref().unwrap());
+        let label = format!("Fragment {}\\n{}", id, operators.join("\\n"));
+        output.push_str(&format!(
+            "    {} [ label = \"{}\" ]\n",
+            id,
+            label.replace('"', "\\\"")
+        ));
+    }
+    for edge in graph.edges.iter().sorted_by_key(|e| e.link_id) {
+        output.push_str(&format!(
+            "    {} -> {} [ label = \"{}\" ]\n",
+            edge.upstream_id,
+            edge.downstream_id,
+            dispatcher_type_name(edge)
+        ));
+    }
+    output.push_str("}\n");
+    output
+}
+
+/// Explain the stream graph in JSON format, for programmatic consumption.
+pub fn explain_stream_graph_as_json(graph: &StreamFragmentGraph) -> String {
+    let fragments = graph
+        .fragments
+        .iter()
+        .sorted_by_key(|(id, _)| **id)
+        .map(|(_, fragment)| {
+            serde_json::json!({
+                "id": fragment.get_fragment_id(),
+                "operators": collect_operators(fragment.node.as_ref().unwrap()),
+            })
+        })
+        .collect_vec();
+    let edges = graph
+        .edges
+        .iter()
+        .sorted_by_key(|e| e.link_id)
+        .map(|edge| {
+            serde_json::json!({
+                "from": edge.upstream_id,
+                "to": edge.downstream_id,
+                "dispatcher": dispatcher_type_name(edge),
+            })
+        })
+        .collect_vec();
+    serde_json::to_string_pretty(&serde_json::json!({
+        "fragments": fragments,
+        "edges": edges,
+    }))
+    .unwrap()
+}
+
+/// Collect the identities of all the operators in the fragment in pre-order.
+fn collect_operators(node: &StreamNode) -> Vec<String> {
+    let mut operators = Vec::new();
+    fn visit(node: &StreamNode, operators: &mut Vec<String>) {
+        operators.push(node.identity.clone());
+        for input in &node.input {
+            visit(input, operators);
+        }
+    }
+    visit(node, &mut operators);
+    operators
+}
+
+fn dispatcher_type_name(edge: &StreamFragmentEdge) -> String {
+    let dispatcher_type = edge
+        .dispatch_strategy
+        .as_ref()
+        .map(|d| d.r#type())
+        .unwrap_or(DispatcherType::Unspecified);
+    format!("{:?}", dispatcher_type)
+}
+
 /// A formatter to display the final stream plan graph, used for `explain (distsql) create
 /// materialized view ...`
 struct StreamGraphFormatter {
@@ -131,6 +211,30 @@ impl StreamGraphFormatter {
         if let Some(vnode_col_idx) = tb.vnode_col_index {
             fields.push(("vnode column idx", Pretty::debug(&vnode_col_idx)));
         }
+        if self.verbose {
+            fields.push((
+                "estimated row size",
+                Pretty::Text(format!("{} bytes", estimated_row_size(&tb)).into()),
+            ));
+            // Append-only tables only ever receive inserts, while updatable ones also get
+            // updates and deletes on existing keys.
+            fields.push(("append only", Pretty::debug(&tb.append_only)));
+            let watermark_columns = tb
+                .watermark_columns
+                .ones()
+                .map(|idx| Pretty::display(tb.columns[idx].name()))
+                .collect_vec();
+            if !watermark_columns.is_empty() {
+                fields.push(("watermark columns", Pretty::Array(watermark_columns)));
+                fields.push((
+                    "cleaned by watermark",
+                    Pretty::debug(&tb.cleaned_by_watermark),
+                ));
+            }
+            if let Some(retention_seconds) = tb.properties.retention_seconds() {
+                fields.push(("retention seconds", Pretty::debug(&retention_seconds)));
+            }
+        }
         Pretty::childless_record(name, fields)
     }
 
@@ -263,6 +367,12 @@ impl StreamGraphFormatter {
                     self.pretty_add_table(node.get_state_table().unwrap()),
                 ));
             }
+            stream_node::NodeBody::UpsertNormalize(node) => {
+                fields.push((
+                    "state table",
+                    self.pretty_add_table(node.get_state_table().unwrap()),
+                ));
+            }
             stream_node::NodeBody::StreamScan(node) => fields.push((
                 "state table",
                 self.pretty_add_table(node.get_state_table().unwrap()),
@@ -384,3 +494,17 @@ impl StreamGraphFormatter {
         )
     }
 }
+
+/// Estimate the number of bytes a row of the table occupies in the state store, by summing up
+/// the type length of each column. Variable-length columns are counted as 16 bytes each, so
+/// the result is a rough lower bound rather than an exact figure.
+fn estimated_row_size(tb: &TableCatalog) -> usize {
+    const VAR_LEN_ESTIMATE: usize = 16;
+    tb.columns
+        .iter()
+        .map(|c| match c.data_type().type_len() {
+            len if len > 0 => len as usize,
+            _ => VAR_LEN_ESTIMATE,
+        })
+        .sum()
+}
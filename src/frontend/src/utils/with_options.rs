@@ -34,9 +34,15 @@ use crate::handler::util::get_connection_name;
 use crate::session::SessionImpl;
 
 mod options {
-    use risingwave_common::catalog::hummock::PROPERTIES_RETENTION_SECOND_KEY;
+    use risingwave_common::catalog::hummock::{
+        PROPERTIES_BLOOM_FALSE_POSITIVE_KEY, PROPERTIES_COMPRESSION_ALGORITHM_KEY,
+        PROPERTIES_COMPRESSION_LEVEL_KEY, PROPERTIES_RETENTION_SECOND_KEY,
+    };
 
     pub const RETENTION_SECONDS: &str = PROPERTIES_RETENTION_SECOND_KEY;
+    pub const BLOOM_FALSE_POSITIVE: &str = PROPERTIES_BLOOM_FALSE_POSITIVE_KEY;
+    pub const COMPRESSION_ALGORITHM: &str = PROPERTIES_COMPRESSION_ALGORITHM_KEY;
+    pub const COMPRESSION_LEVEL: &str = PROPERTIES_COMPRESSION_LEVEL_KEY;
 }
 
 /// Options or properties extracted from the `WITH` clause of DDLs.
@@ -102,9 +108,15 @@ impl WithOptions {
 
     /// Get the subset of the options for internal table catalogs.
     ///
-    /// Currently only `retention_seconds` is included.
+    /// Currently `retention_seconds`, `bloom_false_positive` and the compression options
+    /// are included.
     pub fn internal_table_subset(&self) -> Self {
-        self.subset([options::RETENTION_SECONDS])
+        self.subset([
+            options::RETENTION_SECONDS,
+            options::BLOOM_FALSE_POSITIVE,
+            options::COMPRESSION_ALGORITHM,
+            options::COMPRESSION_LEVEL,
+        ])
     }
 
     pub fn value_eq_ignore_case(&self, key: &str, val: &str) -> bool {
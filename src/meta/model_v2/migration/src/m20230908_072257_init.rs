@@ -130,6 +130,12 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(User::CanCreateUser).boolean().not_null())
                     .col(ColumnDef::new(User::CanLogin).boolean().not_null())
                     .col(ColumnDef::new(User::AuthInfo).json())
+                    .col(
+                        ColumnDef::new(User::QueryLimit)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
                     .to_owned(),
             )
             .await?;
@@ -578,6 +584,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(Index::PrimaryTableId).integer().not_null())
                     .col(ColumnDef::new(Index::IndexItems).json().not_null())
                     .col(ColumnDef::new(Index::OriginalColumns).json().not_null())
+                    .col(ColumnDef::new(Index::Predicate).json())
                     .col(ColumnDef::new(Index::JobStatus).string().not_null())
                     .foreign_key(
                         &mut ForeignKey::create()
@@ -822,6 +829,7 @@ enum User {
     CanCreateUser,
     CanLogin,
     AuthInfo,
+    QueryLimit,
 }
 
 #[derive(DeriveIden)]
@@ -971,6 +979,7 @@ enum Index {
     PrimaryTableId,
     IndexItems,
     OriginalColumns,
+    Predicate,
     JobStatus,
 }
 
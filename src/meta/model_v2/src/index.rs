@@ -14,7 +14,7 @@
 
 use sea_orm::entity::prelude::*;
 
-use crate::{ExprNodeArray, I32Array, IndexId, JobStatus, TableId};
+use crate::{ExprNode, ExprNodeArray, I32Array, IndexId, JobStatus, TableId};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "index")]
@@ -26,6 +26,7 @@ pub struct Model {
     pub primary_table_id: TableId,
     pub index_items: ExprNodeArray,
     pub original_columns: I32Array,
+    pub predicate: Option<ExprNode>,
     pub job_status: JobStatus,
 }
 
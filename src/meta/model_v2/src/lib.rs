@@ -172,6 +172,7 @@ derive_from_json_struct!(
     WatermarkDescArray,
     Vec<risingwave_pb::catalog::PbWatermarkDesc>
 );
+derive_from_json_struct!(ExprNode, risingwave_pb::expr::PbExprNode);
 derive_from_json_struct!(ExprNodeArray, Vec<risingwave_pb::expr::PbExprNode>);
 derive_from_json_struct!(ColumnOrderArray, Vec<risingwave_pb::common::PbColumnOrder>);
 derive_from_json_struct!(SinkFormatDesc, risingwave_pb::catalog::PbSinkFormatDesc);
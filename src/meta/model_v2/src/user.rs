@@ -31,6 +31,7 @@ pub struct Model {
     pub can_create_user: bool,
     pub can_login: bool,
     pub auth_info: Option<AuthInfo>,
+    pub query_limit: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -62,6 +63,7 @@ impl From<PbUserInfo> for ActiveModel {
             can_create_user: Set(user.can_create_user),
             can_login: Set(user.can_login),
             auth_info: Set(user.auth_info.map(AuthInfo)),
+            query_limit: Set(user.query_limit as _),
         }
     }
 }
@@ -77,6 +79,7 @@ impl From<Model> for PbUserInfo {
             can_login: val.can_login,
             auth_info: val.auth_info.map(|x| x.into_inner()),
             grant_privileges: vec![], // fill in later
+            query_limit: val.query_limit as _,
         }
     }
 }
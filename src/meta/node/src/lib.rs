@@ -175,6 +175,7 @@ use std::future::Future;
 use std::pin::Pin;
 
 use risingwave_common::config::{load_config, MetaBackend, RwConfig};
+use risingwave_rpc_client::RpcRetryConfig;
 use tracing::info;
 
 /// Start meta node
@@ -206,6 +207,12 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                 },
             },
             MetaBackend::Mem => MetaStoreBackend::Mem,
+            MetaBackend::Sql => MetaStoreBackend::Sql {
+                endpoint: opts
+                    .sql_endpoint
+                    .clone()
+                    .expect("sql endpoint is required when using SQL as the meta store backend"),
+            },
         };
         let sql_backend = opts
             .sql_endpoint
@@ -223,6 +230,7 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
             Duration::from_secs(config.meta.max_heartbeat_interval_secs as u64);
         let max_idle_ms = config.meta.dangerous_max_idle_secs.unwrap_or(0) * 1000;
         let in_flight_barrier_nums = config.streaming.in_flight_barrier_nums;
+        let min_in_flight_barrier_nums = config.streaming.min_in_flight_barrier_nums;
         let privatelink_endpoint_default_tags =
             opts.privatelink_endpoint_default_tags.map(|tags| {
                 tags.split(',')
@@ -250,10 +258,22 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
             MetaOpts {
                 enable_recovery: !config.meta.disable_recovery,
                 enable_scale_in_when_recovery: config.meta.enable_scale_in_when_recovery,
+                enable_partial_recovery: config.meta.enable_partial_recovery,
+                checkpoint_alignment_timeout_sec: config.meta.checkpoint_alignment_timeout_sec,
+                recover_stuck_barrier: config.meta.recover_stuck_barrier,
+                worker_rpc_retry: RpcRetryConfig {
+                    retry_max_attempts: config.meta.worker_rpc_retry_max_attempts,
+                    retry_base_interval_ms: config.meta.worker_rpc_retry_base_interval_ms,
+                    circuit_break_threshold: config.meta.worker_rpc_circuit_break_threshold,
+                    circuit_break_cooldown_ms: config.meta.worker_rpc_circuit_break_cooldown_ms,
+                },
+                enable_failure_domain_spread: config.meta.enable_failure_domain_spread,
                 in_flight_barrier_nums,
+                min_in_flight_barrier_nums,
                 max_idle_ms,
                 compaction_deterministic_test: config.meta.enable_compaction_deterministic,
                 default_parallelism: config.meta.default_parallelism,
+                max_serving_parallelism: config.meta.max_serving_parallelism,
                 vacuum_interval_sec: config.meta.vacuum_interval_sec,
                 vacuum_spin_interval_ms: config.meta.vacuum_spin_interval_ms,
                 hummock_version_checkpoint_interval_sec: config
@@ -268,8 +288,14 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                     .meta
                     .collect_gc_watermark_spin_interval_sec,
                 enable_committed_sst_sanity_check: config.meta.enable_committed_sst_sanity_check,
+                enable_canary_checkpoint_validation: config
+                    .meta
+                    .enable_canary_checkpoint_validation,
                 periodic_compaction_interval_sec: config.meta.periodic_compaction_interval_sec,
                 node_num_monitor_interval_sec: config.meta.node_num_monitor_interval_sec,
+                alert_webhook_url: config.meta.alert_webhook_url.clone(),
+                alert_interval_sec: config.meta.alert_interval_sec,
+                alert_rules: config.meta.alert_rules.clone(),
                 prometheus_endpoint: opts.prometheus_endpoint,
                 vpc_id: opts.vpc_id,
                 security_group_id: opts.security_group_id,
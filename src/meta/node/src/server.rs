@@ -25,6 +25,7 @@ use risingwave_common::telemetry::manager::TelemetryManager;
 use risingwave_common::telemetry::telemetry_env_enabled;
 use risingwave_common_service::metrics_manager::MetricsManager;
 use risingwave_common_service::tracing::TracingExtractLayer;
+use risingwave_meta::rpc::alerting;
 use risingwave_meta::rpc::intercept::MetricsMiddlewareLayer;
 use risingwave_meta::rpc::ElectionClientRef;
 use risingwave_meta_model_migration::{Migrator, MigratorTrait};
@@ -32,6 +33,7 @@ use risingwave_meta_service::backup_service::BackupServiceImpl;
 use risingwave_meta_service::cloud_service::CloudServiceImpl;
 use risingwave_meta_service::cluster_service::ClusterServiceImpl;
 use risingwave_meta_service::ddl_service::DdlServiceImpl;
+use risingwave_meta_service::event_log_service::EventLogServiceImpl;
 use risingwave_meta_service::health_service::HealthServiceImpl;
 use risingwave_meta_service::heartbeat_service::HeartbeatServiceImpl;
 use risingwave_meta_service::hummock_service::HummockServiceImpl;
@@ -51,6 +53,7 @@ use risingwave_pb::ddl_service::ddl_service_server::DdlServiceServer;
 use risingwave_pb::health::health_server::HealthServer;
 use risingwave_pb::hummock::hummock_manager_service_server::HummockManagerServiceServer;
 use risingwave_pb::meta::cluster_service_server::ClusterServiceServer;
+use risingwave_pb::meta::event_log_service_server::EventLogServiceServer;
 use risingwave_pb::meta::heartbeat_service_server::HeartbeatServiceServer;
 use risingwave_pb::meta::meta_member_service_server::MetaMemberServiceServer;
 use risingwave_pb::meta::notification_service_server::NotificationServiceServer;
@@ -62,7 +65,7 @@ use risingwave_pb::meta::telemetry_info_service_server::TelemetryInfoServiceServ
 use risingwave_pb::meta::SystemParams;
 use risingwave_pb::user::user_service_server::UserServiceServer;
 use risingwave_rpc_client::ComputeClientPool;
-use sea_orm::{ConnectionTrait, DbBackend};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend};
 use tokio::sync::oneshot::{channel as OneChannel, Receiver as OneReceiver};
 use tokio::sync::watch;
 use tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender};
@@ -88,7 +91,7 @@ use crate::rpc::metrics::{
 };
 use crate::serving::ServingVnodeMapping;
 use crate::storage::{
-    EtcdMetaStore, MemStore, MetaStore, MetaStoreBoxExt, MetaStoreRef,
+    EtcdMetaStore, MemStore, MetaStore, MetaStoreBoxExt, MetaStoreRef, SqlKvMetaStore,
     WrappedEtcdClient as EtcdClient,
 };
 use crate::stream::{GlobalStreamManager, SourceManager};
@@ -125,18 +128,8 @@ pub async fn rpc_serve(
     };
 
     let mut election_client = if let Some(sql_store) = &meta_store_sql {
-        let id = address_info.advertise_addr.clone();
-        let conn = sql_store.conn.clone();
-        let election_client: ElectionClientRef = match conn.get_database_backend() {
-            DbBackend::Sqlite => {
-                Arc::new(SqlBackendElectionClient::new(id, SqliteDriver::new(conn)))
-            }
-            DbBackend::Postgres => {
-                Arc::new(SqlBackendElectionClient::new(id, PostgresDriver::new(conn)))
-            }
-            DbBackend::MySql => Arc::new(SqlBackendElectionClient::new(id, MySqlDriver::new(conn))),
-        };
-
+        let election_client =
+            build_sql_election_client(address_info.advertise_addr.clone(), sql_store.conn.clone());
         election_client.init().await?;
 
         Some(election_client)
@@ -203,6 +196,43 @@ pub async fn rpc_serve(
                 init_system_params,
             )
         }
+        MetaStoreBackend::Sql { endpoint } => {
+            let mut options = sea_orm::ConnectOptions::new(endpoint);
+            options
+                .max_connections(20)
+                .connect_timeout(Duration::from_secs(10))
+                .idle_timeout(Duration::from_secs(30));
+            let conn = sea_orm::Database::connect(options).await?;
+            let meta_store = SqlKvMetaStore::new(conn.clone()).await?.into_ref();
+
+            if election_client.is_none() {
+                let client =
+                    build_sql_election_client(address_info.advertise_addr.clone(), conn);
+                client.init().await?;
+                election_client = Some(client);
+            }
+
+            rpc_serve_with_store(
+                meta_store,
+                election_client,
+                meta_store_sql,
+                address_info,
+                max_cluster_heartbeat_interval,
+                lease_interval_secs,
+                opts,
+                init_system_params,
+            )
+        }
+    }
+}
+
+fn build_sql_election_client(id: String, conn: DatabaseConnection) -> ElectionClientRef {
+    match conn.get_database_backend() {
+        DbBackend::Sqlite => Arc::new(SqlBackendElectionClient::new(id, SqliteDriver::new(conn))),
+        DbBackend::Postgres => {
+            Arc::new(SqlBackendElectionClient::new(id, PostgresDriver::new(conn)))
+        }
+        DbBackend::MySql => Arc::new(SqlBackendElectionClient::new(id, MySqlDriver::new(conn))),
     }
 }
 
@@ -416,7 +446,9 @@ pub async fn start_service_as_election_leader(
             .await
             .unwrap(),
     );
-    let serving_vnode_mapping = Arc::new(ServingVnodeMapping::default());
+    let serving_vnode_mapping = Arc::new(ServingVnodeMapping::new(
+        env.opts.max_serving_parallelism,
+    ));
     serving::on_meta_start(
         env.notification_manager_ref(),
         cluster_manager.clone(),
@@ -585,6 +617,7 @@ pub async fn start_service_as_election_leader(
         catalog_manager.clone(),
         stream_manager.clone(),
         barrier_manager.clone(),
+        hummock_manager.clone(),
     );
 
     let cluster_srv = ClusterServiceImpl::new(cluster_manager.clone());
@@ -620,6 +653,7 @@ pub async fn start_service_as_election_leader(
     let serving_srv =
         ServingServiceImpl::new(serving_vnode_mapping.clone(), fragment_manager.clone());
     let cloud_srv = CloudServiceImpl::new(catalog_manager.clone(), aws_cli);
+    let event_log_srv = EventLogServiceImpl::new(env.event_log_manager_ref());
 
     if let Some(prometheus_addr) = address_info.prometheus_addr {
         MetricsManager::boot_metrics_service(prometheus_addr.to_string())
@@ -645,6 +679,14 @@ pub async fn start_service_as_election_leader(
         hummock_manager.clone(),
         meta_metrics.clone(),
     ));
+    if let Some(webhook_url) = &env.opts.alert_webhook_url {
+        sub_tasks.push(alerting::start_alert_monitor(
+            cluster_manager.clone(),
+            webhook_url.clone(),
+            Duration::from_secs(env.opts.alert_interval_sec),
+            env.opts.alert_rules.clone(),
+        ));
+    }
     if let Some(system_params_ctl) = system_params_controller {
         sub_tasks.push(SystemParamsController::start_params_notifier(
             system_params_ctl,
@@ -765,6 +807,7 @@ pub async fn start_service_as_election_leader(
         .add_service(ServingServiceServer::new(serving_srv))
         .add_service(CloudServiceServer::new(cloud_srv))
         .add_service(SinkCoordinationServiceServer::new(sink_coordination_srv))
+        .add_service(EventLogServiceServer::new(event_log_srv))
         .monitored_serve_with_shutdown(
             address_info.listen_addr,
             "grpc-meta-leader-service",
@@ -438,6 +438,17 @@ impl HummockManagerService for HummockServiceImpl {
         Ok(Response::new(SplitCompactionGroupResponse { new_group_id }))
     }
 
+    async fn ingest_sstables(
+        &self,
+        request: Request<IngestSstablesRequest>,
+    ) -> Result<Response<IngestSstablesResponse>, Status> {
+        let req = request.into_inner();
+        self.hummock_manager
+            .ingest_sstables(req.compaction_group_id, req.target_level, req.sstables)
+            .await?;
+        Ok(Response::new(IngestSstablesResponse {}))
+    }
+
     async fn rise_ctl_pause_version_checkpoint(
         &self,
         _request: Request<RiseCtlPauseVersionCheckpointRequest>,
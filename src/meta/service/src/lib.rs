@@ -24,6 +24,7 @@ pub mod backup_service;
 pub mod cloud_service;
 pub mod cluster_service;
 pub mod ddl_service;
+pub mod event_log_service;
 pub mod health_service;
 pub mod heartbeat_service;
 pub mod hummock_service;
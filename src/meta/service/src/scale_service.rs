@@ -18,13 +18,15 @@ use risingwave_meta::stream::{ScaleController, ScaleControllerRef};
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::meta::scale_service_server::ScaleService;
 use risingwave_pb::meta::{
-    GetClusterInfoRequest, GetClusterInfoResponse, GetReschedulePlanRequest,
-    GetReschedulePlanResponse, Reschedule, RescheduleRequest, RescheduleResponse,
+    CompleteDrainWorkersRequest, CompleteDrainWorkersResponse, GetClusterInfoRequest,
+    GetClusterInfoResponse, GetReschedulePlanRequest, GetReschedulePlanResponse, Reschedule,
+    RescheduleRequest, RescheduleResponse,
 };
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
 use tonic::{Request, Response, Status};
 
 use crate::barrier::BarrierManagerRef;
+use crate::hummock::HummockManagerRef;
 use crate::manager::{CatalogManagerRef, ClusterManagerRef, FragmentManagerRef};
 use crate::model::MetadataModel;
 use crate::stream::{
@@ -38,6 +40,7 @@ pub struct ScaleServiceImpl {
     catalog_manager: CatalogManagerRef,
     stream_manager: GlobalStreamManagerRef,
     barrier_manager: BarrierManagerRef,
+    hummock_manager: HummockManagerRef,
     scale_controller: ScaleControllerRef,
 }
 
@@ -49,6 +52,7 @@ impl ScaleServiceImpl {
         catalog_manager: CatalogManagerRef,
         stream_manager: GlobalStreamManagerRef,
         barrier_manager: BarrierManagerRef,
+        hummock_manager: HummockManagerRef,
     ) -> Self {
         let scale_controller = Arc::new(ScaleController::new(
             fragment_manager.clone(),
@@ -63,6 +67,7 @@ impl ScaleServiceImpl {
             catalog_manager,
             stream_manager,
             barrier_manager,
+            hummock_manager,
             scale_controller,
         }
     }
@@ -244,4 +249,57 @@ impl ScaleService for ScaleServiceImpl {
                 .collect(),
         }))
     }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn complete_drain_workers(
+        &self,
+        request: Request<CompleteDrainWorkersRequest>,
+    ) -> Result<Response<CompleteDrainWorkersResponse>, Status> {
+        let CompleteDrainWorkersRequest { worker_ids } = request.into_inner();
+
+        // Prevent a concurrent reschedule from moving actors back to the workers.
+        let _reschedule_job_lock = self.stream_manager.reschedule_lock.read().await;
+
+        let worker_nodes = self
+            .cluster_manager
+            .list_worker_node(WorkerType::ComputeNode, None)
+            .await;
+
+        // The workers must be cordoned first, otherwise newly created streaming jobs may still
+        // schedule actors on them.
+        for worker_id in &worker_ids {
+            let unschedulable = worker_nodes
+                .iter()
+                .find(|worker| worker.id == *worker_id)
+                .and_then(|worker| worker.property.as_ref())
+                .map(|property| property.is_unschedulable)
+                .unwrap_or(false);
+            if !unschedulable {
+                return Err(Status::failed_precondition(format!(
+                    "worker {} is not cordoned",
+                    worker_id
+                )));
+            }
+        }
+
+        // All actors must have been rescheduled away from the workers.
+        let node_actors = self.fragment_manager.all_node_actors(true).await;
+        for worker_id in &worker_ids {
+            if let Some(actors) = node_actors.get(worker_id)
+                && !actors.is_empty()
+            {
+                return Err(Status::failed_precondition(format!(
+                    "worker {} still hosts {} actors",
+                    worker_id,
+                    actors.len()
+                )));
+            }
+        }
+
+        // Unpin the Hummock versions and snapshots held by the workers, so that they no longer
+        // block vacuuming even if they are terminated abruptly afterwards.
+        self.hummock_manager.release_contexts(worker_ids).await?;
+
+        Ok(Response::new(CompleteDrainWorkersResponse {}))
+    }
 }
@@ -12,21 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
+use risingwave_connector::source::SplitMetaData;
 use risingwave_pb::meta::cancel_creating_jobs_request::Jobs;
 use risingwave_pb::meta::list_table_fragments_response::{
     ActorInfo, FragmentInfo, TableFragmentInfo,
 };
 use risingwave_pb::meta::stream_manager_service_server::StreamManagerService;
 use risingwave_pb::meta::*;
+use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
+use risingwave_pb::stream_plan::barrier::Mutation;
+use risingwave_pb::stream_plan::{DispatcherType, PauseSinkMutation, ResumeSinkMutation};
+use risingwave_rpc_client::ComputeClientPool;
 use tonic::{Request, Response, Status};
 
 use crate::barrier::{BarrierScheduler, Command};
 use crate::manager::{CatalogManagerRef, FragmentManagerRef, MetaSrvEnv};
-use crate::stream::GlobalStreamManagerRef;
+use crate::model::FragmentId;
+use crate::stream::{GlobalStreamManagerRef, ParallelUnitReschedule, RescheduleOptions};
+use crate::MetaError;
 
 pub type TonicResponse<T> = Result<Response<T>, Status>;
 
@@ -240,4 +247,205 @@ impl StreamManagerService for StreamServiceImpl {
                 .collect_vec(),
         }))
     }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_actor_splits(
+        &self,
+        _request: Request<ListActorSplitsRequest>,
+    ) -> Result<Response<ListActorSplitsResponse>, Status> {
+        let core = self.fragment_manager.get_fragment_read_guard().await;
+
+        let mut actor_splits = Vec::new();
+        for tf in core.table_fragments().values() {
+            let fragment_to_source: HashMap<_, _> = tf
+                .stream_source_fragments()
+                .into_iter()
+                .flat_map(|(source_id, fragment_ids)| {
+                    fragment_ids
+                        .into_iter()
+                        .map(move |fragment_id| (fragment_id, source_id))
+                })
+                .collect();
+            let actor_to_fragment = tf.actor_fragment_mapping();
+
+            for (&actor_id, splits) in &tf.actor_splits {
+                let fragment_id = actor_to_fragment[&actor_id];
+                // Skip the actors that do not read an external stream source, e.g. backfill.
+                let Some(&source_id) = fragment_to_source.get(&fragment_id) else {
+                    continue;
+                };
+                actor_splits.extend(splits.iter().map(|split| {
+                    list_actor_splits_response::ActorSplit {
+                        actor_id,
+                        fragment_id,
+                        source_id,
+                        split_id: split.id().to_string(),
+                    }
+                }));
+            }
+        }
+        actor_splits.sort_unstable_by_key(|s| s.actor_id);
+
+        Ok(Response::new(ListActorSplitsResponse { actor_splits }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_actor_traces(
+        &self,
+        _request: Request<ListActorTracesRequest>,
+    ) -> Result<Response<ListActorTracesResponse>, Status> {
+        let worker_nodes = self
+            .stream_manager
+            .cluster_manager
+            .list_active_streaming_compute_nodes()
+            .await;
+
+        let compute_clients = ComputeClientPool::default();
+        let mut traces = Vec::new();
+        for worker_node in &worker_nodes {
+            let client = compute_clients
+                .get(worker_node)
+                .await
+                .map_err(MetaError::from)?;
+            let result = client.stack_trace().await.map_err(MetaError::from)?;
+            traces.extend(result.actor_traces.into_iter().map(|(actor_id, trace)| {
+                list_actor_traces_response::ActorTrace {
+                    actor_id,
+                    worker_id: worker_node.id,
+                    trace,
+                }
+            }));
+        }
+        traces.sort_unstable_by_key(|t| t.actor_id);
+
+        Ok(Response::new(ListActorTracesResponse { traces }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn alter_parallelism(
+        &self,
+        request: Request<AlterParallelismRequest>,
+    ) -> Result<Response<AlterParallelismResponse>, Status> {
+        let req = request.into_inner();
+        let parallelism = req.parallelism as usize;
+        if parallelism == 0 {
+            return Err(Status::invalid_argument("parallelism must be positive"));
+        }
+
+        let _reschedule_job_lock = self.stream_manager.reschedule_lock.write().await;
+
+        let table_fragments = self
+            .fragment_manager
+            .select_table_fragments_by_table_id(&TableId::new(req.table_id))
+            .await?;
+
+        let parallel_units = self
+            .stream_manager
+            .cluster_manager
+            .list_active_streaming_parallel_units()
+            .await;
+        if parallelism > parallel_units.len() {
+            return Err(Status::invalid_argument(format!(
+                "target parallelism {} exceeds the total parallelism {} of the cluster",
+                parallelism,
+                parallel_units.len()
+            )));
+        }
+        let target_parallel_units: BTreeSet<_> = parallel_units
+            .into_iter()
+            .map(|parallel_unit| parallel_unit.id)
+            .take(parallelism)
+            .collect();
+
+        // Fragments receiving a no-shuffle dispatch must follow the distribution of their
+        // upstream fragment, so they are rescheduled along with it instead of on their own.
+        let no_shuffle_targets: HashSet<_> = table_fragments
+            .fragments
+            .values()
+            .flat_map(|fragment| fragment.actors.iter())
+            .flat_map(|actor| actor.dispatcher.iter())
+            .filter(|dispatcher| dispatcher.r#type() == DispatcherType::NoShuffle)
+            .map(|dispatcher| dispatcher.dispatcher_id as FragmentId)
+            .collect();
+
+        let mut reschedules = HashMap::new();
+        for (fragment_id, fragment) in &table_fragments.fragments {
+            if fragment.distribution_type() != FragmentDistributionType::Hash
+                || no_shuffle_targets.contains(fragment_id)
+            {
+                continue;
+            }
+            let current_parallel_units: BTreeSet<_> = fragment
+                .actors
+                .iter()
+                .map(|actor| {
+                    table_fragments.actor_status[&actor.actor_id]
+                        .parallel_unit
+                        .as_ref()
+                        .unwrap()
+                        .id
+                })
+                .collect();
+            let added_parallel_units: BTreeSet<_> = target_parallel_units
+                .difference(&current_parallel_units)
+                .cloned()
+                .collect();
+            let removed_parallel_units: BTreeSet<_> = current_parallel_units
+                .difference(&target_parallel_units)
+                .cloned()
+                .collect();
+            if added_parallel_units.is_empty() && removed_parallel_units.is_empty() {
+                continue;
+            }
+            reschedules.insert(
+                *fragment_id,
+                ParallelUnitReschedule {
+                    added_parallel_units,
+                    removed_parallel_units,
+                },
+            );
+        }
+
+        if !reschedules.is_empty() {
+            self.stream_manager
+                .reschedule_actors(
+                    reschedules,
+                    RescheduleOptions {
+                        resolve_no_shuffle_upstream: false,
+                    },
+                )
+                .await?;
+        }
+
+        Ok(Response::new(AlterParallelismResponse {}))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn alter_sink_pause(
+        &self,
+        request: Request<AlterSinkPauseRequest>,
+    ) -> Result<Response<AlterSinkPauseResponse>, Status> {
+        let req = request.into_inner();
+
+        // Persist the pause state first, so that it is reflected in the catalog and the sink
+        // can be re-paused after recovery.
+        self.catalog_manager
+            .alter_sink_pause(req.sink_id, req.pause)
+            .await?;
+
+        let mutation = if req.pause {
+            Mutation::PauseSinks(PauseSinkMutation {
+                sink_ids: vec![req.sink_id],
+            })
+        } else {
+            Mutation::ResumeSinks(ResumeSinkMutation {
+                sink_ids: vec![req.sink_id],
+            })
+        };
+        self.barrier_scheduler
+            .run_command(Command::Plain(Some(mutation)))
+            .await?;
+
+        Ok(Response::new(AlterSinkPauseResponse {}))
+    }
 }
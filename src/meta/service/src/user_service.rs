@@ -90,6 +90,18 @@ impl UserServiceImpl {
                     }
                     expanded_privileges.push(privilege);
                 }
+            } else if let Some(Object::AllSinksSchemaId(schema_id)) = &privilege.object {
+                let sinks = self.catalog_manager.list_sink_ids(*schema_id).await;
+                for sink_id in sinks {
+                    let mut privilege = privilege.clone();
+                    privilege.object = Some(Object::SinkId(sink_id));
+                    if let Some(with_grant_option) = with_grant_option {
+                        privilege.action_with_opts.iter_mut().for_each(|p| {
+                            p.with_grant_option = with_grant_option;
+                        });
+                    }
+                    expanded_privileges.push(privilege);
+                }
             } else {
                 let mut privilege = privilege.clone();
                 if let Some(with_grant_option) = with_grant_option {
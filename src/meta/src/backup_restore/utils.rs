@@ -61,6 +61,9 @@ pub async fn get_meta_store(opts: RestoreOpts) -> BackupResult<MetaStoreBackendI
             },
         },
         MetaBackend::Mem => MetaStoreBackend::Mem,
+        MetaBackend::Sql => {
+            return Err(anyhow::anyhow!("restore to a SQL meta store backend is not supported yet").into());
+        }
     };
     match meta_store_backend {
         MetaStoreBackend::Etcd {
@@ -78,6 +81,7 @@ pub async fn get_meta_store(opts: RestoreOpts) -> BackupResult<MetaStoreBackendI
             Ok(MetaStoreBackendImpl::Etcd(EtcdMetaStore::new(client)))
         }
         MetaStoreBackend::Mem => Ok(MetaStoreBackendImpl::Mem(MemStore::new())),
+        MetaStoreBackend::Sql { .. } => unreachable!("handled above"),
     }
 }
 
@@ -354,6 +354,8 @@ impl CommandContext {
                     actor_splits,
                     // If the cluster is already paused, the new actors should be paused too.
                     pause: self.current_paused_reason.is_some(),
+                    // A newly created sink is never paused.
+                    paused_sinks: vec![],
                 }))
             }
 
@@ -18,10 +18,13 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::mem::take;
 use std::ops::Deref;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use anyhow::anyhow;
 use fail::fail_point;
 use futures::future::try_join_all;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
 use itertools::Itertools;
 use prometheus::HistogramTimer;
 use risingwave_common::bail;
@@ -39,7 +42,7 @@ use risingwave_pb::stream_plan::Barrier;
 use risingwave_pb::stream_service::{
     BarrierCompleteRequest, BarrierCompleteResponse, InjectBarrierRequest,
 };
-use risingwave_rpc_client::StreamClientPoolRef;
+use risingwave_rpc_client::{ComputeClientPool, StreamClientPoolRef};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot::{Receiver, Sender};
 use tokio::sync::Mutex;
@@ -57,8 +60,8 @@ use crate::barrier::BarrierEpochState::{Completed, InFlight};
 use crate::hummock::HummockManagerRef;
 use crate::manager::sink_coordination::SinkCoordinatorManager;
 use crate::manager::{
-    CatalogManagerRef, ClusterManagerRef, FragmentManagerRef, LocalNotification, MetaSrvEnv,
-    WorkerId,
+    CatalogManagerRef, ClusterManagerRef, EventLevel, FragmentManagerRef, LocalNotification,
+    MetaSrvEnv, WorkerId,
 };
 use crate::model::{ActorId, BarrierManagerState, TableFragments};
 use crate::rpc::metrics::MetaMetrics;
@@ -168,6 +171,9 @@ pub struct GlobalBarrierManager {
     /// The max barrier nums in flight
     in_flight_barrier_nums: usize,
 
+    /// The min barrier nums in flight, i.e. the floor of the adaptive barrier concurrency
+    min_in_flight_barrier_nums: usize,
+
     cluster_manager: ClusterManagerRef,
 
     pub catalog_manager: CatalogManagerRef,
@@ -189,6 +195,62 @@ pub struct GlobalBarrierManager {
     tracker: Mutex<CreateMviewProgressTracker>,
 }
 
+/// Adaptively controls how many barriers are allowed to be in-flight concurrently.
+///
+/// The limit starts at the configured `in_flight_barrier_nums` (the ceiling) and is adjusted
+/// with the completion latency of barriers: when the latency of a barrier spikes compared to
+/// the recent average, the limit is halved so that concurrent barriers do not pile up behind
+/// the stall; when barriers complete quickly, the limit is increased by one at a time until
+/// it reaches the ceiling again. The limit never goes below the configured
+/// `min_in_flight_barrier_nums` (the floor).
+struct ConcurrentControl {
+    /// The current limit on the number of in-flight barriers.
+    limit: usize,
+    /// The lower bound of the limit.
+    floor: usize,
+    /// The upper bound of the limit.
+    ceiling: usize,
+    /// Exponential moving average of recent barrier completion latencies.
+    avg_latency: Option<Duration>,
+}
+
+impl ConcurrentControl {
+    /// Latencies shorter than this are never considered a stall, no matter how they compare
+    /// to the recent average.
+    const SHRINK_THRESHOLD: Duration = Duration::from_millis(500);
+
+    fn new(floor: usize, ceiling: usize) -> Self {
+        let floor = floor.clamp(1, ceiling);
+        Self {
+            limit: ceiling,
+            floor,
+            ceiling,
+            avg_latency: None,
+        }
+    }
+
+    /// The current limit on the number of in-flight barriers, further reduced by the number of
+    /// completed barriers that are still waiting to be committed to Hummock. A growing commit
+    /// backlog indicates that the meta node or the object store cannot keep up, so injecting
+    /// more barriers would only amplify the stall.
+    fn in_flight_barrier_nums(&self, commit_backlog: usize) -> usize {
+        self.limit.saturating_sub(commit_backlog).max(self.floor)
+    }
+
+    /// Feed the completion latency of a barrier to adjust the limit.
+    fn observe_latency(&mut self, latency: Duration) {
+        let avg = *self.avg_latency.get_or_insert(latency);
+        if latency > Self::SHRINK_THRESHOLD && latency > avg * 2 {
+            // Latency is growing. Shrink multiplicatively to react fast to stalls.
+            self.limit = (self.limit / 2).max(self.floor);
+        } else if latency <= avg {
+            // Barriers are completing quickly. Expand conservatively back to the ceiling.
+            self.limit = (self.limit + 1).min(self.ceiling);
+        }
+        self.avg_latency = Some((avg * 7 + latency) / 8);
+    }
+}
+
 /// Controls the concurrent execution of commands.
 struct CheckpointControl {
     /// Save the state and message of barrier in order.
@@ -209,12 +271,15 @@ struct CheckpointControl {
 
     metrics: Arc<MetaMetrics>,
 
+    /// Controls the number of in-flight barriers allowed.
+    concurrent_control: ConcurrentControl,
+
     /// Get notified when we finished Create MV and collect a barrier(checkpoint = true)
     finished_jobs: Vec<TrackingJob>,
 }
 
 impl CheckpointControl {
-    fn new(metrics: Arc<MetaMetrics>) -> Self {
+    fn new(metrics: Arc<MetaMetrics>, concurrent_control: ConcurrentControl) -> Self {
         Self {
             command_ctx_queue: Default::default(),
             creating_tables: Default::default(),
@@ -222,6 +287,7 @@ impl CheckpointControl {
             adding_actors: Default::default(),
             removing_actors: Default::default(),
             metrics,
+            concurrent_control,
             finished_jobs: Default::default(),
         }
     }
@@ -359,6 +425,7 @@ impl CheckpointControl {
         let timer = self.metrics.barrier_latency.start_timer();
 
         self.command_ctx_queue.push_back(EpochNode {
+            enqueue_time: Instant::now(),
             timer: Some(timer),
             wait_commit_timer: None,
 
@@ -385,6 +452,8 @@ impl CheckpointControl {
             assert!(matches!(node.state, InFlight));
             node.wait_commit_timer = Some(wait_commit_timer);
             node.state = Completed(result);
+            self.concurrent_control
+                .observe_latency(node.enqueue_time.elapsed());
         };
         // Find all continuous nodes with 'Complete' starting from first node
         let index = self
@@ -409,13 +478,16 @@ impl CheckpointControl {
     }
 
     /// Pause inject barrier until True.
-    fn can_inject_barrier(&self, in_flight_barrier_nums: usize) -> bool {
-        let in_flight_not_full = self
+    fn can_inject_barrier(&self) -> bool {
+        let in_flight_nums = self
             .command_ctx_queue
             .iter()
             .filter(|x| matches!(x.state, InFlight))
-            .count()
-            < in_flight_barrier_nums;
+            .count();
+        // Completed barriers still in the queue are waiting for earlier epochs to be committed.
+        let commit_backlog = self.command_ctx_queue.len() - in_flight_nums;
+        let in_flight_not_full =
+            in_flight_nums < self.concurrent_control.in_flight_barrier_nums(commit_backlog);
 
         // Whether some command requires pausing concurrent barrier. If so, it must be the last one.
         let should_pause = self
@@ -486,6 +558,8 @@ impl CheckpointControl {
 
 /// The state and message of this barrier, a node for concurrent checkpoint.
 pub struct EpochNode {
+    /// The time when this barrier was enqueued, for the adaptive barrier concurrency.
+    enqueue_time: Instant,
     /// Timer for recording barrier latency, taken after `complete_barriers`.
     timer: Option<HistogramTimer>,
     /// The timer of `barrier_wait_commit_latency`
@@ -513,6 +587,10 @@ enum BarrierEpochState {
 struct BarrierCompletion {
     prev_epoch: u64,
     result: MetaResult<Vec<BarrierCompleteResponse>>,
+    /// The workers that failed to collect the barrier, used to attribute the failure to a
+    /// subset of the graph for partial recovery. Empty if the failure cannot be attributed
+    /// to specific workers, e.g. when the injection itself failed.
+    failed_workers: Vec<WorkerId>,
 }
 
 impl GlobalBarrierManager {
@@ -531,6 +609,7 @@ impl GlobalBarrierManager {
     ) -> Self {
         let enable_recovery = env.opts.enable_recovery;
         let in_flight_barrier_nums = env.opts.in_flight_barrier_nums;
+        let min_in_flight_barrier_nums = env.opts.min_in_flight_barrier_nums;
 
         let tracker = CreateMviewProgressTracker::new();
         let scale_controller = Arc::new(ScaleController::new(
@@ -544,6 +623,7 @@ impl GlobalBarrierManager {
             status: Mutex::new(BarrierManagerStatus::Starting),
             scheduled_barriers,
             in_flight_barrier_nums,
+            min_in_flight_barrier_nums,
             cluster_manager,
             catalog_manager,
             fragment_manager,
@@ -606,10 +686,11 @@ impl GlobalBarrierManager {
                 .barrier_interval_ms() as u64,
         );
         tracing::info!(
-            "Starting barrier manager with: interval={:?}, enable_recovery={}, in_flight_barrier_nums={}",
+            "Starting barrier manager with: interval={:?}, enable_recovery={}, in_flight_barrier_nums={}, min_in_flight_barrier_nums={}",
             interval,
             self.enable_recovery,
             self.in_flight_barrier_nums,
+            self.min_in_flight_barrier_nums,
         );
 
         if !self.enable_recovery && self.fragment_manager.has_any_table_fragments().await {
@@ -647,7 +728,10 @@ impl GlobalBarrierManager {
         let mut min_interval = tokio::time::interval(interval);
         min_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
         let (barrier_complete_tx, mut barrier_complete_rx) = tokio::sync::mpsc::unbounded_channel();
-        let mut checkpoint_control = CheckpointControl::new(self.metrics.clone());
+        let mut checkpoint_control = CheckpointControl::new(
+            self.metrics.clone(),
+            ConcurrentControl::new(self.min_in_flight_barrier_nums, self.in_flight_barrier_nums),
+        );
         let (local_notification_tx, mut local_notification_rx) =
             tokio::sync::mpsc::unbounded_channel();
         self.env
@@ -690,12 +774,12 @@ impl GlobalBarrierManager {
                 }
 
                 // There's barrier scheduled.
-                _ = self.scheduled_barriers.wait_one(), if checkpoint_control.can_inject_barrier(self.in_flight_barrier_nums) => {
+                _ = self.scheduled_barriers.wait_one(), if checkpoint_control.can_inject_barrier() => {
                     min_interval.reset(); // Reset the interval as we have a new barrier.
                     self.handle_new_barrier(&barrier_complete_tx, &mut state, &mut checkpoint_control).await;
                 }
                 // Minimum interval reached.
-                _ = min_interval.tick(), if checkpoint_control.can_inject_barrier(self.in_flight_barrier_nums) => {
+                _ = min_interval.tick(), if checkpoint_control.can_inject_barrier() => {
                     self.handle_new_barrier(&barrier_complete_tx, &mut state, &mut checkpoint_control).await;
                 }
             }
@@ -710,7 +794,7 @@ impl GlobalBarrierManager {
         state: &mut BarrierManagerState,
         checkpoint_control: &mut CheckpointControl,
     ) {
-        assert!(checkpoint_control.can_inject_barrier(self.in_flight_barrier_nums));
+        assert!(checkpoint_control.can_inject_barrier());
 
         let Scheduled {
             command,
@@ -788,12 +872,15 @@ impl GlobalBarrierManager {
                     self.env.stream_client_pool_ref(),
                     command_context,
                     barrier_complete_tx.clone(),
+                    self.env.opts.checkpoint_alignment_timeout_sec,
+                    self.env.opts.recover_stuck_barrier,
                 ));
             }
             Err(e) => {
                 let _ = barrier_complete_tx.send(BarrierCompletion {
                     prev_epoch,
                     result: Err(e),
+                    failed_workers: vec![],
                 });
             }
         }
@@ -832,8 +919,6 @@ impl GlobalBarrierManager {
                     passed_actors: vec![],
                 };
                 async move {
-                    let client = self.env.stream_client_pool().get(node).await?;
-
                     let request = InjectBarrierRequest {
                         request_id,
                         barrier: Some(barrier),
@@ -846,7 +931,15 @@ impl GlobalBarrierManager {
                     );
 
                     // This RPC returns only if this worker node has injected this barrier.
-                    client.inject_barrier(request).await
+                    // Transient connection failures are retried by the pool, so that a
+                    // network blip does not directly escalate into a full recovery.
+                    self.env
+                        .stream_client_pool()
+                        .execute(node, |client| {
+                            let request = request.clone();
+                            async move { client.inject_barrier(request).await }
+                        })
+                        .await
                 }
                 .into()
             }
@@ -855,12 +948,18 @@ impl GlobalBarrierManager {
         Ok(node_need_collect)
     }
 
-    /// Send barrier-complete-rpc and wait for responses from all CNs
+    /// Send barrier-complete-rpc and wait for responses from all CNs.
+    ///
+    /// If `alignment_timeout_sec` is non-zero and the barrier is not collected in time, the
+    /// stuck workers are reported with diagnostics, and with `recover_stuck_barrier` the
+    /// collection is additionally aborted so that they go through recovery.
     async fn collect_barrier(
         node_need_collect: HashMap<WorkerId, bool>,
         client_pool_ref: StreamClientPoolRef,
         command_context: Arc<CommandContext>,
         barrier_complete_tx: UnboundedSender<BarrierCompletion>,
+        alignment_timeout_sec: u64,
+        recover_stuck_barrier: bool,
     ) {
         let prev_epoch = command_context.prev_epoch.value().0;
         let tracing_context =
@@ -875,31 +974,150 @@ impl GlobalBarrierManager {
             } else {
                 let request_id = Uuid::new_v4().to_string();
                 let tracing_context = tracing_context.clone();
+                let node_id = *node_id;
                 async move {
-                    let client = client_pool.get(node).await?;
-                    let request = BarrierCompleteRequest {
-                        request_id,
-                        prev_epoch,
-                        tracing_context,
-                    };
-                    tracing::debug!(
-                        target: "events::meta::barrier::barrier_complete",
-                        ?request, "barrier complete"
-                    );
+                    let result = async {
+                        let client = client_pool.get(node).await?;
+                        let request = BarrierCompleteRequest {
+                            request_id,
+                            prev_epoch,
+                            tracing_context,
+                        };
+                        tracing::debug!(
+                            target: "events::meta::barrier::barrier_complete",
+                            ?request, "barrier complete"
+                        );
 
-                    // This RPC returns only if this worker node has collected this barrier.
-                    client.barrier_complete(request).await
+                        // This RPC returns only if this worker node has collected this barrier.
+                        client.barrier_complete(request).await
+                    }
+                    .await;
+                    (node_id, result)
                 }
                 .into()
             }
         });
 
-        let result = try_join_all(collect_futures).await.map_err(Into::into);
+        // Collect from all workers so that the failure can be attributed to the exact ones,
+        // instead of bailing out on the first error.
+        let mut collect_futures: FuturesUnordered<_> = collect_futures.collect();
+        let mut pending_workers: HashSet<WorkerId> = node_need_collect
+            .iter()
+            .filter(|(_, need_collect)| **need_collect)
+            .map(|(node_id, _)| *node_id)
+            .collect();
+        let deadline = (alignment_timeout_sec > 0)
+            .then(|| tokio::time::Instant::now() + Duration::from_secs(alignment_timeout_sec));
+        let mut alignment_reported = false;
+
+        let mut responses = Vec::new();
+        let mut failed_workers = Vec::new();
+        let mut first_err: Option<MetaError> = None;
+        loop {
+            let next = collect_futures.next();
+            let completed = match deadline {
+                Some(deadline) if !alignment_reported => {
+                    match tokio::time::timeout_at(deadline, next).await {
+                        Ok(completed) => completed,
+                        Err(_) => {
+                            // The barrier is stuck: report the workers that have not collected
+                            // it yet, along with their pending actors and stack traces.
+                            alignment_reported = true;
+                            Self::report_stuck_workers(&info, &pending_workers, prev_epoch).await;
+                            if recover_stuck_barrier {
+                                failed_workers.extend(pending_workers.iter().copied());
+                                first_err = Some(
+                                    anyhow!(
+                                        "barrier exceeded the alignment timeout of {}s, stuck workers: {:?}",
+                                        alignment_timeout_sec,
+                                        pending_workers
+                                    )
+                                    .into(),
+                                );
+                                break;
+                            }
+                            // Otherwise, keep waiting for the stragglers.
+                            continue;
+                        }
+                    }
+                }
+                _ => next.await,
+            };
+            let Some((node_id, result)) = completed else {
+                break;
+            };
+            pending_workers.remove(&node_id);
+            match result {
+                Ok(resp) => responses.push(resp),
+                Err(err) => {
+                    failed_workers.push(node_id);
+                    if first_err.is_none() {
+                        first_err = Some(err.into());
+                    }
+                }
+            }
+        }
+        let result = match first_err {
+            None => Ok(responses),
+            Some(err) => Err(err),
+        };
         let _ = barrier_complete_tx
-            .send(BarrierCompletion { prev_epoch, result })
+            .send(BarrierCompletion {
+                prev_epoch,
+                result,
+                failed_workers,
+            })
             .inspect_err(|err| tracing::warn!("failed to complete barrier: {err}"));
     }
 
+    /// Log diagnostics for the workers that have not collected a barrier in time: the actors
+    /// that are still pending on them, and their await-tree dumps if enabled on the compute
+    /// nodes.
+    async fn report_stuck_workers(
+        info: &BarrierActorInfo,
+        stuck_workers: &HashSet<WorkerId>,
+        prev_epoch: u64,
+    ) {
+        let compute_clients = ComputeClientPool::default();
+        for node_id in stuck_workers {
+            let Some(node) = info.node_map.get(node_id) else {
+                continue;
+            };
+            let pending_actors = info.actor_ids_to_collect(node_id).collect_vec();
+            tracing::warn!(
+                worker_id = *node_id,
+                host = ?node.host,
+                ?pending_actors,
+                prev_epoch,
+                "worker has not collected the barrier within the alignment timeout"
+            );
+            let traces = match compute_clients.get(node).await {
+                Ok(client) => client.stack_trace().await,
+                Err(err) => Err(err),
+            };
+            match traces {
+                Ok(resp) => {
+                    for actor_id in &pending_actors {
+                        if let Some(trace) = resp.actor_traces.get(actor_id) {
+                            tracing::warn!(
+                                actor_id = *actor_id,
+                                "await-tree dump of pending actor:\n{}",
+                                trace
+                            );
+                        }
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        worker_id = *node_id,
+                        "failed to dump stack trace of stuck worker: {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
     /// Changes the state to `Complete`, and try to commit all epoch that state is `Complete` in
     /// order. If commit is err, all nodes will be handled.
     async fn handle_barrier_complete(
@@ -908,7 +1126,11 @@ impl GlobalBarrierManager {
         state: &mut BarrierManagerState,
         checkpoint_control: &mut CheckpointControl,
     ) {
-        let BarrierCompletion { prev_epoch, result } = completion;
+        let BarrierCompletion {
+            prev_epoch,
+            result,
+            failed_workers,
+        } = completion;
 
         // Received barrier complete responses with an epoch that is not managed by checkpoint
         // control, which means a recovery has been triggered. We should ignore it because
@@ -928,7 +1150,7 @@ impl GlobalBarrierManager {
             fail_point!("inject_barrier_err_success");
             let fail_node = checkpoint_control.barrier_failed();
             tracing::warn!("Failed to complete epoch {}: {:?}", prev_epoch, err);
-            self.failure_recovery(err, fail_node, state, checkpoint_control)
+            self.failure_recovery(err, failed_workers, fail_node, state, checkpoint_control)
                 .await;
             return;
         }
@@ -955,7 +1177,8 @@ impl GlobalBarrierManager {
                 .drain(index..)
                 .chain(checkpoint_control.barrier_failed().into_iter());
             tracing::warn!("Failed to commit epoch {}: {:?}", prev_epoch, err);
-            self.failure_recovery(err, fail_nodes, state, checkpoint_control)
+            // Commit failures are not attributable to specific workers.
+            self.failure_recovery(err, vec![], fail_nodes, state, checkpoint_control)
                 .await;
         }
     }
@@ -963,6 +1186,7 @@ impl GlobalBarrierManager {
     async fn failure_recovery(
         &self,
         err: MetaError,
+        failed_workers: Vec<WorkerId>,
         fail_nodes: impl IntoIterator<Item = EpochNode>,
         state: &mut BarrierManagerState,
         checkpoint_control: &mut CheckpointControl,
@@ -982,9 +1206,42 @@ impl GlobalBarrierManager {
         }
 
         if self.enable_recovery {
+            self.env.event_log_manager().report(
+                "RECOVERY",
+                EventLevel::Error,
+                format!("recovery triggered: {}", err),
+            );
             self.set_status(BarrierManagerStatus::Recovering).await;
             let latest_snapshot = self.hummock_manager.latest_snapshot();
             let prev_epoch = TracedEpoch::new(latest_snapshot.committed_epoch.into()); // we can only recovery from the committed epoch
+
+            // If the failure is attributed to specific workers, first try to recover only the
+            // actors on them, so that actors on healthy workers keep running.
+            if self.env.opts.enable_partial_recovery && !failed_workers.is_empty() {
+                let span = tracing::info_span!(
+                    "partial_recovery",
+                    %err,
+                    prev_epoch = prev_epoch.value().0
+                );
+                match self
+                    .partial_recovery(prev_epoch.clone(), &failed_workers)
+                    .instrument(span)
+                    .await
+                {
+                    Ok(new_state) => {
+                        *state = new_state;
+                        self.set_status(BarrierManagerStatus::Running).await;
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "partial recovery failed: {:?}, fallback to full recovery",
+                            e
+                        );
+                    }
+                }
+            }
+
             let span = tracing::info_span!(
                 "failure_recovery",
                 %err,
@@ -38,8 +38,8 @@ use crate::barrier::command::CommandContext;
 use crate::barrier::info::BarrierActorInfo;
 use crate::barrier::notifier::Notifier;
 use crate::barrier::progress::CreateMviewProgressTracker;
-use crate::barrier::{CheckpointControl, Command, GlobalBarrierManager};
-use crate::manager::WorkerId;
+use crate::barrier::{CheckpointControl, Command, ConcurrentControl, GlobalBarrierManager};
+use crate::manager::{EventLevel, WorkerId};
 use crate::model::{BarrierManagerState, MigrationPlan};
 use crate::stream::{build_actor_connector_splits, RescheduleOptions};
 use crate::MetaResult;
@@ -60,7 +60,13 @@ impl GlobalBarrierManager {
 
     async fn resolve_actor_info_for_recovery(&self) -> BarrierActorInfo {
         self.resolve_actor_info(
-            &mut CheckpointControl::new(self.metrics.clone()),
+            &mut CheckpointControl::new(
+                self.metrics.clone(),
+                ConcurrentControl::new(
+                    self.min_in_flight_barrier_nums,
+                    self.in_flight_barrier_nums,
+                ),
+            ),
             &Command::barrier(),
         )
         .await
@@ -273,15 +279,17 @@ impl GlobalBarrierManager {
                     }
 
                     // Reset all compute nodes, stop and drop existing actors.
-                    self.reset_compute_nodes(&info).await.inspect_err(|err| {
-                        warn!(err = ?err, "reset compute nodes failed");
-                    })?;
+                    self.reset_compute_nodes(&info, None).await.inspect_err(
+                        |err| {
+                            warn!(err = ?err, "reset compute nodes failed");
+                        },
+                    )?;
 
                     // update and build all actors.
-                    self.update_actors(&info).await.inspect_err(|err| {
+                    self.update_actors(&info, None).await.inspect_err(|err| {
                         warn!(err = ?err, "update actors failed");
                     })?;
-                    self.build_actors(&info).await.inspect_err(|err| {
+                    self.build_actors(&info, None).await.inspect_err(|err| {
                         warn!(err = ?err, "build_actors failed");
                     })?;
 
@@ -293,6 +301,8 @@ impl GlobalBarrierManager {
                         added_actors: Default::default(),
                         actor_splits: build_actor_connector_splits(&source_split_assignments),
                         pause: paused_reason.is_some(),
+                        // Restore the pause state of sinks paused by `ALTER SINK ... PAUSE`.
+                        paused_sinks: self.catalog_manager.list_paused_sink_ids().await,
                     })));
 
                     // Use a different `curr_epoch` for each recovery attempt.
@@ -366,10 +376,140 @@ impl GlobalBarrierManager {
             paused = ?state.paused_reason(),
             "recovery success"
         );
+        self.env.event_log_manager().report(
+            "RECOVERY",
+            EventLevel::Info,
+            format!(
+                "recovery succeeded, epoch: {}",
+                state.in_flight_prev_epoch().value().0
+            ),
+        );
 
         state
     }
 
+    /// Recover only the actors on the given `failed_workers` from the latest epoch, while
+    /// actors on the other workers keep running.
+    ///
+    /// Unlike [`Self::recovery`], this makes a single attempt without retrying: any error is
+    /// returned to the caller, which falls back to full recovery. In particular, we bail out
+    /// if one of the failed workers has gone offline, since its actors can then only be
+    /// brought back by migration.
+    pub async fn partial_recovery(
+        &self,
+        prev_epoch: TracedEpoch,
+        failed_workers: &[WorkerId],
+    ) -> MetaResult<BarrierManagerState> {
+        // Mark blocked and abort buffered schedules, they might be dirty already.
+        self.scheduled_barriers
+            .abort_and_mark_blocked("cluster is under recovering")
+            .await;
+
+        tracing::info!(workers = ?failed_workers, "partial recovery start!");
+        let recovery_timer = self.metrics.recovery_latency.start_timer();
+
+        let recovery_result: MetaResult<_> = try {
+            let info = self.resolve_actor_info_for_recovery().await;
+            let failed_workers: HashSet<WorkerId> = failed_workers.iter().copied().collect();
+
+            if failed_workers
+                .iter()
+                .any(|worker| !info.node_map.contains_key(worker))
+            {
+                Err(anyhow!(
+                    "some failed workers have gone offline, their actors must be migrated"
+                ))?;
+            }
+
+            // Reset only the failed workers, stop and drop the actors on them.
+            self.reset_compute_nodes(&info, Some(&failed_workers))
+                .await
+                .inspect_err(|err| {
+                    warn!(err = ?err, "reset failed workers failed");
+                })?;
+
+            // update and build the actors on the failed workers.
+            self.update_actors(&info, Some(&failed_workers))
+                .await
+                .inspect_err(|err| {
+                    warn!(err = ?err, "update actors failed");
+                })?;
+            self.build_actors(&info, Some(&failed_workers))
+                .await
+                .inspect_err(|err| {
+                    warn!(err = ?err, "build_actors failed");
+                })?;
+
+            // get split assignments for all actors
+            let source_split_assignments = self.source_manager.list_assignments().await;
+            let command = Command::Plain(Some(Mutation::Add(AddMutation {
+                // Actors built during recovery is not treated as newly added actors.
+                actor_dispatchers: Default::default(),
+                added_actors: Default::default(),
+                actor_splits: build_actor_connector_splits(&source_split_assignments),
+                pause: false,
+                // Restore the pause state of sinks paused by `ALTER SINK ... PAUSE`.
+                paused_sinks: self.catalog_manager.list_paused_sink_ids().await,
+            })));
+
+            let new_epoch = prev_epoch.next();
+
+            // Inject the `Initial` barrier to re-initialize all executors. The actors on the
+            // healthy workers simply realign to the new epoch.
+            let command_ctx = Arc::new(CommandContext::new(
+                self.fragment_manager.clone(),
+                self.catalog_manager.clone(),
+                self.hummock_manager.clone(),
+                self.env.stream_client_pool_ref(),
+                info,
+                prev_epoch.clone(),
+                new_epoch.clone(),
+                None,
+                command,
+                BarrierKind::Initial,
+                self.source_manager.clone(),
+                self.scale_controller.clone(),
+                tracing::Span::current(), // recovery span
+            ));
+
+            let (barrier_complete_tx, mut barrier_complete_rx) =
+                tokio::sync::mpsc::unbounded_channel();
+            self.inject_barrier(command_ctx.clone(), &barrier_complete_tx)
+                .await;
+            let res = match barrier_complete_rx.recv().await.unwrap().result {
+                Ok(response) => {
+                    if let Err(err) = command_ctx.post_collect().await {
+                        warn!(err = ?err, "post_collect failed");
+                        Err(err)
+                    } else {
+                        Ok((new_epoch.clone(), response))
+                    }
+                }
+                Err(err) => {
+                    warn!(err = ?err, "inject_barrier failed");
+                    Err(err)
+                }
+            };
+            let (new_epoch, _) = res?;
+
+            BarrierManagerState::new(new_epoch, command_ctx.next_paused_reason())
+        };
+        if recovery_result.is_err() {
+            self.metrics.recovery_failure_cnt.inc();
+        }
+        recovery_timer.observe_duration();
+
+        let state = recovery_result?;
+        self.scheduled_barriers.mark_ready().await;
+
+        tracing::info!(
+            epoch = state.in_flight_prev_epoch().value().0,
+            "partial recovery success"
+        );
+
+        Ok(state)
+    }
+
     /// Migrate actors in expired CNs to newly joined ones, return true if any actor is migrated.
     async fn migrate_actors(&self, info: &BarrierActorInfo) -> MetaResult<bool> {
         debug!("start migrate actors.");
@@ -584,8 +724,14 @@ impl GlobalBarrierManager {
         Ok(new_plan)
     }
 
-    /// Update all actors in compute nodes.
-    async fn update_actors(&self, info: &BarrierActorInfo) -> MetaResult<()> {
+    /// Update all actors in compute nodes. If `workers` is `Some`, only the actors on these
+    /// workers are updated, while the actor info to broadcast still covers all actors so that
+    /// the updated ones can find their remote peers.
+    async fn update_actors(
+        &self,
+        info: &BarrierActorInfo,
+        workers: Option<&HashSet<WorkerId>>,
+    ) -> MetaResult<()> {
         if info.actor_map.is_empty() {
             tracing::debug!("no actor to update, skipping.");
             return Ok(());
@@ -607,6 +753,9 @@ impl GlobalBarrierManager {
 
         let mut node_actors = self.fragment_manager.all_node_actors(false).await;
         for (node_id, actors) in &info.actor_map {
+            if let Some(workers) = workers && !workers.contains(node_id) {
+                continue;
+            }
             let node = info.node_map.get(node_id).unwrap();
             let client = self.env.stream_client_pool().get(node).await?;
 
@@ -629,14 +778,21 @@ impl GlobalBarrierManager {
         Ok(())
     }
 
-    /// Build all actors in compute nodes.
-    async fn build_actors(&self, info: &BarrierActorInfo) -> MetaResult<()> {
+    /// Build all actors in compute nodes, or only those on the given `workers` if specified.
+    async fn build_actors(
+        &self,
+        info: &BarrierActorInfo,
+        workers: Option<&HashSet<WorkerId>>,
+    ) -> MetaResult<()> {
         if info.actor_map.is_empty() {
             tracing::debug!("no actor to build, skipping.");
             return Ok(());
         }
 
         for (node_id, actors) in &info.actor_map {
+            if let Some(workers) = workers && !workers.contains(node_id) {
+                continue;
+            }
             let node = info.node_map.get(node_id).unwrap();
             let client = self.env.stream_client_pool().get(node).await?;
 
@@ -653,17 +809,26 @@ impl GlobalBarrierManager {
         Ok(())
     }
 
-    /// Reset all compute nodes by calling `force_stop_actors`.
-    async fn reset_compute_nodes(&self, info: &BarrierActorInfo) -> MetaResult<()> {
-        let futures = info.node_map.values().map(|worker_node| async move {
-            let client = self.env.stream_client_pool().get(worker_node).await?;
-            debug!(worker = ?worker_node.id, "force stop actors");
-            client
-                .force_stop_actors(ForceStopActorsRequest {
-                    request_id: Uuid::new_v4().to_string(),
-                })
-                .await
-        });
+    /// Reset all compute nodes by calling `force_stop_actors`, or only the given `workers` if
+    /// specified.
+    async fn reset_compute_nodes(
+        &self,
+        info: &BarrierActorInfo,
+        workers: Option<&HashSet<WorkerId>>,
+    ) -> MetaResult<()> {
+        let futures = info
+            .node_map
+            .values()
+            .filter(|worker_node| workers.map_or(true, |workers| workers.contains(&worker_node.id)))
+            .map(|worker_node| async move {
+                let client = self.env.stream_client_pool().get(worker_node).await?;
+                debug!(worker = ?worker_node.id, "force stop actors");
+                client
+                    .force_stop_actors(ForceStopActorsRequest {
+                        request_id: Uuid::new_v4().to_string(),
+                    })
+                    .await
+            });
 
         try_join_all(futures).await?;
         debug!("all compute nodes have been reset.");
@@ -198,6 +198,8 @@ impl From<ObjectModel<sink::Model>> for PbSink {
             sink_from_name: value.0.sink_from_name,
             stream_job_status: PbStreamJobStatus::from(value.0.job_status) as _,
             format_desc: value.0.sink_format_desc.map(|desc| desc.0),
+            // The pause state is not persisted in the SQL backend yet.
+            is_paused: false,
         }
     }
 }
@@ -214,6 +216,7 @@ impl From<ObjectModel<index::Model>> for PbIndex {
             primary_table_id: value.0.primary_table_id as _,
             index_item: value.0.index_items.0,
             original_columns: value.0.original_columns.0,
+            predicate: value.0.predicate.map(|predicate| predicate.0),
             initialized_at_epoch: Some(
                 Epoch::from_unix_millis(value.1.initialized_at.timestamp_millis() as _).0,
             ),
@@ -122,6 +122,7 @@ impl CatalogController {
                 user.auth_info = Set(update_user.auth_info.clone().map(AuthInfo))
             }
             PbUpdateField::Rename => user.name = Set(update_user.name.clone()),
+            PbUpdateField::QueryLimit => user.query_limit = Set(update_user.query_limit as _),
         });
 
         let user = user.update(&inner.db).await?;
@@ -100,6 +100,7 @@ impl IntraCompactionPicker {
             }
 
             if level_handler.is_level_all_pending_compact(level) {
+                stats.skip_by_pending_files += 1;
                 continue;
             }
 
@@ -365,6 +365,9 @@ mod test {
                         table_id as u32,
                         TableOption {
                             retention_seconds: Some(5_u32),
+                            bloom_false_positive: None,
+                            compression_algorithm: None,
+                            compression_level: None,
                         },
                     )
                 })
@@ -413,6 +416,9 @@ mod test {
                         table_id as u32,
                         TableOption {
                             retention_seconds: Some(5_u32),
+                            bloom_false_positive: None,
+                            compression_algorithm: None,
+                            compression_level: None,
                         },
                     )
                 })
@@ -493,6 +499,9 @@ mod test {
                         table_id as u32,
                         TableOption {
                             retention_seconds: Some(7200),
+                            bloom_false_positive: None,
+                            compression_algorithm: None,
+                            compression_level: None,
                         },
                     )
                 })
@@ -502,6 +511,9 @@ mod test {
                 5,
                 TableOption {
                     retention_seconds: Some(5),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -576,6 +588,9 @@ mod test {
                         table_id as u32,
                         TableOption {
                             retention_seconds: Some(5_u32),
+                            bloom_false_positive: None,
+                            compression_algorithm: None,
+                            compression_level: None,
                         },
                     )
                 })
@@ -586,6 +601,9 @@ mod test {
                 5,
                 TableOption {
                     retention_seconds: Some(7200_u32),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -593,6 +611,9 @@ mod test {
                 8,
                 TableOption {
                     retention_seconds: Some(7200_u32),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -600,6 +621,9 @@ mod test {
                 9,
                 TableOption {
                     retention_seconds: Some(7200_u32),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -658,6 +682,9 @@ mod test {
                         table_id as u32,
                         TableOption {
                             retention_seconds: Some(5_u32),
+                            bloom_false_positive: None,
+                            compression_algorithm: None,
+                            compression_level: None,
                         },
                     )
                 })
@@ -668,6 +695,9 @@ mod test {
                 5,
                 TableOption {
                     retention_seconds: Some(7200_u32),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -675,6 +705,9 @@ mod test {
                 8,
                 TableOption {
                     retention_seconds: Some(7200_u32),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -682,6 +715,9 @@ mod test {
                 9,
                 TableOption {
                     retention_seconds: Some(7200_u32),
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
             );
 
@@ -693,6 +729,9 @@ mod test {
                         5,
                         TableOption {
                             retention_seconds: Some(5_u32),
+                            bloom_false_positive: None,
+                            compression_algorithm: None,
+                            compression_level: None,
                         },
                     );
                 }
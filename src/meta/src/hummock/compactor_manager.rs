@@ -47,6 +47,8 @@ pub struct Compactor {
 
 struct TaskHeartbeat {
     task: CompactTask,
+    /// The context of the compactor the task is assigned to.
+    context_id: HummockContextId,
     num_ssts_sealed: u32,
     num_ssts_uploaded: u32,
     num_progress_key: u64,
@@ -134,7 +136,8 @@ impl CompactorManagerInner {
         };
         // Initialize heartbeat for existing tasks.
         task_assignment.into_iter().for_each(|assignment| {
-            manager.initiate_task_heartbeat(assignment.compact_task.unwrap());
+            manager
+                .initiate_task_heartbeat(assignment.context_id, assignment.compact_task.unwrap());
         });
         Ok(manager)
     }
@@ -248,6 +251,7 @@ impl CompactorManagerInner {
         for TaskHeartbeat {
             expire_at,
             task,
+            context_id: _,
             create_time,
             num_ssts_sealed,
             num_ssts_uploaded,
@@ -286,7 +290,7 @@ impl CompactorManagerInner {
         cancellable_tasks
     }
 
-    pub fn initiate_task_heartbeat(&mut self, task: CompactTask) {
+    pub fn initiate_task_heartbeat(&mut self, context_id: HummockContextId, task: CompactTask) {
         let now = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .expect("Clock may have gone backwards")
@@ -295,6 +299,7 @@ impl CompactorManagerInner {
             task.task_id,
             TaskHeartbeat {
                 task,
+                context_id,
                 num_ssts_sealed: 0,
                 num_ssts_uploaded: 0,
                 num_progress_key: 0,
@@ -310,6 +315,27 @@ impl CompactorManagerInner {
         self.task_heartbeats.remove(&task_id).unwrap();
     }
 
+    /// Records that the task has been sent to the compactor identified by `context_id`.
+    pub fn assign_compact_task(
+        &mut self,
+        task_id: HummockCompactionTaskId,
+        context_id: HummockContextId,
+    ) {
+        if let Some(heartbeat) = self.task_heartbeats.get_mut(&task_id) {
+            heartbeat.context_id = context_id;
+        }
+    }
+
+    /// Returns the tasks assigned to the compactor identified by `context_id`. The heartbeats of
+    /// the returned tasks are kept until the tasks are successfully reported.
+    pub fn get_assigned_tasks(&self, context_id: HummockContextId) -> Vec<CompactTask> {
+        self.task_heartbeats
+            .values()
+            .filter(|heartbeat| heartbeat.context_id == context_id)
+            .map(|heartbeat| heartbeat.task.clone())
+            .collect()
+    }
+
     pub fn update_task_heartbeats(
         &mut self,
         progress_list: &Vec<CompactTaskProgress>,
@@ -422,14 +448,26 @@ impl CompactorManager {
         self.inner.read().get_expired_tasks(interval_sec)
     }
 
-    pub fn initiate_task_heartbeat(&self, task: CompactTask) {
-        self.inner.write().initiate_task_heartbeat(task);
+    pub fn initiate_task_heartbeat(&self, context_id: HummockContextId, task: CompactTask) {
+        self.inner.write().initiate_task_heartbeat(context_id, task);
     }
 
     pub fn remove_task_heartbeat(&self, task_id: u64) {
         self.inner.write().remove_task_heartbeat(task_id);
     }
 
+    pub fn assign_compact_task(
+        &self,
+        task_id: HummockCompactionTaskId,
+        context_id: HummockContextId,
+    ) {
+        self.inner.write().assign_compact_task(task_id, context_id);
+    }
+
+    pub fn get_assigned_tasks(&self, context_id: HummockContextId) -> Vec<CompactTask> {
+        self.inner.read().get_assigned_tasks(context_id)
+    }
+
     pub fn update_task_heartbeats(
         &self,
         progress_list: &Vec<CompactTaskProgress>,
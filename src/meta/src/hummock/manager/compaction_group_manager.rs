@@ -41,6 +41,7 @@ use crate::hummock::compaction::compaction_config::{
     validate_compaction_config, CompactionConfigBuilder,
 };
 use crate::hummock::error::{Error, Result};
+use crate::hummock::manager::versioning::Versioning;
 use crate::hummock::manager::{drop_sst, read_lock, HummockManager};
 use crate::hummock::metrics_utils::remove_compaction_group_in_sst_stat;
 use crate::hummock::model::CompactionGroup;
@@ -477,7 +478,9 @@ impl HummockManager {
             }
         }
 
-        if table_ids.len() == parent_group.member_table_ids.len() {
+        if target_group_id.is_none() && table_ids.len() == parent_group.member_table_ids.len() {
+            // Splitting out all member tables into a new group would only rename the group.
+            // Moving them all to an existing group is allowed though, which merges the groups.
             return Err(Error::CompactionGroup(format!(
                 "invalid split attempt for group {}: all member tables are moved",
                 parent_group_id
@@ -638,6 +641,20 @@ impl HummockManager {
         new_version_delta.commit();
         branched_ssts.commit_memory();
         self.notify_last_version_delta(versioning);
+        if target_group_id.is_some() {
+            // If all member tables have been moved to the target group, the now-empty origin
+            // group is destroyed in a follow-up version delta. A failure here only leaves an
+            // empty group behind and does not affect the move itself.
+            if let Err(e) = self
+                .destroy_empty_compaction_group(versioning, parent_group_id)
+                .await
+            {
+                warn!(
+                    "failed to destroy empty compaction group {}: {:?}",
+                    parent_group_id, e
+                );
+            }
+        }
         drop(versioning_guard);
         let mut canceled_tasks = vec![];
         for task_assignment in compaction_guard.compact_task_assignment.values() {
@@ -691,6 +708,76 @@ impl HummockManager {
         Ok(target_compaction_group_id)
     }
 
+    /// Destroys the given compaction group if it has become empty, e.g. after all its member
+    /// tables have been moved to another group. SSTs whose data has been branched into other
+    /// groups are kept alive by those branches; the rest only contain data of dropped tables
+    /// and are subject to GC.
+    async fn destroy_empty_compaction_group(
+        &self,
+        versioning: &mut Versioning,
+        group_id: CompactionGroupId,
+    ) -> Result<()> {
+        if group_id <= StaticCompactionGroupId::End as CompactionGroupId {
+            return Ok(());
+        }
+        match versioning.current_version.levels.get(&group_id) {
+            Some(levels) if levels.member_table_ids.is_empty() => {}
+            _ => return Ok(()),
+        }
+        let current_version = &versioning.current_version;
+        let mut new_version_delta = BTreeMapEntryTransaction::new_insert(
+            &mut versioning.hummock_version_deltas,
+            current_version.id + 1,
+            build_version_delta_after_version(current_version),
+        );
+        let mut branched_ssts = BTreeMapTransaction::new(&mut versioning.branched_ssts);
+        for (object_id, sst_id) in get_compaction_group_ssts(current_version, group_id) {
+            if drop_sst(&mut branched_ssts, group_id, object_id, sst_id) {
+                new_version_delta.gc_object_ids.push(object_id);
+            }
+        }
+        let group_deltas = &mut new_version_delta
+            .group_deltas
+            .entry(group_id)
+            .or_default()
+            .group_deltas;
+        group_deltas.push(GroupDelta {
+            delta_type: Some(DeltaType::GroupDestroy(GroupDestroy {})),
+        });
+
+        let mut current_version = versioning.current_version.clone();
+        let sst_split_info = current_version.apply_version_delta(&new_version_delta);
+        assert!(sst_split_info.is_empty());
+
+        let mut trx = Transaction::default();
+        new_version_delta.apply_to_txn(&mut trx).await?;
+        self.env.meta_store().txn(trx).await?;
+
+        let max_level = versioning
+            .current_version
+            .get_compaction_group_levels(group_id)
+            .get_levels()
+            .len();
+        remove_compaction_group_in_sst_stat(&self.metrics, group_id, max_level);
+
+        versioning.current_version = current_version;
+        new_version_delta.commit();
+        branched_ssts.commit_memory();
+        self.notify_last_version_delta(versioning);
+
+        self.compaction_group_manager
+            .write()
+            .await
+            .purge(
+                HashSet::from_iter(get_compaction_group_ids(&versioning.current_version)),
+                self.env.meta_store(),
+            )
+            .await
+            .inspect_err(|e| tracing::warn!("failed to purge stale compaction group config. {}", e))
+            .ok();
+        Ok(())
+    }
+
     #[named]
     pub async fn calculate_compaction_group_statistic(&self) -> Vec<TableGroupInfo> {
         let mut infos = vec![];
@@ -19,7 +19,7 @@ use fail::fail_point;
 use function_name::named;
 use itertools::Itertools;
 use risingwave_hummock_sdk::{
-    ExtendedSstableInfo, HummockContextId, HummockEpoch, HummockSstableObjectId,
+    ExtendedSstableInfo, HummockContextId, HummockEpoch, HummockSstableObjectId, OBJECT_SUFFIX,
 };
 use risingwave_pb::hummock::{HummockVersion, ValidationTask};
 
@@ -142,6 +142,10 @@ impl HummockManager {
             .into());
         }
 
+        if self.env.opts.enable_canary_checkpoint_validation {
+            self.validate_committed_sst_objects(sstables).await?;
+        }
+
         async {
             if !self.env.opts.enable_committed_sst_sanity_check {
                 return;
@@ -175,6 +179,57 @@ impl HummockManager {
         Ok(())
     }
 
+    /// Canary validation of newly committed SST objects: read back each object's metadata and a
+    /// tiny tail of its content, which covers (part of) the sstable metadata/footer region, to
+    /// catch object store write corruption before the commit becomes visible and downstream
+    /// sinks acknowledge the epoch to external systems.
+    async fn validate_committed_sst_objects(
+        &self,
+        sstables: &[ExtendedSstableInfo],
+    ) -> Result<()> {
+        /// Upper bound of the canary read size per object.
+        const CANARY_READ_BYTES: u64 = 4096;
+
+        for ExtendedSstableInfo { sst_info, .. } in sstables {
+            let object_id = sst_info.get_object_id();
+            let path = format!(
+                "{}/{}{}.{}",
+                self.object_store_dir,
+                self.object_store.get_object_prefix(object_id),
+                object_id,
+                OBJECT_SUFFIX
+            );
+            let metadata = self.object_store.metadata(&path).await?;
+            if metadata.total_size as u64 != sst_info.file_size {
+                return Err(anyhow::anyhow!(
+                    "committed SST object {} has size {} in object store, expected {}",
+                    path,
+                    metadata.total_size,
+                    sst_info.file_size
+                )
+                .into());
+            }
+            let read_start = sst_info
+                .file_size
+                .saturating_sub(CANARY_READ_BYTES)
+                .max(sst_info.meta_offset);
+            let footer = self
+                .object_store
+                .read(&path, read_start as usize..sst_info.file_size as usize)
+                .await?;
+            if footer.len() as u64 != sst_info.file_size - read_start {
+                return Err(anyhow::anyhow!(
+                    "committed SST object {} footer read returned {} bytes, expected {}",
+                    path,
+                    footer.len(),
+                    sst_info.file_size - read_start
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
     pub async fn release_meta_context(&self) -> Result<()> {
         self.release_contexts([META_NODE_ID]).await
     }
@@ -38,10 +38,11 @@ use risingwave_hummock_sdk::compaction_group::hummock_version_ext::{
     get_table_compaction_group_id_mapping, try_get_compaction_group_id_by_table_id,
     BranchedSstInfo, HummockLevelsExt, HummockVersionExt, HummockVersionUpdateExt,
 };
+use risingwave_hummock_sdk::prost_key_range::KeyRangeExt;
 use risingwave_hummock_sdk::{
-    version_checkpoint_path, CompactionGroupId, ExtendedSstableInfo, HummockCompactionTaskId,
-    HummockContextId, HummockEpoch, HummockSstableId, HummockSstableObjectId, HummockVersionId,
-    SstObjectIdRange, INVALID_VERSION_ID,
+    can_concat, version_checkpoint_path, CompactionGroupId, ExtendedSstableInfo,
+    HummockCompactionTaskId, HummockContextId, HummockEpoch, HummockSstableId,
+    HummockSstableObjectId, HummockVersionId, SstObjectIdRange, INVALID_VERSION_ID,
 };
 use risingwave_pb::hummock::compact_task::{self, TaskStatus, TaskType};
 use risingwave_pb::hummock::group_delta::DeltaType;
@@ -80,7 +81,8 @@ use crate::hummock::metrics_utils::{
 };
 use crate::hummock::{CompactorManagerRef, TASK_NORMAL};
 use crate::manager::{
-    CatalogManagerRef, ClusterManagerRef, FragmentManagerRef, IdCategory, MetaSrvEnv, META_NODE_ID,
+    CatalogManagerRef, ClusterManagerRef, EventLevel, FragmentManagerRef, IdCategory, MetaSrvEnv,
+    META_NODE_ID,
 };
 use crate::model::{
     BTreeMapEntryTransaction, BTreeMapTransaction, ClusterId, MetadataModel, ValTransaction,
@@ -132,6 +134,8 @@ pub struct HummockManager {
     event_sender: HummockManagerEventSender,
 
     object_store: ObjectStoreRef,
+    /// Data directory of the object store, in which SST objects reside.
+    object_store_dir: String,
     version_checkpoint_path: String,
     pause_version_checkpoint: AtomicBool,
     history_table_throughput: parking_lot::RwLock<HashMap<u32, VecDeque<u64>>>,
@@ -385,6 +389,7 @@ impl HummockManager {
             }),
             event_sender: tx,
             object_store,
+            object_store_dir: state_store_dir.to_string(),
             version_checkpoint_path: checkpoint_path,
             pause_version_checkpoint: AtomicBool::new(false),
             history_table_throughput: parking_lot::RwLock::new(HashMap::default()),
@@ -984,9 +989,10 @@ impl HummockManager {
                 compact_task_assignment
             )?;
 
-            // Initiate heartbeat for the task to track its progress.
+            // Initiate heartbeat for the task to track its progress. The task is attributed to
+            // the compactor it is sent to via `assign_compact_task` later.
             self.compactor_manager
-                .initiate_task_heartbeat(compact_task.clone());
+                .initiate_task_heartbeat(META_NODE_ID, compact_task.clone());
 
             // this task has been finished.
             compact_task.set_task_status(TaskStatus::Pending);
@@ -1087,6 +1093,26 @@ impl HummockManager {
         Ok(ret)
     }
 
+    /// Cancels all tasks assigned to the compactor identified by `context_id`, so that a compactor
+    /// leaving the cluster does not block the level handlers until the heartbeats of its tasks
+    /// expire. The input SSTs are released and can be picked again by other compactors.
+    pub async fn cancel_compact_tasks_assigned_to(&self, context_id: HummockContextId) {
+        for task in self.compactor_manager.get_assigned_tasks(context_id) {
+            tracing::info!(
+                "Cancelling compaction task {} since the compactor {} it is assigned to has left the cluster",
+                task.task_id,
+                context_id,
+            );
+            if let Err(e) = self
+                .cancel_compact_task(task.task_id, TaskStatus::HeartbeatCanceled)
+                .await
+            {
+                tracing::error!("Attempt to cancel compaction task on compactor exit failed. We will continue to track its heartbeat
+                    until we can successfully report its status. task_id: {}, ERR: {e:?}", task.task_id);
+            }
+        }
+    }
+
     // need mutex protect
     async fn precheck_compaction_group(
         &self,
@@ -1343,6 +1369,22 @@ impl HummockManager {
         let task_status_label = task_status.as_str_name();
         let task_type_label = compact_task.task_type().as_str_name();
 
+        if matches!(
+            task_status,
+            TaskStatus::ExecuteFailed
+                | TaskStatus::JoinHandleFailed
+                | TaskStatus::TrackSstObjectIdFailed
+        ) {
+            self.env.event_log_manager().report(
+                "COMPACTION",
+                EventLevel::Error,
+                format!(
+                    "compaction task {} of group {} failed with status {}",
+                    compact_task.task_id, compact_task.compaction_group_id, task_status_label
+                ),
+            );
+        }
+
         let label = if is_trivial_reclaim {
             "trivial-space-reclaim"
         } else if is_trivial_move {
@@ -1653,6 +1695,138 @@ impl HummockManager {
         Ok(Some(snapshot))
     }
 
+    /// Registers externally built SSTs into the given level of `compaction_group_id` directly,
+    /// bypassing the shared-buffer write path. This is the bulk-load path: the SSTs must only
+    /// contain data of member tables of the group, with all event epochs no greater than the max
+    /// committed epoch, so that the data becomes visible right after the version delta is applied.
+    ///
+    /// `target_level` must be a non-L0 level, so the SSTs are required to be sorted by key and
+    /// non-overlapping, both among themselves and with the SSTs already in the level.
+    #[named]
+    pub async fn ingest_sstables(
+        &self,
+        compaction_group_id: CompactionGroupId,
+        target_level: u32,
+        sstables: Vec<SstableInfo>,
+    ) -> Result<()> {
+        if sstables.is_empty() {
+            return Ok(());
+        }
+        if target_level == 0 {
+            return Err(anyhow::anyhow!(
+                "cannot ingest SSTs into L0, use the regular write path instead"
+            )
+            .into());
+        }
+
+        let mut versioning_guard = write_lock!(self, versioning).await;
+        let _timer = start_measure_real_process_timer!(self);
+        let versioning = versioning_guard.deref_mut();
+        let old_version = &versioning.current_version;
+
+        let Some(compaction_group) = old_version.levels.get(&compaction_group_id) else {
+            return Err(Error::CompactionGroup(format!(
+                "invalid group {}",
+                compaction_group_id
+            )));
+        };
+        let Some(level) = compaction_group
+            .levels
+            .get(target_level as usize - 1)
+            .filter(|l| l.level_idx == target_level)
+        else {
+            return Err(anyhow::anyhow!(
+                "level {} does not exist in compaction group {}",
+                target_level,
+                compaction_group_id
+            )
+            .into());
+        };
+        for sst in &sstables {
+            if !sst
+                .table_ids
+                .iter()
+                .all(|t| compaction_group.member_table_ids.contains(t))
+            {
+                return Err(anyhow::anyhow!(
+                    "SST {} contains tables that are not members of compaction group {}",
+                    sst.get_object_id(),
+                    compaction_group_id
+                )
+                .into());
+            }
+        }
+        // Check that the level remains non-overlapping after the insertion, which is what
+        // `level_insert_ssts` will assert when the delta is applied.
+        let merged = level
+            .table_infos
+            .iter()
+            .chain(sstables.iter())
+            .cloned()
+            .sorted_by(|sst1, sst2| {
+                let a = sst1.key_range.as_ref().unwrap();
+                let b = sst2.key_range.as_ref().unwrap();
+                a.compare(b)
+            })
+            .collect_vec();
+        if !can_concat(&merged) {
+            return Err(anyhow::anyhow!(
+                "ingested SSTs overlap with each other or with level {} of compaction group {}",
+                target_level,
+                compaction_group_id
+            )
+            .into());
+        }
+
+        let mut new_version_delta = BTreeMapEntryTransaction::new_insert(
+            &mut versioning.hummock_version_deltas,
+            old_version.id + 1,
+            build_version_delta_after_version(old_version),
+        );
+        let group_deltas = &mut new_version_delta
+            .group_deltas
+            .entry(compaction_group_id)
+            .or_default()
+            .group_deltas;
+        group_deltas.push(GroupDelta {
+            delta_type: Some(DeltaType::IntraLevel(IntraLevelDelta {
+                level_idx: target_level,
+                inserted_table_infos: sstables,
+                ..Default::default()
+            })),
+        });
+
+        let mut new_hummock_version = old_version.clone();
+        new_hummock_version.id = new_version_delta.id;
+        new_hummock_version.apply_version_delta(new_version_delta.deref());
+        commit_multi_var!(self, None, Transaction::default(), new_version_delta)?;
+        versioning.current_version = new_hummock_version;
+
+        trigger_version_stat(
+            &self.metrics,
+            &versioning.current_version,
+            &versioning.version_stats,
+        );
+        trigger_sst_stat(
+            &self.metrics,
+            None,
+            &versioning.current_version,
+            compaction_group_id,
+        );
+        self.notify_last_version_delta(versioning);
+        trigger_delta_log_stats(&self.metrics, versioning.hummock_version_deltas.len());
+        drop(versioning_guard);
+
+        // The ingested SSTs may have pushed the level over its size limit.
+        self.try_send_compaction_request(compaction_group_id, compact_task::TaskType::Dynamic);
+        self.try_update_write_limits(&[compaction_group_id]).await;
+        #[cfg(test)]
+        {
+            self.check_state_consistency().await;
+        }
+        Ok(())
+    }
+
     /// We don't commit an epoch without checkpoint. We will only update the `max_current_epoch`.
     pub fn update_current_epoch(&self, max_current_epoch: HummockEpoch) -> HummockSnapshot {
         // We only update `max_current_epoch`!
@@ -1954,6 +2128,7 @@ impl HummockManager {
 
         // 3. send task to compactor
         let compact_task_string = compact_task_to_string(&compact_task);
+        let task_id = compact_task.task_id;
         if let Err(e) = compactor.send_event(ResponseEvent::CompactTask(compact_task)) {
             // TODO: shall we need to cancel on meta ?
             return Err(anyhow::anyhow!(
@@ -1963,6 +2138,8 @@ impl HummockManager {
             )
             .into());
         }
+        self.compactor_manager
+            .assign_compact_task(task_id, compactor.context_id());
 
         tracing::info!(
             "Trigger manual compaction task. {}. cost time: {:?}",
@@ -2204,6 +2381,7 @@ impl HummockManager {
                                     }
 
                                     hummock_manager.on_handle_check_split_multi_group().await;
+                                    hummock_manager.on_handle_check_merge_group().await;
                                 }
 
                                 HummockTimerEvent::Report => {
@@ -2226,6 +2404,12 @@ impl HummockManager {
                                             versioning_deref.version_stats.clone(),
                                         )
                                     };
+                                    let compaction_statuses = {
+                                        let compaction_guard =
+                                            read_lock!(hummock_manager.as_ref(), compaction)
+                                                .await;
+                                        compaction_guard.compaction_statuses.clone()
+                                    };
 
                                     if let Some(mv_id_to_all_table_ids) = hummock_manager
                                         .fragment_manager
@@ -2259,6 +2443,8 @@ impl HummockManager {
                                         trigger_lsm_stat(
                                             &hummock_manager.metrics,
                                             compaction_group_config.compaction_config(),
+                                            compaction_statuses
+                                                .get(&compaction_group_config.group_id()),
                                             group_levels,
                                             compaction_group_config.group_id(),
                                         )
@@ -2559,6 +2745,87 @@ impl HummockManager {
         }
     }
 
+    /// Merges a compaction group split out by `on_handle_check_split_multi_group` back into its
+    /// parent group, once the write throughput of all its member tables has stayed below
+    /// `min_table_split_write_throughput` for a full statistic window and the group has shrunk
+    /// below `min_table_split_size`, i.e. the reverse of the split condition. The emptied group
+    /// is destroyed afterwards.
+    async fn on_handle_check_merge_group(&self) {
+        let params = self.env.system_params_manager().get_params().await;
+        let barrier_interval_ms = params.barrier_interval_ms() as u64;
+        let checkpoint_secs = std::cmp::max(
+            1,
+            params.checkpoint_frequency() * barrier_interval_ms / 1000,
+        );
+        let window_size = HISTORY_TABLE_INFO_STATISTIC_TIME / (checkpoint_secs as usize);
+        let table_write_throughput = self.history_table_throughput.read().clone();
+        let group_infos = self.calculate_compaction_group_statistic().await;
+        let current_version = self.get_current_version().await;
+        for group in &group_infos {
+            // Only groups created by a split are merged back, never the static ones.
+            if group.group_id <= StaticCompactionGroupId::End as CompactionGroupId {
+                continue;
+            }
+            if group.table_statistic.is_empty()
+                || group.group_size > self.env.opts.min_table_split_size
+            {
+                continue;
+            }
+            // Require a full window of statistics for every member table, so that a table which
+            // has just been created or just stopped writing does not trigger a premature merge.
+            let is_low_write_throughput = group.table_statistic.keys().all(|table_id| {
+                table_write_throughput
+                    .get(table_id)
+                    .map(|history| {
+                        history.len() >= window_size
+                            && history.iter().all(|throughput| {
+                                *throughput / checkpoint_secs
+                                    < self.env.opts.min_table_split_write_throughput
+                            })
+                    })
+                    .unwrap_or(false)
+            });
+            if !is_low_write_throughput {
+                continue;
+            }
+            let parent_group_id = match current_version.levels.get(&group.group_id) {
+                Some(levels) if current_version.levels.contains_key(&levels.parent_group_id) => {
+                    levels.parent_group_id
+                }
+                _ => continue,
+            };
+            let table_ids = group.table_statistic.keys().cloned().collect_vec();
+            let ret = self
+                .move_state_table_to_compaction_group(
+                    group.group_id,
+                    &table_ids,
+                    Some(parent_group_id),
+                    false,
+                    0,
+                )
+                .await;
+            match ret {
+                Ok(_) => {
+                    tracing::info!(
+                        "merge state tables {:?} from group-{} back to group-{} success",
+                        table_ids,
+                        group.group_id,
+                        parent_group_id
+                    );
+                    return;
+                }
+                Err(e) => {
+                    tracing::info!(
+                        "failed to merge group-{} back to group-{} because {:?}",
+                        group.group_id,
+                        parent_group_id,
+                        e
+                    )
+                }
+            }
+        }
+    }
+
     #[named]
     pub fn compaction_event_loop(
         hummock_manager: Arc<Self>,
@@ -2619,6 +2886,7 @@ impl HummockManager {
                                 tracing::warn!("compactor {} leaving the cluster with err {:?}", context_id, err);
                                 hummock_manager.compactor_manager
                                     .remove_compactor(context_id);
+                                hummock_manager.cancel_compact_tasks_assigned_to(context_id).await;
                                 continue
                             }
 
@@ -2626,6 +2894,7 @@ impl HummockManager {
                                 tracing::warn!("compactor {} leaving the cluster", context_id);
                                 hummock_manager.compactor_manager
                                     .remove_compactor(context_id);
+                                hummock_manager.cancel_compact_tasks_assigned_to(context_id).await;
                                 continue
                             },
                         };
@@ -2695,6 +2964,9 @@ impl HummockManager {
                                                         compactor_alive = false;
                                                         break;
                                                     }
+
+                                                    hummock_manager.compactor_manager
+                                                        .assign_compact_task(task_id, context_id);
                                                 },
                                                 Ok(None) => {
                                                     // no compact_task to be picked
@@ -2791,6 +3063,7 @@ impl HummockManager {
                             tracing::warn!("compactor {} leaving the cluster since it's not alive", context_id);
                             hummock_manager.compactor_manager
                                 .remove_compactor(context_id);
+                            hummock_manager.cancel_compact_tasks_assigned_to(context_id).await;
                         }
                     }
                 }
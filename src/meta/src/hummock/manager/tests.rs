@@ -1475,6 +1475,47 @@ async fn test_split_compaction_group_on_demand_basic() {
     }
 }
 
+#[tokio::test]
+async fn test_merge_compaction_group_back() {
+    let (_env, hummock_manager, _, _worker_node) = setup_compute_env(80).await;
+    hummock_manager
+        .register_table_ids(&[(100, 2)])
+        .await
+        .unwrap();
+    hummock_manager
+        .register_table_ids(&[(101, 2)])
+        .await
+        .unwrap();
+    let new_group_id = hummock_manager
+        .split_compaction_group(2, &[100])
+        .await
+        .unwrap();
+    assert!(new_group_id > StaticCompactionGroupId::End as u64);
+    let current_version = hummock_manager.get_current_version().await;
+    assert_eq!(current_version.levels.len(), 3);
+
+    // Move the only member table back to the parent group. The emptied group is destroyed.
+    hummock_manager
+        .move_state_table_to_compaction_group(new_group_id, &[100], Some(2), false, 0)
+        .await
+        .unwrap();
+    let current_version = hummock_manager.get_current_version().await;
+    assert_eq!(
+        current_version.levels.keys().cloned().sorted().collect_vec(),
+        vec![2, 3]
+    );
+    assert_eq!(
+        current_version
+            .get_compaction_group_levels(2)
+            .member_table_ids
+            .iter()
+            .cloned()
+            .sorted()
+            .collect_vec(),
+        vec![100, 101]
+    );
+}
+
 #[tokio::test]
 async fn test_split_compaction_group_on_demand_non_trivial() {
     let (_env, hummock_manager, _, worker_node) = setup_compute_env(80).await;
@@ -2127,3 +2168,84 @@ async fn test_gc_stats() {
     );
     assert_eq_gc_stats(6, 3, 0, 0, 2, 4);
 }
+
+#[tokio::test]
+async fn test_ingest_sstables() {
+    let (_env, hummock_manager, _, worker_node) = setup_compute_env(80).await;
+    let context_id = worker_node.id;
+    hummock_manager
+        .register_table_ids(&[(100, 2)])
+        .await
+        .unwrap();
+    // Create compaction group 2 in the version via the regular write path.
+    let sst_1 = gen_extend_sstable_info(10, 2, 1, vec![100]);
+    hummock_manager
+        .commit_epoch(30, vec![sst_1], HashMap::from([(10, context_id)]))
+        .await
+        .unwrap();
+    let current_version = hummock_manager.get_current_version().await;
+    let bottom_level = current_version.get_compaction_group_levels(2).levels.len() as u32;
+
+    // Ingestion into L0 is rejected.
+    assert!(hummock_manager
+        .ingest_sstables(2, 0, vec![gen_sstable_info(11, 2, vec![100])])
+        .await
+        .is_err());
+    // Unknown compaction groups and levels are rejected.
+    assert!(hummock_manager
+        .ingest_sstables(100, bottom_level, vec![gen_sstable_info(11, 2, vec![100])])
+        .await
+        .is_err());
+    assert!(hummock_manager
+        .ingest_sstables(2, bottom_level + 1, vec![gen_sstable_info(11, 2, vec![100])])
+        .await
+        .is_err());
+    // SSTs of non-member tables are rejected.
+    assert!(hummock_manager
+        .ingest_sstables(2, bottom_level, vec![gen_sstable_info(11, 2, vec![101])])
+        .await
+        .is_err());
+    // Overlapping SSTs are rejected.
+    assert!(hummock_manager
+        .ingest_sstables(
+            2,
+            bottom_level,
+            vec![
+                gen_sstable_info(11, 2, vec![100]),
+                gen_sstable_info(12, 2, vec![100])
+            ]
+        )
+        .await
+        .is_err());
+
+    hummock_manager
+        .ingest_sstables(
+            2,
+            bottom_level,
+            vec![
+                gen_sstable_info(11, 2, vec![100]),
+                gen_sstable_info(12, 3, vec![100]),
+            ],
+        )
+        .await
+        .unwrap();
+    let new_version = hummock_manager.get_current_version().await;
+    assert_eq!(new_version.id, current_version.id + 1);
+    assert_eq!(
+        new_version.get_compaction_group_levels(2).levels[bottom_level as usize - 1]
+            .table_infos
+            .iter()
+            .map(|sst| sst.object_id)
+            .collect_vec(),
+        vec![11, 12]
+    );
+    // Ingesting again into the now non-empty level must not overlap with the existing SSTs.
+    assert!(hummock_manager
+        .ingest_sstables(2, bottom_level, vec![gen_sstable_info(13, 3, vec![100])])
+        .await
+        .is_err());
+    hummock_manager
+        .ingest_sstables(2, bottom_level, vec![gen_sstable_info(13, 4, vec![100])])
+        .await
+        .unwrap();
+}
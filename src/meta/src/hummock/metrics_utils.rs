@@ -404,6 +404,7 @@ pub fn trigger_delta_log_stats(metrics: &MetaMetrics, total_number: usize) {
 pub fn trigger_lsm_stat(
     metrics: &MetaMetrics,
     compaction_config: Arc<CompactionConfig>,
+    compact_status: Option<&CompactStatus>,
     levels: &Levels,
     compaction_group_id: CompactionGroupId,
 ) {
@@ -411,6 +412,21 @@ pub fn trigger_lsm_stat(
     // compact_pending_bytes
     let dynamic_level_core = DynamicLevelSelectorCore::new(compaction_config.clone());
     let ctx = dynamic_level_core.calculate_level_base_size(levels);
+    if let Some(compact_status) = compact_status {
+        // compact_level_score
+        let ctx = dynamic_level_core.get_priority_levels(levels, &compact_status.level_handlers);
+        for picker_info in &ctx.score_levels {
+            metrics
+                .compact_level_score
+                .with_label_values(&[
+                    &group_label,
+                    &picker_info.select_level.to_string(),
+                    &picker_info.target_level.to_string(),
+                    &picker_info.picker_type.to_string(),
+                ])
+                .set(picker_info.score as _);
+        }
+    }
     {
         let compact_pending_bytes_needed =
             dynamic_level_core.compact_pending_bytes_needed_with_ctx(levels, &ctx);
@@ -60,4 +60,7 @@ pub enum MetaStoreBackend {
         credentials: Option<(String, String)>,
     },
     Mem,
+    Sql {
+        endpoint: String,
+    },
 }
@@ -319,6 +319,14 @@ impl DatabaseManager {
             .collect_vec()
     }
 
+    pub fn list_paused_sink_ids(&self) -> Vec<SinkId> {
+        self.sinks
+            .values()
+            .filter(|sink| sink.is_paused)
+            .map(|sink| sink.id)
+            .collect_vec()
+    }
+
     pub fn list_sources(&self) -> Vec<Source> {
         self.sources.values().cloned().collect_vec()
     }
@@ -331,6 +339,14 @@ impl DatabaseManager {
             .collect_vec()
     }
 
+    pub fn list_sink_ids(&self, schema_id: SchemaId) -> Vec<SinkId> {
+        self.sinks
+            .values()
+            .filter(|&s| s.schema_id == schema_id)
+            .map(|s| s.id)
+            .collect_vec()
+    }
+
     pub fn get_connection(&self, connection_id: ConnectionId) -> Option<&Connection> {
         self.connections.get(&connection_id)
     }
@@ -45,7 +45,9 @@ use risingwave_pb::user::{GrantPrivilege, UserInfo};
 use tokio::sync::{Mutex, MutexGuard};
 use user::*;
 
-use crate::manager::{IdCategory, MetaSrvEnv, NotificationVersion, StreamingJob};
+use crate::manager::{
+    IdCategory, MetaSrvEnv, NotificationVersion, StreamingJob, IGNORED_NOTIFICATION_VERSION,
+};
 use crate::model::{BTreeMapTransaction, MetadataModel, TableFragments, ValTransaction};
 use crate::storage::Transaction;
 use crate::{MetaError, MetaResult};
@@ -1708,6 +1710,33 @@ impl CatalogManager {
         Ok(version)
     }
 
+    pub async fn alter_sink_pause(
+        &self,
+        sink_id: SinkId,
+        pause: bool,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_sink_id(sink_id)?;
+
+        let mut sink = database_core.sinks.get(&sink_id).unwrap().clone();
+        if sink.is_paused == pause {
+            // Already in the requested state, no need to commit or notify.
+            return Ok(IGNORED_NOTIFICATION_VERSION);
+        }
+        sink.is_paused = pause;
+
+        let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        sinks.insert(sink_id, sink.clone());
+        commit_meta!(self, sinks)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Sink(sink))
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn alter_source_name(
         &self,
         source_id: SourceId,
@@ -2547,6 +2576,11 @@ impl CatalogManager {
         self.core.lock().await.database.list_tables()
     }
 
+    /// Lists ids of all sinks paused by `ALTER SINK ... PAUSE`.
+    pub async fn list_paused_sink_ids(&self) -> Vec<SinkId> {
+        self.core.lock().await.database.list_paused_sink_ids()
+    }
+
     /// Lists table catalogs for mviews, without their internal tables.
     pub async fn list_creating_background_mvs(&self) -> Vec<Table> {
         self.core
@@ -2593,6 +2627,10 @@ impl CatalogManager {
         self.core.lock().await.database.list_source_ids(schema_id)
     }
 
+    pub async fn list_sink_ids(&self, schema_id: SchemaId) -> Vec<SinkId> {
+        self.core.lock().await.database.list_sink_ids(schema_id)
+    }
+
     pub async fn get_table_name_and_type_mapping(&self) -> HashMap<TableId, (String, String)> {
         self.core
             .lock()
@@ -2759,6 +2797,7 @@ impl CatalogManager {
             UpdateField::Rename => {
                 user.name = update_user.name.clone();
             }
+            UpdateField::QueryLimit => user.query_limit = update_user.query_limit,
         });
 
         let new_user: UserInfo = user.clone();
@@ -503,6 +503,18 @@ pub struct StreamingClusterInfo {
     pub unschedulable_parallel_units: HashMap<ParallelUnitId, ParallelUnit>,
 }
 
+impl StreamingClusterInfo {
+    /// Map each worker to its failure domain. The domain of a worker is currently identified by
+    /// the host it registered with, so that workers colocated on one machine (or in one zone,
+    /// when hostnames encode the zone) are treated as a single domain.
+    pub fn failure_domains(&self) -> HashMap<WorkerId, String> {
+        self.worker_nodes
+            .iter()
+            .map(|(&worker_id, worker)| (worker_id, worker.host.as_ref().unwrap().host.clone()))
+            .collect()
+    }
+}
+
 pub struct ClusterManagerCore {
     /// Record for workers in the cluster.
     workers: HashMap<WorkerKey, Worker>,
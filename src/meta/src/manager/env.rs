@@ -15,18 +15,18 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
-use risingwave_common::config::{CompactionConfig, DefaultParallelism};
+use risingwave_common::config::{AlertRule, CompactionConfig, DefaultParallelism};
 use risingwave_meta_model_v2::prelude::Cluster;
 use risingwave_pb::meta::SystemParams;
-use risingwave_rpc_client::{ConnectorClient, StreamClientPool, StreamClientPoolRef};
+use risingwave_rpc_client::{ConnectorClient, RpcRetryConfig, StreamClientPool, StreamClientPoolRef};
 use sea_orm::EntityTrait;
 
 use super::{SystemParamsManager, SystemParamsManagerRef};
 use crate::controller::system_param::{SystemParamsController, SystemParamsControllerRef};
 use crate::controller::SqlMetaStore;
 use crate::manager::{
-    IdGeneratorManager, IdGeneratorManagerRef, IdleManager, IdleManagerRef, NotificationManager,
-    NotificationManagerRef,
+    EventLogManager, EventLogManagerRef, IdGeneratorManager, IdGeneratorManagerRef, IdleManager,
+    IdleManagerRef, NotificationManager, NotificationManagerRef,
 };
 use crate::model::ClusterId;
 use crate::storage::MetaStoreRef;
@@ -56,6 +56,9 @@ pub struct MetaSrvEnv {
     /// idle status manager.
     idle_manager: IdleManagerRef,
 
+    /// user-facing cluster event log.
+    event_log_manager: EventLogManagerRef,
+
     /// system param manager.
     system_params_manager: SystemParamsManagerRef,
 
@@ -83,8 +86,25 @@ pub struct MetaOpts {
     pub enable_recovery: bool,
     /// Whether to enable the scale-in feature when compute-node is removed.
     pub enable_scale_in_when_recovery: bool,
+    /// Whether to recover only the actors on the failed workers first, before resorting to
+    /// full recovery.
+    pub enable_partial_recovery: bool,
+    /// The maximum time a barrier may stay in the collection phase before the stuck workers
+    /// are reported with diagnostics, in seconds. 0 disables the check.
+    pub checkpoint_alignment_timeout_sec: u64,
+    /// Whether exceeding the alignment timeout also aborts the collection so that the stuck
+    /// workers go through recovery, instead of only logging diagnostics.
+    pub recover_stuck_barrier: bool,
+    /// Retry and circuit-breaking policy of the RPC clients to the worker nodes.
+    pub worker_rpc_retry: RpcRetryConfig,
+    /// Whether to spread the actors of each fragment across failure domains when scheduling
+    /// streaming jobs.
+    pub enable_failure_domain_spread: bool,
     /// The maximum number of barriers in-flight in the compute nodes.
     pub in_flight_barrier_nums: usize,
+    /// The minimum number of barriers in-flight in the compute nodes, i.e. the floor that the
+    /// adaptive barrier concurrency may shrink to.
+    pub min_in_flight_barrier_nums: usize,
     /// After specified seconds of idle (no mview or flush), the process will be exited.
     /// 0 for infinite, process will never be exited due to long idle time.
     pub max_idle_ms: u64,
@@ -92,6 +112,9 @@ pub struct MetaOpts {
     pub compaction_deterministic_test: bool,
     /// Default parallelism of units for all streaming jobs.
     pub default_parallelism: DefaultParallelism,
+    /// The maximum number of parallel units used to serve a fragment's batch reads on the
+    /// serving compute nodes. `None` for unlimited.
+    pub max_serving_parallelism: Option<u64>,
 
     /// Interval of invoking a vacuum job, to remove stale metadata from meta store and objects
     /// from object store.
@@ -115,11 +138,22 @@ pub struct MetaOpts {
     pub collect_gc_watermark_spin_interval_sec: u64,
     /// Enable sanity check when SSTs are committed
     pub enable_committed_sst_sanity_check: bool,
+    /// Perform a canary read of each newly committed SST object before the commit becomes
+    /// visible, so that object store write corruption is caught before downstream sinks
+    /// acknowledge the epoch to external systems.
+    pub enable_canary_checkpoint_validation: bool,
     /// Schedule compaction for all compaction groups with this interval.
     pub periodic_compaction_interval_sec: u64,
     /// Interval of reporting the number of nodes in the cluster.
     pub node_num_monitor_interval_sec: u64,
 
+    /// Webhook to POST alerts fired by the built-in alerting rules to, if configured.
+    pub alert_webhook_url: Option<String>,
+    /// Interval of evaluating the built-in alerting rules.
+    pub alert_interval_sec: u64,
+    /// User-defined alerting rules, evaluated together with the built-in ones.
+    pub alert_rules: Vec<AlertRule>,
+
     /// The prometheus endpoint for dashboard service.
     pub prometheus_endpoint: Option<String>,
 
@@ -177,10 +211,17 @@ impl MetaOpts {
         Self {
             enable_recovery,
             enable_scale_in_when_recovery: false,
+            enable_partial_recovery: false,
+            checkpoint_alignment_timeout_sec: 0,
+            recover_stuck_barrier: false,
+            worker_rpc_retry: RpcRetryConfig::default(),
+            enable_failure_domain_spread: false,
             in_flight_barrier_nums: 40,
+            min_in_flight_barrier_nums: 1,
             max_idle_ms: 0,
             compaction_deterministic_test: false,
             default_parallelism: DefaultParallelism::Full,
+            max_serving_parallelism: None,
             vacuum_interval_sec: 30,
             vacuum_spin_interval_ms: 0,
             hummock_version_checkpoint_interval_sec: 30,
@@ -189,8 +230,12 @@ impl MetaOpts {
             full_gc_interval_sec: 3600 * 24 * 7,
             collect_gc_watermark_spin_interval_sec: 5,
             enable_committed_sst_sanity_check: false,
+            enable_canary_checkpoint_validation: false,
             periodic_compaction_interval_sec: 60,
             node_num_monitor_interval_sec: 10,
+            alert_webhook_url: None,
+            alert_interval_sec: 60,
+            alert_rules: vec![],
             prometheus_endpoint: None,
             vpc_id: None,
             security_group_id: None,
@@ -222,9 +267,11 @@ impl MetaSrvEnv {
     ) -> MetaResult<Self> {
         // change to sync after refactor `IdGeneratorManager::new` sync.
         let id_gen_manager = Arc::new(IdGeneratorManager::new(meta_store.clone()).await);
-        let stream_client_pool = Arc::new(StreamClientPool::default());
+        let stream_client_pool =
+            Arc::new(StreamClientPool::new_with_config(1, opts.worker_rpc_retry));
         let notification_manager = Arc::new(NotificationManager::new(meta_store.clone()).await);
         let idle_manager = Arc::new(IdleManager::new(opts.max_idle_ms));
+        let event_log_manager = Arc::new(EventLogManager::new());
         let (mut cluster_id, cluster_first_launch) =
             if let Some(id) = ClusterId::from_meta_store(&meta_store).await? {
                 (id, false)
@@ -270,6 +317,7 @@ impl MetaSrvEnv {
             notification_manager,
             stream_client_pool,
             idle_manager,
+            event_log_manager,
             system_params_manager,
             system_params_controller,
             cluster_id,
@@ -315,6 +363,14 @@ impl MetaSrvEnv {
         self.idle_manager.deref()
     }
 
+    pub fn event_log_manager_ref(&self) -> EventLogManagerRef {
+        self.event_log_manager.clone()
+    }
+
+    pub fn event_log_manager(&self) -> &EventLogManager {
+        self.event_log_manager.deref()
+    }
+
     pub fn system_params_manager_ref(&self) -> SystemParamsManagerRef {
         self.system_params_manager.clone()
     }
@@ -371,6 +427,7 @@ impl MetaSrvEnv {
         let notification_manager = Arc::new(NotificationManager::new(meta_store.clone()).await);
         let stream_client_pool = Arc::new(StreamClientPool::default());
         let idle_manager = Arc::new(IdleManager::disabled());
+        let event_log_manager = Arc::new(EventLogManager::new());
         let (cluster_id, cluster_first_launch) = (ClusterId::new(), true);
         let system_params_manager = Arc::new(
             SystemParamsManager::new(
@@ -403,6 +460,7 @@ impl MetaSrvEnv {
             notification_manager,
             stream_client_pool,
             idle_manager,
+            event_log_manager,
             system_params_manager,
             system_params_controller,
             cluster_id,
@@ -0,0 +1,90 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use risingwave_pb::meta::EventLog;
+use uuid::Uuid;
+
+pub type EventLogManagerRef = Arc<EventLogManager>;
+
+/// The maximum number of events retained. Once exceeded, the oldest events are dropped.
+const EVENT_LOG_CAPACITY: usize = 4096;
+
+/// Severity of an event recorded in the event log.
+#[derive(Clone, Copy, Debug)]
+pub enum EventLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl EventLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// `EventLogManager` keeps a bounded, in-memory log of noteworthy cluster events, e.g.
+/// recoveries, DDL lifecycle and compaction failures, so that users can inspect them
+/// afterwards via the `rw_catalog.rw_event_log` system table.
+pub struct EventLogManager {
+    event_logs: Mutex<VecDeque<EventLog>>,
+}
+
+impl Default for EventLogManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventLogManager {
+    pub fn new() -> Self {
+        Self {
+            event_logs: Mutex::new(VecDeque::with_capacity(EVENT_LOG_CAPACITY)),
+        }
+    }
+
+    /// Records an event. The oldest event is dropped if the capacity is exceeded.
+    pub fn report(&self, event_type: &str, level: EventLevel, info: String) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("SystemTime before UNIX EPOCH")
+            .as_millis() as u64;
+        let event_log = EventLog {
+            unique_id: Uuid::new_v4().to_string(),
+            timestamp,
+            event_type: event_type.to_string(),
+            level: level.as_str().to_string(),
+            info,
+        };
+        let mut event_logs = self.event_logs.lock();
+        if event_logs.len() >= EVENT_LOG_CAPACITY {
+            event_logs.pop_front();
+        }
+        event_logs.push_back(event_log);
+    }
+
+    /// Returns all retained events, from the oldest to the latest.
+    pub fn list_event_logs(&self) -> Vec<EventLog> {
+        self.event_logs.lock().iter().cloned().collect()
+    }
+}
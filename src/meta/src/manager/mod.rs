@@ -15,6 +15,7 @@
 mod catalog;
 mod cluster;
 mod env;
+mod event_log;
 mod id;
 mod idle;
 mod notification;
@@ -25,6 +26,7 @@ mod system_param;
 pub use catalog::*;
 pub use cluster::{WorkerKey, *};
 pub use env::{MetaSrvEnv, *};
+pub use event_log::*;
 pub use id::*;
 pub use idle::*;
 pub use notification::{LocalNotification, MessageStatus, NotificationManagerRef, *};
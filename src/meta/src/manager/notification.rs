@@ -15,13 +15,14 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::meta::relation::RelationInfo;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::{
-    MetaSnapshot, Relation, RelationGroup, SubscribeResponse, SubscribeType,
+    MetaSnapshot, NotificationHeartbeat, Relation, RelationGroup, SubscribeResponse, SubscribeType,
 };
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio::sync::Mutex;
@@ -38,6 +39,10 @@ pub type NotificationVersion = u64;
 /// NOTE(kwannoel): This is just ignored, used in background DDL
 pub const IGNORED_NOTIFICATION_VERSION: u64 = 0;
 
+/// The interval of heartbeats sent to subscribers, which carry the latest sent notification
+/// version so that subscribers can detect missed notifications and catch up in time.
+const NOTIFICATION_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Clone, Debug)]
 pub enum LocalNotification {
     WorkerNodeDeleted(WorkerNode),
@@ -101,6 +106,29 @@ impl NotificationManager {
             }
         });
 
+        let heartbeat_core = core_clone.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval_at(
+                tokio::time::Instant::now() + NOTIFICATION_HEARTBEAT_INTERVAL,
+                NOTIFICATION_HEARTBEAT_INTERVAL,
+            );
+            loop {
+                interval.tick().await;
+                let mut core_guard = heartbeat_core.lock().await;
+                if core_guard.exiting {
+                    break;
+                }
+                for subscribe_type in [
+                    SubscribeType::Frontend,
+                    SubscribeType::Hummock,
+                    SubscribeType::Compactor,
+                    SubscribeType::Compute,
+                ] {
+                    core_guard.heartbeat(subscribe_type);
+                }
+            }
+        });
+
         Self {
             core: core_clone,
             task_tx,
@@ -337,6 +365,9 @@ struct NotificationManagerCore {
     compute_senders: HashMap<WorkerKey, UnboundedSender<Notification>>,
     /// The notification sender to local subscribers.
     local_senders: Vec<UnboundedSender<LocalNotification>>,
+    /// The version of the latest versioned notification sent to each subscriber type, carried
+    /// by heartbeats and snapshots so that subscribers can detect missed notifications.
+    last_sent_versions: HashMap<SubscribeType, NotificationVersion>,
     exiting: bool,
 }
 
@@ -348,11 +379,12 @@ impl NotificationManagerCore {
             compactor_senders: HashMap::new(),
             compute_senders: HashMap::new(),
             local_senders: vec![],
+            last_sent_versions: HashMap::new(),
             exiting: false,
         }
     }
 
-    fn notify(&mut self, target: Target, response: SubscribeResponse) {
+    fn notify(&mut self, target: Target, mut response: SubscribeResponse) {
         macro_rules! warn_send_failure {
             ($subscribe_type:expr, $worker_key:expr, $err:expr) => {
                 tracing::warn!(
@@ -364,6 +396,17 @@ impl NotificationManagerCore {
             };
         }
 
+        let last_sent_version = self
+            .last_sent_versions
+            .entry(target.subscribe_type)
+            .or_default();
+        if target.worker_key.is_none() && response.version > *last_sent_version {
+            *last_sent_version = response.version;
+        } else if response.operation() == Operation::Snapshot && response.version == 0 {
+            // Give the new subscriber a baseline to compare the heartbeats against.
+            response.version = *last_sent_version;
+        }
+
         let senders = self.senders_of(target.subscribe_type);
 
         if let Some(worker_key) = target.worker_key {
@@ -390,6 +433,24 @@ impl NotificationManagerCore {
         }
     }
 
+    /// Send a heartbeat carrying the latest sent version to all subscribers of the given type.
+    fn heartbeat(&mut self, subscribe_type: SubscribeType) {
+        let last_sent_version = self
+            .last_sent_versions
+            .get(&subscribe_type)
+            .copied()
+            .unwrap_or_default();
+        self.notify(
+            subscribe_type.into(),
+            SubscribeResponse {
+                status: None,
+                operation: Operation::Unspecified as i32,
+                info: Some(Info::Heartbeat(NotificationHeartbeat { last_sent_version })),
+                version: 0,
+            },
+        );
+    }
+
     fn senders_of(&mut self, subscribe_type: SubscribeType) -> &mut SenderMap {
         match subscribe_type {
             SubscribeType::Frontend => &mut self.frontend_senders,
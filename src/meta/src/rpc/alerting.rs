@@ -0,0 +1,161 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, SystemTime};
+
+use risingwave_common::config::{AlertCompareOp, AlertRule, AlertSignal};
+use risingwave_pb::common::worker_node::State;
+use risingwave_pb::common::WorkerType;
+use serde::Serialize;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::manager::ClusterManagerRef;
+
+/// An alert fired by the rule evaluator, POSTed to the configured webhook as JSON so that
+/// small deployments get basic alerting without an external Prometheus stack.
+#[derive(Debug, Serialize)]
+pub struct Alert {
+    /// Stable identifier of the rule that fired.
+    pub name: String,
+    /// Human-readable description of the condition.
+    pub message: String,
+    /// Unix timestamp in seconds at which the rule was evaluated.
+    pub timestamp: u64,
+}
+
+/// Observe the cluster signal a user-defined rule compares against.
+async fn observe_signal(cluster_manager: &ClusterManagerRef, signal: AlertSignal) -> u64 {
+    let (worker_type, state) = match signal {
+        AlertSignal::RunningComputeNodes => (WorkerType::ComputeNode, State::Running),
+        AlertSignal::StartingComputeNodes => (WorkerType::ComputeNode, State::Starting),
+        AlertSignal::RunningCompactors => (WorkerType::Compactor, State::Running),
+        AlertSignal::RunningFrontends => (WorkerType::Frontend, State::Running),
+    };
+    cluster_manager
+        .list_worker_node(worker_type, Some(state))
+        .await
+        .len() as u64
+}
+
+fn compare(op: AlertCompareOp, lhs: u64, rhs: u64) -> bool {
+    match op {
+        AlertCompareOp::Lt => lhs < rhs,
+        AlertCompareOp::Le => lhs <= rhs,
+        AlertCompareOp::Gt => lhs > rhs,
+        AlertCompareOp::Ge => lhs >= rhs,
+        AlertCompareOp::Eq => lhs == rhs,
+    }
+}
+
+fn compare_op_str(op: AlertCompareOp) -> &'static str {
+    match op {
+        AlertCompareOp::Lt => "<",
+        AlertCompareOp::Le => "<=",
+        AlertCompareOp::Gt => ">",
+        AlertCompareOp::Ge => ">=",
+        AlertCompareOp::Eq => "==",
+    }
+}
+
+/// Evaluate the built-in and the user-defined alerting rules against the current cluster
+/// state.
+async fn evaluate_rules(cluster_manager: &ClusterManagerRef, rules: &[AlertRule]) -> Vec<Alert> {
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let mut alerts = Vec::new();
+
+    let running_compute_nodes =
+        observe_signal(cluster_manager, AlertSignal::RunningComputeNodes).await;
+    if running_compute_nodes == 0 {
+        alerts.push(Alert {
+            name: "no_running_compute_nodes".to_owned(),
+            message: "no compute node is running, streaming jobs are stalled".to_owned(),
+            timestamp,
+        });
+    }
+
+    let starting_compute_nodes =
+        observe_signal(cluster_manager, AlertSignal::StartingComputeNodes).await;
+    if starting_compute_nodes > 0 {
+        alerts.push(Alert {
+            name: "starting_compute_nodes".to_owned(),
+            message: format!(
+                "{} compute node(s) have registered but not become running yet",
+                starting_compute_nodes
+            ),
+            timestamp,
+        });
+    }
+
+    for rule in rules {
+        let value = observe_signal(cluster_manager, rule.signal).await;
+        if compare(rule.op, value, rule.threshold) {
+            alerts.push(Alert {
+                name: rule.name.clone(),
+                message: format!(
+                    "{:?} is {}, which is {} {}",
+                    rule.signal,
+                    value,
+                    compare_op_str(rule.op),
+                    rule.threshold
+                ),
+                timestamp,
+            });
+        }
+    }
+
+    alerts
+}
+
+/// Start a background task that periodically evaluates the built-in and the user-defined
+/// alerting rules and POSTs fired alerts to the webhook at `webhook_url`.
+pub fn start_alert_monitor(
+    cluster_manager: ClusterManagerRef,
+    webhook_url: String,
+    interval: Duration,
+    rules: Vec<AlertRule>,
+) -> (JoinHandle<()>, Sender<()>) {
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let client = reqwest::Client::new();
+        let mut monitor_interval = tokio::time::interval(interval);
+        monitor_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = monitor_interval.tick() => {},
+                // Shutdown monitor
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Alert monitor is stopped");
+                    return;
+                }
+            }
+
+            let alerts = evaluate_rules(&cluster_manager, &rules).await;
+            if alerts.is_empty() {
+                continue;
+            }
+
+            let payload = serde_json::json!({ "alerts": alerts });
+            if let Err(e) = client.post(&webhook_url).json(&payload).send().await {
+                tracing::warn!("failed to push {} alert(s) to webhook: {}", alerts.len(), e);
+            }
+        }
+    });
+
+    (join_handle, shutdown_tx)
+}
@@ -38,10 +38,10 @@ use tracing::Instrument;
 
 use crate::barrier::BarrierManagerRef;
 use crate::manager::{
-    CatalogManagerRef, ClusterManagerRef, ConnectionId, DatabaseId, FragmentManagerRef, FunctionId,
-    IdCategory, IndexId, LocalNotification, MetaSrvEnv, NotificationVersion, RelationIdEnum,
-    SchemaId, SinkId, SourceId, StreamingClusterInfo, StreamingJob, TableId, UserId, ViewId,
-    IGNORED_NOTIFICATION_VERSION,
+    CatalogManagerRef, ClusterManagerRef, ConnectionId, DatabaseId, EventLevel, FragmentManagerRef,
+    FunctionId, IdCategory, IndexId, LocalNotification, MetaSrvEnv, NotificationVersion,
+    RelationIdEnum, SchemaId, SinkId, SourceId, StreamingClusterInfo, StreamingJob, TableId,
+    UserId, ViewId, IGNORED_NOTIFICATION_VERSION,
 };
 use crate::model::{StreamEnvironment, TableFragments};
 use crate::rpc::cloud_provider::AwsEc2Client;
@@ -109,6 +109,33 @@ pub enum DdlCommand {
     CommentOn(Comment),
 }
 
+impl DdlCommand {
+    /// Name of the command recorded in the event log.
+    fn name(&self) -> &'static str {
+        match self {
+            DdlCommand::CreateDatabase(_) => "CREATE DATABASE",
+            DdlCommand::DropDatabase(_) => "DROP DATABASE",
+            DdlCommand::CreateSchema(_) => "CREATE SCHEMA",
+            DdlCommand::DropSchema(_) => "DROP SCHEMA",
+            DdlCommand::CreateSource(_) => "CREATE SOURCE",
+            DdlCommand::DropSource(_, _) => "DROP SOURCE",
+            DdlCommand::CreateFunction(_) => "CREATE FUNCTION",
+            DdlCommand::DropFunction(_) => "DROP FUNCTION",
+            DdlCommand::CreateView(_) => "CREATE VIEW",
+            DdlCommand::DropView(_, _) => "DROP VIEW",
+            DdlCommand::CreateStreamingJob(_, _, _) => "CREATE STREAMING JOB",
+            DdlCommand::DropStreamingJob(_, _) => "DROP STREAMING JOB",
+            DdlCommand::ReplaceTable(_, _, _) => "REPLACE TABLE",
+            DdlCommand::AlterRelationName(_, _) => "ALTER RELATION NAME",
+            DdlCommand::AlterSourceColumn(_) => "ALTER SOURCE COLUMN",
+            DdlCommand::AlterTableOwner(_, _) => "ALTER TABLE OWNER",
+            DdlCommand::CreateConnection(_) => "CREATE CONNECTION",
+            DdlCommand::DropConnection(_) => "DROP CONNECTION",
+            DdlCommand::CommentOn(_) => "COMMENT ON",
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct DdlController {
     env: MetaSrvEnv,
@@ -226,6 +253,7 @@ impl DdlController {
     /// would be a huge hassle and pain if we don't spawn here.
     pub async fn run_command(&self, command: DdlCommand) -> MetaResult<NotificationVersion> {
         self.check_barrier_manager_status().await?;
+        let command_name = command.name();
         let ctrl = self.clone();
         let fut = async move {
             match command {
@@ -271,7 +299,20 @@ impl DdlController {
             }
         }
         .in_current_span();
-        tokio::spawn(fut).await.unwrap()
+        let result = tokio::spawn(fut).await.unwrap();
+        match &result {
+            Ok(_) => self.env.event_log_manager().report(
+                "DDL",
+                EventLevel::Info,
+                format!("{} succeeded", command_name),
+            ),
+            Err(err) => self.env.event_log_manager().report(
+                "DDL",
+                EventLevel::Error,
+                format!("{} failed: {}", command_name, err),
+            ),
+        }
+        result
     }
 
     pub async fn get_ddl_progress(&self) -> Vec<DdlProgress> {
@@ -711,8 +752,12 @@ impl DdlController {
         let default_parallelism =
             self.resolve_stream_parallelism(default_parallelism, &cluster_info)?;
 
-        let actor_graph_builder =
-            ActorGraphBuilder::new(complete_graph, cluster_info, default_parallelism)?;
+        let actor_graph_builder = ActorGraphBuilder::new(
+            complete_graph,
+            cluster_info,
+            default_parallelism,
+            self.env.opts.enable_failure_domain_spread,
+        )?;
 
         let ActorGraphBuildResult {
             graph,
@@ -1031,8 +1076,12 @@ impl DdlController {
         let cluster_info = self.cluster_manager.get_streaming_cluster_info().await;
         let default_parallelism =
             self.resolve_stream_parallelism(default_parallelism, &cluster_info)?;
-        let actor_graph_builder =
-            ActorGraphBuilder::new(complete_graph, cluster_info, default_parallelism)?;
+        let actor_graph_builder = ActorGraphBuilder::new(
+            complete_graph,
+            cluster_info,
+            default_parallelism,
+            self.env.opts.enable_failure_domain_spread,
+        )?;
 
         let ActorGraphBuildResult {
             graph,
@@ -136,6 +136,8 @@ pub struct MetaMetrics {
     pub compact_skip_frequency: IntCounterVec,
     /// Bytes of lsm tree needed to reach balance
     pub compact_pending_bytes: IntGaugeVec,
+    /// Per level selector score of the lsm tree
+    pub compact_level_score: IntGaugeVec,
     /// Per level compression ratio
     pub compact_level_compression_ratio: GenericGaugeVec<AtomicF64>,
     /// Per level number of running compaction task
@@ -471,6 +473,14 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let compact_level_score = register_int_gauge_vec_with_registry!(
+            "storage_compact_level_score",
+            "selector score of each picker of the lsm tree",
+            &["group", "select_level", "target_level", "type"],
+            registry
+        )
+        .unwrap();
+
         let compact_level_compression_ratio = register_gauge_vec_with_registry!(
             "storage_compact_level_compression_ratio",
             "compression ratio of each level of the lsm tree",
@@ -659,6 +669,7 @@ impl MetaMetrics {
             worker_num,
             meta_type,
             compact_pending_bytes,
+            compact_level_score,
             compact_level_compression_ratio,
             level_compact_task_cnt,
             object_store_metric,
@@ -34,9 +34,18 @@ pub type ServingVnodeMappingRef = Arc<ServingVnodeMapping>;
 #[derive(Default)]
 pub struct ServingVnodeMapping {
     serving_vnode_mappings: RwLock<HashMap<FragmentId, ParallelUnitMapping>>,
+    /// The maximum parallelism of the serving mapping of each fragment. `None` for unlimited.
+    max_serving_parallelism: Option<u64>,
 }
 
 impl ServingVnodeMapping {
+    pub fn new(max_serving_parallelism: Option<u64>) -> Self {
+        Self {
+            serving_vnode_mappings: RwLock::default(),
+            max_serving_parallelism,
+        }
+    }
+
     pub fn all(&self) -> HashMap<FragmentId, ParallelUnitMapping> {
         self.serving_vnode_mappings.read().clone()
     }
@@ -54,8 +63,13 @@ impl ServingVnodeMapping {
         for (fragment_id, streaming_parallelism) in streaming_parallelisms {
             let new_mapping = {
                 let old_mapping = serving_vnode_mappings.get(&fragment_id);
-                // Set max serving parallelism to `streaming_parallelism`. It's not a must.
-                place_vnode(old_mapping, workers, streaming_parallelism)
+                // Set max serving parallelism to `streaming_parallelism`, further capped by
+                // `max_serving_parallelism` if configured. It's not a must.
+                let max_parallelism = match self.max_serving_parallelism {
+                    Some(limit) => streaming_parallelism.min(limit as usize),
+                    None => streaming_parallelism,
+                };
+                place_vnode(old_mapping, workers, max_parallelism)
             };
             match new_mapping {
                 None => {
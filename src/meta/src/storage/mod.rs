@@ -16,6 +16,7 @@ mod etcd_meta_store;
 mod etcd_retry_client;
 mod mem_meta_store;
 pub mod meta_store;
+mod sql_meta_store;
 #[cfg(test)]
 mod tests;
 mod transaction;
@@ -28,5 +29,6 @@ pub type Value = Vec<u8>;
 pub use etcd_meta_store::*;
 pub use mem_meta_store::*;
 pub use meta_store::*;
+pub use sql_meta_store::*;
 pub use transaction::*;
 pub use wrapped_etcd_client::*;
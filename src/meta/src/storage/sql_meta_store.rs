@@ -0,0 +1,283 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use risingwave_common::config::MetaBackend;
+use sea_orm::sea_query::{
+    BlobSize, ColumnDef, Expr, Iden, Index, OnConflict, Query, SelectStatement, Table,
+};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, IsolationLevel,
+    TransactionTrait,
+};
+use tokio::sync::OnceCell;
+
+use super::{Key, MetaStore, MetaStoreError, MetaStoreResult, Snapshot, Transaction, Value};
+
+impl From<DbErr> for MetaStoreError {
+    fn from(err: DbErr) -> Self {
+        MetaStoreError::Internal(anyhow::Error::new(err))
+    }
+}
+
+/// The table holding all key-value pairs, keyed by column family and key.
+#[derive(Clone, Copy)]
+enum MetaKv {
+    Table,
+    Cf,
+    Key,
+    Value,
+}
+
+impl Iden for MetaKv {
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(
+            s,
+            "{}",
+            match self {
+                MetaKv::Table => "meta_key_value",
+                MetaKv::Cf => "cf",
+                MetaKv::Key => "k",
+                MetaKv::Value => "v",
+            }
+        )
+        .unwrap()
+    }
+}
+
+/// [`MetaStore`] implemented on a relational database (Postgres, MySQL or SQLite), for
+/// deployments where etcd is not available. All statements are built through `sea-query`,
+/// so a single implementation serves all the backends.
+#[derive(Clone)]
+pub struct SqlKvMetaStore {
+    conn: DatabaseConnection,
+}
+
+pub struct SqlKvSnapshot {
+    conn: DatabaseConnection,
+    /// The transaction in which all reads of this snapshot run, started lazily on the
+    /// first read.
+    txn: OnceCell<DatabaseTransaction>,
+}
+
+fn select_value(cf: &str, key: &[u8]) -> SelectStatement {
+    Query::select()
+        .column(MetaKv::Value)
+        .from(MetaKv::Table)
+        .and_where(Expr::col(MetaKv::Cf).eq(cf))
+        .and_where(Expr::col(MetaKv::Key).eq(key))
+        .to_owned()
+}
+
+async fn get_cf_inner<C: ConnectionTrait>(
+    conn: &C,
+    cf: &str,
+    key: &[u8],
+) -> MetaStoreResult<Vec<u8>> {
+    let stmt = conn.get_database_backend().build(&select_value(cf, key));
+    let row = conn
+        .query_one(stmt)
+        .await?
+        .ok_or_else(|| MetaStoreError::ItemNotFound(hex::encode(key)))?;
+    Ok(row.try_get("", &MetaKv::Value.to_string())?)
+}
+
+async fn list_cf_inner<C: ConnectionTrait>(
+    conn: &C,
+    cf: &str,
+) -> MetaStoreResult<Vec<(Vec<u8>, Vec<u8>)>> {
+    let stmt = conn.get_database_backend().build(
+        Query::select()
+            .columns([MetaKv::Key, MetaKv::Value])
+            .from(MetaKv::Table)
+            .and_where(Expr::col(MetaKv::Cf).eq(cf))
+            .order_by(MetaKv::Key, sea_orm::sea_query::Order::Asc),
+    );
+    let rows = conn.query_all(stmt).await?;
+    rows.into_iter()
+        .map(|row| {
+            Ok((
+                row.try_get("", &MetaKv::Key.to_string())?,
+                row.try_get("", &MetaKv::Value.to_string())?,
+            ))
+        })
+        .collect()
+}
+
+fn put_statement(cf: &str, key: Key, value: Value) -> sea_orm::sea_query::InsertStatement {
+    Query::insert()
+        .into_table(MetaKv::Table)
+        .columns([MetaKv::Cf, MetaKv::Key, MetaKv::Value])
+        .values_panic([cf.into(), key.into(), value.into()])
+        .on_conflict(
+            OnConflict::columns([MetaKv::Cf, MetaKv::Key])
+                .update_column(MetaKv::Value)
+                .to_owned(),
+        )
+        .to_owned()
+}
+
+fn delete_statement(cf: &str, key: &[u8]) -> sea_orm::sea_query::DeleteStatement {
+    Query::delete()
+        .from_table(MetaKv::Table)
+        .and_where(Expr::col(MetaKv::Cf).eq(cf))
+        .and_where(Expr::col(MetaKv::Key).eq(key))
+        .to_owned()
+}
+
+impl SqlKvMetaStore {
+    /// Create a new SQL-backed meta store, creating the key-value table if it does not
+    /// exist yet.
+    pub async fn new(conn: DatabaseConnection) -> MetaStoreResult<Self> {
+        let stmt = conn.get_database_backend().build(
+            Table::create()
+                .table(MetaKv::Table)
+                .if_not_exists()
+                // Both the cf and the key are part of the primary key, so they must be of
+                // bounded length to be indexable on MySQL.
+                .col(ColumnDef::new(MetaKv::Cf).string_len(255).not_null())
+                .col(ColumnDef::new(MetaKv::Key).var_binary(255).not_null())
+                .col(
+                    ColumnDef::new(MetaKv::Value)
+                        .blob(BlobSize::Long)
+                        .not_null(),
+                )
+                .primary_key(Index::create().col(MetaKv::Cf).col(MetaKv::Key)),
+        );
+        conn.execute(stmt).await?;
+        Ok(Self { conn })
+    }
+
+    async fn begin_snapshot_txn(conn: &DatabaseConnection) -> Result<DatabaseTransaction, DbErr> {
+        match conn.get_database_backend() {
+            // SQLite serializes all transactions anyway.
+            DbBackend::Sqlite => conn.begin().await,
+            // The default isolation level of Postgres and MySQL does not pin a consistent
+            // view across statements, so raise it explicitly.
+            DbBackend::Postgres | DbBackend::MySql => {
+                conn.begin_with_config(Some(IsolationLevel::RepeatableRead), None)
+                    .await
+            }
+        }
+    }
+}
+
+impl SqlKvSnapshot {
+    async fn txn(&self) -> MetaStoreResult<&DatabaseTransaction> {
+        self.txn
+            .get_or_try_init(|| SqlKvMetaStore::begin_snapshot_txn(&self.conn))
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[async_trait]
+impl Snapshot for SqlKvSnapshot {
+    async fn list_cf(&self, cf: &str) -> MetaStoreResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        list_cf_inner(self.txn().await?, cf).await
+    }
+
+    async fn get_cf(&self, cf: &str, key: &[u8]) -> MetaStoreResult<Vec<u8>> {
+        get_cf_inner(self.txn().await?, cf, key).await
+    }
+}
+
+#[async_trait]
+impl MetaStore for SqlKvMetaStore {
+    type Snapshot = SqlKvSnapshot;
+
+    fn meta_store_type(&self) -> MetaBackend {
+        MetaBackend::Sql
+    }
+
+    async fn snapshot(&self) -> Self::Snapshot {
+        SqlKvSnapshot {
+            conn: self.conn.clone(),
+            txn: OnceCell::new(),
+        }
+    }
+
+    async fn put_cf(&self, cf: &str, key: Key, value: Value) -> MetaStoreResult<()> {
+        let stmt = self
+            .conn
+            .get_database_backend()
+            .build(&put_statement(cf, key, value));
+        self.conn.execute(stmt).await?;
+        Ok(())
+    }
+
+    async fn delete_cf(&self, cf: &str, key: &[u8]) -> MetaStoreResult<()> {
+        let stmt = self
+            .conn
+            .get_database_backend()
+            .build(&delete_statement(cf, key));
+        self.conn.execute(stmt).await?;
+        Ok(())
+    }
+
+    async fn txn(&self, trx: Transaction) -> MetaStoreResult<()> {
+        use super::Operation::*;
+        use super::Precondition::*;
+
+        let (preconditions, operations) = trx.into_parts();
+        let txn = self.conn.begin().await?;
+        let backend = txn.get_database_backend();
+
+        // Lock the rows read by the preconditions until commit with `SELECT ... FOR UPDATE`,
+        // so that a concurrent transaction cannot invalidate a precondition between the check
+        // and the writes. SQLite does not support `FOR UPDATE`, but serializes all write
+        // transactions anyway.
+        let precondition_stmt = |cf: &str, key: &[u8]| {
+            let mut select = select_value(cf, key);
+            if !matches!(backend, DbBackend::Sqlite) {
+                select.lock_exclusive();
+            }
+            backend.build(&select)
+        };
+
+        for cond in preconditions {
+            let satisfied = match cond {
+                KeyExists { cf, key } => txn
+                    .query_one(precondition_stmt(&cf, &key))
+                    .await?
+                    .is_some(),
+                KeyEqual { cf, key, value } => {
+                    let current: Option<Vec<u8>> = txn
+                        .query_one(precondition_stmt(&cf, &key))
+                        .await?
+                        .map(|row| row.try_get("", &MetaKv::Value.to_string()))
+                        .transpose()?;
+                    current == Some(value)
+                }
+            };
+            if !satisfied {
+                txn.rollback().await?;
+                return Err(MetaStoreError::TransactionAbort());
+            }
+        }
+
+        for op in operations {
+            let stmt = match op {
+                Put { cf, key, value } => backend.build(&put_statement(&cf, key, value)),
+                Delete { cf, key } => backend.build(&delete_statement(&cf, &key)),
+            };
+            txn.execute(stmt).await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+}
@@ -264,3 +264,15 @@ async fn test_mem_store() -> MetaStoreResult<()> {
     test_meta_store_transaction(&store).await.unwrap();
     Ok(())
 }
+
+#[cfg(not(madsim))]
+#[tokio::test]
+async fn test_sql_kv_store() -> MetaStoreResult<()> {
+    let conn = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+    let store = crate::storage::SqlKvMetaStore::new(conn).await.unwrap();
+    test_meta_store_basic(&store).await.unwrap();
+    test_meta_store_keys_share_prefix(&store).await.unwrap();
+    test_meta_store_overlapped_cf(&store).await.unwrap();
+    test_meta_store_transaction(&store).await.unwrap();
+    Ok(())
+}
@@ -193,6 +193,7 @@ struct ConnectorSourceWorkerHandle {
     sync_call_tx: UnboundedSender<oneshot::Sender<MetaResult<()>>>,
     splits: SharedSplitMapRef,
     enable_scale_in: bool,
+    enable_rebalance: bool,
 }
 
 impl ConnectorSourceWorkerHandle {
@@ -282,13 +283,19 @@ impl SourceManagerCore {
 
                     if let Some(change) = diff_splits(
                         *fragment_id,
-                        prev_actor_splits,
+                        prev_actor_splits.clone(),
                         &discovered_splits,
                         SplitDiffOptions {
                             enable_scale_in: handle.enable_scale_in,
                         },
                     ) {
                         split_assignment.insert(*fragment_id, change);
+                    } else if handle.enable_rebalance
+                        && let Some(change) = rebalance_splits(*fragment_id, prev_actor_splits)
+                    {
+                        // Nothing discovered or dropped, check whether the current assignment
+                        // is skewed and move splits from overloaded actors to underloaded ones.
+                        split_assignment.insert(*fragment_id, change);
                     }
                 }
             }
@@ -488,6 +495,62 @@ where
     )
 }
 
+/// Rebalance the splits of a fragment across its actors, so that the most and the least loaded
+/// actor own at most one split more or less than each other.
+///
+/// The load of an actor is currently approximated by the number of splits it owns, since the
+/// readers do not report per-split throughput to the meta node yet. Returns `None` if the
+/// assignment is already balanced, so that a steady state does not keep generating barriers.
+fn rebalance_splits<T>(
+    fragment_id: FragmentId,
+    actor_splits: HashMap<ActorId, Vec<T>>,
+) -> Option<HashMap<ActorId, Vec<T>>>
+where
+    T: SplitMetaData + Clone,
+{
+    if actor_splits.len() < 2 {
+        return None;
+    }
+
+    let mut assignment: Vec<ActorSplitsAssignment<T>> = actor_splits
+        .into_iter()
+        .map(|(actor_id, splits)| ActorSplitsAssignment { actor_id, splits })
+        .collect();
+    // Sort by actor id so that the same skew always resolves to the same assignment.
+    assignment.sort_by_key(|a| a.actor_id);
+
+    // Repeatedly move one split from the most loaded actor to the least loaded one, until they
+    // differ by at most one split. Each migration detaches the split from its current reader,
+    // so only the minimum number of splits is moved.
+    let mut moved = 0;
+    loop {
+        let most = assignment.iter().position_max_by_key(|a| a.splits.len());
+        let least = assignment.iter().position_min_by_key(|a| a.splits.len());
+        let (Some(most), Some(least)) = (most, least) else {
+            unreachable!()
+        };
+        if assignment[most].splits.len() - assignment[least].splits.len() <= 1 {
+            break;
+        }
+
+        let split = assignment[most].splits.pop().unwrap();
+        assignment[least].splits.push(split);
+        moved += 1;
+    }
+    if moved == 0 {
+        return None;
+    }
+
+    tracing::info!(fragment_id, moved, "rebalancing splits between actors");
+
+    Some(
+        assignment
+            .into_iter()
+            .map(|ActorSplitsAssignment { actor_id, splits }| (actor_id, splits))
+            .collect(),
+    )
+}
+
 impl SourceManager {
     const DEFAULT_SOURCE_TICK_INTERVAL: Duration = Duration::from_secs(10);
     const DEFAULT_SOURCE_TICK_TIMEOUT: Duration = Duration::from_secs(10);
@@ -706,6 +769,7 @@ impl SourceManager {
 
         let connector_properties = extract_prop_from_source(&source)?;
         let enable_scale_in = connector_properties.enable_split_scale_in();
+        let enable_rebalance = connector_properties.enable_split_rebalance();
         let handle = tokio::spawn(async move {
             let mut ticker = time::interval(Self::DEFAULT_SOURCE_TICK_INTERVAL);
             ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
@@ -744,6 +808,7 @@ impl SourceManager {
                 sync_call_tx,
                 splits: current_splits_ref,
                 enable_scale_in,
+                enable_rebalance,
             },
         );
         Ok(())
@@ -759,6 +824,7 @@ impl SourceManager {
         let current_splits_ref = Arc::new(Mutex::new(SharedSplitMap { splits: None }));
         let connector_properties = extract_prop_from_source(source)?;
         let enable_scale_in = connector_properties.enable_split_scale_in();
+        let enable_rebalance = connector_properties.enable_split_rebalance();
         let (sync_call_tx, sync_call_rx) = tokio::sync::mpsc::unbounded_channel();
         let handle = dispatch_source_prop!(connector_properties, prop, {
             let mut worker = ConnectorSourceWorker::create(
@@ -801,6 +867,7 @@ impl SourceManager {
                 sync_call_tx,
                 splits: current_splits_ref,
                 enable_scale_in,
+                enable_rebalance,
             },
         );
 
@@ -900,7 +967,7 @@ mod tests {
     use serde::{Deserialize, Serialize};
 
     use crate::model::{ActorId, FragmentId};
-    use crate::stream::source_manager::{diff_splits, SplitDiffOptions};
+    use crate::stream::source_manager::{diff_splits, rebalance_splits, SplitDiffOptions};
 
     #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
     struct TestSplit {
@@ -1118,4 +1185,60 @@ mod tests {
 
         check_all_splits(&discovered_splits, &diff);
     }
+
+    #[test]
+    fn test_rebalance_splits() {
+        // A single actor can never be rebalanced.
+        let actor_splits: HashMap<ActorId, Vec<TestSplit>> =
+            HashMap::from([(0, (0..4).map(|i| TestSplit { id: i }).collect())]);
+        assert!(rebalance_splits(FragmentId::default(), actor_splits).is_none());
+
+        // A balanced assignment is left untouched.
+        let actor_splits: HashMap<ActorId, Vec<TestSplit>> = (0..3)
+            .map(|i| (i, vec![TestSplit { id: i }]))
+            .chain([(3, vec![])])
+            .collect();
+        assert!(rebalance_splits(FragmentId::default(), actor_splits).is_none());
+
+        // All splits on one actor are spread across all actors.
+        let mut actor_splits: HashMap<ActorId, Vec<TestSplit>> =
+            HashMap::from([(0, (0..6).map(|i| TestSplit { id: i }).collect())]);
+        actor_splits.insert(1, vec![]);
+        actor_splits.insert(2, vec![]);
+
+        let rebalanced = rebalance_splits(FragmentId::default(), actor_splits).unwrap();
+        assert_eq!(rebalanced.len(), 3);
+        for splits in rebalanced.values() {
+            assert_eq!(splits.len(), 2);
+        }
+
+        let discovered_splits: BTreeMap<SplitId, TestSplit> = (0..6)
+            .map(|i| {
+                let split = TestSplit { id: i };
+                (split.id(), split)
+            })
+            .collect();
+        check_all_splits(&discovered_splits, &rebalanced);
+
+        // No actor exceeds the average, but one is starved: still rebalanced.
+        let actor_splits: HashMap<ActorId, Vec<TestSplit>> = HashMap::from([
+            (0, vec![TestSplit { id: 0 }, TestSplit { id: 1 }]),
+            (1, vec![TestSplit { id: 2 }, TestSplit { id: 3 }]),
+            (2, vec![]),
+        ]);
+
+        let rebalanced = rebalance_splits(FragmentId::default(), actor_splits).unwrap();
+        for splits in rebalanced.values() {
+            let len = splits.len();
+            assert!(len == 1 || len == 2);
+        }
+
+        let discovered_splits: BTreeMap<SplitId, TestSplit> = (0..4)
+            .map(|i| {
+                let split = TestSplit { id: i };
+                (split.id(), split)
+            })
+            .collect();
+        check_all_splits(&discovered_splits, &rebalanced);
+    }
 }
@@ -646,12 +646,20 @@ impl ActorGraphBuilder {
         fragment_graph: CompleteStreamFragmentGraph,
         cluster_info: StreamingClusterInfo,
         default_parallelism: NonZeroUsize,
+        spread_failure_domains: bool,
     ) -> MetaResult<Self> {
         let existing_distributions = fragment_graph.existing_distribution();
 
+        let failure_domains = if spread_failure_domains {
+            cluster_info.failure_domains()
+        } else {
+            Default::default()
+        };
+
         // Schedule the distribution of all building fragments.
         let distributions = schedule::Scheduler::new(
             cluster_info.parallel_units.values().cloned(),
+            &failure_domains,
             default_parallelism,
         )
         .schedule(&fragment_graph)?;
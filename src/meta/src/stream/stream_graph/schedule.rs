@@ -18,7 +18,7 @@
     reason = "generated by crepe"
 )]
 
-use std::collections::{BTreeMap, HashMap, LinkedList};
+use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
 use std::num::NonZeroUsize;
 
 use either::Either;
@@ -202,8 +202,13 @@ impl Scheduler {
     /// Each hash-distributed fragment will be scheduled to at most `default_parallelism` parallel
     /// units, in a round-robin fashion on all compute nodes. If the `default_parallelism` is
     /// `None`, all parallel units will be used.
+    ///
+    /// If `failure_domains` is not empty, the round-robin additionally interleaves the failure
+    /// domains of the workers, so that truncating to the default parallelism still leaves the
+    /// fragment spread across as many domains as possible.
     pub fn new(
         parallel_units: impl IntoIterator<Item = ParallelUnit>,
+        failure_domains: &HashMap<WorkerId, String>,
         default_parallelism: NonZeroUsize,
     ) -> Self {
         // Group parallel units with worker node.
@@ -215,9 +220,36 @@ impl Scheduler {
                 .push(p);
         }
 
-        let mut parallel_units: LinkedList<_> = parallel_units_map
-            .into_values()
-            .map(|v| v.into_iter().sorted_by_key(|p| p.id))
+        // Decide the order to visit the workers. Without failure domains, this is simply the
+        // order of the worker ids; otherwise, workers of different domains are interleaved.
+        let worker_order: Vec<WorkerId> = if failure_domains.is_empty() {
+            parallel_units_map.keys().copied().collect()
+        } else {
+            let mut domains: BTreeMap<&str, VecDeque<WorkerId>> = BTreeMap::new();
+            for worker_id in parallel_units_map.keys() {
+                let domain = failure_domains
+                    .get(worker_id)
+                    .map(|d| d.as_str())
+                    .unwrap_or_default();
+                domains.entry(domain).or_default().push_back(*worker_id);
+            }
+            let mut order = Vec::with_capacity(parallel_units_map.len());
+            while order.len() < parallel_units_map.len() {
+                for workers in domains.values_mut() {
+                    if let Some(worker_id) = workers.pop_front() {
+                        order.push(worker_id);
+                    }
+                }
+            }
+            order
+        };
+
+        let mut parallel_units: LinkedList<_> = worker_order
+            .into_iter()
+            .map(|worker_id| {
+                let units = parallel_units_map.remove(&worker_id).unwrap();
+                units.into_iter().sorted_by_key(|p| p.id)
+            })
             .collect();
 
         // Visit the parallel units in a round-robin manner on each worker.
@@ -466,6 +466,7 @@ async fn test_graph_builder() -> MetaResult<()> {
         CompleteStreamFragmentGraph::for_test(fragment_graph),
         make_cluster_info(),
         NonZeroUsize::new(parallel_degree).unwrap(),
+        false,
     )?;
     let ActorGraphBuildResult { graph, .. } = actor_graph_builder
         .generate_graph(env.id_gen_manager_ref(), &job)
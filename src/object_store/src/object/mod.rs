@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::ops::RangeBounds;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -254,6 +255,7 @@ impl ObjectStoreImpl {
         streaming_upload_timeout_ms: u64,
         read_timeout_ms: u64,
         upload_timeout_ms: u64,
+        unavailable_threshold: usize,
     ) {
         match self {
             ObjectStoreImpl::InMem(s) => {
@@ -262,6 +264,7 @@ impl ObjectStoreImpl {
                     streaming_upload_timeout_ms,
                     read_timeout_ms,
                     upload_timeout_ms,
+                    unavailable_threshold,
                 );
             }
             ObjectStoreImpl::Opendal(s) => {
@@ -270,6 +273,7 @@ impl ObjectStoreImpl {
                     streaming_upload_timeout_ms,
                     read_timeout_ms,
                     upload_timeout_ms,
+                    unavailable_threshold,
                 );
             }
             ObjectStoreImpl::S3(s) => {
@@ -278,10 +282,12 @@ impl ObjectStoreImpl {
                     streaming_upload_timeout_ms,
                     read_timeout_ms,
                     upload_timeout_ms,
+                    unavailable_threshold,
                 );
             }
         }
     }
+
 }
 
 fn try_update_failure_metric<T>(
@@ -297,6 +303,59 @@ fn try_update_failure_metric<T>(
     }
 }
 
+/// Tracks whether the object store is responding, based on the results of all monitored
+/// operations.
+///
+/// After `threshold` consecutive failures the store is considered unavailable, until the
+/// next operation succeeds. The state is exported as the `object_store_unavailable` gauge
+/// and logged on every transition, so an outage is visible at the cluster level.
+struct ObjectStoreAvailability {
+    object_store_metrics: Arc<ObjectStoreMetrics>,
+    /// Number of consecutive failures after which the store is reported unavailable.
+    /// 0 disables the tracking.
+    threshold: AtomicUsize,
+    consecutive_failures: AtomicUsize,
+    unavailable: AtomicBool,
+}
+
+impl ObjectStoreAvailability {
+    fn new(object_store_metrics: Arc<ObjectStoreMetrics>) -> Self {
+        Self {
+            object_store_metrics,
+            threshold: AtomicUsize::new(0),
+            consecutive_failures: AtomicUsize::new(0),
+            unavailable: AtomicBool::new(false),
+        }
+    }
+
+    fn set_threshold(&self, threshold: usize) {
+        self.threshold.store(threshold, Ordering::Relaxed);
+    }
+
+    fn track<T>(&self, result: &ObjectResult<T>) {
+        let threshold = self.threshold.load(Ordering::Relaxed);
+        if threshold == 0 {
+            return;
+        }
+        if result.is_ok() {
+            self.consecutive_failures.store(0, Ordering::Relaxed);
+            if self.unavailable.swap(false, Ordering::Relaxed) {
+                self.object_store_metrics.unavailable.set(0);
+                tracing::info!("object store is available again");
+            }
+        } else {
+            let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+            if failures >= threshold && !self.unavailable.swap(true, Ordering::Relaxed) {
+                self.object_store_metrics.unavailable.set(1);
+                tracing::error!(
+                    failures,
+                    "object store is considered unavailable due to consecutive request failures"
+                );
+            }
+        }
+    }
+}
+
 /// `MonitoredStreamingUploader` will report the following metrics.
 /// - `write_bytes`: The number of bytes uploaded from the uploader's creation to finish.
 /// - `operation_size`:
@@ -311,6 +370,7 @@ fn try_update_failure_metric<T>(
 pub struct MonitoredStreamingUploader {
     inner: BoxedStreamingUploader,
     object_store_metrics: Arc<ObjectStoreMetrics>,
+    availability: Arc<ObjectStoreAvailability>,
     /// Length of data uploaded with this uploader.
     operation_size: usize,
     media_type: &'static str,
@@ -322,11 +382,13 @@ impl MonitoredStreamingUploader {
         media_type: &'static str,
         handle: BoxedStreamingUploader,
         object_store_metrics: Arc<ObjectStoreMetrics>,
+        availability: Arc<ObjectStoreAvailability>,
         streaming_upload_timeout: Option<Duration>,
     ) -> Self {
         Self {
             inner: handle,
             object_store_metrics,
+            availability,
             operation_size: 0,
             media_type,
             streaming_upload_timeout,
@@ -370,6 +432,7 @@ impl MonitoredStreamingUploader {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -399,6 +462,7 @@ impl MonitoredStreamingUploader {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -411,6 +475,7 @@ type BoxedStreamingReader = Box<dyn AsyncRead + Unpin + Send + Sync>;
 pub struct MonitoredStreamingReader {
     inner: BoxedStreamingReader,
     object_store_metrics: Arc<ObjectStoreMetrics>,
+    availability: Arc<ObjectStoreAvailability>,
     operation_size: usize,
     media_type: &'static str,
     timer: Option<HistogramTimer>,
@@ -422,6 +487,7 @@ impl MonitoredStreamingReader {
         media_type: &'static str,
         handle: BoxedStreamingReader,
         object_store_metrics: Arc<ObjectStoreMetrics>,
+        availability: Arc<ObjectStoreAvailability>,
         streaming_read_timeout: Option<Duration>,
     ) -> Self {
         let operation_type = "streaming_read";
@@ -432,6 +498,7 @@ impl MonitoredStreamingReader {
         Self {
             inner: handle,
             object_store_metrics,
+            availability,
             operation_size: 0,
             media_type,
             timer: Some(timer),
@@ -475,6 +542,7 @@ impl MonitoredStreamingReader {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 }
@@ -493,6 +561,7 @@ impl Drop for MonitoredStreamingReader {
 pub struct MonitoredObjectStore<OS: ObjectStore> {
     inner: OS,
     object_store_metrics: Arc<ObjectStoreMetrics>,
+    availability: Arc<ObjectStoreAvailability>,
     streaming_read_timeout: Option<Duration>,
     streaming_upload_timeout: Option<Duration>,
     read_timeout: Option<Duration>,
@@ -517,9 +586,11 @@ pub struct MonitoredObjectStore<OS: ObjectStore> {
 ///   - `failure-count`
 impl<OS: ObjectStore> MonitoredObjectStore<OS> {
     pub fn new(store: OS, object_store_metrics: Arc<ObjectStoreMetrics>) -> Self {
+        let availability = Arc::new(ObjectStoreAvailability::new(object_store_metrics.clone()));
         Self {
             inner: store,
             object_store_metrics,
+            availability,
             streaming_read_timeout: None,
             streaming_upload_timeout: None,
             read_timeout: None,
@@ -563,6 +634,7 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -588,10 +660,12 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         Ok(MonitoredStreamingUploader::new(
             media_type,
             res?,
             self.object_store_metrics.clone(),
+            self.availability.clone(),
             self.streaming_upload_timeout,
         ))
     }
@@ -617,6 +691,7 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
 
         let data = res?;
         self.object_store_metrics
@@ -658,10 +733,12 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         Ok(MonitoredStreamingReader::new(
             media_type,
             res?,
             self.object_store_metrics.clone(),
+            self.availability.clone(),
             self.streaming_read_timeout,
         ))
     }
@@ -688,6 +765,7 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -713,6 +791,7 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -738,6 +817,7 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -763,6 +843,7 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         };
 
         try_update_failure_metric(&self.object_store_metrics, &res, operation_type);
+        self.availability.track(&res);
         res
     }
 
@@ -772,11 +853,13 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
         streaming_upload_timeout_ms: u64,
         read_timeout_ms: u64,
         upload_timeout_ms: u64,
+        unavailable_threshold: usize,
     ) {
         self.streaming_read_timeout = Some(Duration::from_millis(streaming_read_timeout_ms));
         self.streaming_upload_timeout = Some(Duration::from_millis(streaming_upload_timeout_ms));
         self.read_timeout = Some(Duration::from_millis(read_timeout_ms));
         self.upload_timeout = Some(Duration::from_millis(upload_timeout_ms));
+        self.availability.set_threshold(unavailable_threshold);
     }
 }
 
@@ -17,8 +17,8 @@ use std::sync::LazyLock;
 use prometheus::core::{AtomicU64, GenericCounter, GenericCounterVec};
 use prometheus::{
     exponential_buckets, histogram_opts, register_histogram_vec_with_registry,
-    register_int_counter_vec_with_registry, register_int_counter_with_registry, HistogramVec,
-    Registry,
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, HistogramVec, IntGauge, Registry,
 };
 use risingwave_common::monitor::GLOBAL_METRICS_REGISTRY;
 
@@ -33,6 +33,7 @@ pub struct ObjectStoreMetrics {
     pub operation_size: HistogramVec,
     pub failure_count: GenericCounterVec<AtomicU64>,
     pub request_retry_count: GenericCounterVec<AtomicU64>,
+    pub unavailable: IntGauge,
 }
 
 impl ObjectStoreMetrics {
@@ -97,6 +98,13 @@ impl ObjectStoreMetrics {
         )
         .unwrap();
 
+        let unavailable = register_int_gauge_with_registry!(
+            "object_store_unavailable",
+            "Whether the object store is considered unavailable (1) after consecutive request failures",
+            registry
+        )
+        .unwrap();
+
         Self {
             write_bytes,
             read_bytes,
@@ -104,6 +112,7 @@ impl ObjectStoreMetrics {
             operation_size,
             failure_count,
             request_retry_count,
+            unavailable,
         }
     }
 
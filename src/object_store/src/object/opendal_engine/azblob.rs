@@ -18,6 +18,13 @@ use opendal::Operator;
 
 use super::{EngineType, OpendalObjectStore};
 use crate::object::ObjectResult;
+
+/// The minimum number of bytes that is buffered before they are uploaded as a block, will be used
+/// in streaming upload.
+///
+/// Reference: <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block>
+const AZBLOB_PART_SIZE: usize = 16 * 1024 * 1024;
+
 impl OpendalObjectStore {
     /// create opendal azblob engine.
     pub fn new_azblob_engine(container_name: String, root: String) -> ObjectResult<Self> {
@@ -26,16 +33,28 @@ impl OpendalObjectStore {
         builder.root(&root);
         builder.container(&container_name);
 
+        builder.write_min_size(AZBLOB_PART_SIZE);
+
         let endpoint = std::env::var("AZBLOB_ENDPOINT")
             .unwrap_or_else(|_| panic!("AZBLOB_ENDPOINT not found from environment variables"));
         let account_name = std::env::var("AZBLOB_ACCOUNT_NAME")
             .unwrap_or_else(|_| panic!("AZBLOB_ACCOUNT_NAME not found from environment variables"));
-        let account_key = std::env::var("AZBLOB_ACCOUNT_KEY")
-            .unwrap_or_else(|_| panic!("AZBLOB_ACCOUNT_KEY not found from environment variables"));
 
         builder.endpoint(&endpoint);
         builder.account_name(&account_name);
-        builder.account_key(&account_key);
+
+        // Credentials are resolved in order: shared account key, SAS token, and finally the
+        // default Azure credential chain (e.g. workload identity / managed identity).
+        if let Ok(account_key) = std::env::var("AZBLOB_ACCOUNT_KEY") {
+            builder.account_key(&account_key);
+        } else if let Ok(sas_token) = std::env::var("AZBLOB_SAS_TOKEN") {
+            builder.sas_token(&sas_token);
+        } else {
+            tracing::info!(
+                "AZBLOB_ACCOUNT_KEY and AZBLOB_SAS_TOKEN not found from environment variables, \
+                 fall back to the default Azure credential chain"
+            );
+        }
         let op: Operator = Operator::new(builder)?
             .layer(LoggingLayer::default())
             .layer(RetryLayer::default())
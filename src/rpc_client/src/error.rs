@@ -38,6 +38,19 @@ pub enum RpcError {
 
 static_assertions::const_assert_eq!(std::mem::size_of::<RpcError>(), 16);
 
+impl RpcError {
+    /// Returns whether the error is likely to be transient and the RPC may succeed if retried
+    /// on a (re-established) connection: the transport failed, or the peer reported itself
+    /// unavailable. Errors returned by the service itself are never considered transient.
+    pub fn is_connection_error(&self) -> bool {
+        match self {
+            RpcError::TransportError(_) => true,
+            RpcError::GrpcStatus(status) => status.inner().code() == tonic::Code::Unavailable,
+            RpcError::Internal(_) => false,
+        }
+    }
+}
+
 impl From<tonic::transport::Error> for RpcError {
     fn from(e: tonic::transport::Error) -> Self {
         RpcError::TransportError(Box::new(e))
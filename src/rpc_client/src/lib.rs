@@ -52,6 +52,7 @@ mod compute_client;
 mod connector_client;
 mod hummock_meta_client;
 mod meta_client;
+mod retry;
 mod sink_coordinate_client;
 mod stream_client;
 mod tracing;
@@ -61,6 +62,7 @@ pub use compute_client::{ComputeClient, ComputeClientPool, ComputeClientPoolRef}
 pub use connector_client::{ConnectorClient, SinkCoordinatorStreamHandle, SinkWriterStreamHandle};
 pub use hummock_meta_client::{CompactionEventItem, HummockMetaClient};
 pub use meta_client::{MetaClient, SinkCoordinationRpcClient};
+pub use retry::RpcRetryConfig;
 use risingwave_common::util::await_future_with_monitor_error_stream;
 pub use sink_coordinate_client::CoordinatorStreamHandle;
 pub use stream_client::{StreamClient, StreamClientPool, StreamClientPoolRef};
@@ -79,6 +81,8 @@ pub struct RpcClientPool<S> {
     connection_pool_size: u16,
 
     clients: Cache<HostAddr, Vec<S>>,
+
+    breaker: Arc<retry::CircuitBreaker>,
 }
 
 impl<S> Default for RpcClientPool<S>
@@ -95,9 +99,14 @@ where
     S: RpcClient,
 {
     pub fn new(connection_pool_size: u16) -> Self {
+        Self::new_with_config(connection_pool_size, RpcRetryConfig::default())
+    }
+
+    pub fn new_with_config(connection_pool_size: u16, retry_config: RpcRetryConfig) -> Self {
         Self {
             connection_pool_size,
             clients: Cache::new(u64::MAX),
+            breaker: Arc::new(retry::CircuitBreaker::new(retry_config)),
         }
     }
 
@@ -110,7 +119,10 @@ where
 
     /// Gets the RPC client for the given addr. If the connection is not established, a
     /// new client will be created and returned.
+    ///
+    /// Fails fast if the circuit breaker of the peer is currently open.
     pub async fn get_by_addr(&self, addr: HostAddr) -> Result<S> {
+        self.breaker.check(&addr)?;
         Ok(self
             .clients
             .try_get_with(
@@ -125,6 +137,51 @@ where
             .unwrap()
             .clone())
     }
+
+    /// Gets a client for the given node and invokes the RPC `f` on it, retrying transient
+    /// connection failures with exponential backoff so that a network blip does not directly
+    /// escalate to the caller (e.g. into a full recovery when injecting barriers).
+    ///
+    /// Errors returned by the service itself are not retried. Consecutive connection failures
+    /// eventually open the circuit breaker of the peer, making further RPCs to it fail fast
+    /// until the configured cooldown elapses.
+    pub async fn execute<T, F, Fut>(&self, node: &WorkerNode, f: F) -> Result<T>
+    where
+        F: Fn(S) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let addr: HostAddr = node.get_host().unwrap().into();
+        let mut backoff = self.breaker.backoff();
+        loop {
+            self.breaker.check(&addr)?;
+            let err = match self.get_by_addr(addr.clone()).await {
+                // Client creation only fails on connection problems, so it's always retryable.
+                Err(e) => e,
+                Ok(client) => match f(client).await {
+                    Err(e) if e.is_connection_error() => e,
+                    result => {
+                        // A response from the service, even an error one, proves the peer to
+                        // be reachable.
+                        self.breaker.on_success(&addr);
+                        return result;
+                    }
+                },
+            };
+            if self.breaker.on_failure(&addr) {
+                // Drop the pooled clients of the peer so that we connect from scratch once
+                // the circuit closes again.
+                self.clients.invalidate(&addr).await;
+                return Err(err);
+            }
+            match backoff.next() {
+                Some(delay) => {
+                    tracing::warn!("RPC to {} failed, retrying in {:?}: {}", addr, delay, err);
+                    tokio::time::sleep(delay).await;
+                }
+                None => return Err(err),
+            }
+        }
+    }
 }
 
 /// `ExtraInfoSource` is used by heartbeat worker to pull extra info that needs to be piggybacked.
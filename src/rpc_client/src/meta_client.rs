@@ -61,10 +61,13 @@ use risingwave_pb::hummock::*;
 use risingwave_pb::meta::add_worker_node_request::Property;
 use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
 use risingwave_pb::meta::cluster_service_client::ClusterServiceClient;
+use risingwave_pb::meta::event_log_service_client::EventLogServiceClient;
 use risingwave_pb::meta::get_reschedule_plan_request::PbPolicy;
 use risingwave_pb::meta::heartbeat_request::{extra_info, ExtraInfo};
 use risingwave_pb::meta::heartbeat_service_client::HeartbeatServiceClient;
+use risingwave_pb::meta::list_actor_splits_response::ActorSplit;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
+use risingwave_pb::meta::list_actor_traces_response::ActorTrace;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
@@ -782,6 +785,39 @@ impl MetaClient {
         Ok(resp.states)
     }
 
+    pub async fn list_actor_splits(&self) -> Result<Vec<ActorSplit>> {
+        let resp = self
+            .inner
+            .list_actor_splits(ListActorSplitsRequest {})
+            .await?;
+        Ok(resp.actor_splits)
+    }
+
+    pub async fn list_actor_traces(&self) -> Result<Vec<ActorTrace>> {
+        let resp = self
+            .inner
+            .list_actor_traces(ListActorTracesRequest {})
+            .await?;
+        Ok(resp.traces)
+    }
+
+    pub async fn alter_parallelism(&self, table_id: u32, parallelism: u32) -> Result<()> {
+        self.inner
+            .alter_parallelism(AlterParallelismRequest {
+                table_id,
+                parallelism,
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn alter_sink_pause(&self, sink_id: u32, pause: bool) -> Result<()> {
+        self.inner
+            .alter_sink_pause(AlterSinkPauseRequest { sink_id, pause })
+            .await?;
+        Ok(())
+    }
+
     pub async fn pause(&self) -> Result<PauseResponse> {
         let request = PauseRequest {};
         let resp = self.inner.pause(request).await?;
@@ -828,6 +864,14 @@ impl MetaClient {
         Ok(resp)
     }
 
+    pub async fn complete_drain_workers(&self, worker_ids: &[u32]) -> Result<()> {
+        let request = CompleteDrainWorkersRequest {
+            worker_ids: worker_ids.to_vec(),
+        };
+        self.inner.complete_drain_workers(request).await?;
+        Ok(())
+    }
+
     pub async fn risectl_get_pinned_versions_summary(
         &self,
     ) -> Result<RiseCtlGetPinnedVersionsSummaryResponse> {
@@ -1006,6 +1050,12 @@ impl MetaClient {
         Ok(resp)
     }
 
+    pub async fn list_event_log(&self) -> Result<Vec<EventLog>> {
+        let req = ListEventLogRequest {};
+        let resp = self.inner.list_event_log(req).await?;
+        Ok(resp.event_logs)
+    }
+
     pub async fn get_system_params(&self) -> Result<SystemParamsReader> {
         let req = GetSystemParamsRequest {};
         let resp = self.inner.get_system_params(req).await?;
@@ -1041,6 +1091,21 @@ impl MetaClient {
         Ok(resp.new_group_id)
     }
 
+    pub async fn ingest_sstables(
+        &self,
+        compaction_group_id: CompactionGroupId,
+        target_level: u32,
+        sstables: Vec<SstableInfo>,
+    ) -> Result<()> {
+        let req = IngestSstablesRequest {
+            compaction_group_id,
+            target_level,
+            sstables,
+        };
+        let _resp = self.inner.ingest_sstables(req).await?;
+        Ok(())
+    }
+
     pub async fn get_tables(&self, table_ids: &[u32]) -> Result<HashMap<u32, Table>> {
         let req = GetTablesRequest {
             table_ids: table_ids.to_vec(),
@@ -1342,6 +1407,7 @@ struct GrpcMetaClientCore {
     serving_client: ServingServiceClient<Channel>,
     cloud_client: CloudServiceClient<Channel>,
     sink_coordinate_client: SinkCoordinationRpcClient,
+    event_log_client: EventLogServiceClient<Channel>,
 }
 
 impl GrpcMetaClientCore {
@@ -1366,7 +1432,8 @@ impl GrpcMetaClientCore {
         let system_params_client = SystemParamsServiceClient::new(channel.clone());
         let serving_client = ServingServiceClient::new(channel.clone());
         let cloud_client = CloudServiceClient::new(channel.clone());
-        let sink_coordinate_client = SinkCoordinationServiceClient::new(channel);
+        let sink_coordinate_client = SinkCoordinationServiceClient::new(channel.clone());
+        let event_log_client = EventLogServiceClient::new(channel);
 
         GrpcMetaClientCore {
             cluster_client,
@@ -1384,6 +1451,7 @@ impl GrpcMetaClientCore {
             serving_client,
             cloud_client,
             sink_coordinate_client,
+            event_log_client,
         }
     }
 }
@@ -1735,6 +1803,10 @@ macro_rules! for_all_meta_rpc {
             ,{ stream_client, list_table_fragment_states, ListTableFragmentStatesRequest, ListTableFragmentStatesResponse }
             ,{ stream_client, list_fragment_distribution, ListFragmentDistributionRequest, ListFragmentDistributionResponse }
             ,{ stream_client, list_actor_states, ListActorStatesRequest, ListActorStatesResponse }
+            ,{ stream_client, list_actor_splits, ListActorSplitsRequest, ListActorSplitsResponse }
+            ,{ stream_client, list_actor_traces, ListActorTracesRequest, ListActorTracesResponse }
+            ,{ stream_client, alter_parallelism, AlterParallelismRequest, AlterParallelismResponse }
+            ,{ stream_client, alter_sink_pause, AlterSinkPauseRequest, AlterSinkPauseResponse }
             ,{ ddl_client, create_table, CreateTableRequest, CreateTableResponse }
             ,{ ddl_client, alter_relation_name, AlterRelationNameRequest, AlterRelationNameResponse }
             ,{ ddl_client, alter_owner, AlterOwnerRequest, AlterOwnerResponse }
@@ -1791,6 +1863,7 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, rise_ctl_resume_version_checkpoint, RiseCtlResumeVersionCheckpointRequest, RiseCtlResumeVersionCheckpointResponse }
             ,{ hummock_client, init_metadata_for_replay, InitMetadataForReplayRequest, InitMetadataForReplayResponse }
             ,{ hummock_client, split_compaction_group, SplitCompactionGroupRequest, SplitCompactionGroupResponse }
+            ,{ hummock_client, ingest_sstables, IngestSstablesRequest, IngestSstablesResponse }
             ,{ hummock_client, rise_ctl_list_compaction_status, RiseCtlListCompactionStatusRequest, RiseCtlListCompactionStatusResponse }
             ,{ hummock_client, get_compaction_score, GetCompactionScoreRequest, GetCompactionScoreResponse }
             ,{ hummock_client, rise_ctl_rebuild_table_stats, RiseCtlRebuildTableStatsRequest, RiseCtlRebuildTableStatsResponse }
@@ -1806,6 +1879,7 @@ macro_rules! for_all_meta_rpc {
             ,{ scale_client, get_cluster_info, GetClusterInfoRequest, GetClusterInfoResponse }
             ,{ scale_client, reschedule, RescheduleRequest, RescheduleResponse }
             ,{ scale_client, get_reschedule_plan, GetReschedulePlanRequest, GetReschedulePlanResponse }
+            ,{ scale_client, complete_drain_workers, CompleteDrainWorkersRequest, CompleteDrainWorkersResponse }
             ,{ notification_client, subscribe, SubscribeRequest, Streaming<SubscribeResponse> }
             ,{ backup_client, backup_meta, BackupMetaRequest, BackupMetaResponse }
             ,{ backup_client, get_backup_job_status, GetBackupJobStatusRequest, GetBackupJobStatusResponse }
@@ -1816,6 +1890,7 @@ macro_rules! for_all_meta_rpc {
             ,{ system_params_client, set_system_param, SetSystemParamRequest, SetSystemParamResponse }
             ,{ serving_client, get_serving_vnode_mappings, GetServingVnodeMappingsRequest, GetServingVnodeMappingsResponse }
             ,{ cloud_client, rw_cloud_validate_source, RwCloudValidateSourceRequest, RwCloudValidateSourceResponse }
+            ,{ event_log_client, list_event_log, ListEventLogRequest, ListEventLogResponse }
         }
     };
 }
@@ -0,0 +1,312 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retry and circuit-breaking policies applied to the RPC clients pooled per peer in
+//! [`RpcClientPool`](crate::RpcClientPool).
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry, IntCounterVec,
+    IntGaugeVec, Registry,
+};
+use risingwave_common::monitor::GLOBAL_METRICS_REGISTRY;
+use risingwave_common::util::addr::HostAddr;
+use tokio_retry::strategy::{jitter, ExponentialBackoff};
+
+use crate::error::Result;
+
+/// Cap of the backoff interval between two attempts of an RPC.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// Configuration of the retry and circuit-breaking behavior of an RPC client pool.
+#[derive(Clone, Copy, Debug)]
+pub struct RpcRetryConfig {
+    /// The maximum number of attempts for a single RPC, including the first one.
+    pub retry_max_attempts: usize,
+
+    /// Base interval of the exponential backoff between two attempts, in milliseconds.
+    pub retry_base_interval_ms: u64,
+
+    /// The number of consecutive connection failures to a peer after which its circuit opens
+    /// and RPCs to it fail fast, instead of going through the full retry backoff each time.
+    pub circuit_break_threshold: usize,
+
+    /// How long an open circuit rejects RPCs before the next attempt is let through to probe
+    /// the peer again, in milliseconds.
+    pub circuit_break_cooldown_ms: u64,
+}
+
+impl Default for RpcRetryConfig {
+    fn default() -> Self {
+        Self {
+            retry_max_attempts: 3,
+            retry_base_interval_ms: 50,
+            circuit_break_threshold: 10,
+            circuit_break_cooldown_ms: 10000,
+        }
+    }
+}
+
+struct RpcClientMetrics {
+    peer_connection_failure_count: IntCounterVec,
+    peer_circuit_open: IntGaugeVec,
+}
+
+static GLOBAL_RPC_CLIENT_METRICS: LazyLock<RpcClientMetrics> =
+    LazyLock::new(|| RpcClientMetrics::new(&GLOBAL_METRICS_REGISTRY));
+
+impl RpcClientMetrics {
+    fn new(registry: &Registry) -> Self {
+        let peer_connection_failure_count = register_int_counter_vec_with_registry!(
+            "rpc_client_peer_connection_failure_count",
+            "The number of connection-level RPC failures to the peer",
+            &["peer"],
+            registry
+        )
+        .unwrap();
+        let peer_circuit_open = register_int_gauge_vec_with_registry!(
+            "rpc_client_peer_circuit_open",
+            "Whether the circuit breaker of the peer is currently open (1) or closed (0)",
+            &["peer"],
+            registry
+        )
+        .unwrap();
+        Self {
+            peer_connection_failure_count,
+            peer_circuit_open,
+        }
+    }
+}
+
+#[derive(Default)]
+struct PeerState {
+    consecutive_failures: usize,
+    open_until: Option<Instant>,
+}
+
+/// Tracks the connection health of each peer of an [`RpcClientPool`](crate::RpcClientPool).
+///
+/// After [`RpcRetryConfig::circuit_break_threshold`] consecutive connection failures, the
+/// circuit of the peer opens and RPCs to it fail fast until the cooldown elapses, so that an
+/// unreachable peer does not delay every caller by the full retry backoff. The per-peer health
+/// is surfaced in the `rpc_client_peer_*` metrics.
+pub struct CircuitBreaker {
+    config: RpcRetryConfig,
+    peers: Mutex<HashMap<HostAddr, PeerState>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: RpcRetryConfig) -> Self {
+        Self {
+            config,
+            peers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The backoff intervals between the attempts of a single RPC.
+    pub(crate) fn backoff(&self) -> impl Iterator<Item = Duration> {
+        ExponentialBackoff::from_millis(self.config.retry_base_interval_ms)
+            .max_delay(RETRY_MAX_DELAY)
+            .take(self.config.retry_max_attempts.saturating_sub(1))
+            .map(jitter)
+    }
+
+    /// Errors if the circuit of the peer is currently open.
+    pub(crate) fn check(&self, addr: &HostAddr) -> Result<()> {
+        let mut peers = self.peers.lock().unwrap();
+        if let Some(state) = peers.get_mut(addr)
+            && let Some(open_until) = state.open_until
+        {
+            if Instant::now() < open_until {
+                return Err(anyhow!(
+                    "circuit breaker of {} is open after {} consecutive connection failures",
+                    addr,
+                    state.consecutive_failures
+                )
+                .into());
+            }
+            // Half-open: let the next attempt through to probe the peer. A failure will open
+            // the circuit again right away.
+            state.open_until = None;
+        }
+        Ok(())
+    }
+
+    /// Records a connection failure to the peer. Returns whether this failure opened the
+    /// circuit.
+    pub(crate) fn on_failure(&self, addr: &HostAddr) -> bool {
+        GLOBAL_RPC_CLIENT_METRICS
+            .peer_connection_failure_count
+            .with_label_values(&[&addr.to_string()])
+            .inc();
+
+        let mut peers = self.peers.lock().unwrap();
+        let state = peers.entry(addr.clone()).or_default();
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.config.circuit_break_threshold
+            && state.open_until.is_none()
+        {
+            state.open_until =
+                Some(Instant::now() + Duration::from_millis(self.config.circuit_break_cooldown_ms));
+            GLOBAL_RPC_CLIENT_METRICS
+                .peer_circuit_open
+                .with_label_values(&[&addr.to_string()])
+                .set(1);
+            tracing::warn!(
+                "circuit breaker of {} opened after {} consecutive connection failures",
+                addr,
+                state.consecutive_failures
+            );
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Records a successful RPC to the peer, resetting its failure count and closing its
+    /// circuit.
+    pub(crate) fn on_success(&self, addr: &HostAddr) {
+        let mut peers = self.peers.lock().unwrap();
+        if peers.remove(addr).is_some() {
+            GLOBAL_RPC_CLIENT_METRICS
+                .peer_circuit_open
+                .with_label_values(&[&addr.to_string()])
+                .set(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use risingwave_pb::common::{HostAddress, WorkerNode};
+
+    use super::*;
+    use crate::error::RpcError;
+    use crate::{RpcClient, RpcClientPool};
+
+    #[derive(Clone)]
+    struct TestClient;
+
+    #[async_trait]
+    impl RpcClient for TestClient {
+        async fn new_client(_host_addr: HostAddr) -> Result<Self> {
+            Ok(TestClient)
+        }
+    }
+
+    fn test_node() -> WorkerNode {
+        WorkerNode {
+            host: Some(HostAddress {
+                host: "127.0.0.1".to_string(),
+                port: 1234,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn unavailable() -> RpcError {
+        tonic::Status::unavailable("peer is down").into()
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_transient_failures() {
+        let pool = RpcClientPool::<TestClient>::new_with_config(
+            1,
+            RpcRetryConfig {
+                retry_max_attempts: 3,
+                retry_base_interval_ms: 1,
+                ..Default::default()
+            },
+        );
+        let node = test_node();
+
+        // Succeeds on the third attempt.
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let result = pool
+            .execute(&node, |_client| {
+                let attempts = attempts.clone();
+                async move {
+                    if attempts.fetch_add(1, Ordering::Relaxed) < 2 {
+                        Err(unavailable())
+                    } else {
+                        Ok(42)
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+        // Errors returned by the service itself are not retried.
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let result: Result<()> = pool
+            .execute(&node, |_client| {
+                let attempts = attempts.clone();
+                async move {
+                    attempts.fetch_add(1, Ordering::Relaxed);
+                    Err(tonic::Status::internal("oops").into())
+                }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_fails_fast() {
+        let pool = RpcClientPool::<TestClient>::new_with_config(
+            1,
+            RpcRetryConfig {
+                retry_max_attempts: 2,
+                retry_base_interval_ms: 1,
+                circuit_break_threshold: 2,
+                circuit_break_cooldown_ms: 100,
+            },
+        );
+        let node = test_node();
+
+        // Exhausting the attempts reaches the threshold and opens the circuit.
+        let result: Result<()> = pool
+            .execute(&node, |_client| async { Err(unavailable()) })
+            .await;
+        assert!(result.is_err());
+
+        // The RPC now fails fast without being attempted at all.
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let result: Result<()> = pool
+            .execute(&node, |_client| {
+                let attempts = attempts.clone();
+                async move {
+                    attempts.fetch_add(1, Ordering::Relaxed);
+                    Err(unavailable())
+                }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::Relaxed), 0);
+
+        // After the cooldown, the peer is probed again and recovers.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let result = pool.execute(&node, |_client| async { Ok(42) }).await;
+        assert_eq!(result.unwrap(), 42);
+    }
+}
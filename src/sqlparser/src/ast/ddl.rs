@@ -104,6 +104,10 @@ pub enum AlterIndexOperation {
 pub enum AlterViewOperation {
     RenameView { view_name: ObjectName },
     ChangeOwner { new_owner_name: Ident },
+    /// `SET PARALLELISM = <parallelism>`, materialized views only.
+    SetParallelism { parallelism: u64 },
+    /// `COMPACT`, materialized views only.
+    Compact,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -112,6 +116,10 @@ pub enum AlterViewOperation {
 pub enum AlterSinkOperation {
     RenameSink { sink_name: ObjectName },
     ChangeOwner { new_owner_name: Ident },
+    /// `PAUSE`, stop delivering to the external system while keeping the sink job alive.
+    Pause,
+    /// `RESUME`, resume delivering to the external system.
+    Resume,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -218,6 +226,12 @@ impl fmt::Display for AlterViewOperation {
             AlterViewOperation::ChangeOwner { new_owner_name } => {
                 write!(f, "OWNER TO {}", new_owner_name)
             }
+            AlterViewOperation::SetParallelism { parallelism } => {
+                write!(f, "SET PARALLELISM = {}", parallelism)
+            }
+            AlterViewOperation::Compact => {
+                write!(f, "COMPACT")
+            }
         }
     }
 }
@@ -231,6 +245,12 @@ impl fmt::Display for AlterSinkOperation {
             AlterSinkOperation::ChangeOwner { new_owner_name } => {
                 write!(f, "OWNER TO {}", new_owner_name)
             }
+            AlterSinkOperation::Pause => {
+                write!(f, "PAUSE")
+            }
+            AlterSinkOperation::Resume => {
+                write!(f, "RESUME")
+            }
         }
     }
 }
@@ -38,8 +38,8 @@ pub use self::ddl::{
 pub use self::operator::{BinaryOperator, QualifiedOperator, UnaryOperator};
 pub use self::query::{
     Cte, Distinct, Fetch, Join, JoinConstraint, JoinOperator, LateralView, OrderByExpr, Query,
-    Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Top, Values,
-    With,
+    Select, SelectItem, SetExpr, SetOperator, TableAlias, TableAliasColumnDef, TableFactor,
+    TableWithJoins, Top, Values, With,
 };
 pub use self::statement::*;
 pub use self::value::{
@@ -859,6 +859,7 @@ pub enum ShowObject {
     Cluster,
     Jobs,
     ProcessList,
+    FrontendNodes,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -901,6 +902,7 @@ impl fmt::Display for ShowObject {
             }
             ShowObject::Jobs => write!(f, "JOBS"),
             ShowObject::ProcessList => write!(f, "PROCESSLIST"),
+            ShowObject::FrontendNodes => write!(f, "FRONTEND_NODES"),
         }
     }
 }
@@ -965,6 +967,24 @@ impl fmt::Display for ExplainType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExplainFormat {
+    Text,
+    Json,
+    Dot,
+}
+
+impl fmt::Display for ExplainFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExplainFormat::Text => f.write_str("TEXT"),
+            ExplainFormat::Json => f.write_str("JSON"),
+            ExplainFormat::Dot => f.write_str("DOT"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExplainOptions {
@@ -972,15 +992,21 @@ pub struct ExplainOptions {
     pub verbose: bool,
     // Trace plan transformation of the optimizer step by step
     pub trace: bool,
+    // Display estimated costs and state sizes of the plan
+    pub costs: bool,
     // explain's plan type
     pub explain_type: ExplainType,
+    // explain's output format
+    pub explain_format: ExplainFormat,
 }
 impl Default for ExplainOptions {
     fn default() -> Self {
         Self {
             verbose: false,
             trace: false,
+            costs: false,
             explain_type: ExplainType::Physical,
+            explain_format: ExplainFormat::Text,
         }
     }
 }
@@ -997,9 +1023,15 @@ impl fmt::Display for ExplainOptions {
             if self.trace {
                 option_strs.push("TRACE".to_string());
             }
+            if self.costs {
+                option_strs.push("COSTS".to_string());
+            }
             if self.explain_type == default.explain_type {
                 option_strs.push(self.explain_type.to_string());
             }
+            if self.explain_format != default.explain_format {
+                option_strs.push(format!("FORMAT {}", self.explain_format));
+            }
             write!(f, "{}", option_strs.iter().format(","))
         }
     }
@@ -1042,6 +1074,16 @@ pub enum Statement {
         /// VALUES a vector of values to be copied
         values: Vec<Option<String>>,
     },
+    /// COPY TO, which exports a snapshot of a table or materialized view to an external
+    /// location
+    CopyTo {
+        /// TABLE or MATERIALIZED VIEW
+        table_name: ObjectName,
+        /// COLUMNS
+        columns: Vec<Ident>,
+        /// Destination, e.g. `s3://bucket/path/`
+        target: String,
+    },
     /// UPDATE
     Update {
         /// TABLE
@@ -1091,6 +1133,8 @@ pub enum Statement {
         source_watermarks: Vec<SourceWatermark>,
         /// Append only table.
         append_only: bool,
+        /// `ON CONFLICT` behavior for rows sharing a primary key.
+        on_conflict: Option<OnConflict>,
         /// `AS ( query )`
         query: Option<Box<Query>>,
         /// `FROM cdc_source TABLE database_name.table_name`
@@ -1104,6 +1148,8 @@ pub enum Statement {
         columns: Vec<OrderByExpr>,
         include: Vec<Ident>,
         distributed_by: Vec<Expr>,
+        /// `WHERE predicate` for a partial index
+        predicate: Option<Expr>,
         unique: bool,
         if_not_exists: bool,
     },
@@ -1203,7 +1249,7 @@ pub enum Statement {
     DropFunction {
         if_exists: bool,
         /// One or more function to drop
-        func_desc: Vec<DropFunctionDesc>,
+        func_desc: Vec<FunctionDesc>,
         /// `CASCADE` or `RESTRICT`
         option: Option<ReferentialAction>,
     },
@@ -1225,6 +1271,19 @@ pub enum Statement {
     StartTransaction { modes: Vec<TransactionMode> },
     /// `BEGIN [ TRANSACTION | WORK ]`
     Begin { modes: Vec<TransactionMode> },
+    /// `BEGIN DDL`
+    ///
+    /// Note: this is a RisingWave-specific statement. It starts a DDL transaction in which
+    /// `CREATE` statements are buffered and applied together at `COMMIT DDL`.
+    BeginDdl,
+    /// `COMMIT DDL`
+    ///
+    /// Note: this is a RisingWave-specific statement.
+    CommitDdl,
+    /// `ROLLBACK DDL`
+    ///
+    /// Note: this is a RisingWave-specific statement.
+    RollbackDdl,
     /// ABORT
     Abort,
     /// `SET TRANSACTION ...`
@@ -1318,6 +1377,11 @@ pub enum Statement {
     /// WAIT for ALL running stream jobs to finish.
     /// It will block the current session the condition is met.
     Wait,
+    /// `DISCARD ALL`
+    ///
+    /// Resets the session to its initial state, so that connection poolers can safely reuse
+    /// the connection for another client.
+    Discard,
 }
 
 impl fmt::Display for Statement {
@@ -1405,6 +1469,21 @@ impl fmt::Display for Statement {
                 }
                 write!(f, "\n\\.")
             }
+            Statement::CopyTo {
+                table_name,
+                columns,
+                target,
+            } => {
+                write!(f, "COPY {}", table_name)?;
+                if !columns.is_empty() {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
+                write!(
+                    f,
+                    " TO {} FORMAT PARQUET",
+                    Value::SingleQuotedString(target.clone())
+                )
+            }
             Statement::Update {
                 table_name,
                 assignments,
@@ -1535,6 +1614,7 @@ impl fmt::Display for Statement {
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 query,
                 cdc_table_info,
             } => {
@@ -1562,6 +1642,9 @@ impl fmt::Display for Statement {
                 if *append_only {
                     write!(f, " APPEND ONLY")?;
                 }
+                if let Some(on_conflict) = on_conflict {
+                    write!(f, " ON CONFLICT {}", on_conflict)?;
+                }
                 if !with_options.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_options))?;
                 }
@@ -1583,11 +1666,12 @@ impl fmt::Display for Statement {
                 columns,
                 include,
                 distributed_by,
+                predicate,
                 unique,
                 if_not_exists,
             } => write!(
                 f,
-                "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns}){include}{distributed_by}",
+                "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns}){include}{distributed_by}{predicate}",
                 unique = if *unique { "UNIQUE " } else { "" },
                 if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
                 name = name,
@@ -1602,6 +1686,10 @@ impl fmt::Display for Statement {
                     "".to_string()
                 } else {
                     format!(" DISTRIBUTED BY({})", display_separated(distributed_by, ","))
+                },
+                predicate = match predicate {
+                    Some(predicate) => format!(" WHERE {}", predicate),
+                    None => "".to_string(),
                 }
             ),
             Statement::CreateSource {
@@ -1711,6 +1799,9 @@ impl fmt::Display for Statement {
                 write!(f, " TIME ZONE {}", value)?;
                 Ok(())
             }
+            Statement::BeginDdl => write!(f, "BEGIN DDL"),
+            Statement::CommitDdl => write!(f, "COMMIT DDL"),
+            Statement::RollbackDdl => write!(f, "ROLLBACK DDL"),
             Statement::Commit { chain } => {
                 write!(f, "COMMIT{}", if *chain { " AND CHAIN" } else { "" },)
             }
@@ -1825,6 +1916,9 @@ impl fmt::Display for Statement {
             Statement::Wait => {
                 write!(f, "WAIT")
             }
+            Statement::Discard => {
+                write!(f, "DISCARD ALL")
+            }
             Statement::Begin { modes } => {
                 write!(f, "BEGIN")?;
                 if !modes.is_empty() {
@@ -1860,6 +1954,27 @@ impl fmt::Display for OnInsert {
     }
 }
 
+/// Behavior of a table towards rows that conflict on the primary key, declared by the
+/// `ON CONFLICT` clause of `CREATE TABLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OnConflict {
+    /// `DO UPDATE FULL` (or `OVERWRITE`): the new row overwrites the existing one. This is the
+    /// default behavior of a non-append-only table.
+    UpdateFull,
+    /// `DO NOTHING` (or `IGNORE`): the new row is discarded and the existing one is kept.
+    Nothing,
+}
+
+impl fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OnConflict::UpdateFull => "DO UPDATE FULL",
+            OnConflict::Nothing => "DO NOTHING",
+        })
+    }
+}
+
 /// Privileges granted in a GRANT statement or revoked in a REVOKE statement.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1955,6 +2070,8 @@ pub enum GrantObjects {
     AllTablesInSchema { schemas: Vec<ObjectName> },
     /// Grant privileges on `ALL SOURCES IN SCHEMA <schema_name> [, ...]`
     AllSourcesInSchema { schemas: Vec<ObjectName> },
+    /// Grant privileges on `ALL SINKS IN SCHEMA <schema_name> [, ...]`
+    AllSinksInSchema { schemas: Vec<ObjectName> },
     /// Grant privileges on `ALL MATERIALIZED VIEWS IN SCHEMA <schema_name> [, ...]`
     AllMviewsInSchema { schemas: Vec<ObjectName> },
     /// Grant privileges on specific databases
@@ -1971,6 +2088,8 @@ pub enum GrantObjects {
     Tables(Vec<ObjectName>),
     /// Grant privileges on specific sinks
     Sinks(Vec<ObjectName>),
+    /// Grant privileges on specific functions
+    Functions(Vec<FunctionDesc>),
 }
 
 impl fmt::Display for GrantObjects {
@@ -2006,6 +2125,13 @@ impl fmt::Display for GrantObjects {
                     display_comma_separated(schemas)
                 )
             }
+            GrantObjects::AllSinksInSchema { schemas } => {
+                write!(
+                    f,
+                    "ALL SINKS IN SCHEMA {}",
+                    display_comma_separated(schemas)
+                )
+            }
             GrantObjects::AllMviewsInSchema { schemas } => {
                 write!(
                     f,
@@ -2025,6 +2151,9 @@ impl fmt::Display for GrantObjects {
             GrantObjects::Sinks(sinks) => {
                 write!(f, "SINK {}", display_comma_separated(sinks))
             }
+            GrantObjects::Functions(functions) => {
+                write!(f, "FUNCTION {}", display_comma_separated(functions))
+            }
         }
     }
 }
@@ -2282,15 +2411,24 @@ impl fmt::Display for SqlOption {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EmitMode {
     Immediately,
-    OnWindowClose,
+    OnWindowClose {
+        /// Interval literal of the `ALLOWED LATENESS` clause, if specified.
+        allowed_lateness: Option<Value>,
+    },
 }
 
 impl fmt::Display for EmitMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            EmitMode::Immediately => "IMMEDIATELY",
-            EmitMode::OnWindowClose => "ON WINDOW CLOSE",
-        })
+        match self {
+            EmitMode::Immediately => f.write_str("IMMEDIATELY"),
+            EmitMode::OnWindowClose { allowed_lateness } => {
+                f.write_str("ON WINDOW CLOSE")?;
+                if let Some(allowed_lateness) = allowed_lateness {
+                    write!(f, " ALLOWED LATENESS {}", allowed_lateness)?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
@@ -2405,16 +2543,16 @@ impl fmt::Display for DropFunctionOption {
     }
 }
 
-/// Function describe in DROP FUNCTION.
+/// Function describe in DROP FUNCTION / GRANT / REVOKE.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
-pub struct DropFunctionDesc {
+pub struct FunctionDesc {
     pub name: ObjectName,
     pub args: Option<Vec<OperateFunctionArg>>,
 }
 
-impl fmt::Display for DropFunctionDesc {
+impl fmt::Display for FunctionDesc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.name)?;
         if let Some(args) = &self.args {
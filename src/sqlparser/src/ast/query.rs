@@ -147,6 +147,9 @@ impl fmt::Display for SetOperator {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Select {
     pub distinct: Distinct,
+    /// Optimizer hints in a `/*+ ... */` comment right after the `SELECT` keyword,
+    /// e.g. `SELECT /*+ ordered, broadcast_join */ ...`. Interpreted by the frontend.
+    pub hints: Vec<String>,
     /// projection expressions
     pub projection: Vec<SelectItem>,
     /// FROM
@@ -164,6 +167,9 @@ pub struct Select {
 impl fmt::Display for Select {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "SELECT{}", &self.distinct)?;
+        if !self.hints.is_empty() {
+            write!(f, " /*+ {} */", display_comma_separated(&self.hints))?;
+        }
         write!(f, " {}", display_comma_separated(&self.projection))?;
         if !self.from.is_empty() {
             write!(f, " FROM {}", display_comma_separated(&self.from))?;
@@ -283,11 +289,18 @@ pub struct Cte {
     pub alias: TableAlias,
     pub query: Query,
     pub from: Option<Ident>,
+    /// `MATERIALIZED` (`Some(true)`) or `NOT MATERIALIZED` (`Some(false)`) hint, if specified.
+    pub materialized: Option<bool>,
 }
 
 impl fmt::Display for Cte {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} AS ({})", self.alias, self.query)?;
+        let materialized = match self.materialized {
+            Some(true) => "MATERIALIZED ",
+            Some(false) => "NOT MATERIALIZED ",
+            None => "",
+        };
+        write!(f, "{} AS {}({})", self.alias, materialized, self.query)?;
         if let Some(ref fr) = self.from {
             write!(f, " FROM {}", fr)?;
         }
@@ -460,7 +473,7 @@ impl fmt::Display for TableFactor {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableAlias {
     pub name: Ident,
-    pub columns: Vec<Ident>,
+    pub columns: Vec<TableAliasColumnDef>,
 }
 
 impl fmt::Display for TableAlias {
@@ -473,6 +486,35 @@ impl fmt::Display for TableAlias {
     }
 }
 
+/// A column of the alias of a table factor, with an optional data type for functions returning
+/// `record`, e.g. `jsonb_to_recordset(...) AS t (a int, b text)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableAliasColumnDef {
+    pub name: Ident,
+    pub data_type: Option<DataType>,
+}
+
+impl TableAliasColumnDef {
+    /// A column alias without a data type.
+    pub fn from_name(name: Ident) -> Self {
+        TableAliasColumnDef {
+            name,
+            data_type: None,
+        }
+    }
+}
+
+impl fmt::Display for TableAliasColumnDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {}", data_type)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Join {
@@ -1068,6 +1068,7 @@ pub enum UserOption {
     NoLogin,
     EncryptedPassword(AstString),
     Password(Option<AstString>),
+    QueryLimit(u64),
 }
 
 impl fmt::Display for UserOption {
@@ -1084,6 +1085,7 @@ impl fmt::Display for UserOption {
             UserOption::EncryptedPassword(p) => write!(f, "ENCRYPTED PASSWORD {}", p),
             UserOption::Password(None) => write!(f, "PASSWORD NULL"),
             UserOption::Password(Some(p)) => write!(f, "PASSWORD {}", p),
+            UserOption::QueryLimit(limit) => write!(f, "QUERY LIMIT {}", limit),
         }
     }
 }
@@ -1099,6 +1101,7 @@ struct UserOptionsBuilder {
     create_user: Option<UserOption>,
     login: Option<UserOption>,
     password: Option<UserOption>,
+    query_limit: Option<UserOption>,
 }
 
 impl UserOptionsBuilder {
@@ -1119,6 +1122,9 @@ impl UserOptionsBuilder {
         if let Some(option) = self.password {
             options.push(option);
         }
+        if let Some(option) = self.query_limit {
+            options.push(option);
+        }
         UserOptions(options)
     }
 }
@@ -1171,6 +1177,13 @@ impl ParseTo for UserOptions {
                             UserOption::EncryptedPassword(AstString::parse_to(parser)?),
                         )
                     }
+                    Keyword::QUERY => {
+                        parser.expect_keyword(Keyword::LIMIT)?;
+                        (
+                            &mut builder.query_limit,
+                            UserOption::QueryLimit(parser.parse_literal_uint()?),
+                        )
+                    }
                     _ => {
                         parser.expected(
                             "SUPERUSER | NOSUPERUSER | CREATEDB | NOCREATEDB | LOGIN \
@@ -74,6 +74,7 @@ define_keywords!(
     AGGREGATE,
     ALL,
     ALLOCATE,
+    ALLOWED,
     ALTER,
     ANALYSE,
     ANALYZE,
@@ -134,8 +135,10 @@ define_keywords!(
     COMMENT,
     COMMIT,
     COMMITTED,
+    COMPACT,
     CONCURRENTLY,
     CONDITION,
+    CONFLICT,
     CONFLUENT,
     CONNECT,
     CONNECTION,
@@ -146,6 +149,7 @@ define_keywords!(
     COPY,
     CORR,
     CORRESPONDING,
+    COSTS,
     COUNT,
     COVAR_POP,
     COVAR_SAMP,
@@ -174,6 +178,7 @@ define_keywords!(
     DATABASES,
     DATE,
     DAY,
+    DDL,
     DEALLOCATE,
     DEC,
     DECIMAL,
@@ -188,11 +193,13 @@ define_keywords!(
     DESCRIBE,
     DETERMINISTIC,
     DIRECTORY,
+    DISCARD,
     DISCONNECT,
     DISTINCT,
     DISTRIBUTED,
     DISTSQL,
     DO,
+    DOT,
     DOUBLE,
     DROP,
     DYNAMIC,
@@ -236,6 +243,7 @@ define_keywords!(
     FREE,
     FREEZE,
     FROM,
+    FRONTEND_NODES,
     FULL,
     FUNCTION,
     FUNCTIONS,
@@ -285,6 +293,7 @@ define_keywords!(
     LANGUAGE,
     LARGE,
     LAST,
+    LATENESS,
     LATERAL,
     LEADING,
     LEFT,
@@ -330,6 +339,7 @@ define_keywords!(
     NOSCAN,
     NOSUPERUSER,
     NOT,
+    NOTHING,
     NOTNULL,
     NTH_VALUE,
     NTILE,
@@ -358,13 +368,16 @@ define_keywords!(
     OVER,
     OVERLAPS,
     OVERLAY,
+    OVERWRITE,
     OWNER,
+    PARALLELISM,
     PARAMETER,
     PARQUET,
     PARTITION,
     PARTITIONED,
     PARTITIONS,
     PASSWORD,
+    PAUSE,
     PERCENT,
     PERCENTILE_CONT,
     PERCENTILE_DISC,
@@ -385,6 +398,7 @@ define_keywords!(
     PROCEDURE,
     PROCESSLIST,
     PURGE,
+    QUERY,
     RANGE,
     RANK,
     RCFILE,
@@ -414,6 +428,7 @@ define_keywords!(
     REPLACE,
     RESTRICT,
     RESULT,
+    RESUME,
     RETURN,
     RETURNING,
     RETURNS,
@@ -39,12 +39,30 @@ pub(crate) const UPSTREAM_SOURCE_KEY: &str = "connector";
 pub enum ParserError {
     TokenizerError(String),
     ParserError(String),
+    /// Like [`ParserError::ParserError`], but also carries the location of the offending token
+    /// in the original SQL text.
+    ParserErrorWithLocation {
+        message: String,
+        /// 1-based line number.
+        line: u64,
+        /// 1-based column number.
+        column: u64,
+    },
 }
 
 impl ParserError {
     pub fn inner_msg(self) -> String {
         match self {
             ParserError::TokenizerError(s) | ParserError::ParserError(s) => s,
+            ParserError::ParserErrorWithLocation { message, .. } => message,
+        }
+    }
+
+    /// Returns the 1-based line and column of the error in the original SQL text, if known.
+    pub fn location(&self) -> Option<(u64, u64)> {
+        match self {
+            ParserError::ParserErrorWithLocation { line, column, .. } => Some((*line, *column)),
+            _ => None,
         }
     }
 }
@@ -111,7 +129,11 @@ impl From<WildcardOrExpr> for FunctionArgExpr {
 
 impl From<TokenizerError> for ParserError {
     fn from(e: TokenizerError) -> Self {
-        ParserError::TokenizerError(e.to_string())
+        ParserError::ParserErrorWithLocation {
+            message: e.to_string(),
+            line: e.line,
+            column: e.col,
+        }
     }
 }
 
@@ -123,6 +145,7 @@ impl fmt::Display for ParserError {
             match self {
                 ParserError::TokenizerError(s) => s,
                 ParserError::ParserError(s) => s,
+                ParserError::ParserErrorWithLocation { message, .. } => message,
             }
         )
     }
@@ -261,6 +284,11 @@ impl Parser {
                 Keyword::COMMENT => Ok(self.parse_comment()?),
                 Keyword::FLUSH => Ok(Statement::Flush),
                 Keyword::WAIT => Ok(Statement::Wait),
+                Keyword::DISCARD => {
+                    // Only `DISCARD ALL` is supported.
+                    self.expect_keyword(Keyword::ALL)?;
+                    Ok(Statement::Discard)
+                }
                 _ => self.expected(
                     "an SQL statement",
                     Token::Word(w).with_location(token.location),
@@ -1861,12 +1889,16 @@ impl Parser {
                 Ok(())
             }
         }
-        parser_err!(format!(
-            "Expected {}, found: {}\nNear \"{}\"",
-            expected,
-            found,
-            TokensDisplay(near_tokens),
-        ))
+        Err(ParserError::ParserErrorWithLocation {
+            message: format!(
+                "Expected {}, found: {}\nNear \"{}\"",
+                expected,
+                found,
+                TokensDisplay(near_tokens),
+            ),
+            line: found.location.line,
+            column: found.location.column,
+        })
     }
 
     /// Look for an expected keyword and consume it if it exists
@@ -2359,7 +2391,7 @@ impl Parser {
     /// ```
     fn parse_drop_function(&mut self) -> Result<Statement, ParserError> {
         let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
-        let func_desc = self.parse_comma_separated(Parser::parse_drop_function_desc)?;
+        let func_desc = self.parse_comma_separated(Parser::parse_function_desc)?;
         let option = match self.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]) {
             Some(Keyword::CASCADE) => Some(ReferentialAction::Cascade),
             Some(Keyword::RESTRICT) => Some(ReferentialAction::Restrict),
@@ -2372,7 +2404,7 @@ impl Parser {
         })
     }
 
-    fn parse_drop_function_desc(&mut self) -> Result<DropFunctionDesc, ParserError> {
+    fn parse_function_desc(&mut self) -> Result<FunctionDesc, ParserError> {
         let name = self.parse_object_name()?;
 
         let args = if self.consume_token(&Token::LParen) {
@@ -2387,7 +2419,7 @@ impl Parser {
             None
         };
 
-        Ok(DropFunctionDesc { name, args })
+        Ok(FunctionDesc { name, args })
     }
 
     pub fn parse_create_index(&mut self, unique: bool) -> Result<Statement, ParserError> {
@@ -2410,12 +2442,18 @@ impl Parser {
             distributed_by = self.parse_comma_separated(Parser::parse_expr)?;
             self.expect_token(&Token::RParen)?;
         }
+        let predicate = if self.parse_keyword(Keyword::WHERE) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
         Ok(Statement::CreateIndex {
             name: index_name,
             table_name,
             columns,
             include,
             distributed_by,
+            predicate,
             unique,
             if_not_exists,
         })
@@ -2438,6 +2476,8 @@ impl Parser {
             false
         };
 
+        let on_conflict = self.parse_on_conflict()?;
+
         // PostgreSQL supports `WITH ( options )`, before `AS`
         let with_options = self.parse_with_properties()?;
 
@@ -2492,11 +2532,33 @@ impl Parser {
             source_schema,
             source_watermarks,
             append_only,
+            on_conflict,
             query,
             cdc_table_info,
         })
     }
 
+    /// Parse an optional `ON CONFLICT <behavior>` clause of `CREATE TABLE`.
+    pub fn parse_on_conflict(&mut self) -> Result<Option<OnConflict>, ParserError> {
+        if !self.parse_keywords(&[Keyword::ON, Keyword::CONFLICT]) {
+            return Ok(None);
+        }
+        if self.parse_keyword(Keyword::OVERWRITE)
+            || self.parse_keywords(&[Keyword::DO, Keyword::UPDATE, Keyword::FULL])
+        {
+            Ok(Some(OnConflict::UpdateFull))
+        } else if self.parse_keyword(Keyword::IGNORE)
+            || self.parse_keywords(&[Keyword::DO, Keyword::NOTHING])
+        {
+            Ok(Some(OnConflict::Nothing))
+        } else {
+            self.expected(
+                "OVERWRITE, IGNORE, DO UPDATE FULL or DO NOTHING after ON CONFLICT",
+                self.peek_token(),
+            )
+        }
+    }
+
     pub fn parse_columns_with_watermark(&mut self) -> Result<ColumnsDefTuple, ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
@@ -2770,7 +2832,17 @@ impl Parser {
                 Some(Keyword::IMMEDIATELY) => Ok(Some(EmitMode::Immediately)),
                 Some(Keyword::ON) => {
                     self.expect_keywords(&[Keyword::WINDOW, Keyword::CLOSE])?;
-                    Ok(Some(EmitMode::OnWindowClose))
+                    let allowed_lateness =
+                        if self.parse_keywords(&[Keyword::ALLOWED, Keyword::LATENESS]) {
+                            self.expect_keyword(Keyword::INTERVAL)?;
+                            let Expr::Value(value) = self.parse_literal_interval()? else {
+                                unreachable!()
+                            };
+                            Some(value)
+                        } else {
+                            None
+                        };
+                    Ok(Some(EmitMode::OnWindowClose { allowed_lateness }))
                 }
                 Some(_) => unreachable!(),
                 None => self.expected(
@@ -2973,10 +3045,21 @@ impl Parser {
             AlterViewOperation::ChangeOwner {
                 new_owner_name: owner_name,
             }
+        } else if materialized && self.parse_keywords(&[Keyword::SET, Keyword::PARALLELISM]) {
+            self.expect_token(&Token::Eq)?;
+            let parallelism = self.parse_literal_uint()?;
+            AlterViewOperation::SetParallelism { parallelism }
+        } else if materialized && self.parse_keyword(Keyword::COMPACT) {
+            AlterViewOperation::Compact
         } else {
             return self.expected(
                 &format!(
-                    "RENAME or OWNER TO after ALTER {}VIEW",
+                    "RENAME, OWNER TO{} after ALTER {}VIEW",
+                    if materialized {
+                        ", SET PARALLELISM or COMPACT"
+                    } else {
+                        ""
+                    },
                     if materialized { "MATERIALIZED " } else { "" }
                 ),
                 self.peek_token(),
@@ -3004,8 +3087,15 @@ impl Parser {
             AlterSinkOperation::ChangeOwner {
                 new_owner_name: owner_name,
             }
+        } else if self.parse_keyword(Keyword::PAUSE) {
+            AlterSinkOperation::Pause
+        } else if self.parse_keyword(Keyword::RESUME) {
+            AlterSinkOperation::Resume
         } else {
-            return self.expected("RENAME or OWNER TO after ALTER SINK", self.peek_token());
+            return self.expected(
+                "RENAME, OWNER TO, PAUSE or RESUME after ALTER SINK",
+                self.peek_token(),
+            );
         };
 
         Ok(Statement::AlterSink {
@@ -3060,6 +3150,16 @@ impl Parser {
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
+        if self.parse_keyword(Keyword::TO) {
+            let target = self.parse_literal_string()?;
+            // Parquet is the only supported export format for now.
+            self.expect_keywords(&[Keyword::FORMAT, Keyword::PARQUET])?;
+            return Ok(Statement::CopyTo {
+                table_name,
+                columns,
+                target,
+            });
+        }
         self.expect_keywords(&[Keyword::FROM, Keyword::STDIN])?;
         self.expect_token(&Token::SemiColon)?;
         let values = self.parse_tsv();
@@ -3399,13 +3499,34 @@ impl Parser {
     ) -> Result<Option<TableAlias>, ParserError> {
         match self.parse_optional_alias(reserved_kwds)? {
             Some(name) => {
-                let columns = self.parse_parenthesized_column_list(Optional)?;
+                let columns = self.parse_table_alias_column_defs()?;
                 Ok(Some(TableAlias { name, columns }))
             }
             None => Ok(None),
         }
     }
 
+    /// Parse a parenthesized, comma-separated list of column definitions within a table alias.
+    /// The data type is optional, e.g. both `(a, b)` and `(a int, b text)` are accepted, the
+    /// latter being the column definition list of a function returning `record`.
+    fn parse_table_alias_column_defs(&mut self) -> Result<Vec<TableAliasColumnDef>, ParserError> {
+        if self.consume_token(&Token::LParen) {
+            let defs = self.parse_comma_separated(|parser| {
+                let name = parser.parse_identifier_non_reserved()?;
+                let data_type = if let Token::Word(_) = parser.peek_token().token {
+                    Some(parser.parse_data_type()?)
+                } else {
+                    None
+                };
+                Ok(TableAliasColumnDef { name, data_type })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            Ok(defs)
+        } else {
+            Ok(vec![])
+        }
+    }
+
     /// syntax `FOR SYSTEM_TIME AS OF PROCTIME()` is used for temporal join.
     pub fn parse_for_system_time_as_of_proctime(&mut self) -> Result<bool, ParserError> {
         let after_for = self.parse_keyword(Keyword::FOR);
@@ -3619,10 +3740,12 @@ impl Parser {
         let explain_key_words = [
             Keyword::VERBOSE,
             Keyword::TRACE,
+            Keyword::COSTS,
             Keyword::TYPE,
             Keyword::LOGICAL,
             Keyword::PHYSICAL,
             Keyword::DISTSQL,
+            Keyword::FORMAT,
         ];
 
         let parse_explain_option = |parser: &mut Parser| -> Result<(), ParserError> {
@@ -3630,6 +3753,7 @@ impl Parser {
             match keyword {
                 Keyword::VERBOSE => options.verbose = parser.parse_optional_boolean(true),
                 Keyword::TRACE => options.trace = parser.parse_optional_boolean(true),
+                Keyword::COSTS => options.costs = parser.parse_optional_boolean(true),
                 Keyword::TYPE => {
                     let explain_type = parser.expect_one_of_keywords(&[
                         Keyword::LOGICAL,
@@ -3646,6 +3770,19 @@ impl Parser {
                 Keyword::LOGICAL => options.explain_type = ExplainType::Logical,
                 Keyword::PHYSICAL => options.explain_type = ExplainType::Physical,
                 Keyword::DISTSQL => options.explain_type = ExplainType::DistSql,
+                Keyword::FORMAT => {
+                    let explain_format = parser.expect_one_of_keywords(&[
+                        Keyword::TEXT,
+                        Keyword::JSON,
+                        Keyword::DOT,
+                    ])?;
+                    match explain_format {
+                        Keyword::TEXT => options.explain_format = ExplainFormat::Text,
+                        Keyword::JSON => options.explain_format = ExplainFormat::Json,
+                        Keyword::DOT => options.explain_format = ExplainFormat::Dot,
+                        _ => unreachable!("{}", keyword),
+                    }
+                }
                 _ => unreachable!("{}", keyword),
             };
             Ok(())
@@ -3734,6 +3871,7 @@ impl Parser {
         let name = self.parse_identifier_non_reserved()?;
 
         let mut cte = if self.parse_keyword(Keyword::AS) {
+            let materialized = self.parse_cte_materialized();
             self.expect_token(&Token::LParen)?;
             let query = self.parse_query()?;
             self.expect_token(&Token::RParen)?;
@@ -3745,10 +3883,16 @@ impl Parser {
                 alias,
                 query,
                 from: None,
+                materialized,
             }
         } else {
-            let columns = self.parse_parenthesized_column_list(Optional)?;
+            let columns = self
+                .parse_parenthesized_column_list(Optional)?
+                .into_iter()
+                .map(TableAliasColumnDef::from_name)
+                .collect();
             self.expect_keyword(Keyword::AS)?;
+            let materialized = self.parse_cte_materialized();
             self.expect_token(&Token::LParen)?;
             let query = self.parse_query()?;
             self.expect_token(&Token::RParen)?;
@@ -3757,6 +3901,7 @@ impl Parser {
                 alias,
                 query,
                 from: None,
+                materialized,
             }
         };
         if self.parse_keyword(Keyword::FROM) {
@@ -3765,6 +3910,17 @@ impl Parser {
         Ok(cte)
     }
 
+    /// Parse the optional `[NOT] MATERIALIZED` hint following `AS` in a CTE.
+    fn parse_cte_materialized(&mut self) -> Option<bool> {
+        if self.parse_keyword(Keyword::MATERIALIZED) {
+            Some(true)
+        } else if self.parse_keywords(&[Keyword::NOT, Keyword::MATERIALIZED]) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
     /// Parse a "query body", which is an expression with roughly the
     /// following grammar:
     /// ```text
@@ -3830,6 +3986,10 @@ impl Parser {
     /// Parse a restricted `SELECT` statement (no CTEs / `UNION` / `ORDER BY`),
     /// assuming the initial `SELECT` was already consumed
     pub fn parse_select(&mut self) -> Result<Select, ParserError> {
+        // The optimizer hint comment sits right after the `SELECT` keyword, before `DISTINCT`.
+        // It's never consumed as comments are treated as whitespace, so peek it here.
+        let hints = self.peek_optimizer_hints();
+
         let distinct = self.parse_all_or_distinct_on()?;
 
         let projection = self.parse_comma_separated(Parser::parse_select_item)?;
@@ -3895,6 +4055,7 @@ impl Parser {
 
         Ok(Select {
             distinct,
+            hints,
             projection,
             from,
             lateral_views,
@@ -3904,6 +4065,29 @@ impl Parser {
         })
     }
 
+    /// Look for an optimizer hint comment (`/*+ hint1, hint2 */`) at the current position,
+    /// without advancing. The comment content is split on commas into individual hints.
+    fn peek_optimizer_hints(&self) -> Vec<String> {
+        let mut index = self.index;
+        loop {
+            match self.tokens.get(index).map(|t| &t.token) {
+                Some(Token::Whitespace(Whitespace::MultiLineComment(comment))) => {
+                    if let Some(hints) = comment.strip_prefix('+') {
+                        return hints
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|hint| !hint.is_empty())
+                            .map(str::to_owned)
+                            .collect();
+                    }
+                    index += 1;
+                }
+                Some(Token::Whitespace(_)) => index += 1,
+                _ => return vec![],
+            }
+        }
+    }
+
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
         let modifier = self.parse_one_of_keywords(&[Keyword::SESSION, Keyword::LOCAL]);
         if self.parse_keywords(&[Keyword::TIME, Keyword::ZONE]) {
@@ -4096,6 +4280,12 @@ impl Parser {
                         filter: self.parse_show_statement_filter()?,
                     });
                 }
+                Keyword::FRONTEND_NODES => {
+                    return Ok(Statement::ShowObjects {
+                        object: ShowObject::FrontendNodes,
+                        filter: self.parse_show_statement_filter()?,
+                    });
+                }
                 _ => {}
             }
         }
@@ -4461,6 +4651,15 @@ impl Parser {
             GrantObjects::AllSourcesInSchema {
                 schemas: self.parse_comma_separated(Parser::parse_object_name)?,
             }
+        } else if self.parse_keywords(&[
+            Keyword::ALL,
+            Keyword::SINKS,
+            Keyword::IN,
+            Keyword::SCHEMA,
+        ]) {
+            GrantObjects::AllSinksInSchema {
+                schemas: self.parse_comma_separated(Parser::parse_object_name)?,
+            }
         } else if self.parse_keywords(&[
             Keyword::ALL,
             Keyword::MATERIALIZED,
@@ -4473,6 +4672,8 @@ impl Parser {
             }
         } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
             GrantObjects::Mviews(self.parse_comma_separated(Parser::parse_object_name)?)
+        } else if self.parse_keyword(Keyword::FUNCTION) {
+            GrantObjects::Functions(self.parse_comma_separated(Parser::parse_function_desc)?)
         } else {
             let object_type = self.parse_one_of_keywords(&[
                 Keyword::SEQUENCE,
@@ -4480,6 +4681,7 @@ impl Parser {
                 Keyword::SCHEMA,
                 Keyword::TABLE,
                 Keyword::SOURCE,
+                Keyword::SINK,
             ]);
             let objects = self.parse_comma_separated(Parser::parse_object_name);
             match object_type {
@@ -4487,6 +4689,7 @@ impl Parser {
                 Some(Keyword::SCHEMA) => GrantObjects::Schemas(objects?),
                 Some(Keyword::SEQUENCE) => GrantObjects::Sequences(objects?),
                 Some(Keyword::SOURCE) => GrantObjects::Sources(objects?),
+                Some(Keyword::SINK) => GrantObjects::Sinks(objects?),
                 Some(Keyword::TABLE) | None => GrantObjects::Tables(objects?),
                 _ => unreachable!(),
             }
@@ -4740,6 +4943,9 @@ impl Parser {
     }
 
     pub fn parse_begin(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword(Keyword::DDL) {
+            return Ok(Statement::BeginDdl);
+        }
         let _ = self.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
         Ok(Statement::Begin {
             modes: self.parse_transaction_modes()?,
@@ -4783,12 +4989,18 @@ impl Parser {
     }
 
     pub fn parse_commit(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword(Keyword::DDL) {
+            return Ok(Statement::CommitDdl);
+        }
         Ok(Statement::Commit {
             chain: self.parse_commit_rollback_chain()?,
         })
     }
 
     pub fn parse_rollback(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword(Keyword::DDL) {
+            return Ok(Statement::RollbackDdl);
+        }
         Ok(Statement::Rollback {
             chain: self.parse_commit_rollback_chain()?,
         })
@@ -1755,6 +1755,14 @@ fn parse_explain_analyze_with_simple_select() {
             ..Default::default()
         },
     );
+    run_explain_analyze(
+        "EXPLAIN (COSTS) SELECT sqrt(id) FROM foo",
+        false,
+        ExplainOptions {
+            costs: true,
+            ..Default::default()
+        },
+    );
     run_explain_analyze(
         "EXPLAIN ANALYZE (VERBOSE) SELECT sqrt(id) FROM foo",
         true,
@@ -1780,6 +1788,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: true,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1789,6 +1798,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1798,6 +1808,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
 }
@@ -2674,6 +2685,23 @@ fn parse_cte_renamed_columns() {
     );
 }
 
+#[test]
+fn parse_materialized_cte() {
+    let sql = "WITH a AS MATERIALIZED (SELECT 1) SELECT * FROM a";
+    let query = verified_query(sql);
+    assert_eq!(
+        query.with.unwrap().cte_tables.first().unwrap().materialized,
+        Some(true)
+    );
+
+    let sql = "WITH a AS NOT MATERIALIZED (SELECT 1) SELECT * FROM a";
+    let query = verified_query(sql);
+    assert_eq!(
+        query.with.unwrap().cte_tables.first().unwrap().materialized,
+        Some(false)
+    );
+}
+
 #[test]
 fn parse_recursive_cte() {
     let cte_query = "SELECT 1 UNION ALL SELECT val + 1 FROM nums WHERE val < 10".to_owned();
@@ -2691,10 +2719,11 @@ fn parse_recursive_cte() {
     let expected = Cte {
         alias: TableAlias {
             name: Ident::new_unchecked("nums"),
-            columns: vec![Ident::new_unchecked("val")],
+            columns: vec![TableAliasColumnDef::from_name(Ident::new_unchecked("val"))],
         },
         query: cte_query,
         from: None,
+        materialized: None,
     };
     assert_eq!(with.cte_tables.first().unwrap(), &expected);
 }
@@ -3143,7 +3172,50 @@ fn parse_create_materialized_view_emit_on_window_close() {
             assert!(materialized);
             assert_eq!(with_options, vec![]);
             assert!(!or_replace);
-            assert_eq!(emit_mode, Some(EmitMode::OnWindowClose));
+            assert_eq!(
+                emit_mode,
+                Some(EmitMode::OnWindowClose {
+                    allowed_lateness: None
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_materialized_view_emit_with_allowed_lateness() {
+    let sql = "CREATE MATERIALIZED VIEW myschema.myview AS SELECT foo FROM bar EMIT ON WINDOW CLOSE ALLOWED LATENESS INTERVAL '5 minutes'";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            if_not_exists,
+            name,
+            or_replace,
+            columns,
+            query,
+            materialized,
+            with_options,
+            emit_mode,
+        } => {
+            assert!(!if_not_exists);
+            assert_eq!("myschema.myview", name.to_string());
+            assert_eq!(Vec::<Ident>::new(), columns);
+            assert_eq!("SELECT foo FROM bar", query.to_string());
+            assert!(materialized);
+            assert_eq!(with_options, vec![]);
+            assert!(!or_replace);
+            assert_eq!(
+                emit_mode,
+                Some(EmitMode::OnWindowClose {
+                    allowed_lateness: Some(Value::Interval {
+                        value: "5 minutes".to_string(),
+                        leading_field: None,
+                        leading_precision: None,
+                        last_field: None,
+                        fractional_seconds_precision: None,
+                    })
+                })
+            );
         }
         _ => unreachable!(),
     }
@@ -3552,7 +3624,7 @@ fn parse_rollback() {
 
 #[test]
 fn parse_create_index() {
-    let sql = "CREATE UNIQUE INDEX IF NOT EXISTS idx_name ON test(name, age DESC) INCLUDE(other) DISTRIBUTED BY(name)";
+    let sql = "CREATE UNIQUE INDEX IF NOT EXISTS idx_name ON test(name, age DESC) INCLUDE(other) DISTRIBUTED BY(name) WHERE age > 18";
     let indexed_columns = vec![
         OrderByExpr {
             expr: Expr::Identifier(Ident::new_unchecked("name")),
@@ -3568,6 +3640,11 @@ fn parse_create_index() {
 
     let include_columns = vec![Ident::new_unchecked("other")];
     let distributed_columns = vec![Expr::Identifier(Ident::new_unchecked("name"))];
+    let index_predicate = Expr::BinaryOp {
+        left: Box::new(Expr::Identifier(Ident::new_unchecked("age"))),
+        op: BinaryOperator::Gt,
+        right: Box::new(Expr::Value(number("18"))),
+    };
     match verified_stmt(sql) {
         Statement::CreateIndex {
             name,
@@ -3575,6 +3652,7 @@ fn parse_create_index() {
             columns,
             include,
             distributed_by,
+            predicate,
             unique,
             if_not_exists,
         } => {
@@ -3583,6 +3661,7 @@ fn parse_create_index() {
             assert_eq!(indexed_columns, columns);
             assert_eq!(include_columns, include);
             assert_eq!(distributed_columns, distributed_by);
+            assert_eq!(Some(index_predicate), predicate);
             assert!(unique);
             assert!(if_not_exists)
         }
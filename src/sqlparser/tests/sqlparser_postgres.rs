@@ -853,7 +853,7 @@ fn parse_drop_function() {
         verified_stmt(sql),
         Statement::DropFunction {
             if_exists: true,
-            func_desc: vec![DropFunctionDesc {
+            func_desc: vec![FunctionDesc {
                 name: ObjectName(vec![Ident::new_unchecked("test_func")]),
                 args: None
             }],
@@ -866,7 +866,7 @@ fn parse_drop_function() {
         verified_stmt(sql),
         Statement::DropFunction {
             if_exists: true,
-            func_desc: vec![DropFunctionDesc {
+            func_desc: vec![FunctionDesc {
                 name: ObjectName(vec![Ident::new_unchecked("test_func")]),
                 args: Some(vec![
                     OperateFunctionArg::with_name("a", DataType::Int),
@@ -888,7 +888,7 @@ fn parse_drop_function() {
         Statement::DropFunction {
             if_exists: true,
             func_desc: vec![
-                DropFunctionDesc {
+                FunctionDesc {
                     name: ObjectName(vec![Ident::new_unchecked("test_func1")]),
                     args: Some(vec![
                         OperateFunctionArg::with_name("a", DataType::Int),
@@ -900,7 +900,7 @@ fn parse_drop_function() {
                         }
                     ]),
                 },
-                DropFunctionDesc {
+                FunctionDesc {
                     name: ObjectName(vec![Ident::new_unchecked("test_func2")]),
                     args: Some(vec![
                         OperateFunctionArg::with_name("a", DataType::Varchar),
@@ -927,6 +927,7 @@ fn parse_array() {
             with: None,
             body: SetExpr::Select(Box::new(Select {
                 distinct: Distinct::All,
+                hints: vec![],
                 projection: vec![SelectItem::UnnamedExpr(Expr::Array(Array {
                     elem: vec![
                         Expr::Array(Array {
@@ -966,6 +967,7 @@ fn parse_array() {
             with: None,
             body: SetExpr::Select(Box::new(Select {
                 distinct: Distinct::All,
+                hints: vec![],
                 projection: vec![SelectItem::UnnamedExpr(Expr::Array(Array {
                     elem: vec![
                         Expr::Array(Array {
@@ -1005,6 +1007,7 @@ fn parse_array() {
             with: None,
             body: SetExpr::Select(Box::new(Select {
                 distinct: Distinct::All,
+                hints: vec![],
                 projection: vec![SelectItem::UnnamedExpr(Expr::Array(Array {
                     elem: vec![
                         Expr::Array(Array {
@@ -127,6 +127,7 @@ pub async fn prepare_start_parameters(
         storage_opts.object_store_streaming_upload_timeout_ms,
         storage_opts.object_store_read_timeout_ms,
         storage_opts.object_store_upload_timeout_ms,
+        storage_opts.object_store_unavailable_threshold,
     );
     let object_store = Arc::new(object_store);
     let sstable_store = Arc::new(SstableStore::for_compactor(
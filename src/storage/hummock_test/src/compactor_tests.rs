@@ -283,6 +283,8 @@ pub(crate) mod tests {
                 0,
                 TableOption {
                     retention_seconds: 64,
+                    bloom_false_positive: 0.0,
+                    ..Default::default()
                 },
             )]);
             compact_task.current_epoch_time = 0;
@@ -955,6 +957,8 @@ pub(crate) mod tests {
             existing_table_id,
             TableOption {
                 retention_seconds: retention_seconds_expire_second,
+                bloom_false_positive: 0.0,
+                ..Default::default()
             },
         )]);
         compact_task.current_epoch_time = epoch;
@@ -1385,6 +1385,9 @@ async fn test_replicated_local_hummock_storage() {
             false,
             TableOption {
                 retention_seconds: None,
+                bloom_false_positive: None,
+                compression_algorithm: None,
+                compression_level: None,
             },
         ))
         .await;
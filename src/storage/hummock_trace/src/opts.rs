@@ -127,6 +127,11 @@ impl From<TracedTableOption> for TableOption {
     fn from(value: TracedTableOption) -> Self {
         Self {
             retention_seconds: value.retention_seconds,
+            // Only affects how compaction builds SSTs, not read behavior,
+            // so they are not recorded in the trace.
+            bloom_false_positive: None,
+            compression_algorithm: None,
+            compression_level: None,
         }
     }
 }
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use itertools::Itertools;
 use parking_lot::RwLock;
 use risingwave_common::catalog::ColumnDesc;
+use risingwave_common::constants::hummock::PROPERTIES_FILTER_KEY_MODE_KEY;
 use risingwave_common::hash::VirtualNode;
 use risingwave_common::util::row_serde::OrderedRowSerde;
 use risingwave_common::util::sort_util::OrderType;
@@ -45,6 +46,22 @@ pub enum FilterKeyExtractorImpl {
 
 impl FilterKeyExtractorImpl {
     pub fn from_table(table_catalog: &Table) -> Self {
+        // The table may override the default prefix-based filter key, e.g. to cover point
+        // lookups on non-prefix key columns with full-key filters.
+        match table_catalog
+            .properties
+            .get(PROPERTIES_FILTER_KEY_MODE_KEY)
+            .map(|s| s.as_str())
+        {
+            Some("full_key") => {
+                return FilterKeyExtractorImpl::FullKey(FullKeyFilterKeyExtractor);
+            }
+            Some("none") => {
+                return FilterKeyExtractorImpl::Dummy(DummyFilterKeyExtractor);
+            }
+            _ => {}
+        }
+
         let read_prefix_len = table_catalog.get_read_prefix_len_hint() as usize;
 
         if read_prefix_len == 0 || read_prefix_len > table_catalog.get_pk().len() {
@@ -439,7 +456,9 @@ mod tests {
     use bytes::{BufMut, BytesMut};
     use itertools::Itertools;
     use risingwave_common::catalog::ColumnDesc;
-    use risingwave_common::constants::hummock::PROPERTIES_RETENTION_SECOND_KEY;
+    use risingwave_common::constants::hummock::{
+        PROPERTIES_FILTER_KEY_MODE_KEY, PROPERTIES_RETENTION_SECOND_KEY,
+    };
     use risingwave_common::hash::VirtualNode;
     use risingwave_common::row::OwnedRow;
     use risingwave_common::types::DataType;
@@ -583,6 +602,28 @@ mod tests {
         assert_eq!(1 + mem::size_of::<i64>(), output_key.len());
     }
 
+    #[test]
+    fn test_filter_key_mode_property() {
+        let mut prost_table = build_table_with_prefix_column_num(1);
+        prost_table.properties.insert(
+            String::from(PROPERTIES_FILTER_KEY_MODE_KEY),
+            String::from("full_key"),
+        );
+        assert!(matches!(
+            FilterKeyExtractorImpl::from_table(&prost_table),
+            FilterKeyExtractorImpl::FullKey(_)
+        ));
+
+        prost_table.properties.insert(
+            String::from(PROPERTIES_FILTER_KEY_MODE_KEY),
+            String::from("none"),
+        );
+        assert!(matches!(
+            FilterKeyExtractorImpl::from_table(&prost_table),
+            FilterKeyExtractorImpl::Dummy(_)
+        ));
+    }
+
     #[test]
     fn test_multi_filter_key_extractor() {
         let mut multi_filter_key_extractor = MultiFilterKeyExtractor::default();
@@ -77,6 +77,38 @@ impl CompactorRunner {
         };
 
         options.capacity = estimate_task_output_capacity(context.clone(), &task);
+        // An output SST may mix data of multiple state tables, so take the tightest
+        // per-table false positive rate among them, if any is configured.
+        if let Some(fpr) = task
+            .table_options
+            .values()
+            .filter(|table_option| table_option.bloom_false_positive > 0.0)
+            .map(|table_option| table_option.bloom_false_positive)
+            .min_by(|a, b| a.total_cmp(b))
+        {
+            options.bloom_false_positive = fpr;
+        }
+        // Likewise, a per-table codec overrides the compaction group one only with the
+        // strongest codec among those configured (zstd > lz4 > none), so that no table gets
+        // a weaker codec than it asked for.
+        if let Some(algorithm) = task
+            .table_options
+            .values()
+            .filter(|table_option| table_option.compression_algorithm > 0)
+            .map(|table_option| table_option.compression_algorithm - 1)
+            .max()
+        {
+            options.compression_algorithm = algorithm.into();
+        }
+        if let Some(level) = task
+            .table_options
+            .values()
+            .filter(|table_option| table_option.compression_level > 0)
+            .map(|table_option| table_option.compression_level)
+            .max()
+        {
+            options.compression_level = level;
+        }
         let kv_count = task
             .input_ssts
             .iter()
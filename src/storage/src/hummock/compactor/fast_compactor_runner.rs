@@ -282,6 +282,7 @@ pub struct CompactorRunner {
         RemoteBuilderFactory<StreamingSstableWriterFactory, BlockedXor16FilterBuilder>,
     >,
     compression_algorithm: CompressionAlgorithm,
+    compression_level: u32,
     metrics: Arc<CompactorMetrics>,
 }
 
@@ -294,9 +295,42 @@ impl CompactorRunner {
         task_progress: Arc<TaskProgress>,
     ) -> Self {
         let mut options: SstableBuilderOptions = context.storage_opts.as_ref().into();
-        let compression_algorithm: CompressionAlgorithm = task.compression_algorithm.into();
-        options.compression_algorithm = compression_algorithm;
+        options.compression_algorithm = task.compression_algorithm.into();
         options.capacity = task.target_file_size as usize;
+        // An output SST may mix data of multiple state tables, so take the tightest
+        // per-table false positive rate among them, if any is configured.
+        if let Some(fpr) = task
+            .table_options
+            .values()
+            .filter(|table_option| table_option.bloom_false_positive > 0.0)
+            .map(|table_option| table_option.bloom_false_positive)
+            .min_by(|a, b| a.total_cmp(b))
+        {
+            options.bloom_false_positive = fpr;
+        }
+        // Likewise, a per-table codec overrides the compaction group one only with the
+        // strongest codec among those configured (zstd > lz4 > none), so that no table gets
+        // a weaker codec than it asked for.
+        if let Some(algorithm) = task
+            .table_options
+            .values()
+            .filter(|table_option| table_option.compression_algorithm > 0)
+            .map(|table_option| table_option.compression_algorithm - 1)
+            .max()
+        {
+            options.compression_algorithm = algorithm.into();
+        }
+        if let Some(level) = task
+            .table_options
+            .values()
+            .filter(|table_option| table_option.compression_level > 0)
+            .map(|table_option| table_option.compression_level)
+            .max()
+        {
+            options.compression_level = level;
+        }
+        let compression_algorithm = options.compression_algorithm;
+        let compression_level = options.compression_level;
         let get_id_time = Arc::new(AtomicU64::new(0));
 
         let key_range = KeyRange::inf();
@@ -351,6 +385,7 @@ impl CompactorRunner {
             task_id: task.task_id,
             metrics: context.compactor_metrics.clone(),
             compression_algorithm,
+            compression_level,
         }
     }
 
@@ -404,7 +439,11 @@ impl CompactorRunner {
                     if algorithm == CompressionAlgorithm::None
                         && algorithm != self.compression_algorithm
                     {
-                        block = BlockBuilder::compress_block(block, self.compression_algorithm)?;
+                        block = BlockBuilder::compress_block(
+                            block,
+                            self.compression_algorithm,
+                            self.compression_level,
+                        )?;
                         meta.len = block.len() as u32;
                     }
 
@@ -32,6 +32,7 @@ use crate::hummock::{HummockError, HummockResult};
 pub const DEFAULT_BLOCK_SIZE: usize = 4 * 1024;
 pub const DEFAULT_RESTART_INTERVAL: usize = 16;
 pub const DEFAULT_ENTRY_SIZE: usize = 24; // table_id(u64) + primary_key(u64) + epoch(u64)
+pub const DEFAULT_COMPRESSION_LEVEL: u32 = 4;
 
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -388,6 +389,8 @@ pub struct BlockBuilderOptions {
     pub capacity: usize,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
+    /// Compression level of the algorithm above.
+    pub compression_level: u32,
     /// Restart point interval.
     pub restart_interval: usize,
 }
@@ -397,6 +400,7 @@ impl Default for BlockBuilderOptions {
         Self {
             capacity: DEFAULT_BLOCK_SIZE,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
             restart_interval: DEFAULT_RESTART_INTERVAL,
         }
     }
@@ -416,6 +420,8 @@ pub struct BlockBuilder {
     entry_count: usize,
     /// Compression algorithm.
     compression_algorithm: CompressionAlgorithm,
+    /// Compression level of the algorithm above.
+    compression_level: u32,
 
     table_id: Option<u32>,
     // restart_points_type_index stores only the restart_point corresponding to each type change,
@@ -435,6 +441,7 @@ impl BlockBuilder {
             last_key: vec![],
             entry_count: 0,
             compression_algorithm: options.compression_algorithm,
+            compression_level: options.compression_level,
             table_id: None,
             restart_points_type_index: Vec::default(),
         }
@@ -601,7 +608,11 @@ impl BlockBuilder {
 
         self.buf.put_u32_le(self.table_id.unwrap());
         if self.compression_algorithm != CompressionAlgorithm::None {
-            self.buf = Self::compress(&self.buf[..], self.compression_algorithm);
+            self.buf = Self::compress(
+                &self.buf[..],
+                self.compression_algorithm,
+                self.compression_level,
+            );
         }
 
         self.compression_algorithm.encode(&mut self.buf);
@@ -615,6 +626,7 @@ impl BlockBuilder {
     pub fn compress_block(
         buf: Bytes,
         target_compression: CompressionAlgorithm,
+        compression_level: u32,
     ) -> HummockResult<Bytes> {
         // Verify checksum.
         let checksum = (&buf[buf.len() - 8..]).get_u64_le();
@@ -623,7 +635,7 @@ impl BlockBuilder {
         let compression = CompressionAlgorithm::decode(&mut &buf[buf.len() - 9..buf.len() - 8])?;
         let compressed_data = &buf[..buf.len() - 9];
         assert_eq!(compression, CompressionAlgorithm::None);
-        let mut writer = Self::compress(compressed_data, target_compression);
+        let mut writer = Self::compress(compressed_data, target_compression, compression_level);
 
         target_compression.encode(&mut writer);
         let checksum = xxhash64_checksum(&writer);
@@ -631,12 +643,16 @@ impl BlockBuilder {
         Ok(writer.freeze())
     }
 
-    pub fn compress(buf: &[u8], compression_algorithm: CompressionAlgorithm) -> BytesMut {
+    pub fn compress(
+        buf: &[u8],
+        compression_algorithm: CompressionAlgorithm,
+        compression_level: u32,
+    ) -> BytesMut {
         match compression_algorithm {
             CompressionAlgorithm::None => unreachable!(),
             CompressionAlgorithm::Lz4 => {
                 let mut encoder = lz4::EncoderBuilder::new()
-                    .level(4)
+                    .level(compression_level)
                     .build(BytesMut::with_capacity(buf.len()).writer())
                     .map_err(HummockError::encode_error)
                     .unwrap();
@@ -649,10 +665,12 @@ impl BlockBuilder {
                 writer.into_inner()
             }
             CompressionAlgorithm::Zstd => {
-                let mut encoder =
-                    zstd::Encoder::new(BytesMut::with_capacity(buf.len()).writer(), 4)
-                        .map_err(HummockError::encode_error)
-                        .unwrap();
+                let mut encoder = zstd::Encoder::new(
+                    BytesMut::with_capacity(buf.len()).writer(),
+                    compression_level as i32,
+                )
+                .map_err(HummockError::encode_error)
+                .unwrap();
                 encoder
                     .write_all(buf)
                     .map_err(HummockError::encode_error)
@@ -738,13 +756,16 @@ mod tests {
 
     #[test]
     fn test_compressed_block_enc_dec() {
-        inner_test_compressed(CompressionAlgorithm::Lz4);
-        inner_test_compressed(CompressionAlgorithm::Zstd);
+        inner_test_compressed(CompressionAlgorithm::Lz4, DEFAULT_COMPRESSION_LEVEL);
+        inner_test_compressed(CompressionAlgorithm::Zstd, DEFAULT_COMPRESSION_LEVEL);
+        // The level only affects the encoded bytes, not decodability.
+        inner_test_compressed(CompressionAlgorithm::Zstd, 19);
     }
 
-    fn inner_test_compressed(algo: CompressionAlgorithm) {
+    fn inner_test_compressed(algo: CompressionAlgorithm, level: u32) {
         let options = BlockBuilderOptions {
             compression_algorithm: algo,
+            compression_level: level,
             ..Default::default()
         };
         let mut builder = BlockBuilder::new(options);
@@ -26,7 +26,8 @@ use risingwave_pb::hummock::{BloomFilterType, SstableInfo};
 use super::utils::CompressionAlgorithm;
 use super::{
     BlockBuilder, BlockBuilderOptions, BlockMeta, MonotonicDeleteEvent, SstableMeta, SstableWriter,
-    DEFAULT_BLOCK_SIZE, DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, VERSION,
+    DEFAULT_BLOCK_SIZE, DEFAULT_COMPRESSION_LEVEL, DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL,
+    VERSION,
 };
 use crate::filter_key_extractor::{FilterKeyExtractorImpl, FullKeyFilterKeyExtractor};
 use crate::hummock::sstable::{utils, FilterBuilder};
@@ -53,6 +54,8 @@ pub struct SstableBuilderOptions {
     pub bloom_false_positive: f64,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
+    /// Compression level of the algorithm above.
+    pub compression_level: u32,
     pub max_sst_size: u64,
 }
 
@@ -65,6 +68,7 @@ impl From<&StorageOpts> for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: options.bloom_false_positive,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
             max_sst_size: options.compactor_max_sst_size,
         }
     }
@@ -78,6 +82,7 @@ impl Default for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: DEFAULT_BLOOM_FALSE_POSITIVE,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
             max_sst_size: DEFAULT_MAX_SST_SIZE,
         }
     }
@@ -167,6 +172,7 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
                 capacity: options.block_capacity,
                 restart_interval: options.restart_interval,
                 compression_algorithm: options.compression_algorithm,
+                compression_level: options.compression_level,
             }),
             filter_builder,
             block_metas: Vec::with_capacity(options.capacity / options.block_capacity + 1),
@@ -118,6 +118,8 @@ pub struct StorageOpts {
     pub object_store_upload_timeout_ms: u64,
     /// object store read timeout.
     pub object_store_read_timeout_ms: u64,
+    /// consecutive failures after which the object store is reported unavailable.
+    pub object_store_unavailable_threshold: usize,
 
     pub object_store_recv_buffer_size: Option<usize>,
     pub compactor_max_sst_key_count: u64,
@@ -232,6 +234,7 @@ impl From<(&RwConfig, &SystemParamsReader, &StorageMemoryConfig)> for StorageOpt
                 .object_store_streaming_upload_timeout_ms,
             object_store_read_timeout_ms: c.storage.object_store_read_timeout_ms,
             object_store_upload_timeout_ms: c.storage.object_store_upload_timeout_ms,
+            object_store_unavailable_threshold: c.storage.object_store_unavailable_threshold,
             backup_storage_url: p.backup_storage_url().to_string(),
             backup_storage_directory: p.backup_storage_directory().to_string(),
             object_store_recv_buffer_size: c.storage.object_store_recv_buffer_size,
@@ -444,6 +444,9 @@ impl NewLocalOptions {
             is_consistent_op: false,
             table_option: TableOption {
                 retention_seconds: None,
+                bloom_false_positive: None,
+                compression_algorithm: None,
+                compression_level: None,
             },
             is_replicated: false,
         }
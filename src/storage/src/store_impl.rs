@@ -624,6 +624,7 @@ impl StateStoreImpl {
                     opts.object_store_streaming_upload_timeout_ms,
                     opts.object_store_read_timeout_ms,
                     opts.object_store_upload_timeout_ms,
+                    opts.object_store_unavailable_threshold,
                 );
 
                 let sstable_store = Arc::new(SstableStore::new(
@@ -213,6 +213,9 @@ impl<S: StateStore> LogStoreFactory for KvLogStoreFactory<S> {
                 is_consistent_op: false,
                 table_option: TableOption {
                     retention_seconds: None,
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 },
                 is_replicated: false,
             })
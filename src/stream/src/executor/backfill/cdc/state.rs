@@ -159,6 +159,44 @@ impl<S: StateStore> SingleTableState<S> {
                 value.to_string()
             });
             state.inner.snapshot_done = true;
+        } else if let SplitImpl::PostgresCdc(split) = &mut self.cdc_split
+            && let Some(state) = split.pg_split.as_mut() {
+            let start_offset =
+                last_binlog_offset.as_ref().map(|cdc_offset| {
+                    let source_offset =
+                        if let CdcOffset::Postgres(o) = cdc_offset
+                        {
+                            DebeziumSourceOffset {
+                                lsn: Some(o.lsn),
+                                txid: Some(o.txid),
+                                tx_usec: Some(o.tx_usec),
+                                ..Default::default()
+                            }
+                        } else {
+                            DebeziumSourceOffset::default()
+                        };
+
+                    let mut server = "RW_CDC_".to_string();
+                    server.push_str(
+                        self.cdc_table_id.to_string().as_str(),
+                    );
+                    DebeziumOffset {
+                        source_partition: hashmap! {
+                            "server".to_string() => server
+                        },
+                        source_offset,
+                        // upstream heartbeat event would not emit to the cdc backfill executor,
+                        // since we don't parse heartbeat event in the source parser.
+                        is_heartbeat: false,
+                    }
+                });
+
+            // persist the last WAL offset into split state
+            state.inner.start_offset = start_offset.map(|o| {
+                let value = serde_json::to_value(o).unwrap();
+                value.to_string()
+            });
+            state.inner.snapshot_done = true;
         }
         // write the last binlog offset that will be used upon recovery
         self.source_state_handler
@@ -17,17 +17,16 @@ use std::sync::Arc;
 
 use futures::{pin_mut, stream, StreamExt};
 use futures_async_stream::try_stream;
-use risingwave_common::array::{Array, ArrayImpl, Op, StreamChunk};
+use risingwave_common::array::{Array, ArrayImpl, DataChunk, Op, StreamChunk};
 use risingwave_common::bail;
 use risingwave_common::buffer::{Bitmap, BitmapBuilder};
 use risingwave_common::catalog::Schema;
 use risingwave_common::hash::VnodeBitmapExt;
 use risingwave_common::row::{self, once, OwnedRow, OwnedRow as RowData, Row};
-use risingwave_common::types::{DataType, Datum, DefaultOrd, ScalarImpl, ToDatumRef, ToOwnedDatum};
+use risingwave_common::types::{DataType, Datum, DefaultOrd, ScalarImpl, ToOwnedDatum};
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_common::util::iter_util::ZipEqDebug;
-use risingwave_expr::expr::{
-    build_func_non_strict, InputRefExpression, LiteralExpression, NonStrictExpression,
-};
+use risingwave_expr::expr::{build_func_non_strict, InputRefExpression, NonStrictExpression};
 use risingwave_pb::expr::expr_node::Type as ExprNodeType;
 use risingwave_pb::expr::expr_node::Type::{
     GreaterThan, GreaterThanOrEqual, LessThan, LessThanOrEqual,
@@ -54,6 +53,13 @@ pub struct DynamicFilterExecutor<S: StateStore, const USE_WATERMARK_CACHE: bool>
     pk_indices: PkIndices,
     identity: String,
     comparator: ExprNodeType,
+    /// The compiled driving comparison between the left key and the first RHS column,
+    /// over the concatenation of a left row and the RHS row. Built when the stream
+    /// starts, since it reports evaluation errors with the actor context.
+    driving_condition: Option<NonStrictExpression>,
+    /// Residual conjuncts of a composite condition, over the same concatenated schema.
+    residual_condition: Option<NonStrictExpression>,
+    rhs_data_types: Vec<DataType>,
     left_table: WatermarkCacheParameterizedStateTable<S, USE_WATERMARK_CACHE>,
     right_table: StateTable<S>,
     schema: Schema,
@@ -72,12 +78,14 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         pk_indices: PkIndices,
         executor_id: u64,
         comparator: ExprNodeType,
+        residual_condition: Option<NonStrictExpression>,
         state_table_l: WatermarkCacheParameterizedStateTable<S, USE_WATERMARK_CACHE>,
         state_table_r: StateTable<S>,
         metrics: Arc<StreamingMetrics>,
         chunk_size: usize,
     ) -> Self {
         let schema = source_l.schema().clone();
+        let rhs_data_types = source_r.schema().data_types();
         Self {
             ctx,
             source_l: Some(source_l),
@@ -86,6 +94,9 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
             pk_indices,
             identity: format!("DynamicFilterExecutor {:X}", executor_id),
             comparator,
+            driving_condition: None,
+            residual_condition,
+            rhs_data_types,
             left_table: state_table_l,
             right_table: state_table_r,
             metrics,
@@ -94,34 +105,69 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         }
     }
 
+    /// Evaluate the filter condition for all rows of `chunk` against the given RHS row
+    /// in a vectorized manner. A missing RHS row implies a false evaluation for all rows.
+    async fn eval_matches(
+        &self,
+        chunk: &DataChunk,
+        rhs_row: Option<&OwnedRow>,
+    ) -> Result<Vec<bool>, StreamExecutorError> {
+        let len = chunk.capacity();
+        let Some(rhs_row) = rhs_row else {
+            return Ok(vec![false; len]);
+        };
+
+        // Evaluate over the concatenation of the chunk and the RHS row broadcast as
+        // constant columns, so that a change of the RHS only requires re-evaluation
+        // instead of rebuilding the expressions.
+        let mut columns = chunk.columns().to_vec();
+        for (datum, data_type) in rhs_row.iter().zip_eq_debug(&self.rhs_data_types) {
+            let mut builder = data_type.create_array_builder(len);
+            builder.append_n(len, datum);
+            columns.push(builder.finish().into());
+        }
+        let concat_chunk = DataChunk::new(columns, chunk.visibility().clone());
+
+        let driving = self
+            .driving_condition
+            .as_ref()
+            .expect("driving condition not built")
+            .eval_infallible(&concat_chunk)
+            .await;
+        let residual = match &self.residual_condition {
+            Some(cond) => Some(cond.eval_infallible(&concat_chunk).await),
+            None => None,
+        };
+        let bool_at = |array: &ArrayImpl, idx: usize| {
+            if let ArrayImpl::Bool(array) = array {
+                array.value_at(idx).unwrap_or(false)
+            } else {
+                panic!("condition eval must return bool array")
+            }
+        };
+        Ok((0..len)
+            .map(|idx| {
+                bool_at(&driving, idx) && residual.as_ref().map_or(true, |r| bool_at(r, idx))
+            })
+            .collect())
+    }
+
     async fn apply_batch(
         &mut self,
         chunk: &StreamChunk,
-        condition: Option<NonStrictExpression>,
+        rhs_row: Option<&OwnedRow>,
+        clean_watermark: Option<&ScalarImpl>,
     ) -> Result<(Vec<Op>, Bitmap), StreamExecutorError> {
         let mut new_ops = Vec::with_capacity(chunk.capacity());
         let mut new_visibility = BitmapBuilder::with_capacity(chunk.capacity());
         let mut last_res = false;
 
-        let eval_results = if let Some(cond) = condition {
-            Some(cond.eval_infallible(chunk).await)
-        } else {
-            None
-        };
+        let matches = self.eval_matches(chunk.data_chunk(), rhs_row).await?;
 
         for (idx, (op, row)) in chunk.rows().enumerate() {
             let left_val = row.datum_at(self.key_l).to_owned_datum();
 
-            let res = if let Some(array) = &eval_results {
-                if let ArrayImpl::Bool(results) = &**array {
-                    results.value_at(idx).unwrap_or(false)
-                } else {
-                    panic!("condition eval must return bool array")
-                }
-            } else {
-                // A NULL right value implies a false evaluation for all rows
-                false
-            };
+            let res = matches[idx];
 
             match op {
                 Op::Insert | Op::Delete => {
@@ -166,13 +212,25 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
             // Store the rows without a null left key
             // null key in left side of predicate should never be stored
             // (it will never satisfy the filter condition)
-            if left_val.is_some() {
-                match op {
-                    Op::Insert | Op::UpdateInsert => {
-                        self.left_table.insert(row);
-                    }
-                    Op::Delete | Op::UpdateDelete => {
-                        self.left_table.delete(row);
+            if let Some(left_val) = &left_val {
+                // A row that fails the condition and is below the state cleaning watermark
+                // will never satisfy the condition again, since the right side is guaranteed
+                // not to go below the watermark. There is no need to store it, and the
+                // cleaning watermark covers whatever was stored for it before. This notably
+                // avoids writing the rows of the historical snapshot that are already out of
+                // the bound of a temporal filter during backfill.
+                let never_matches = !res
+                    && clean_watermark
+                        .map(|watermark| left_val.default_cmp(watermark).is_lt())
+                        .unwrap_or(false);
+                if !never_matches {
+                    match op {
+                        Op::Insert | Op::UpdateInsert => {
+                            self.left_table.insert(row);
+                        }
+                        Op::Delete | Op::UpdateDelete => {
+                            self.left_table.delete(row);
+                        }
                     }
                 }
             }
@@ -183,6 +241,32 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         Ok((new_ops, new_visibility))
     }
 
+    /// Re-filter one batch of stored rows against both the old and the new RHS row,
+    /// appending the difference to `builder` and returning any chunks that got full.
+    async fn rediff_batch(
+        &self,
+        data_chunk: &DataChunk,
+        old_rhs_row: Option<&OwnedRow>,
+        new_rhs_row: Option<&OwnedRow>,
+        builder: &mut StreamChunkBuilder,
+    ) -> Result<Vec<StreamChunk>, StreamExecutorError> {
+        let old_matches = self.eval_matches(data_chunk, old_rhs_row).await?;
+        let new_matches = self.eval_matches(data_chunk, new_rhs_row).await?;
+
+        let mut chunks = vec![];
+        for (idx, row) in data_chunk.rows().enumerate() {
+            let op = match (old_matches[idx], new_matches[idx]) {
+                (false, true) => Op::Insert,
+                (true, false) => Op::Delete,
+                _ => continue,
+            };
+            if let Some(chunk) = builder.append_row(op, row) {
+                chunks.push(chunk);
+            }
+        }
+        Ok(chunks)
+    }
+
     /// Returns the required range, whether the latest value is in lower bound (rather than upper)
     /// and whether to insert or delete the range.
     fn get_range(
@@ -262,25 +346,21 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
         let r_data_type = input_r.schema().data_types()[0].clone();
         // The types are aligned by frontend.
         assert_eq!(l_data_type, r_data_type);
-        let dynamic_cond = {
-            let eval_error_report = ActorEvalErrorReport {
-                actor_context: self.ctx.clone(),
-                identity: Arc::from(self.identity.as_str()),
-            };
-            move |literal: Datum| {
-                literal.map(|scalar| {
-                    build_func_non_strict(
-                        self.comparator,
-                        DataType::Boolean,
-                        vec![
-                            Box::new(InputRefExpression::new(l_data_type.clone(), self.key_l)),
-                            Box::new(LiteralExpression::new(r_data_type.clone(), Some(scalar))),
-                        ],
-                        eval_error_report.clone(),
-                    )
-                })
-            }
+        let eval_error_report = ActorEvalErrorReport {
+            actor_context: self.ctx.clone(),
+            identity: Arc::from(self.identity.as_str()),
         };
+        // The driving comparison refers to the left key and the first RHS column in the
+        // concatenated schema used by `eval_matches`.
+        self.driving_condition = Some(build_func_non_strict(
+            self.comparator,
+            DataType::Boolean,
+            vec![
+                Box::new(InputRefExpression::new(l_data_type, self.key_l)),
+                Box::new(InputRefExpression::new(r_data_type, self.schema.len())),
+            ],
+            eval_error_report,
+        )?);
 
         let aligned_stream = barrier_align(
             input_l.execute(),
@@ -313,6 +393,10 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
 
         let watermark_can_clean_state = !matches!(self.comparator, LessThan | LessThanOrEqual);
         let mut unused_clean_hint = None;
+        // The latest state cleaning watermark received from the right side. It is not
+        // persisted, so after recovery we conservatively store all rows again until the
+        // next watermark arrives.
+        let mut latest_clean_watermark: Option<ScalarImpl> = None;
 
         #[for_await]
         for msg in aligned_stream {
@@ -320,13 +404,14 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                 AlignedMessage::Left(chunk) => {
                     // Reuse the logic from `FilterExecutor`
                     let chunk = chunk.compact(); // Is this unnecessary work?
-                    let right_val = prev_epoch_value.clone().flatten();
 
-                    // The condition is `None` if it is always false by virtue of a NULL right
-                    // input, so we save evaluating it on the datachunk
-                    let condition = dynamic_cond(right_val).transpose()?;
-
-                    let (new_ops, new_visibility) = self.apply_batch(&chunk, condition).await?;
+                    let (new_ops, new_visibility) = self
+                        .apply_batch(
+                            &chunk,
+                            last_committed_epoch_row.as_ref(),
+                            latest_clean_watermark.as_ref(),
+                        )
+                        .await?;
 
                     let columns = chunk.into_parts().0.into_parts().0;
 
@@ -348,15 +433,13 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                                 current_epoch_row = Some(row.into_owned_row());
                             }
                             _ => {
-                                // To be consistent, there must be an existing `current_epoch_value`
-                                // equivalent to row indicated for
+                                // To be consistent, there must be an existing `current_epoch_row`
+                                // equivalent to the row indicated for
                                 // deletion.
-                                if Some(row.datum_at(0))
-                                    != current_epoch_value.as_ref().map(ToDatumRef::to_datum_ref)
-                                {
+                                if current_epoch_row != Some(row.to_owned_row()) {
                                     bail!(
                                         "Inconsistent Delete - current: {:?}, delete: {:?}",
-                                        current_epoch_value,
+                                        current_epoch_row,
                                         row
                                     );
                                 }
@@ -371,6 +454,7 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                 }
                 AlignedMessage::WatermarkRight(watermark) => {
                     if watermark_can_clean_state {
+                        latest_clean_watermark = Some(watermark.val.clone());
                         unused_clean_hint = Some(watermark);
                     }
                 }
@@ -386,7 +470,66 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                     // no new chunks have arrived since the previous barrier.
                     let curr: Datum = current_epoch_value.clone().flatten();
                     let prev: Datum = prev_epoch_value.flatten();
-                    if prev != curr {
+                    if self.residual_condition.is_some() {
+                        if last_committed_epoch_row != current_epoch_row {
+                            // A composite condition is not monotone in the sort key of the left
+                            // table, so a change of the RHS row requires re-evaluating all stored
+                            // rows. They are re-filtered in batches against both the old and the
+                            // new RHS row, and only the rows whose result flips are emitted.
+                            let range: (Bound<OwnedRow>, Bound<OwnedRow>) = (Unbounded, Unbounded);
+                            let streams = futures::future::try_join_all(
+                                self.left_table.vnodes().iter_vnodes().map(|vnode| {
+                                    self.left_table.iter_with_vnode(
+                                        vnode,
+                                        &range,
+                                        PrefetchOptions::new_for_exhaust_iter(),
+                                    )
+                                }),
+                            )
+                            .await?
+                            .into_iter()
+                            .map(Box::pin);
+
+                            let mut data_chunk_builder =
+                                DataChunkBuilder::new(self.schema.data_types(), self.chunk_size);
+                            #[for_await]
+                            for res in stream::select_all(streams) {
+                                let row = res?;
+                                if let Some(data_chunk) =
+                                    data_chunk_builder.append_one_row(row.as_ref())
+                                {
+                                    for chunk in self
+                                        .rediff_batch(
+                                            &data_chunk,
+                                            last_committed_epoch_row.as_ref(),
+                                            current_epoch_row.as_ref(),
+                                            &mut stream_chunk_builder,
+                                        )
+                                        .await?
+                                    {
+                                        yield Message::Chunk(chunk);
+                                    }
+                                }
+                            }
+                            if let Some(data_chunk) = data_chunk_builder.consume_all() {
+                                for chunk in self
+                                    .rediff_batch(
+                                        &data_chunk,
+                                        last_committed_epoch_row.as_ref(),
+                                        current_epoch_row.as_ref(),
+                                        &mut stream_chunk_builder,
+                                    )
+                                    .await?
+                                {
+                                    yield Message::Chunk(chunk);
+                                }
+                            }
+
+                            if let Some(chunk) = stream_chunk_builder.take() {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                    } else if prev != curr {
                         let (range, _latest_is_lower, is_insert) = self.get_range(&curr, prev);
                         let range = (Self::to_row_bound(range.0), Self::to_row_bound(range.1));
 
@@ -495,10 +638,12 @@ mod tests {
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::*;
     use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema, TableId};
+    use risingwave_common::util::epoch::EpochPair;
     use risingwave_common::util::sort_util::OrderType;
     use risingwave_storage::memory::MemoryStateStore;
 
     use super::*;
+    use crate::executor::test_utils::expr::build_from_pretty;
     use crate::executor::test_utils::{MessageSender, MockSource, StreamExecutorTestExt};
     use crate::executor::{ActorContext, StreamExecutorResult};
 
@@ -552,6 +697,7 @@ mod tests {
             vec![0],
             1,
             comparator,
+            None,
             mem_state_l,
             mem_state_r,
             Arc::new(StreamingMetrics::unused()),
@@ -1145,4 +1291,220 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_dynamic_filter_composite_condition() -> StreamExecutorResult<()> {
+        let mem_state = MemoryStateStore::new();
+        let column_descs = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+        ];
+        let state_table_l = StateTable::new_without_distribution_inconsistent_op(
+            mem_state.clone(),
+            TableId::new(0),
+            column_descs.clone(),
+            vec![OrderType::ascending()],
+            vec![0],
+        )
+        .await;
+        let state_table_r = StateTable::new_without_distribution_inconsistent_op(
+            mem_state,
+            TableId::new(1),
+            column_descs,
+            vec![OrderType::ascending()],
+            vec![0],
+        )
+        .await;
+
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (mut tx_l, source_l) = MockSource::channel(schema.clone(), vec![0]);
+        let (mut tx_r, source_r) = MockSource::channel(schema, vec![]);
+
+        // `a > x AND b < y`, where `(a, b)` is a left row and `(x, y)` the right row.
+        let residual = build_from_pretty("(less_than:boolean $1:int8 $3:int8)");
+        let executor = DynamicFilterExecutor::<MemoryStateStore, false>::new(
+            ActorContext::create(123),
+            Box::new(source_l),
+            Box::new(source_r),
+            0,
+            vec![0],
+            1,
+            ExprNodeType::GreaterThan,
+            Some(residual),
+            state_table_l,
+            state_table_r,
+            Arc::new(StreamingMetrics::unused()),
+            1024,
+        );
+        let mut dynamic_filter = Box::new(executor).execute();
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // push the right row `(10, 5)`
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 10 5",
+        ));
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        // No rows are stored yet, so the RHS change produces no output.
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // `(12, 3)` matches, `(13, 7)` fails the residual, `(5, 1)` fails the comparison
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 12 3
+             + 13 7
+             + 5 1",
+        ));
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I
+                 + 12 3"
+            )
+        );
+
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // relax the residual bound: `(10, 5)` -> `(10, 8)`, now `(13, 7)` matches as well
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 10 5
+             + 10 8",
+        ));
+        tx_l.push_barrier(4, false);
+        tx_r.push_barrier(4, false);
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I
+                 + 13 7"
+            )
+        );
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // tighten the comparison bound: `(10, 8)` -> `(12, 8)`, now `(12, 3)` is filtered out
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 10 8
+             + 12 8",
+        ));
+        tx_l.push_barrier(5, false);
+        tx_r.push_barrier(5, false);
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I
+                 - 12 3"
+            )
+        );
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // delete the right row entirely, all remaining rows are filtered out
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 12 8",
+        ));
+        tx_l.push_barrier(6, false);
+        tx_r.push_barrier(6, false);
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I
+                 - 13 7"
+            )
+        );
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_filter_skips_rows_below_watermark() -> StreamExecutorResult<()> {
+        let mem_state = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut dynamic_filter) =
+            create_executor_inner(ExprNodeType::GreaterThan, mem_state.clone()).await;
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // push the right value and its watermark, as emitted by e.g. the now executor
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 10",
+        ));
+        tx_r.push_int64_watermark(0, 10);
+
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+
+        // the watermark is propagated on the left key at the barrier
+        let watermark = dynamic_filter.next_unwrap_ready_watermark()?;
+        assert_eq!(watermark.col_idx, 0);
+        assert_eq!(watermark.val, ScalarImpl::Int64(10));
+
+        // Get the barrier
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // 5 is below the watermark and fails the condition, so it will never match again
+        // and must not be stored; 15 matches and must be stored
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 5
+             + 15",
+        ));
+
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+
+        let chunk = dynamic_filter.next_unwrap_ready_chunk()?.compact();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                + 15"
+            )
+        );
+
+        // Get the barrier
+        dynamic_filter.next_unwrap_ready_barrier()?;
+
+        // Drop the executor and inspect the committed left state table
+        drop(tx_l);
+        drop(tx_r);
+        drop(dynamic_filter);
+
+        let (mut state_table_l, _) = create_in_memory_state_table(mem_state).await;
+        state_table_l.init_epoch(EpochPair::new_test_epoch(4));
+        let sub_range: &(Bound<OwnedRow>, Bound<OwnedRow>) = &(Unbounded, Unbounded);
+        let rows: Vec<_> = state_table_l
+            .iter_with_prefix(row::empty(), sub_range, Default::default())
+            .await?
+            .collect()
+            .await;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            rows.into_iter().next().unwrap()?.into_owned_row(),
+            OwnedRow::new(vec![Some(ScalarImpl::Int64(15))])
+        );
+
+        Ok(())
+    }
 }
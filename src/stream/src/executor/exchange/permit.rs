@@ -100,6 +100,12 @@ impl Permits {
         .map(SemaphorePermit::forget)
     }
 
+    /// Get the number of currently available permits for records, i.e., the remaining credits
+    /// of the channel. Only used for monitoring the back-pressure.
+    pub fn records_available(&self) -> usize {
+        self.records.available_permits()
+    }
+
     /// Close the semaphores so that all pending `acquire` will fail immediately.
     fn close(&self) {
         self.records.close();
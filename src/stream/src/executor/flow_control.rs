@@ -14,6 +14,7 @@
 
 use std::fmt::{Debug, Formatter};
 use std::num::NonZeroU32;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use governor::clock::MonotonicClock;
 use governor::{InsufficientCapacity, Quota, RateLimiter};
@@ -33,11 +34,51 @@ use super::*;
 pub struct FlowControlExecutor {
     input: BoxedExecutor,
     rate_limit: Option<u32>,
+    throttle: Option<MaintenanceWindowThrottle>,
+}
+
+/// A daily maintenance window during which the input runs at the configured `rate_limit` (or at
+/// full speed if there is none). Outside the window, the throttled rate limit applies instead,
+/// so that e.g. backfills do not starve serving traffic at peak hours.
+#[derive(Debug, Clone, Copy)]
+pub struct MaintenanceWindowThrottle {
+    /// The rate limit applied outside the maintenance window.
+    pub rate_limit: u32,
+    /// The start hour of the window in UTC, inclusive.
+    pub window_start_hour: u64,
+    /// The end hour of the window in UTC, exclusive. The window may wrap around midnight; if
+    /// equal to the start hour, the window is empty.
+    pub window_end_hour: u64,
+}
+
+impl MaintenanceWindowThrottle {
+    /// Whether the current time is within the maintenance window.
+    fn in_window(&self) -> bool {
+        let hour = (SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            / 3600)
+            % 24;
+        if self.window_start_hour <= self.window_end_hour {
+            (self.window_start_hour..self.window_end_hour).contains(&hour)
+        } else {
+            hour >= self.window_start_hour || hour < self.window_end_hour
+        }
+    }
 }
 
 impl FlowControlExecutor {
-    pub fn new(input: Box<dyn Executor>, rate_limit: Option<u32>) -> Self {
-        Self { input, rate_limit }
+    pub fn new(
+        input: Box<dyn Executor>,
+        rate_limit: Option<u32>,
+        throttle: Option<MaintenanceWindowThrottle>,
+    ) -> Self {
+        Self {
+            input,
+            rate_limit,
+            throttle,
+        }
     }
 
     #[try_stream(ok = Message, error = StreamExecutorError)]
@@ -48,6 +89,7 @@ impl FlowControlExecutor {
             RateLimiter::direct_with_clock(quota, &clock)
         };
         let rate_limiter = self.rate_limit.map(get_rate_limiter);
+        let throttled_limiter = self.throttle.map(|t| get_rate_limiter(t.rate_limit));
         #[for_await]
         for msg in self.input.execute() {
             let msg = msg?;
@@ -58,7 +100,13 @@ impl FlowControlExecutor {
                         // Handle case where chunk is empty
                         continue;
                     };
-                    if let Some(rate_limiter) = &rate_limiter {
+                    // Outside the maintenance window, the throttled rate limit takes precedence
+                    // over the configured one.
+                    let rate_limiter = match (&self.throttle, &throttled_limiter) {
+                        (Some(throttle), Some(limiter)) if !throttle.in_window() => Some(limiter),
+                        _ => rate_limiter.as_ref(),
+                    };
+                    if let Some(rate_limiter) = rate_limiter {
                         let result = rate_limiter.until_n_ready(n).await;
                         if let Err(InsufficientCapacity(_max_cells)) = result {
                             tracing::error!(
@@ -38,8 +38,9 @@ use risingwave_pb::stream_plan::barrier::{BarrierKind, PbMutation};
 use risingwave_pb::stream_plan::stream_message::StreamMessage;
 use risingwave_pb::stream_plan::update_mutation::{DispatcherUpdate, MergeUpdate};
 use risingwave_pb::stream_plan::{
-    AddMutation, Dispatchers, PauseMutation, PbBarrier, PbDispatcher, PbStreamMessage, PbWatermark,
-    ResumeMutation, SourceChangeSplitMutation, StopMutation, UpdateMutation,
+    AddMutation, Dispatchers, PauseMutation, PauseSinkMutation, PbBarrier, PbDispatcher,
+    PbStreamMessage, PbWatermark, ResumeMutation, ResumeSinkMutation, SourceChangeSplitMutation,
+    StopMutation, UpdateMutation,
 };
 use smallvec::SmallVec;
 
@@ -92,6 +93,7 @@ pub mod subtask;
 mod temporal_join;
 mod top_n;
 mod union;
+mod upsert_normalize;
 mod values;
 mod watermark;
 mod watermark_filter;
@@ -116,7 +118,7 @@ pub use dynamic_filter::DynamicFilterExecutor;
 pub use error::{StreamExecutorError, StreamExecutorResult};
 pub use expand::ExpandExecutor;
 pub use filter::FilterExecutor;
-pub use flow_control::FlowControlExecutor;
+pub use flow_control::{FlowControlExecutor, MaintenanceWindowThrottle};
 pub use hash_agg::HashAggExecutor;
 pub use hash_join::*;
 pub use hop_window::HopWindowExecutor;
@@ -125,7 +127,7 @@ pub use lookup_union::LookupUnionExecutor;
 pub use merge::MergeExecutor;
 pub use mview::*;
 pub use no_op::NoOpExecutor;
-pub use now::NowExecutor;
+pub use now::{NowExecutor, NowMode};
 pub use over_window::*;
 pub use project::ProjectExecutor;
 pub use project_set::*;
@@ -142,6 +144,7 @@ pub use top_n::{
     AppendOnlyGroupTopNExecutor, AppendOnlyTopNExecutor, GroupTopNExecutor, TopNExecutor,
 };
 pub use union::UnionExecutor;
+pub use upsert_normalize::UpsertNormalizeExecutor;
 pub use utils::DummyExecutor;
 pub use values::ValuesExecutor;
 pub use watermark_filter::WatermarkFilterExecutor;
@@ -237,10 +240,15 @@ pub enum Mutation {
         // TODO: remove this and use `SourceChangesSplit` after we support multiple mutations.
         splits: HashMap<ActorId, Vec<SplitImpl>>,
         pause: bool,
+        /// Sinks that should start with delivery to the external system paused. Only set for
+        /// the recovery barrier.
+        paused_sinks: HashSet<u32>,
     },
     SourceChangeSplit(HashMap<ActorId, Vec<SplitImpl>>),
     Pause,
     Resume,
+    PauseSinks(HashSet<u32>),
+    ResumeSinks(HashSet<u32>),
 }
 
 #[derive(Debug, Clone)]
@@ -339,6 +347,19 @@ impl Barrier {
         matches!(self.mutation.as_deref(), Some(Mutation::Resume))
     }
 
+    /// Returns the new pause state for the sink with `sink_id`, if this barrier is to pause or
+    /// resume its delivery to the external system.
+    pub fn sink_pause_state(&self, sink_id: u32) -> Option<bool> {
+        match self.mutation.as_deref() {
+            Some(Mutation::PauseSinks(sink_ids)) if sink_ids.contains(&sink_id) => Some(true),
+            Some(Mutation::ResumeSinks(sink_ids)) if sink_ids.contains(&sink_id) => Some(false),
+            Some(Mutation::Add { paused_sinks, .. }) if paused_sinks.contains(&sink_id) => {
+                Some(true)
+            }
+            _ => None,
+        }
+    }
+
     /// Returns the [`MergeUpdate`] if this barrier is to update the merge executors for the actor
     /// with `actor_id`.
     pub fn as_update_merge(
@@ -445,6 +466,7 @@ impl Mutation {
                 added_actors,
                 splits,
                 pause,
+                paused_sinks,
             } => PbMutation::Add(AddMutation {
                 actor_dispatchers: adds
                     .iter()
@@ -460,6 +482,7 @@ impl Mutation {
                 added_actors: added_actors.iter().copied().collect(),
                 actor_splits: actor_splits_to_protobuf(splits),
                 pause: *pause,
+                paused_sinks: paused_sinks.iter().copied().collect(),
             }),
             Mutation::SourceChangeSplit(changes) => PbMutation::Splits(SourceChangeSplitMutation {
                 actor_splits: changes
@@ -476,6 +499,12 @@ impl Mutation {
             }),
             Mutation::Pause => PbMutation::Pause(PauseMutation {}),
             Mutation::Resume => PbMutation::Resume(ResumeMutation {}),
+            Mutation::PauseSinks(sink_ids) => PbMutation::PauseSinks(PauseSinkMutation {
+                sink_ids: sink_ids.iter().copied().collect(),
+            }),
+            Mutation::ResumeSinks(sink_ids) => PbMutation::ResumeSinks(ResumeSinkMutation {
+                sink_ids: sink_ids.iter().copied().collect(),
+            }),
         }
     }
 
@@ -545,6 +574,7 @@ impl Mutation {
                     })
                     .collect(),
                 pause: add.pause,
+                paused_sinks: add.paused_sinks.iter().copied().collect(),
             },
 
             PbMutation::Splits(s) => {
@@ -566,6 +596,12 @@ impl Mutation {
             }
             PbMutation::Pause(_) => Mutation::Pause,
             PbMutation::Resume(_) => Mutation::Resume,
+            PbMutation::PauseSinks(pause) => {
+                Mutation::PauseSinks(pause.sink_ids.iter().copied().collect())
+            }
+            PbMutation::ResumeSinks(resume) => {
+                Mutation::ResumeSinks(resume.sink_ids.iter().copied().collect())
+            }
         };
         Ok(mutation)
     }
@@ -15,25 +15,47 @@
 use std::ops::Bound;
 use std::ops::Bound::Unbounded;
 
+use anyhow::anyhow;
 use futures::{pin_mut, StreamExt};
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::row::{self, OwnedRow};
-use risingwave_common::types::{DataType, Datum};
+use risingwave_common::types::{DataType, Datum, Interval, ScalarImpl, Timestamptz};
 use risingwave_storage::StateStore;
 use tokio::sync::mpsc::UnboundedReceiver;
 
 use super::{
     Barrier, BoxedMessageStream, Executor, Message, Mutation, PkIndices, PkIndicesRef,
-    StreamExecutorError, Watermark,
+    StreamExecutorError, StreamExecutorResult, Watermark,
 };
 use crate::common::table::state_table::StateTable;
 
+/// The mode of the `Now` executor.
+#[derive(Debug, Clone)]
+pub enum NowMode {
+    /// Emit the current timestamp on each barrier, retracting the one emitted in the previous
+    /// epoch.
+    UpdateCurrent,
+    /// Emit a series of timestamps starting from `start_timestamp` with `interval` step, in an
+    /// append-only manner, as long as they are no later than the current timestamp. The
+    /// `interval` must not have month/day part, because adding it to a `timestamptz` value
+    /// would require a time zone.
+    GenerateSeries {
+        start_timestamp: Timestamptz,
+        interval: Interval,
+    },
+}
+
 pub struct NowExecutor<S: StateStore> {
     /// Receiver of barrier channel.
     barrier_receiver: UnboundedReceiver<Barrier>,
 
+    mode: NowMode,
+    /// The maximum number of rows to emit in one [`StreamChunk`].
+    max_chunk_size: usize,
+
     pk_indices: PkIndices,
     identity: String,
     schema: Schema,
@@ -44,17 +66,25 @@ impl<S: StateStore> NowExecutor<S> {
     pub fn new(
         barrier_receiver: UnboundedReceiver<Barrier>,
         executor_id: u64,
+        mode: NowMode,
+        max_chunk_size: usize,
         state_table: StateTable<S>,
     ) -> Self {
+        let name = match &mode {
+            NowMode::UpdateCurrent => "now",
+            NowMode::GenerateSeries { .. } => "ts",
+        };
         let schema = Schema::new(vec![Field {
             data_type: DataType::Timestamptz,
-            name: String::from("now"),
+            name: String::from(name),
             sub_fields: vec![],
             type_name: String::default(),
         }]);
 
         Self {
             barrier_receiver,
+            mode,
+            max_chunk_size,
             pk_indices: vec![],
             identity: format!("NowExecutor {:X}", executor_id),
             schema,
@@ -66,6 +96,8 @@ impl<S: StateStore> NowExecutor<S> {
     async fn into_stream(self) {
         let Self {
             mut barrier_receiver,
+            mode,
+            max_chunk_size,
             mut state_table,
             schema,
             ..
@@ -123,35 +155,99 @@ impl<S: StateStore> NowExecutor<S> {
                 continue;
             }
 
-            let stream_chunk = if last_timestamp.is_some() {
-                let last_row = row::once(&last_timestamp);
-                let row = row::once(&timestamp);
-                state_table.update(last_row, row);
+            match &mode {
+                NowMode::UpdateCurrent => {
+                    let stream_chunk = if last_timestamp.is_some() {
+                        let last_row = row::once(&last_timestamp);
+                        let row = row::once(&timestamp);
+                        state_table.update(last_row, row);
+
+                        StreamChunk::from_rows(
+                            &[(Op::Delete, last_row), (Op::Insert, row)],
+                            &schema.data_types(),
+                        )
+                    } else {
+                        let row = row::once(&timestamp);
+                        state_table.insert(row);
 
-                StreamChunk::from_rows(
-                    &[(Op::Delete, last_row), (Op::Insert, row)],
-                    &schema.data_types(),
-                )
-            } else {
-                let row = row::once(&timestamp);
-                state_table.insert(row);
+                        StreamChunk::from_rows(&[(Op::Insert, row)], &schema.data_types())
+                    };
 
-                StreamChunk::from_rows(&[(Op::Insert, row)], &schema.data_types())
-            };
+                    yield Message::Chunk(stream_chunk);
 
-            yield Message::Chunk(stream_chunk);
+                    yield Message::Watermark(Watermark::new(
+                        0,
+                        DataType::Timestamptz,
+                        timestamp.clone().unwrap(),
+                    ));
 
-            yield Message::Watermark(Watermark::new(
-                0,
-                DataType::Timestamptz,
-                timestamp.clone().unwrap(),
-            ));
+                    last_timestamp = timestamp;
+                }
+                NowMode::GenerateSeries {
+                    start_timestamp,
+                    interval,
+                } => {
+                    let current_timestamp = *timestamp.as_ref().unwrap().as_timestamptz();
+
+                    // The first timestamp that is not yet emitted.
+                    let mut next = match &last_timestamp {
+                        Some(last) => timestamptz_interval_add(*last.as_timestamptz(), interval)?,
+                        None => *start_timestamp,
+                    };
+                    let mut new_datums: Vec<Datum> = Vec::new();
+                    while next <= current_timestamp {
+                        new_datums.push(Some(ScalarImpl::Timestamptz(next)));
+                        next = timestamptz_interval_add(next, interval)?;
+                    }
+
+                    // It's possible that no timestamp is due to be emitted in this epoch, if
+                    // `interval` is larger than the duration of one epoch.
+                    let Some(new_last_timestamp) = new_datums.last().cloned() else {
+                        continue;
+                    };
+
+                    if last_timestamp.is_some() {
+                        state_table
+                            .update(row::once(&last_timestamp), row::once(&new_last_timestamp));
+                    } else {
+                        state_table.insert(row::once(&new_last_timestamp));
+                    }
 
-            last_timestamp = timestamp;
+                    for datums in new_datums.chunks(max_chunk_size) {
+                        let rows = datums
+                            .iter()
+                            .map(|d| (Op::Insert, row::once(d)))
+                            .collect_vec();
+                        yield Message::Chunk(StreamChunk::from_rows(&rows, &schema.data_types()));
+                    }
+
+                    yield Message::Watermark(Watermark::new(
+                        0,
+                        DataType::Timestamptz,
+                        new_last_timestamp.clone().unwrap(),
+                    ));
+
+                    last_timestamp = new_last_timestamp;
+                }
+            }
         }
     }
 }
 
+/// Add an `interval` without month/day part to a `timestamptz` value.
+fn timestamptz_interval_add(
+    ts: Timestamptz,
+    interval: &Interval,
+) -> StreamExecutorResult<Timestamptz> {
+    debug_assert_eq!(interval.months(), 0);
+    debug_assert_eq!(interval.days(), 0);
+    let usecs = ts
+        .timestamp_micros()
+        .checked_add(interval.usecs())
+        .ok_or_else(|| anyhow!("timestamp out of range"))?;
+    Ok(Timestamptz::from_micros(usecs))
+}
+
 impl<S: StateStore> Executor for NowExecutor<S> {
     fn execute(self: Box<Self>) -> BoxedMessageStream {
         self.into_stream().boxed()
@@ -175,12 +271,12 @@ mod tests {
     use risingwave_common::array::StreamChunk;
     use risingwave_common::catalog::{ColumnDesc, ColumnId, TableId};
     use risingwave_common::test_prelude::StreamChunkTestExt;
-    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_common::types::{DataType, Interval, ScalarImpl};
     use risingwave_common::util::sort_util::OrderType;
     use risingwave_storage::memory::MemoryStateStore;
     use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 
-    use super::NowExecutor;
+    use super::{NowExecutor, NowMode};
     use crate::common::table::state_table::StateTable;
     use crate::executor::test_utils::StreamExecutorTestExt;
     use crate::executor::{
@@ -190,7 +286,7 @@ mod tests {
     #[tokio::test]
     async fn test_now() -> StreamExecutorResult<()> {
         let state_store = create_state_store();
-        let (tx, mut now_executor) = create_executor(&state_store).await;
+        let (tx, mut now_executor) = create_executor(NowMode::UpdateCurrent, &state_store).await;
 
         // Init barrier
         tx.send(Barrier::with_prev_epoch_for_test(1 << 16, 1))
@@ -257,7 +353,7 @@ mod tests {
 
         // Recovery
         drop((tx, now_executor));
-        let (tx, mut now_executor) = create_executor(&state_store).await;
+        let (tx, mut now_executor) = create_executor(NowMode::UpdateCurrent, &state_store).await;
         tx.send(Barrier::with_prev_epoch_for_test(3 << 16, 1 << 16))
             .unwrap();
 
@@ -289,7 +385,7 @@ mod tests {
 
         // Recovery with paused
         drop((tx, now_executor));
-        let (tx, mut now_executor) = create_executor(&state_store).await;
+        let (tx, mut now_executor) = create_executor(NowMode::UpdateCurrent, &state_store).await;
         tx.send(Barrier::new_test_barrier(4 << 16).with_mutation(Mutation::Pause))
             .unwrap();
 
@@ -337,7 +433,7 @@ mod tests {
     #[tokio::test]
     async fn test_now_start_with_paused() -> StreamExecutorResult<()> {
         let state_store = create_state_store();
-        let (tx, mut now_executor) = create_executor(&state_store).await;
+        let (tx, mut now_executor) = create_executor(NowMode::UpdateCurrent, &state_store).await;
 
         // Init barrier
         tx.send(Barrier::with_prev_epoch_for_test(1 << 16, 1).with_mutation(Mutation::Pause))
@@ -387,6 +483,102 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_now_generate_series() -> StreamExecutorResult<()> {
+        let start_timestamp = "2021-04-01T00:00:00.000Z".parse().unwrap();
+        let interval = Interval::from_month_day_usec(0, 0, 1000); // 1ms
+        let mode = NowMode::GenerateSeries {
+            start_timestamp,
+            interval,
+        };
+
+        let state_store = create_state_store();
+        let (tx, mut now_executor) = create_executor(mode.clone(), &state_store).await;
+
+        // Init barrier
+        tx.send(Barrier::with_prev_epoch_for_test(1 << 16, 1))
+            .unwrap();
+        now_executor.next_unwrap_ready_barrier()?;
+
+        // All timestamps from `start_timestamp` to the current timestamp should be emitted.
+        let chunk_msg = now_executor.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk_msg.compact(),
+            StreamChunk::from_pretty(
+                " TZ
+                + 2021-04-01T00:00:00.000Z
+                + 2021-04-01T00:00:00.001Z"
+            )
+        );
+
+        let watermark = now_executor.next_unwrap_ready_watermark()?;
+        assert_eq!(
+            watermark,
+            Watermark::new(
+                0,
+                DataType::Timestamptz,
+                ScalarImpl::Timestamptz("2021-04-01T00:00:00.001Z".parse().unwrap())
+            )
+        );
+
+        tx.send(Barrier::with_prev_epoch_for_test(2 << 16, 1 << 16))
+            .unwrap();
+        now_executor.next_unwrap_ready_barrier()?;
+
+        // Only the new timestamps should be emitted, in an append-only manner.
+        let chunk_msg = now_executor.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk_msg.compact(),
+            StreamChunk::from_pretty(
+                " TZ
+                + 2021-04-01T00:00:00.002Z"
+            )
+        );
+
+        let watermark = now_executor.next_unwrap_ready_watermark()?;
+        assert_eq!(
+            watermark,
+            Watermark::new(
+                0,
+                DataType::Timestamptz,
+                ScalarImpl::Timestamptz("2021-04-01T00:00:00.002Z".parse().unwrap())
+            )
+        );
+
+        // No more messages until the next barrier
+        now_executor.next_unwrap_pending();
+
+        // Recovery. The last timestamp committed to the state table is `00.001Z`, so the series
+        // should resume from there without emitting duplicates of it.
+        drop((tx, now_executor));
+        let (tx, mut now_executor) = create_executor(mode, &state_store).await;
+        tx.send(Barrier::with_prev_epoch_for_test(3 << 16, 1 << 16))
+            .unwrap();
+        now_executor.next_unwrap_ready_barrier()?;
+
+        let chunk_msg = now_executor.next_unwrap_ready_chunk()?;
+        assert_eq!(
+            chunk_msg.compact(),
+            StreamChunk::from_pretty(
+                " TZ
+                + 2021-04-01T00:00:00.002Z
+                + 2021-04-01T00:00:00.003Z"
+            )
+        );
+
+        let watermark = now_executor.next_unwrap_ready_watermark()?;
+        assert_eq!(
+            watermark,
+            Watermark::new(
+                0,
+                DataType::Timestamptz,
+                ScalarImpl::Timestamptz("2021-04-01T00:00:00.003Z".parse().unwrap())
+            )
+        );
+
+        Ok(())
+    }
+
     #[inline]
     fn create_pk_indices() -> PkIndices {
         vec![]
@@ -402,6 +594,7 @@ mod tests {
     }
 
     async fn create_executor(
+        mode: NowMode,
         state_store: &MemoryStateStore,
     ) -> (UnboundedSender<Barrier>, BoxedMessageStream) {
         let table_id = TableId::new(1);
@@ -418,7 +611,7 @@ mod tests {
         .await;
 
         let (sender, barrier_receiver) = unbounded_channel();
-        let now_executor = NowExecutor::new(barrier_receiver, 1, state_table);
+        let now_executor = NowExecutor::new(barrier_receiver, 1, mode, 1024, state_table);
         (sender, Box::new(now_executor).execute())
     }
 }
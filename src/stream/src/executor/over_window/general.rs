@@ -595,9 +595,21 @@ impl<S: StateStore> OverWindowExecutor<S> {
         for msg in input {
             let msg = msg?;
             match msg {
-                Message::Watermark(_) => {
-                    // TODO(rc): ignore watermark for now, we need to think about watermark for
-                    // window functions like `lead` carefully.
+                Message::Watermark(watermark) => {
+                    if !this.partition_key_indices.is_empty()
+                        && watermark.col_idx == this.partition_key_indices[0]
+                    {
+                        // The watermark is on the first state table pk column, so out-of-date
+                        // partitions can be cleaned from the state table. Cached entries of those
+                        // partitions won't be accessed anymore and will be evicted by the LRU
+                        // policy eventually.
+                        this.state_table.update_watermark(watermark.val.clone(), false);
+                        yield Message::Watermark(watermark);
+                    }
+                    // TODO(rc): watermark on the order key column cannot be used for state
+                    // cleaning yet, because the order key is not a pk prefix of the state table.
+                    // We also need to think about watermark for window functions like `lead`
+                    // carefully before propagating it.
                     continue;
                 }
                 Message::Chunk(chunk) => {
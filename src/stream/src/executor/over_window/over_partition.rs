@@ -836,12 +836,16 @@ fn find_affected_ranges<'cache>(
     let first_key = part_with_delta.first_key().unwrap();
     let last_key = part_with_delta.last_key().unwrap();
 
+    // For `GROUPS` frames we don't know in advance how many rows a frame spans, so we
+    // conservatively treat them as unbounded when estimating the affected ranges.
+    let is_groups_frame =
+        |call: &WindowFuncCall| matches!(call.frame.bounds, FrameBounds::Groups(..));
     let start_is_unbounded = calls
         .iter()
-        .any(|call| call.frame.bounds.start_is_unbounded());
+        .any(|call| call.frame.bounds.start_is_unbounded() || is_groups_frame(call));
     let end_is_unbounded = calls
         .iter()
-        .any(|call| call.frame.bounds.end_is_unbounded());
+        .any(|call| call.frame.bounds.end_is_unbounded() || is_groups_frame(call));
 
     let first_curr_key = if end_is_unbounded {
         // If the frame end is unbounded, the frame corresponding to the first key is always
@@ -864,6 +868,9 @@ fn find_affected_ranges<'cache>(
                     }
                     cursor.key().unwrap_or(first_key)
                 }
+                FrameBounds::Groups(..) => {
+                    unreachable!("`GROUPS` frames are treated as unbounded above")
+                }
             })
             .min()
             .expect("# of window function calls > 0")
@@ -887,6 +894,9 @@ fn find_affected_ranges<'cache>(
                     }
                     cursor.key().unwrap_or(first_key)
                 }
+                FrameBounds::Groups(..) => {
+                    unreachable!("`GROUPS` frames are treated as unbounded above")
+                }
             })
             .min()
             .expect("# of window function calls > 0")
@@ -909,6 +919,9 @@ fn find_affected_ranges<'cache>(
                     }
                     cursor.key().unwrap_or(last_key)
                 }
+                FrameBounds::Groups(..) => {
+                    unreachable!("`GROUPS` frames are treated as unbounded above")
+                }
             })
             .max()
             .expect("# of window function calls > 0")
@@ -930,6 +943,9 @@ fn find_affected_ranges<'cache>(
                     }
                     cursor.key().unwrap_or(last_key)
                 }
+                FrameBounds::Groups(..) => {
+                    unreachable!("`GROUPS` frames are treated as unbounded above")
+                }
             })
             .max()
             .expect("# of window function calls > 0")
@@ -31,6 +31,7 @@ use risingwave_connector::sink::log_store::{
 use risingwave_connector::sink::{
     build_sink, LogSinker, Sink, SinkImpl, SinkParam, SinkWriterParam,
 };
+use tokio::sync::watch;
 
 use super::error::{StreamExecutorError, StreamExecutorResult};
 use super::{BoxedExecutor, Executor, Message, PkIndices};
@@ -118,6 +119,11 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                 .any(|i| !self.sink_param.downstream_pk.contains(i))
         };
 
+        // The write path observes pause state changes from barrier mutations and the consume
+        // path holds back delivery to the external system accordingly, while the log store
+        // keeps buffering upstream changes.
+        let (pause_tx, pause_rx) = watch::channel(false);
+
         let write_log_stream = Self::execute_write_log(
             self.input,
             stream_key,
@@ -127,12 +133,13 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
             self.sink_param.sink_type,
             self.actor_context.clone(),
             stream_key_sink_pk_mismatch,
+            pause_tx,
         );
 
         dispatch_sink!(self.sink, sink, {
             let consume_log_stream = Self::execute_consume_log(
                 sink,
-                self.log_reader,
+                self.log_reader.pausable(pause_rx),
                 self.input_columns,
                 self.sink_writer_param,
                 self.actor_context,
@@ -150,6 +157,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
         sink_type: SinkType,
         actor_context: ActorContextRef,
         stream_key_sink_pk_mismatch: bool,
+        pause_tx: watch::Sender<bool>,
     ) {
         let mut input = input.execute();
 
@@ -159,6 +167,12 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
 
         log_writer.init(epoch_pair).await?;
 
+        // The sink may have been paused before this actor was (re)built, e.g. when recovering
+        // with a sink paused by `ALTER SINK ... PAUSE`.
+        if let Some(pause) = barrier.sink_pause_state(sink_id.sink_id) {
+            let _ = pause_tx.send(pause);
+        }
+
         // Propagate the first barrier
         yield Message::Barrier(barrier);
 
@@ -245,6 +259,9 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                         {
                             log_writer.update_vnode_bitmap(vnode_bitmap).await?;
                         }
+                        if let Some(pause) = barrier.sink_pause_state(sink_id.sink_id) {
+                            let _ = pause_tx.send(pause);
+                        }
                         yield Message::Barrier(barrier);
                     }
                 }
@@ -280,6 +297,9 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                         {
                             log_writer.update_vnode_bitmap(vnode_bitmap).await?;
                         }
+                        if let Some(pause) = barrier.sink_pause_state(sink_id.sink_id) {
+                            let _ = pause_tx.send(pause);
+                        }
                         yield Message::Barrier(barrier);
                     }
                 }
@@ -16,6 +16,7 @@ use futures::StreamExt;
 use futures_async_stream::try_stream;
 use risingwave_common::array::Op;
 use risingwave_common::catalog::Schema;
+use risingwave_expr::expr::NonStrictExpression;
 use risingwave_storage::StateStore;
 
 use super::sort_buffer::SortBuffer;
@@ -41,6 +42,8 @@ pub struct SortExecutorArgs<S: StateStore> {
     pub buffer_table: StateTable<S>,
     pub chunk_size: usize,
     pub sort_column_index: usize,
+    /// Expression to delay the watermark on the sort column by the allowed lateness, if any.
+    pub lateness_expr: Option<NonStrictExpression>,
 }
 
 struct ExecutorInner<S: StateStore> {
@@ -50,6 +53,7 @@ struct ExecutorInner<S: StateStore> {
     buffer_table: StateTable<S>,
     chunk_size: usize,
     sort_column_index: usize,
+    lateness_expr: Option<NonStrictExpression>,
 }
 
 struct ExecutionVars<S: StateStore> {
@@ -90,6 +94,7 @@ impl<S: StateStore> SortExecutor<S> {
                 buffer_table: args.buffer_table,
                 chunk_size: args.chunk_size,
                 sort_column_index: args.sort_column_index,
+                lateness_expr: args.lateness_expr,
             },
         }
     }
@@ -121,6 +126,20 @@ impl<S: StateStore> SortExecutor<S> {
                 Message::Watermark(watermark @ Watermark { col_idx, .. })
                     if col_idx == this.sort_column_index =>
                 {
+                    let watermark = if let Some(lateness_expr) = &this.lateness_expr {
+                        let Some(watermark) = watermark
+                            .transform_with_expr(lateness_expr, this.sort_column_index)
+                            .await
+                        else {
+                            // The delayed watermark is not available, e.g. due to overflow.
+                            // No rows can be released this time.
+                            continue;
+                        };
+                        watermark
+                    } else {
+                        watermark
+                    };
+
                     let mut chunk_builder =
                         StreamChunkBuilder::new(this.chunk_size, this.info.schema.data_types());
 
@@ -180,16 +199,18 @@ mod tests {
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::StreamChunk;
     use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema, TableId};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_common::util::sort_util::OrderType;
     use risingwave_storage::memory::MemoryStateStore;
 
     use super::*;
+    use crate::executor::test_utils::expr::build_from_pretty;
     use crate::executor::test_utils::{MessageSender, MockSource, StreamExecutorTestExt};
     use crate::executor::{ActorContext, BoxedMessageStream, Executor};
 
     async fn create_executor<S: StateStore>(
         sort_column_index: usize,
+        lateness_expr: Option<NonStrictExpression>,
         store: S,
     ) -> (MessageSender, BoxedMessageStream) {
         let input_schema = Schema::new(vec![
@@ -225,6 +246,7 @@ mod tests {
             buffer_table,
             chunk_size: 1024,
             sort_column_index,
+            lateness_expr,
         });
         (tx, sort_executor.boxed().execute())
     }
@@ -234,7 +256,7 @@ mod tests {
         let sort_column_index = 1;
 
         let store = MemoryStateStore::new();
-        let (mut tx, mut sort_executor) = create_executor(sort_column_index, store).await;
+        let (mut tx, mut sort_executor) = create_executor(sort_column_index, None, store).await;
 
         // Init barrier
         tx.push_barrier(1, false);
@@ -314,12 +336,73 @@ mod tests {
         sort_executor.expect_watermark().await;
     }
 
+    #[tokio::test]
+    async fn test_sort_executor_with_lateness() {
+        let sort_column_index = 1;
+
+        let store = MemoryStateStore::new();
+        let lateness_expr = build_from_pretty("(subtract:int8 $1:int8 2:int8)");
+        let (mut tx, mut sort_executor) =
+            create_executor(sort_column_index, Some(lateness_expr), store).await;
+
+        // Init barrier
+        tx.push_barrier(1, false);
+
+        // Consume the barrier
+        sort_executor.expect_barrier().await;
+
+        // Push data chunk
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I
+            + 1 1
+            + 2 2
+            + 3 6
+            + 4 7",
+        ));
+
+        // Push watermark1 on sorted column
+        tx.push_int64_watermark(sort_column_index, 5_i64);
+
+        // Consume the data chunk, buffered rows are released based on the delayed watermark.
+        let chunk = sort_executor.expect_chunk().await;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I
+                + 1 1
+                + 2 2"
+            )
+        );
+
+        // Consume the watermark, which is also delayed by the lateness.
+        let watermark = sort_executor.expect_watermark().await;
+        assert_eq!(watermark.val, ScalarImpl::Int64(3));
+
+        // Push watermark2 on sorted column
+        tx.push_int64_watermark(sort_column_index, 9_i64);
+
+        // Consume the data chunk
+        let chunk = sort_executor.expect_chunk().await;
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I
+                + 3 6"
+            )
+        );
+
+        // Consume the watermark
+        let watermark = sort_executor.expect_watermark().await;
+        assert_eq!(watermark.val, ScalarImpl::Int64(7));
+    }
+
     #[tokio::test]
     async fn test_sort_executor_fail_over() {
         let sort_column_index = 1;
 
         let store = MemoryStateStore::new();
-        let (mut tx, mut sort_executor) = create_executor(sort_column_index, store.clone()).await;
+        let (mut tx, mut sort_executor) =
+            create_executor(sort_column_index, None, store.clone()).await;
 
         // Init barrier
         tx.push_barrier(1, false);
@@ -351,7 +434,7 @@ mod tests {
 
         // Mock fail over
         let (mut recovered_tx, mut recovered_sort_executor) =
-            create_executor(sort_column_index, store).await;
+            create_executor(sort_column_index, None, store).await;
 
         // Push barrier
         recovered_tx.push_barrier(3, false);
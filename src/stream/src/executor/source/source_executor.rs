@@ -771,6 +771,7 @@ mod tests {
                 ],
             },
             pause: false,
+            paused_sinks: Default::default(),
         });
         barrier_tx.send(init_barrier).unwrap();
 
@@ -863,6 +864,7 @@ mod tests {
                 ],
             },
             pause: false,
+            paused_sinks: Default::default(),
         });
         barrier_tx.send(init_barrier).unwrap();
 
@@ -0,0 +1,286 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::array::Op;
+use risingwave_common::catalog::Schema;
+use risingwave_common::row::RowExt;
+use risingwave_storage::StateStore;
+
+use crate::common::table::state_table::StateTable;
+use crate::common::StreamChunkBuilder;
+use crate::executor::error::StreamExecutorError;
+use crate::executor::{
+    expect_first_barrier, ActorContextRef, BoxedExecutor, BoxedMessageStream, Executor, Message,
+    PkIndices, PkIndicesRef,
+};
+
+/// [`UpsertNormalizeExecutor`] turns the upsert stream of a source into a proper changelog.
+/// It keeps the latest row of each key in a state table: an upsert overwriting an existing
+/// key is expanded into an update retracting the old row, and a tombstone is expanded into
+/// a deletion of the stored row. Tombstones for absent keys are dropped, so duplicate or
+/// out-of-order deletions of the same key are tolerated.
+///
+/// The input must be distributed by the key columns, so that all messages of a key are
+/// processed by the same partition.
+pub struct UpsertNormalizeExecutor<S: StateStore> {
+    input: Option<BoxedExecutor>,
+    state_table: StateTable<S>,
+
+    /// Indices of the key columns, i.e. the primary key of the upsert source.
+    key_indices: Vec<usize>,
+
+    chunk_size: usize,
+
+    pk_indices: PkIndices,
+    identity: String,
+    schema: Schema,
+    ctx: ActorContextRef,
+}
+
+impl<S: StateStore> UpsertNormalizeExecutor<S> {
+    pub fn new(
+        input: BoxedExecutor,
+        state_table: StateTable<S>,
+        key_indices: Vec<usize>,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        ctx: ActorContextRef,
+        chunk_size: usize,
+    ) -> Self {
+        let schema = input.schema().clone();
+        Self {
+            input: Some(input),
+            state_table,
+            key_indices,
+            chunk_size,
+            pk_indices,
+            identity: format!("UpsertNormalizeExecutor {:X}", executor_id),
+            schema,
+            ctx,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn executor_inner(mut self) {
+        let mut input = self.input.take().unwrap().execute();
+
+        // Consume the first barrier message and initialize state table.
+        let barrier = expect_first_barrier(&mut input).await?;
+        self.state_table.init_epoch(barrier.epoch);
+
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
+
+        let mut builder = StreamChunkBuilder::new(self.chunk_size, self.schema.data_types());
+        let mut commit_data = false;
+
+        #[for_await]
+        for msg in input {
+            match msg? {
+                Message::Chunk(chunk) => {
+                    for (op, row) in chunk.rows() {
+                        let key = row.project(&self.key_indices);
+                        // The state table buffers writes of the current epoch in memory, so
+                        // this also observes the rows written earlier in this chunk.
+                        let old_row = self.state_table.get_row(key).await?;
+
+                        match op {
+                            Op::Insert | Op::UpdateInsert => {
+                                // An upsert: overwrite the stored row, retracting it if any.
+                                match old_row {
+                                    Some(old_row) => {
+                                        self.state_table.update(&old_row, row);
+                                        let none = builder.append_row(Op::UpdateDelete, old_row);
+                                        debug_assert!(none.is_none());
+                                        if let Some(chunk) =
+                                            builder.append_row(Op::UpdateInsert, row)
+                                        {
+                                            yield Message::Chunk(chunk);
+                                        }
+                                    }
+                                    None => {
+                                        self.state_table.insert(row);
+                                        if let Some(chunk) = builder.append_row(Op::Insert, row) {
+                                            yield Message::Chunk(chunk);
+                                        }
+                                    }
+                                }
+                            }
+                            Op::Delete | Op::UpdateDelete => {
+                                // A tombstone: delete the stored row. Note that the input row
+                                // carries only the key, so the stored row must be emitted.
+                                match old_row {
+                                    Some(old_row) => {
+                                        self.state_table.delete(&old_row);
+                                        if let Some(chunk) =
+                                            builder.append_row(Op::Delete, old_row)
+                                        {
+                                            yield Message::Chunk(chunk);
+                                        }
+                                    }
+                                    // The key has already been deleted or was never present.
+                                    None => continue,
+                                }
+                            }
+                        }
+                        commit_data = true;
+                    }
+
+                    if let Some(chunk) = builder.take() {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+
+                Message::Barrier(barrier) => {
+                    if commit_data {
+                        // Only commit when we have new data in this epoch.
+                        self.state_table.commit(barrier.epoch).await?;
+                        commit_data = false;
+                    } else {
+                        self.state_table.commit_no_data_expected(barrier.epoch);
+                    }
+
+                    if let Some(vnode_bitmap) = barrier.as_update_vnode_bitmap(self.ctx.id) {
+                        let _ = self.state_table.update_vnode_bitmap(vnode_bitmap);
+                    }
+
+                    yield Message::Barrier(barrier);
+                }
+
+                Message::Watermark(watermark) => {
+                    yield Message::Watermark(watermark);
+                }
+            }
+        }
+    }
+}
+
+impl<S: StateStore> Executor for UpsertNormalizeExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.executor_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef<'_> {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::StreamChunk;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema, TableId};
+    use risingwave_common::test_prelude::StreamChunkTestExt;
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::common::table::state_table::StateTable;
+    use crate::executor::test_utils::MockSource;
+    use crate::executor::ActorContext;
+
+    #[tokio::test]
+    async fn test_upsert_normalize_executor() {
+        let table_id = TableId::new(1);
+        let column_descs = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+        ];
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
+        let key_indices = vec![0];
+        let order_types = vec![OrderType::ascending()];
+
+        let state_store = MemoryStateStore::new();
+        let state_table = StateTable::new_without_distribution(
+            state_store,
+            table_id,
+            column_descs,
+            order_types,
+            key_indices.clone(),
+        )
+        .await;
+
+        let (mut tx, input) = MockSource::channel(schema, key_indices.clone());
+        let mut normalize_executor = Box::new(UpsertNormalizeExecutor::new(
+            Box::new(input),
+            state_table,
+            key_indices.clone(),
+            key_indices,
+            1,
+            ActorContext::create(123),
+            1024,
+        ))
+        .execute();
+
+        tx.push_barrier(1, false);
+        normalize_executor.next().await.unwrap().unwrap();
+
+        // Upserts of new keys pass through, an overwrite becomes an update, and a
+        // tombstone for an absent key is dropped.
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            + 1 1
+            + 2 2
+            + 1 7
+            - 3 .",
+        );
+        tx.push_chunk(chunk);
+        let msg = normalize_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I
+                +  1 1
+                +  2 2
+                U- 1 1
+                U+ 1 7",
+            )
+        );
+
+        tx.push_barrier(2, false);
+        normalize_executor.next().await.unwrap().unwrap();
+
+        // A tombstone deletes the stored row of the key, even though the input row
+        // carries only the key. A repeated tombstone is dropped.
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            - 2 .
+            - 2 .
+            + 3 9",
+        );
+        tx.push_chunk(chunk);
+        let msg = normalize_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                - 2 2
+                + 3 9",
+            )
+        );
+    }
+}
@@ -231,6 +231,7 @@ mod tests {
             added_actors: maplit::hashset! {actor_id},
             splits: Default::default(),
             pause: false,
+            paused_sinks: Default::default(),
         });
         tx.send(first_message).unwrap();
 
@@ -92,6 +92,9 @@ impl ExecutorBuilder for BatchQueryExecutorBuilder {
             } else {
                 None
             },
+            bloom_false_positive: None,
+            compression_algorithm: None,
+            compression_level: None,
         };
         let value_indices = table_desc
             .get_value_indices()
@@ -14,6 +14,7 @@
 use std::sync::Arc;
 
 use risingwave_common::bail;
+use risingwave_expr::expr::build_non_strict_from_prost;
 use risingwave_pb::expr::expr_node::Type::{
     GreaterThan, GreaterThanOrEqual, LessThan, LessThanOrEqual,
 };
@@ -55,6 +56,14 @@ impl ExecutorBuilder for DynamicFilterExecutorBuilder {
             );
         }
 
+        let residual_condition = match &node.residual_condition {
+            Some(cond) => Some(build_non_strict_from_prost(
+                cond,
+                params.eval_error_report.clone(),
+            )?),
+            None => None,
+        };
+
         let state_table_r =
             StateTable::from_table_catalog(node.get_right_table()?, store.clone(), None).await;
 
@@ -75,6 +84,7 @@ impl ExecutorBuilder for DynamicFilterExecutorBuilder {
                 params.pk_indices,
                 params.executor_id,
                 comparator,
+                residual_condition,
                 state_table_l,
                 state_table_r,
                 params.executor_stats,
@@ -92,6 +102,7 @@ impl ExecutorBuilder for DynamicFilterExecutorBuilder {
                 params.pk_indices,
                 params.executor_id,
                 comparator,
+                residual_condition,
                 state_table_l,
                 state_table_r,
                 params.executor_stats,
@@ -46,6 +46,7 @@ mod stream_scan;
 mod temporal_join;
 mod top_n;
 mod union;
+mod upsert_normalize;
 mod values;
 mod watermark_filter;
 
@@ -86,6 +87,7 @@ use self::stream_scan::*;
 use self::temporal_join::*;
 use self::top_n::*;
 use self::union::*;
+use self::upsert_normalize::*;
 use self::watermark_filter::WatermarkFilterBuilder;
 use crate::error::StreamResult;
 use crate::executor::{BoxedExecutor, Executor, ExecutorInfo};
@@ -166,5 +168,6 @@ pub async fn create_executor(
         NodeBody::EowcOverWindow => EowcOverWindowExecutorBuilder,
         NodeBody::OverWindow => OverWindowExecutorBuilder,
         NodeBody::StreamFsFetch => FsFetchExecutorBuilder,
+        NodeBody::UpsertNormalize => UpsertNormalizeExecutorBuilder,
     }
 }
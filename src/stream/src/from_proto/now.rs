@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_pb::stream_plan::NowNode;
+use anyhow::Context;
+use risingwave_common::types::{DataType, Datum};
+use risingwave_common::util::value_encoding::DatumFromProtoExt;
+use risingwave_pb::stream_plan::now_node::PbMode as PbNowMode;
+use risingwave_pb::stream_plan::{NowNode, PbNowModeGenerateSeries};
 use risingwave_storage::StateStore;
 use tokio::sync::mpsc::unbounded_channel;
 
 use super::ExecutorBuilder;
 use crate::common::table::state_table::StateTable;
 use crate::error::StreamResult;
-use crate::executor::{BoxedExecutor, NowExecutor};
+use crate::executor::{BoxedExecutor, NowExecutor, NowMode};
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
 
 pub struct NowExecutorBuilder;
@@ -39,12 +43,42 @@ impl ExecutorBuilder for NowExecutorBuilder {
             .lock_barrier_manager()
             .register_sender(params.actor_context.id, sender);
 
+        let mode = match &node.mode {
+            // `None` is possible for plans generated before the `mode` field was introduced.
+            None | Some(PbNowMode::UpdateCurrent(_)) => NowMode::UpdateCurrent,
+            Some(PbNowMode::GenerateSeries(PbNowModeGenerateSeries {
+                start_timestamp,
+                interval,
+            })) => {
+                let start_timestamp = Datum::from_protobuf(
+                    start_timestamp
+                        .as_ref()
+                        .context("`start_timestamp` not set")?,
+                    &DataType::Timestamptz,
+                )
+                .context("failed to decode `start_timestamp`")?
+                .context("`start_timestamp` must not be null")?;
+                let interval = Datum::from_protobuf(
+                    interval.as_ref().context("`interval` not set")?,
+                    &DataType::Interval,
+                )
+                .context("failed to decode `interval`")?
+                .context("`interval` must not be null")?;
+                NowMode::GenerateSeries {
+                    start_timestamp: start_timestamp.into_timestamptz(),
+                    interval: interval.into_interval(),
+                }
+            }
+        };
+
         let state_table =
             StateTable::from_table_catalog(node.get_state_table()?, store, None).await;
 
         Ok(Box::new(NowExecutor::new(
             barrier_receiver,
             params.executor_id,
+            mode,
+            params.env.config().developer.chunk_size,
             state_table,
         )))
     }
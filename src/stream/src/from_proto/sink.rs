@@ -19,7 +19,8 @@ use risingwave_common::catalog::ColumnCatalog;
 use risingwave_connector::match_sink_name_str;
 use risingwave_connector::sink::catalog::{SinkFormatDesc, SinkType};
 use risingwave_connector::sink::{
-    SinkError, SinkParam, SinkWriterParam, CONNECTOR_TYPE_KEY, SINK_TYPE_OPTION,
+    SinkError, SinkErrorPolicy, SinkParam, SinkThrottle, SinkWriterParam, CONNECTOR_TYPE_KEY,
+    SINK_TYPE_OPTION,
 };
 use risingwave_pb::stream_plan::{SinkLogStoreType, SinkNode};
 use risingwave_storage::dispatch_state_store;
@@ -118,6 +119,8 @@ impl ExecutorBuilder for SinkExecutorBuilder {
             vnode_bitmap: params.vnode_bitmap.clone(),
             meta_client: params.env.meta_client(),
             sink_metrics,
+            error_policy: SinkErrorPolicy::from_properties(&sink_param.properties)?,
+            throttle: SinkThrottle::from_properties(&sink_param.properties)?,
         };
 
         match node.log_store_type() {
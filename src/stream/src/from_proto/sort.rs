@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use risingwave_expr::expr::build_non_strict_from_prost;
 use risingwave_pb::stream_plan::SortNode;
 
 use super::*;
@@ -35,6 +36,11 @@ impl ExecutorBuilder for SortExecutorBuilder {
         let vnodes = Arc::new(params.vnode_bitmap.expect("vnodes not set for sort"));
         let state_table =
             StateTable::from_table_catalog(node.get_state_table()?, store, Some(vnodes)).await;
+        let lateness_expr = node
+            .lateness_expr
+            .as_ref()
+            .map(|e| build_non_strict_from_prost(e, params.eval_error_report.clone()))
+            .transpose()?;
         Ok(Box::new(SortExecutor::new(SortExecutorArgs {
             input,
             actor_ctx: params.actor_context,
@@ -43,6 +49,7 @@ impl ExecutorBuilder for SortExecutorBuilder {
             buffer_table: state_table,
             chunk_size: params.env.config().developer.chunk_size,
             sort_column_index: node.sort_column_index as _,
+            lateness_expr,
         })))
     }
 }
@@ -112,6 +112,6 @@ impl ExecutorBuilder for FsFetchExecutorBuilder {
         .boxed();
 
         let rate_limit = source.get_rate_limit().cloned().ok();
-        Ok(FlowControlExecutor::new(executor, rate_limit).boxed())
+        Ok(FlowControlExecutor::new(executor, rate_limit, None).boxed())
     }
 }
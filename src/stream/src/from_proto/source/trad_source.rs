@@ -176,7 +176,8 @@ impl ExecutorBuilder for SourceExecutorBuilder {
                             .collect_vec();
 
                         let table_reader = table_type
-                            .create_table_reader(source.properties.clone(), table_schema.clone())?;
+                            .create_table_reader(source.properties.clone(), table_schema.clone())
+                            .await?;
                         let external_table = ExternalStorageTable::new(
                             TableId::new(source.source_id),
                             upstream_table_name,
@@ -212,7 +213,7 @@ impl ExecutorBuilder for SourceExecutorBuilder {
                 }
             };
             let rate_limit = source.get_rate_limit().cloned().ok();
-            Ok(FlowControlExecutor::new(executor, rate_limit).boxed())
+            Ok(FlowControlExecutor::new(executor, rate_limit, None).boxed())
         } else {
             // If there is no external stream source, then no data should be persisted. We pass a
             // `PanicStateStore` type here for indication.
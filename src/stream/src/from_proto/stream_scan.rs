@@ -29,6 +29,7 @@ use crate::common::table::state_table::StateTable;
 use crate::executor::external::ExternalStorageTable;
 use crate::executor::{
     BackfillExecutor, CdcBackfillExecutor, ChainExecutor, FlowControlExecutor,
+    MaintenanceWindowThrottle,
     RearrangedChainExecutor, SourceStateTableHandler,
 };
 
@@ -76,8 +77,9 @@ impl ExecutorBuilder for StreamScanExecutorBuilder {
                     .map(|(k, v)| (k.clone(), v.clone()))
                     .collect();
                 let table_type = CdcTableType::from_properties(&properties);
-                let table_reader =
-                    table_type.create_table_reader(properties.clone(), table_schema.clone())?;
+                let table_reader = table_type
+                    .create_table_reader(properties.clone(), table_schema.clone())
+                    .await?;
 
                 let table_pk_order_types = table_desc
                     .pk
@@ -172,6 +174,9 @@ impl ExecutorBuilder for StreamScanExecutorBuilder {
                     } else {
                         None
                     },
+                    bloom_false_positive: None,
+                    compression_algorithm: None,
+                    compression_level: None,
                 };
                 let value_indices = table_desc
                     .get_value_indices()
@@ -215,6 +220,14 @@ impl ExecutorBuilder for StreamScanExecutorBuilder {
             StreamScanType::Unspecified => unreachable!(),
         };
         let rate_limit = node.get_rate_limit().cloned().ok();
-        Ok(FlowControlExecutor::new(executor, rate_limit).boxed())
+        let developer = &params.env.config().developer;
+        let throttle = developer
+            .backfill_throttled_rate_limit
+            .map(|rate_limit| MaintenanceWindowThrottle {
+                rate_limit,
+                window_start_hour: developer.backfill_maintenance_window_start_hour,
+                window_end_hour: developer.backfill_maintenance_window_end_hour,
+            });
+        Ok(FlowControlExecutor::new(executor, rate_limit, throttle).boxed())
     }
 }
@@ -84,6 +84,9 @@ impl ExecutorBuilder for TemporalJoinExecutorBuilder {
                 } else {
                     None
                 },
+                bloom_false_positive: None,
+                compression_algorithm: None,
+                compression_level: None,
             };
 
             let value_indices = table_desc
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 use anyhow::anyhow;
 use prometheus::HistogramTimer;
@@ -237,6 +238,18 @@ impl LocalBarrierManager {
         }
     }
 
+    /// Return the actors yet to collect the oldest in-flight barrier if no barrier progress has
+    /// been made for `timeout`, along with the `prev_epoch` of that barrier. Used by the
+    /// liveness probe to detect stuck actors.
+    pub fn stuck_actors(&self, timeout: Duration) -> Option<(u64, Vec<ActorId>)> {
+        match &self.state {
+            #[cfg(test)]
+            BarrierState::Local => None,
+
+            BarrierState::Managed(managed_state) => managed_state.stuck_actors(timeout),
+        }
+    }
+
     /// When a actor exit unexpectedly, it should report this event using this function, so meta
     /// will notice actor's exit while collecting.
     pub fn notify_failure(&mut self, actor_id: ActorId, err: StreamError) {
@@ -14,6 +14,7 @@
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter::once;
+use std::time::{Duration, Instant};
 
 use anyhow::anyhow;
 use risingwave_common::bail;
@@ -69,6 +70,10 @@ pub(super) struct ManagedBarrierState {
     /// Record all unexpected exited actors.
     failure_actors: HashMap<ActorId, StreamError>,
 
+    /// The time when a barrier was last issued or collected, used by the liveness probe to
+    /// detect stuck actors.
+    last_progress_instant: Instant,
+
     state_store: StateStoreImpl,
 }
 
@@ -79,6 +84,7 @@ impl ManagedBarrierState {
             epoch_barrier_state_map: BTreeMap::default(),
             create_mview_progress: Default::default(),
             failure_actors: Default::default(),
+            last_progress_instant: Instant::now(),
             state_store,
         }
     }
@@ -191,6 +197,27 @@ impl ManagedBarrierState {
         self.failure_actors.insert(actor_id, err);
     }
 
+    /// Return the actors yet to collect the oldest in-flight barrier, if no barrier has been
+    /// issued or collected for `timeout`. Barriers that have already been notified of a failure
+    /// are skipped, so that a stuck barrier is reported only once.
+    pub(super) fn stuck_actors(&self, timeout: Duration) -> Option<(u64, Vec<ActorId>)> {
+        if self.last_progress_instant.elapsed() < timeout {
+            return None;
+        }
+        self.epoch_barrier_state_map
+            .iter()
+            .find_map(|(_, barrier_state)| match &barrier_state.inner {
+                ManagedBarrierStateInner::Issued {
+                    remaining_actors,
+                    collect_notifier: Some(_),
+                } if !remaining_actors.is_empty() => Some((
+                    barrier_state.prev_epoch,
+                    remaining_actors.iter().copied().collect(),
+                )),
+                _ => None,
+            })
+    }
+
     /// Collect a `barrier` from the actor with `actor_id`.
     pub(super) fn collect(&mut self, actor_id: ActorId, barrier: &Barrier) {
         tracing::debug!(
@@ -199,6 +226,7 @@ impl ManagedBarrierState {
             "collect_barrier",
         );
 
+        self.last_progress_instant = Instant::now();
         match self.epoch_barrier_state_map.get_mut(&barrier.epoch.curr) {
             Some(&mut BarrierState {
                 prev_epoch,
@@ -253,6 +281,7 @@ impl ManagedBarrierState {
         actor_ids_to_collect: impl IntoIterator<Item = ActorId>,
         collect_notifier: oneshot::Sender<StreamResult<CollectResult>>,
     ) -> StreamResult<()> {
+        self.last_progress_instant = Instant::now();
         let inner = match self.epoch_barrier_state_map.get_mut(&barrier.epoch.curr) {
             Some(&mut BarrierState {
                 inner:
@@ -318,6 +347,7 @@ impl ManagedBarrierState {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::time::Duration;
 
     use risingwave_storage::StateStoreImpl;
     use tokio::sync::oneshot;
@@ -422,6 +452,37 @@ mod tests {
         assert!(managed_barrier_state.epoch_barrier_state_map.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_managed_state_stuck_actors() {
+        let mut managed_barrier_state = ManagedBarrierState::new(StateStoreImpl::for_test());
+        let barrier = Barrier::new_test_barrier(2);
+        let (tx, mut rx) = oneshot::channel();
+
+        // No barrier in-flight, nothing can be stuck.
+        assert_eq!(managed_barrier_state.stuck_actors(Duration::ZERO), None);
+
+        managed_barrier_state
+            .transform_to_issued(&barrier, HashSet::from([1, 2]), tx)
+            .unwrap();
+        managed_barrier_state.collect(1, &barrier);
+
+        // The probe has not timed out yet.
+        assert_eq!(
+            managed_barrier_state.stuck_actors(Duration::from_secs(3600)),
+            None
+        );
+        // The remaining actor of the oldest in-flight barrier is considered stuck.
+        assert_eq!(
+            managed_barrier_state.stuck_actors(Duration::ZERO),
+            Some((1, vec![2]))
+        );
+
+        // Once the failure is notified, the barrier should not be reported again.
+        managed_barrier_state.notify_failure(2, anyhow::anyhow!("stuck").into());
+        assert!(rx.try_recv().unwrap().is_err());
+        assert_eq!(managed_barrier_state.stuck_actors(Duration::ZERO), None);
+    }
+
     #[tokio::test]
     async fn test_managed_state_issued_after_collect() {
         let mut managed_barrier_state = ManagedBarrierState::new(StateStoreImpl::for_test());
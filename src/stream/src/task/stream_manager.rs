@@ -225,6 +225,55 @@ impl LocalStreamManager {
         })
     }
 
+    /// Spawn a liveness probe that periodically checks whether barrier collection is making
+    /// progress. If the oldest in-flight barrier has not been collected or advanced for
+    /// `max_missed_intervals` consecutive probes, the actors yet to collect it are considered
+    /// stuck: their await-trees are dumped for diagnostics, and they are reported as failed so
+    /// that the barrier collection fails and the meta service can decide the recovery.
+    pub fn spawn_actor_liveness_probe(
+        self: Arc<Self>,
+        probe_interval: Duration,
+        max_missed_intervals: u32,
+    ) -> JoinHandle<!> {
+        let timeout = probe_interval * max_missed_intervals.max(1);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(probe_interval).await;
+                let stuck = self.context.lock_barrier_manager().stuck_actors(timeout);
+                let Some((prev_epoch, stuck_actors)) = stuck else {
+                    continue;
+                };
+
+                // Dump the await-trees of the stuck actors before failing them, since the
+                // recovery will abort the actors and drop their traces.
+                let traces = self.get_actor_traces().await;
+                for actor_id in &stuck_actors {
+                    match traces.get(actor_id) {
+                        Some(trace) => tracing::error!(
+                            actor_id,
+                            prev_epoch,
+                            "actor is stuck, async stack trace:\n{}",
+                            trace
+                        ),
+                        None => tracing::error!(actor_id, prev_epoch, "actor is stuck"),
+                    }
+                }
+
+                let mut barrier_manager = self.context.lock_barrier_manager();
+                for actor_id in stuck_actors {
+                    barrier_manager.notify_failure(
+                        actor_id,
+                        anyhow!(
+                            "actor {actor_id} makes no barrier progress for {:?} and is considered stuck",
+                            timeout
+                        )
+                        .into(),
+                    );
+                }
+            }
+        })
+    }
+
     /// Get await-tree contexts for all actors.
     pub async fn get_actor_traces(&self) -> HashMap<ActorId, await_tree::TreeContext> {
         let core = self.core.lock().await;
@@ -86,6 +86,72 @@ async fn create_executor<S: StateStore>(
     (tx, executor.boxed().execute())
 }
 
+/// Like [`create_executor`], but with an `int8` partition key so that watermark messages, which
+/// only support `int8` in the test harness, can be sent on it.
+async fn create_executor_int64_partition<S: StateStore>(
+    calls: Vec<WindowFuncCall>,
+    store: S,
+) -> (MessageSender, BoxedMessageStream) {
+    let input_schema = Schema::new(vec![
+        Field::unnamed(DataType::Int64), // order key
+        Field::unnamed(DataType::Int64), // partition key
+        Field::unnamed(DataType::Int64), // pk
+        Field::unnamed(DataType::Int32), // x
+    ]);
+    let input_pk_indices = vec![2];
+    let partition_key_indices = vec![1];
+    let order_key_indices = vec![0];
+    let order_key_order_types = vec![OrderType::ascending()];
+
+    let mut table_columns = vec![
+        ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64), // order key
+        ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64), // partition key
+        ColumnDesc::unnamed(ColumnId::new(2), DataType::Int64), // pk
+        ColumnDesc::unnamed(ColumnId::new(3), DataType::Int32), // x
+    ];
+    for call in &calls {
+        table_columns.push(ColumnDesc::unnamed(
+            ColumnId::new(table_columns.len() as i32),
+            call.return_type.clone(),
+        ));
+    }
+    let table_pk_indices = vec![1, 0, 2];
+    let table_order_types = vec![
+        OrderType::ascending(),
+        OrderType::ascending(),
+        OrderType::ascending(),
+    ];
+
+    let output_pk_indices = vec![2];
+
+    let state_table = StateTable::new_without_distribution(
+        store,
+        TableId::new(1),
+        table_columns,
+        table_order_types,
+        table_pk_indices,
+    )
+    .await;
+
+    let (tx, source) = MockSource::channel(input_schema, input_pk_indices.clone());
+    let executor = OverWindowExecutor::new(OverWindowExecutorArgs {
+        input: source.boxed(),
+        actor_ctx: ActorContext::create(123),
+        pk_indices: output_pk_indices,
+        executor_id: 1,
+        calls,
+        partition_key_indices,
+        order_key_indices,
+        order_key_order_types,
+        state_table,
+        watermark_epoch: Arc::new(AtomicU64::new(0)),
+        metrics: Arc::new(StreamingMetrics::unused()),
+        chunk_size: 1024,
+        cache_policy: OverWindowCachePolicy::Recent,
+    });
+    (tx, executor.boxed().execute())
+}
+
 fn snapshot_options() -> SnapshotOptions {
     SnapshotOptions::default().include_applied_result(true)
 }
@@ -372,6 +438,57 @@ async fn test_over_window_lag_lead_with_updates() {
     .await;
 }
 
+#[tokio::test]
+async fn test_over_window_watermark() {
+    let store = MemoryStateStore::new();
+    let calls = vec![
+        // lag(x, 1)
+        WindowFuncCall {
+            kind: WindowFuncKind::Aggregate(AggKind::FirstValue),
+            args: AggArgs::Unary(DataType::Int32, 3),
+            return_type: DataType::Int32,
+            frame: Frame::rows(FrameBound::Preceding(1), FrameBound::Preceding(1)),
+        },
+    ];
+
+    // Watermarks on the partition key column are propagated and used for state cleaning, while
+    // watermarks on other columns (including the order key) are simply ignored for now.
+    check_with_script(
+        || create_executor_int64_partition(calls.clone(), store.clone()),
+        r###"
+        - !barrier 1
+        - !watermark
+            col_idx: 0
+            val: 100
+        - !watermark
+            col_idx: 1
+            val: 100
+        - !barrier 2
+        "###,
+        expect![[r#"
+            - input: !barrier 1
+              output:
+              - !barrier 1
+            - input: !watermark
+                col_idx: 0
+                val: '100'
+              output: []
+            - input: !watermark
+                col_idx: 1
+                val: '100'
+              output:
+              - !watermark
+                col_idx: 1
+                val: '100'
+            - input: !barrier 2
+              output:
+              - !barrier 2
+        "#]],
+        snapshot_options(),
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_over_window_sum() {
     let store = MemoryStateStore::new();
@@ -276,6 +276,7 @@ CREATE TABLE t3(v1 int, v2 bool, v3 smallint);
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -320,6 +321,7 @@ CREATE TABLE t3(v1 int, v2 bool, v3 smallint);
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -375,6 +377,7 @@ CREATE TABLE t3(v1 int, v2 bool, v3 smallint);
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -506,6 +509,7 @@ CREATE TABLE t4(v1 int PRIMARY KEY, v2 smallint PRIMARY KEY, v3 bool PRIMARY KEY
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -557,6 +561,7 @@ CREATE TABLE t4(v1 int PRIMARY KEY, v2 smallint PRIMARY KEY, v3 bool PRIMARY KEY
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -615,6 +620,7 @@ CREATE TABLE t4(v1 int PRIMARY KEY, v2 smallint PRIMARY KEY, v3 bool PRIMARY KEY
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -691,6 +697,7 @@ CREATE TABLE t4(v1 int PRIMARY KEY, v2 smallint PRIMARY KEY, v3 bool PRIMARY KEY
                             source_schema: None,
                             source_watermarks: [],
                             append_only: false,
+                            on_conflict: None,
                             query: None,
                             cdc_table_info: None,
                         },
@@ -135,6 +135,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             alias: alias.clone(),
             query,
             from,
+            materialized: None,
         };
 
         let with_tables = vec![Table::new(alias.name.real_value(), query_schema)];
@@ -184,6 +185,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let (select_list, schema) = self.gen_select_list(num_select_items);
         let select = Select {
             distinct: Distinct::All,
+            hints: vec![],
             projection: select_list,
             from,
             lateral_views: vec![],
@@ -16,9 +16,154 @@ use std::io::Error as IoError;
 
 use thiserror::Error;
 
+use crate::error_or_notice::SqlState;
 use crate::pg_server::BoxedError;
 pub type PsqlResult<T> = std::result::Result<T, PsqlError>;
 
+/// A wrapper that attaches a SQLSTATE error code to an error. It is recognized when encoding
+/// `ErrorResponse`, so that drivers can branch on the error class instead of matching on the
+/// message.
+#[derive(Debug)]
+pub struct WithSqlState {
+    state: SqlState,
+    inner: BoxedError,
+}
+
+impl WithSqlState {
+    pub fn new(state: SqlState, inner: BoxedError) -> Self {
+        Self { state, inner }
+    }
+
+    pub fn state(&self) -> &SqlState {
+        &self.state
+    }
+}
+
+impl std::fmt::Display for WithSqlState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl std::error::Error for WithSqlState {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.inner.source()
+    }
+}
+
+/// Additional structured fields of an error, rendered as separate fields of the pgwire
+/// `ErrorResponse` so that clients can display them the way they do for PostgreSQL.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorFields {
+    /// Secondary message carrying more detail about the problem.
+    pub detail: Option<String>,
+    /// Suggestion what to do about the problem.
+    pub hint: Option<String>,
+    /// Error cursor position: 1-based index into the original statement text, counted in
+    /// characters rather than bytes.
+    pub position: Option<u64>,
+}
+
+/// A wrapper that attaches [`ErrorFields`] to an error. It is recognized when encoding
+/// `ErrorResponse`, like [`WithSqlState`].
+#[derive(Debug)]
+pub struct WithErrorFields {
+    fields: ErrorFields,
+    inner: BoxedError,
+}
+
+impl WithErrorFields {
+    pub fn new(fields: ErrorFields, inner: BoxedError) -> Self {
+        Self { fields, inner }
+    }
+
+    pub fn fields(&self) -> &ErrorFields {
+        &self.fields
+    }
+}
+
+impl std::fmt::Display for WithErrorFields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl std::error::Error for WithErrorFields {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.inner.source()
+    }
+}
+
+/// Steps to the next error in the chain, descending into the wrapper errors that hide their
+/// direct inner error from `source()` to avoid duplicated messages in the error report.
+fn next_in_chain<'a>(
+    err: &'a (dyn std::error::Error + 'static),
+) -> Option<&'a (dyn std::error::Error + 'static)> {
+    if let Some(e) = err.downcast_ref::<WithSqlState>() {
+        let inner: &(dyn std::error::Error + 'static) = &*e.inner;
+        Some(inner)
+    } else if let Some(e) = err.downcast_ref::<WithErrorFields>() {
+        let inner: &(dyn std::error::Error + 'static) = &*e.inner;
+        Some(inner)
+    } else {
+        err.source()
+    }
+}
+
+/// Finds the SQLSTATE attached to an error or one of its sources, if any.
+pub fn sql_state_of(err: &(dyn std::error::Error + 'static)) -> Option<SqlState> {
+    let mut err = Some(err);
+    while let Some(e) = err {
+        if let Some(e) = e.downcast_ref::<WithSqlState>() {
+            return Some(e.state().clone());
+        }
+        err = next_in_chain(e);
+    }
+    None
+}
+
+/// Finds the [`ErrorFields`] attached to an error or one of its sources, if any.
+pub fn error_fields_of(err: &(dyn std::error::Error + 'static)) -> Option<ErrorFields> {
+    let mut err = Some(err);
+    while let Some(e) = err {
+        if let Some(e) = e.downcast_ref::<WithErrorFields>() {
+            return Some(e.fields().clone());
+        }
+        err = next_in_chain(e);
+    }
+    None
+}
+
+/// Wraps a parser error with the `syntax_error` SQLSTATE and the error cursor position pointing
+/// at the offending token, so that clients can display a caret under it.
+pub fn boxed_syntax_error(err: risingwave_sqlparser::parser::ParserError, sql: &str) -> BoxedError {
+    let fields = ErrorFields {
+        position: err
+            .location()
+            .and_then(|(line, column)| syntax_error_position(sql, line, column)),
+        ..Default::default()
+    };
+    Box::new(WithSqlState::new(
+        SqlState::SYNTAX_ERROR,
+        Box::new(WithErrorFields::new(fields, Box::new(err))),
+    ))
+}
+
+/// Converts a 1-based (line, column) location into the 1-based character offset into `sql`
+/// expected by the protocol.
+fn syntax_error_position(sql: &str, line: u64, column: u64) -> Option<u64> {
+    let mut offset: u64 = 0;
+    for (i, l) in sql.split('\n').enumerate() {
+        let chars = l.chars().count() as u64;
+        if i as u64 + 1 == line {
+            // The tokenizer may report a column just past the end of the line.
+            return (column <= chars + 1).then_some(offset + column);
+        }
+        offset += chars + 1; // `+ 1` for the line break
+    }
+    None
+}
+
 /// Error type used in pgwire crates.
 #[derive(Error, Debug)]
 pub enum PsqlError {
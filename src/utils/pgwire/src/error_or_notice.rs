@@ -17,22 +17,38 @@ pub struct ErrorOrNoticeMessage<'a> {
     pub severity: Severity,
     pub state: SqlState,
     pub message: &'a str,
+    /// Optional secondary message carrying more detail about the problem.
+    pub detail: Option<&'a str>,
+    /// Optional suggestion what to do about the problem.
+    pub hint: Option<&'a str>,
+    /// Optional error cursor position: 1-based character index into the original statement text.
+    pub position: Option<u64>,
 }
 
 impl<'a> ErrorOrNoticeMessage<'a> {
-    pub fn internal_error(message: &'a str) -> Self {
+    pub fn error(state: SqlState, message: &'a str) -> Self {
         Self {
             severity: Severity::Error,
-            state: SqlState::INTERNAL_ERROR,
+            state,
             message,
+            detail: None,
+            hint: None,
+            position: None,
         }
     }
 
+    pub fn internal_error(message: &'a str) -> Self {
+        Self::error(SqlState::INTERNAL_ERROR, message)
+    }
+
     pub fn notice(message: &'a str) -> Self {
         Self {
             severity: Severity::Notice,
             state: SqlState::SUCCESSFUL_COMPLETION,
             message,
+            detail: None,
+            hint: None,
+            position: None,
         }
     }
 }
@@ -74,6 +90,15 @@ impl Severity {
 pub enum Code {
     E00000,
     E01000,
+    E08P01,
+    E0A000,
+    E22000,
+    E22023,
+    E42000,
+    E42501,
+    E42601,
+    E42704,
+    E58030,
     EXX000,
 }
 
@@ -88,11 +113,57 @@ impl SqlState {
     pub const SUCCESSFUL_COMPLETION: SqlState = SqlState(Code::E00000);
     /// Class 01 — Warning
     pub const WARNING: SqlState = SqlState(Code::E01000);
+    /// Class 08 — Connection Exception
+    pub const PROTOCOL_VIOLATION: SqlState = SqlState(Code::E08P01);
+    /// Class 0A — Feature Not Supported
+    pub const FEATURE_NOT_SUPPORTED: SqlState = SqlState(Code::E0A000);
+    /// Class 22 — Data Exception
+    pub const DATA_EXCEPTION: SqlState = SqlState(Code::E22000);
+    /// Class 22 — Data Exception
+    pub const INVALID_PARAMETER_VALUE: SqlState = SqlState(Code::E22023);
+    /// Class 42 — Syntax Error or Access Rule Violation
+    pub const SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION: SqlState = SqlState(Code::E42000);
+    /// Class 42 — Syntax Error or Access Rule Violation
+    pub const INSUFFICIENT_PRIVILEGE: SqlState = SqlState(Code::E42501);
+    /// Class 42 — Syntax Error or Access Rule Violation
+    pub const SYNTAX_ERROR: SqlState = SqlState(Code::E42601);
+    /// Class 42 — Syntax Error or Access Rule Violation
+    pub const UNDEFINED_OBJECT: SqlState = SqlState(Code::E42704);
+    /// Class 58 — System Error
+    pub const IO_ERROR: SqlState = SqlState(Code::E58030);
+
+    /// Parses a 5-character SQLSTATE code, falling back to `INTERNAL_ERROR` for codes that are
+    /// not (yet) recognized.
+    pub fn from_code(code: &str) -> SqlState {
+        match code {
+            "00000" => Self::SUCCESSFUL_COMPLETION,
+            "01000" => Self::WARNING,
+            "08P01" => Self::PROTOCOL_VIOLATION,
+            "0A000" => Self::FEATURE_NOT_SUPPORTED,
+            "22000" => Self::DATA_EXCEPTION,
+            "22023" => Self::INVALID_PARAMETER_VALUE,
+            "42000" => Self::SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION,
+            "42501" => Self::INSUFFICIENT_PRIVILEGE,
+            "42601" => Self::SYNTAX_ERROR,
+            "42704" => Self::UNDEFINED_OBJECT,
+            "58030" => Self::IO_ERROR,
+            _ => Self::INTERNAL_ERROR,
+        }
+    }
 
     pub fn code(&self) -> &str {
         match &self.0 {
             Code::E00000 => "00000",
             Code::E01000 => "01000",
+            Code::E08P01 => "08P01",
+            Code::E0A000 => "0A000",
+            Code::E22000 => "22000",
+            Code::E22023 => "22023",
+            Code::E42000 => "42000",
+            Code::E42501 => "42501",
+            Code::E42601 => "42601",
+            Code::E42704 => "42704",
+            Code::E58030 => "58030",
             Code::EXX000 => "XX000",
         }
     }
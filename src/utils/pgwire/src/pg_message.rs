@@ -21,7 +21,7 @@ use byteorder::{BigEndian, ByteOrder};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use tokio::io::{AsyncRead, AsyncReadExt};
 
-use crate::error_or_notice::ErrorOrNoticeMessage;
+use crate::error_or_notice::{ErrorOrNoticeMessage, Severity, SqlState};
 use crate::pg_field_descriptor::PgFieldDescriptor;
 use crate::pg_response::StatementType;
 use crate::pg_server::BoxedError;
@@ -381,6 +381,7 @@ pub enum BeParameterStatusMessage<'a> {
     StandardConformingString(&'a str),
     ServerVersion(&'a str),
     ApplicationName(&'a str),
+    TimeZone(&'a str),
 }
 
 #[derive(Debug)]
@@ -447,6 +448,7 @@ impl<'a> BeMessage<'a> {
                     }
                     ServerVersion(val) => [b"server_version", val.as_bytes()],
                     ApplicationName(val) => [b"application_name", val.as_bytes()],
+                    TimeZone(val) => [b"TimeZone", val.as_bytes()],
                 };
 
                 // Parameter names and values are passed as null-terminated strings
@@ -629,14 +631,27 @@ impl<'a> BeMessage<'a> {
 
             BeMessage::ErrorResponse(error) => {
                 use thiserror_ext::AsReport;
-                // For all the errors set Severity to Error and error code to
-                // 'internal error'.
+                // Set Severity to Error, and use the SQLSTATE attached to the error if any,
+                // falling back to 'internal error'.
+                let state = crate::error::sql_state_of(error.as_ref())
+                    .unwrap_or(SqlState::INTERNAL_ERROR);
+                let fields = crate::error::error_fields_of(error.as_ref()).unwrap_or_default();
 
                 // 'E' signalizes ErrorResponse messages
                 buf.put_u8(b'E');
                 // Format the error as a pretty report.
                 let msg = error.to_report_string_pretty();
-                write_err_or_notice(buf, &ErrorOrNoticeMessage::internal_error(&msg))?;
+                write_err_or_notice(
+                    buf,
+                    &ErrorOrNoticeMessage {
+                        severity: Severity::Error,
+                        state,
+                        message: &msg,
+                        detail: fields.detail.as_deref(),
+                        hint: fields.hint.as_deref(),
+                        position: fields.position,
+                    },
+                )?;
             }
 
             BeMessage::BackendKeyData((process_id, secret_key)) => {
@@ -717,6 +732,19 @@ fn write_err_or_notice(buf: &mut BytesMut, msg: &ErrorOrNoticeMessage<'_>) -> Re
         buf.put_u8(b'M'); // the message
         write_cstr(buf, msg.message.as_bytes())?;
 
+        if let Some(detail) = msg.detail {
+            buf.put_u8(b'D'); // detail
+            write_cstr(buf, detail.as_bytes())?;
+        }
+        if let Some(hint) = msg.hint {
+            buf.put_u8(b'H'); // hint
+            write_cstr(buf, hint.as_bytes())?;
+        }
+        if let Some(position) = msg.position {
+            buf.put_u8(b'P'); // error cursor position
+            write_cstr(buf, position.to_string().as_bytes())?;
+        }
+
         buf.put_u8(0); // terminator
         Ok(())
     })
@@ -398,6 +398,7 @@ where
                 self.stream
                     .write_parameter_status_msg_no_flush(&ParameterStatus {
                         application_name: application_name.cloned(),
+                        ..Default::default()
                     })?;
                 self.ready_for_query()?;
             }
@@ -471,7 +472,7 @@ where
         // Parse sql.
         let stmts = Parser::parse_sql(&sql)
             .inspect_err(|e| tracing::error!("failed to parse sql:\n{}:\n{}", sql, e))
-            .map_err(|err| PsqlError::QueryError(err.into()))?;
+            .map_err(|err| PsqlError::QueryError(crate::error::boxed_syntax_error(err, &sql)))?;
         if stmts.is_empty() {
             self.stream.write_no_flush(&BeMessage::EmptyQueryResponse)?;
         }
@@ -514,6 +515,17 @@ where
         }
         let mut res = res.map_err(PsqlError::QueryError)?;
 
+        // `DISCARD ALL` resets the session, so the statements and portals prepared via the
+        // extended query protocol are dropped as well.
+        if matches!(stmt, Statement::Discard) {
+            self.result_cache.clear();
+            self.unnamed_prepare_statement.take();
+            self.prepare_statement_store.clear();
+            self.unnamed_portal.take();
+            self.portal_store.clear();
+            self.statement_portal_dependency.clear();
+        }
+
         for notice in res.notices() {
             self.stream
                 .write_no_flush(&BeMessage::NoticeResponse(notice))?;
@@ -525,6 +537,11 @@ where
                 BeParameterStatusMessage::ApplicationName(application_name),
             ))?;
         }
+        if let Some(ref timezone) = status.timezone {
+            self.stream.write_no_flush(&BeMessage::ParameterStatus(
+                BeParameterStatusMessage::TimeZone(timezone),
+            ))?;
+        }
 
         if res.is_query() {
             self.stream
@@ -606,7 +623,7 @@ where
         let stmt = {
             let stmts = Parser::parse_sql(sql)
                 .inspect_err(|e| tracing::error!("failed to parse sql:\n{}:\n{}", sql, e))
-                .map_err(|err| PsqlError::ParseError(err.into()))?;
+                .map_err(|err| PsqlError::ParseError(crate::error::boxed_syntax_error(err, sql)))?;
 
             if stmts.len() > 1 {
                 return Err(PsqlError::ParseError(
@@ -891,6 +908,7 @@ pub struct PgStream<S> {
 #[derive(Debug, Default, Clone)]
 pub struct ParameterStatus {
     pub application_name: Option<String>,
+    pub timezone: Option<String>,
 }
 
 impl<S> PgStream<S>
@@ -920,6 +938,11 @@ where
                 BeParameterStatusMessage::ApplicationName(application_name),
             ))?;
         }
+        if let Some(timezone) = &status.timezone {
+            self.write_no_flush(&BeMessage::ParameterStatus(
+                BeParameterStatusMessage::TimeZone(timezone),
+            ))?;
+        }
         Ok(())
     }
 
@@ -96,6 +96,7 @@ pub enum StatementType {
     SET_TRANSACTION,
     CANCEL_COMMAND,
     WAIT,
+    DISCARD,
 }
 
 impl std::fmt::Display for StatementType {
@@ -232,7 +233,7 @@ impl StatementType {
                     Ok(StatementType::UPDATE_RETURNING)
                 }
             }
-            Statement::Copy { .. } => Ok(StatementType::COPY),
+            Statement::Copy { .. } | Statement::CopyTo { .. } => Ok(StatementType::COPY),
             Statement::CreateTable { .. } => Ok(StatementType::CREATE_TABLE),
             Statement::CreateIndex { .. } => Ok(StatementType::CREATE_INDEX),
             Statement::CreateSchema { .. } => Ok(StatementType::CREATE_SCHEMA),
@@ -283,6 +284,7 @@ impl StatementType {
             Statement::Explain { .. } => Ok(StatementType::EXPLAIN),
             Statement::Flush => Ok(StatementType::FLUSH),
             Statement::Wait => Ok(StatementType::WAIT),
+            Statement::Discard => Ok(StatementType::DISCARD),
             _ => Err("unsupported statement type".to_string()),
         }
     }
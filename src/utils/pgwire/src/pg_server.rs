@@ -22,6 +22,7 @@ use bytes::Bytes;
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::Statement;
 use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::Semaphore;
 
 use crate::net::{AddressRef, Listener};
 use crate::pg_field_descriptor::PgFieldDescriptor;
@@ -155,25 +156,39 @@ impl UserAuthenticator {
 }
 
 /// Binds a Tcp or Unix listener at `addr`. Spawn a coroutine to serve every new connection.
+///
+/// If `max_connections` is specified, at most that many connections are served at the same
+/// time. Further connection attempts are queued until an existing connection is closed,
+/// instead of being rejected.
 pub async fn pg_serve(
     addr: &str,
     session_mgr: Arc<impl SessionManager>,
     tls_config: Option<TlsConfig>,
+    max_connections: Option<usize>,
 ) -> io::Result<()> {
     let listener = Listener::bind(addr).await?;
     tracing::info!(addr, "server started");
 
+    let concurrency = Arc::new(Semaphore::new(
+        max_connections.unwrap_or(Semaphore::MAX_PERMITS),
+    ));
+
     loop {
+        let permit = concurrency
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("semaphore should not be closed");
         let conn_ret = listener.accept().await;
         match conn_ret {
             Ok((stream, peer_addr)) => {
                 tracing::info!(%peer_addr, "accept connection");
-                tokio::spawn(handle_connection(
-                    stream,
-                    session_mgr.clone(),
-                    tls_config.clone(),
-                    Arc::new(peer_addr),
-                ));
+                let session_mgr = session_mgr.clone();
+                let tls_config = tls_config.clone();
+                tokio::spawn(async move {
+                    handle_connection(stream, session_mgr, tls_config, Arc::new(peer_addr)).await;
+                    drop(permit);
+                });
             }
 
             Err(e) => {
@@ -375,7 +390,7 @@ mod tests {
         let pg_config = pg_config.into();
 
         let session_mgr = Arc::new(MockSessionManager {});
-        tokio::spawn(async move { pg_serve(&bind_addr, session_mgr, None).await });
+        tokio::spawn(async move { pg_serve(&bind_addr, session_mgr, None, None).await });
         // wait for server to start
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 